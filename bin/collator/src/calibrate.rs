@@ -0,0 +1,200 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Calibration of declared weights against the measured execution time of existing blocks.
+
+use frame_support::{
+    dispatch::GetDispatchInfo, traits::GetCallMetadata,
+    weights::constants::WEIGHT_REF_TIME_PER_NANOS,
+};
+use parity_scale_codec::{Decode, Encode};
+use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
+use sc_client_api::BlockBackend;
+use serde::Serialize;
+use sp_api::{Core, ProvideRuntimeApi};
+use sp_block_builder::BlockBuilder;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, Extrinsic, ExtrinsicCall, Header as HeaderT};
+use std::{collections::BTreeMap, path::PathBuf, sync::Arc, time::Instant};
+
+/// Re-execute a sample of existing blocks, e.g. imported from mainnet into a dev database,
+/// and compare the measured execution time of extrinsics against their declared weight,
+/// per pallet.
+///
+/// Measured times include the overhead of calling into the runtime, so they are slightly
+/// overestimated, which is most visible for cheap extrinsics.
+#[derive(Debug, clap::Parser)]
+pub struct CalibrateWeightsCmd {
+    /// Number of the first block of the sample.
+    #[clap(long)]
+    pub from: u32,
+
+    /// Number of the last block of the sample, inclusive.
+    #[clap(long)]
+    pub to: u32,
+
+    /// Only execute every n-th block of the range.
+    #[clap(long, default_value = "1")]
+    pub step: u32,
+
+    /// Flag pallets whose measured time deviates from the declared weight by more than
+    /// this percentage.
+    #[clap(long, default_value = "20")]
+    pub drift_threshold: u32,
+
+    /// Also write the report to this file, as JSON.
+    #[clap(long)]
+    pub json: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub import_params: ImportParams,
+}
+
+/// Declared weight and measured time of the extrinsics of a pallet.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PalletReport {
+    /// Number of executed extrinsics.
+    pub extrinsics: u64,
+    /// Sum of the declared `ref_time` weights, in picoseconds.
+    pub declared_ref_time: u128,
+    /// Sum of the measured execution times, in picoseconds.
+    pub measured_ref_time: u128,
+}
+
+impl PalletReport {
+    /// Measured time relative to the declared weight, in percent.
+    pub fn measured_percent(&self) -> u128 {
+        self.measured_ref_time.saturating_mul(100) / self.declared_ref_time.max(1)
+    }
+}
+
+impl CalibrateWeightsCmd {
+    /// Run the calibration on the blocks of `client`.
+    ///
+    /// Extrinsics are decoded as `Xt` to find out their pallet and declared weight.
+    pub fn run<Block, Client, Xt>(&self, client: Arc<Client>) -> Result<()>
+    where
+        Block: BlockT,
+        Client: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockBackend<Block>,
+        Client::Api: Core<Block> + BlockBuilder<Block>,
+        Xt: Decode + ExtrinsicCall,
+        <Xt as Extrinsic>::Call: GetCallMetadata + GetDispatchInfo,
+    {
+        if self.step == 0 || self.from > self.to {
+            return Err("Invalid block range".into());
+        }
+
+        let mut reports = BTreeMap::<String, PalletReport>::new();
+        for number in (self.from..=self.to).step_by(self.step as usize) {
+            let hash = client
+                .hash(number.into())?
+                .ok_or_else(|| format!("Unknown block #{}", number))?;
+            let block = client
+                .block(hash)?
+                .ok_or_else(|| format!("Missing body of block #{}", number))?
+                .block;
+
+            let (mut header, extrinsics) = block.deconstruct();
+            // The seal is checked by the consensus, the runtime doesn't expect it.
+            header.digest_mut().pop();
+            let parent_hash = *header.parent_hash();
+
+            // Changes of the runtime calls are kept in the overlay of `api`,
+            // so every extrinsic is applied on top of the previous ones.
+            let api = client.runtime_api();
+            api.initialize_block(parent_hash, &header)
+                .map_err(|e| format!("Failed to initialize block #{}: {:?}", number, e))?;
+
+            for extrinsic in extrinsics {
+                let decoded = Xt::decode(&mut &extrinsic.encode()[..])
+                    .map_err(|e| format!("Failed to decode extrinsic: {:?}", e))?;
+                let pallet = decoded.call().get_call_metadata().pallet_name;
+                let declared_weight = decoded.call().get_dispatch_info().weight;
+
+                let start = Instant::now();
+                api.apply_extrinsic(parent_hash, extrinsic)
+                    .map_err(|e| format!("Failed to apply extrinsic of #{}: {:?}", number, e))?;
+                let elapsed = start.elapsed();
+
+                let report = reports.entry(pallet.into()).or_default();
+                report.extrinsics += 1;
+                report.declared_ref_time += u128::from(declared_weight.ref_time());
+                report.measured_ref_time +=
+                    elapsed.as_nanos() * u128::from(WEIGHT_REF_TIME_PER_NANOS);
+            }
+        }
+
+        println!(
+            "{:<24} {:>10} {:>16} {:>16} {:>10}",
+            "pallet", "extrinsics", "declared (ns)", "measured (ns)", "measured"
+        );
+        let mut drifting = 0;
+        for (pallet, report) in &reports {
+            let percent = report.measured_percent();
+            let drift = if percent > 100 + u128::from(self.drift_threshold) {
+                drifting += 1;
+                "  UNDERESTIMATED"
+            } else if percent + u128::from(self.drift_threshold) < 100 {
+                "  overestimated"
+            } else {
+                ""
+            };
+            println!(
+                "{:<24} {:>10} {:>16} {:>16} {:>9}%{}",
+                pallet,
+                report.extrinsics,
+                report.declared_ref_time / u128::from(WEIGHT_REF_TIME_PER_NANOS),
+                report.measured_ref_time / u128::from(WEIGHT_REF_TIME_PER_NANOS),
+                percent,
+                drift,
+            );
+        }
+
+        if let Some(path) = &self.json {
+            let json = serde_json::to_string_pretty(&reports)
+                .map_err(|e| format!("Failed to serialize the report: {:?}", e))?;
+            std::fs::write(path, json)?;
+        }
+
+        if drifting > 0 {
+            log::warn!(
+                "{} pallet(s) take more than {}% longer than their declared weight",
+                drifting,
+                self.drift_threshold
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl CliConfiguration for CalibrateWeightsCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn import_params(&self) -> Option<&ImportParams> {
+        Some(&self.import_params)
+    }
+}
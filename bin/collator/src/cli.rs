@@ -60,7 +60,7 @@ pub struct Cli {
 pub enum Subcommand {
     /// Key management cli utilities
     #[clap(subcommand)]
-    Key(sc_cli::KeySubcommand),
+    Key(crate::key::KeySubcommand),
 
     /// Verify a signature for a message, provided on STDIN, with a given (public or secret) key.
     Verify(sc_cli::VerifyCmd),
@@ -98,6 +98,15 @@ pub enum Subcommand {
     /// Export the genesis wasm of the parachain.
     ExportGenesisWasm(cumulus_client_cli::ExportGenesisWasmCommand),
 
+    /// Export the genesis head and wasm of several runtimes for a given parachain id.
+    ExportGenesis(crate::genesis::ExportGenesisCmd),
+
+    /// Compare declared weights with the measured execution time of existing blocks.
+    CalibrateWeights(crate::calibrate::CalibrateWeightsCmd),
+
+    /// Check the Frontier database against the chain, and optionally repair it.
+    CheckFrontierDb(crate::frontier_db::CheckFrontierDbCmd),
+
     /// The custom benchmark subcommmand benchmarking runtime pallets.
     #[cfg(feature = "runtime-benchmarks")]
     #[clap(name = "benchmark", about = "Benchmark runtime pallets.")]
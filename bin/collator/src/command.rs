@@ -474,6 +474,83 @@ pub fn run() -> Result<()> {
                 cmd.run(&*spec)
             })
         }
+        Some(Subcommand::ExportGenesis(cmd)) => cmd.run(),
+        Some(Subcommand::CalibrateWeights(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            let chain_spec = &runner.config().chain_spec;
+
+            if chain_spec.is_astar() {
+                runner.sync_run(|config| {
+                    let params = parachain::new_partial::<astar::RuntimeApi, astar::Executor, _>(
+                        &config,
+                        parachain::build_import_queue,
+                    )?;
+                    cmd.run::<Block, _, astar_runtime::UncheckedExtrinsic>(params.client)
+                })
+            } else if chain_spec.is_shiden() {
+                runner.sync_run(|config| {
+                    let params = parachain::new_partial::<shiden::RuntimeApi, shiden::Executor, _>(
+                        &config,
+                        parachain::build_import_queue,
+                    )?;
+                    cmd.run::<Block, _, shiden_runtime::UncheckedExtrinsic>(params.client)
+                })
+            } else if chain_spec.is_shibuya() {
+                runner.sync_run(|config| {
+                    let params =
+                        parachain::new_partial::<shibuya::RuntimeApi, shibuya::Executor, _>(
+                            &config,
+                            parachain::build_import_queue,
+                        )?;
+                    cmd.run::<Block, _, shibuya_runtime::UncheckedExtrinsic>(params.client)
+                })
+            } else {
+                runner.sync_run(|config| {
+                    let params = local::new_partial(&config)?;
+                    cmd.run::<Block, _, local_runtime::UncheckedExtrinsic>(params.client)
+                })
+            }
+        }
+        Some(Subcommand::CheckFrontierDb(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            let chain_spec = &runner.config().chain_spec;
+
+            if chain_spec.is_astar() {
+                runner.sync_run(|config| {
+                    let params = parachain::new_partial::<astar::RuntimeApi, astar::Executor, _>(
+                        &config,
+                        parachain::build_import_queue,
+                    )?;
+                    let (_, _, _, frontier_backend) = params.other;
+                    cmd.run(params.client, frontier_backend)
+                })
+            } else if chain_spec.is_shiden() {
+                runner.sync_run(|config| {
+                    let params = parachain::new_partial::<shiden::RuntimeApi, shiden::Executor, _>(
+                        &config,
+                        parachain::build_import_queue,
+                    )?;
+                    let (_, _, _, frontier_backend) = params.other;
+                    cmd.run(params.client, frontier_backend)
+                })
+            } else if chain_spec.is_shibuya() {
+                runner.sync_run(|config| {
+                    let params =
+                        parachain::new_partial::<shibuya::RuntimeApi, shibuya::Executor, _>(
+                            &config,
+                            parachain::build_import_queue,
+                        )?;
+                    let (_, _, _, frontier_backend) = params.other;
+                    cmd.run(params.client, frontier_backend)
+                })
+            } else {
+                runner.sync_run(|config| {
+                    let params = local::new_partial(&config)?;
+                    let (_, _, _, frontier_backend) = params.other;
+                    cmd.run(params.client, frontier_backend)
+                })
+            }
+        }
         Some(Subcommand::Key(cmd)) => cmd.run(&cli),
         Some(Subcommand::Sign(cmd)) => cmd.run(),
         Some(Subcommand::Verify(cmd)) => cmd.run(),
@@ -859,6 +936,13 @@ pub fn run() -> Result<()> {
             };
 
             runner.run_node_until_exit(|config| async move {
+                #[cfg(feature = "evm-tracing")]
+                let config = crate::evm_tracing_types::override_runtimes_with_tracing_runtimes(
+                    config,
+                    cli.eth_api_options.tracing_runtimes_dir,
+                    &evm_tracing_config.ethapi,
+                )?;
+
                 #[cfg(feature = "evm-tracing")]
                 if config.chain_spec.is_dev() {
                     return local::start_node(config, evm_tracing_config).map_err(Into::into);
@@ -17,6 +17,8 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use clap::Parser;
+use sc_service::Configuration;
+use std::path::PathBuf;
 
 /// EVM tracing CLI flags.
 #[derive(Debug, PartialEq, Clone)]
@@ -116,4 +118,58 @@ pub struct EthApiOptions {
     /// Maximum number of logs in a query.
     #[clap(long, default_value = "10000")]
     pub max_past_logs: u32,
+
+    /// Path to a directory with EVM tracing runtimes (built with `evm-tracing` feature).
+    ///
+    /// On-chain runtimes don't expose the tracing runtime API, so the matching tracing runtime
+    /// (same spec name & version) is used instead when executing `debug` and `trace` requests,
+    /// including the ones at historical blocks.
+    #[clap(long, value_name = "PATH", requires = "ethapi")]
+    pub tracing_runtimes_dir: Option<PathBuf>,
+}
+
+/// Configure the client to override on-chain runtimes with the EVM tracing runtimes found in the
+/// provided directory.
+pub fn override_runtimes_with_tracing_runtimes(
+    mut config: Configuration,
+    tracing_runtimes_dir: Option<PathBuf>,
+    ethapi: &[EthApi],
+) -> Result<Configuration, sc_service::Error> {
+    let tracing_runtimes_dir = match tracing_runtimes_dir {
+        Some(tracing_runtimes_dir) => tracing_runtimes_dir,
+        None => {
+            if ethapi
+                .iter()
+                .any(|api| matches!(api, EthApi::Debug | EthApi::Trace))
+            {
+                log::warn!(
+                    "No EVM tracing runtimes provided, `debug` & `trace` requests will only succeed for \
+                    blocks executed by a runtime which supports tracing. Use `--tracing-runtimes-dir` \
+                    to provide them."
+                );
+            }
+            return Ok(config);
+        }
+    };
+
+    if config.wasm_runtime_overrides.is_some() {
+        return Err(sc_service::Error::Other(
+            "`--tracing-runtimes-dir` cannot be used together with `--wasm-runtime-overrides`"
+                .into(),
+        ));
+    }
+    if !tracing_runtimes_dir.is_dir() {
+        return Err(sc_service::Error::Other(format!(
+            "EVM tracing runtimes directory `{}` does not exist",
+            tracing_runtimes_dir.display()
+        )));
+    }
+
+    log::info!(
+        "Using EVM tracing runtimes from {}",
+        tracing_runtimes_dir.display()
+    );
+    config.wasm_runtime_overrides = Some(tracing_runtimes_dir);
+
+    Ok(config)
 }
@@ -0,0 +1,238 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Consistency check of the Frontier database against the Substrate chain.
+
+use fc_db::kv::{Backend as FrontierBackend, MappingCommitment};
+use fp_rpc::EthereumRuntimeRPCApi;
+use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::{ops::RangeInclusive, sync::Arc};
+
+/// Verify the Ethereum block & transaction mappings of the Frontier database against the
+/// Substrate chain, e.g. after a crash, and optionally rebuild the missing or corrupted ones
+/// in place.
+///
+/// The node must not be running, since the databases can't be opened twice.
+#[derive(Debug, clap::Parser)]
+pub struct CheckFrontierDbCmd {
+    /// Number of the first block to check.
+    #[clap(long, default_value = "0")]
+    pub from: u32,
+
+    /// Number of the last block to check, inclusive. Defaults to the best block.
+    #[clap(long)]
+    pub to: Option<u32>,
+
+    /// Rebuild the mappings of the inconsistent blocks.
+    #[clap(long)]
+    pub repair: bool,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub import_params: ImportParams,
+}
+
+/// Inconsistency of the mapping of a Substrate block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Inconsistency {
+    /// Block was never synced to the Frontier database.
+    Missing,
+    /// Ethereum block isn't mapped to the Substrate block, or a transaction isn't mapped to it.
+    Mismatch,
+}
+
+impl CheckFrontierDbCmd {
+    /// Check the mappings of `frontier_backend` against the blocks of `client`.
+    pub fn run<Block, Client>(
+        &self,
+        client: Arc<Client>,
+        frontier_backend: Arc<FrontierBackend<Block>>,
+    ) -> Result<()>
+    where
+        Block: BlockT,
+        Client: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+        Client::Api: EthereumRuntimeRPCApi<Block>,
+    {
+        let best_number: u32 = client
+            .info()
+            .best_number
+            .try_into()
+            .map_err(|_| "Best block number doesn't fit in u32")?;
+        let to = self.to.unwrap_or(best_number).min(best_number);
+        if self.from > to {
+            return Err("Invalid block range".into());
+        }
+
+        let mut inconsistent = Vec::<(u32, Block::Hash, Inconsistency)>::new();
+        for number in self.from..=to {
+            let hash = client
+                .hash(number.into())?
+                .ok_or_else(|| format!("Unknown block #{}", number))?;
+
+            if let Some(inconsistency) = Self::check_block(&*client, &frontier_backend, hash)? {
+                inconsistent.push((number, hash, inconsistency));
+            }
+        }
+
+        for (range, inconsistency) in ranges(&inconsistent) {
+            println!(
+                "{:?} mappings: #{}..=#{}",
+                inconsistency,
+                range.start(),
+                range.end()
+            );
+        }
+        println!(
+            "Checked blocks #{}..=#{}, {} inconsistent",
+            self.from,
+            to,
+            inconsistent.len()
+        );
+
+        if self.repair {
+            for (number, hash, _) in &inconsistent {
+                Self::repair_block(&*client, &frontier_backend, *hash)
+                    .map_err(|e| format!("Failed to repair block #{}: {}", number, e))?;
+            }
+            println!("Repaired {} blocks", inconsistent.len());
+        } else if !inconsistent.is_empty() {
+            return Err("Frontier database is inconsistent, run with `--repair` to fix it".into());
+        }
+
+        Ok(())
+    }
+
+    /// Check the mappings of the Substrate block `hash`.
+    fn check_block<Block, Client>(
+        client: &Client,
+        frontier_backend: &FrontierBackend<Block>,
+        hash: Block::Hash,
+    ) -> Result<Option<Inconsistency>>
+    where
+        Block: BlockT,
+        Client: ProvideRuntimeApi<Block>,
+        Client::Api: EthereumRuntimeRPCApi<Block>,
+    {
+        let mapping = frontier_backend.mapping();
+        if !mapping.is_synced(&hash)? {
+            return Ok(Some(Inconsistency::Missing));
+        }
+
+        // Blocks without an Ethereum block are only marked as synced.
+        let ethereum_block = match Self::ethereum_block(client, hash)? {
+            Some(ethereum_block) => ethereum_block,
+            None => return Ok(None),
+        };
+
+        let block_mapped = mapping
+            .block_hash(&ethereum_block.header.hash())?
+            .map_or(false, |hashes| hashes.contains(&hash));
+        if !block_mapped {
+            return Ok(Some(Inconsistency::Mismatch));
+        }
+
+        for transaction in &ethereum_block.transactions {
+            let transaction_mapped = mapping
+                .transaction_metadata(&transaction.hash())?
+                .iter()
+                .any(|metadata| metadata.block_hash == hash);
+            if !transaction_mapped {
+                return Ok(Some(Inconsistency::Mismatch));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Rebuild the mappings of the Substrate block `hash`, as the mapping sync worker does.
+    fn repair_block<Block, Client>(
+        client: &Client,
+        frontier_backend: &FrontierBackend<Block>,
+        hash: Block::Hash,
+    ) -> Result<()>
+    where
+        Block: BlockT,
+        Client: ProvideRuntimeApi<Block>,
+        Client::Api: EthereumRuntimeRPCApi<Block>,
+    {
+        let mapping = frontier_backend.mapping();
+        match Self::ethereum_block(client, hash)? {
+            Some(ethereum_block) => mapping.write_hashes(MappingCommitment {
+                block_hash: hash,
+                ethereum_block_hash: ethereum_block.header.hash(),
+                ethereum_transaction_hashes: ethereum_block
+                    .transactions
+                    .iter()
+                    .map(|transaction| transaction.hash())
+                    .collect(),
+            })?,
+            None => mapping.write_none(hash)?,
+        }
+
+        Ok(())
+    }
+
+    /// Ethereum block built in the Substrate block `hash`, if any.
+    fn ethereum_block<Block, Client>(
+        client: &Client,
+        hash: Block::Hash,
+    ) -> Result<Option<ethereum::BlockV2>>
+    where
+        Block: BlockT,
+        Client: ProvideRuntimeApi<Block>,
+        Client::Api: EthereumRuntimeRPCApi<Block>,
+    {
+        client
+            .runtime_api()
+            .current_block(hash)
+            .map_err(|e| format!("Failed to get the Ethereum block of {:?}: {:?}", hash, e).into())
+    }
+}
+
+/// Group consecutive inconsistent blocks of the same kind into ranges.
+fn ranges<Hash>(
+    inconsistent: &[(u32, Hash, Inconsistency)],
+) -> Vec<(RangeInclusive<u32>, Inconsistency)> {
+    let mut ranges = Vec::<(RangeInclusive<u32>, Inconsistency)>::new();
+    for (number, _, inconsistency) in inconsistent {
+        match ranges.last_mut() {
+            Some((range, kind)) if kind == inconsistency && *range.end() + 1 == *number => {
+                *range = *range.start()..=*number;
+            }
+            _ => ranges.push((*number..=*number, *inconsistency)),
+        }
+    }
+    ranges
+}
+
+impl CliConfiguration for CheckFrontierDbCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn import_params(&self) -> Option<&ImportParams> {
+        Some(&self.import_params)
+    }
+}
@@ -0,0 +1,152 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Export of the genesis head and wasm of the parachain runtimes for a given parachain id.
+
+use cumulus_client_cli::{extract_genesis_wasm, generate_genesis_block};
+use log::info;
+use parity_scale_codec::Encode;
+use sc_cli::{ChainSpec, Result};
+use sp_core::hexdisplay::HexDisplay;
+use sp_runtime::{traits::Block as BlockT, StateVersion};
+use std::path::PathBuf;
+
+use crate::parachain::chain_spec::{self, Extensions};
+use astar_primitives::Block;
+
+/// Runtime whose genesis is exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum GenesisRuntime {
+    /// Astar runtime.
+    Astar,
+    /// Shiden runtime.
+    Shiden,
+    /// Shibuya runtime.
+    Shibuya,
+}
+
+impl GenesisRuntime {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Astar => "astar",
+            Self::Shiden => "shiden",
+            Self::Shibuya => "shibuya",
+        }
+    }
+
+    /// Development chain spec of the runtime, with `para_id` if provided.
+    fn chain_spec(&self, para_id: Option<u32>) -> Box<dyn ChainSpec> {
+        match (self, para_id) {
+            (Self::Astar, Some(id)) => Box::new(chain_spec::astar::get_chain_spec_with_para_id(id)),
+            (Self::Astar, None) => Box::new(chain_spec::astar::get_chain_spec()),
+            (Self::Shiden, Some(id)) => {
+                Box::new(chain_spec::shiden::get_chain_spec_with_para_id(id))
+            }
+            (Self::Shiden, None) => Box::new(chain_spec::shiden::get_chain_spec()),
+            (Self::Shibuya, Some(id)) => {
+                Box::new(chain_spec::shibuya::get_chain_spec_with_para_id(id))
+            }
+            (Self::Shibuya, None) => Box::new(chain_spec::shibuya::get_chain_spec()),
+        }
+    }
+
+    fn state_version(&self) -> StateVersion {
+        match self {
+            Self::Astar => astar_runtime::VERSION.state_version(),
+            Self::Shiden => shiden_runtime::VERSION.state_version(),
+            Self::Shibuya => shibuya_runtime::VERSION.state_version(),
+        }
+    }
+}
+
+/// Export the genesis head and wasm of one or more runtimes in one invocation,
+/// e.g. to register a parachain or to renew its slot lease.
+///
+/// For each runtime, `<runtime>-<para_id>-genesis-head` and `<runtime>-<para_id>-genesis-wasm`
+/// are written to the output directory, hex encoded like with `export-genesis-state` and
+/// `export-genesis-wasm`. Genesis is built from the development chain spec of the runtime.
+#[derive(Debug, clap::Parser)]
+pub struct ExportGenesisCmd {
+    /// Runtimes to export, comma separated. All of them if not provided.
+    #[clap(long, value_enum, value_delimiter = ',')]
+    pub runtime: Vec<GenesisRuntime>,
+
+    /// Parachain id put in the genesis, the default one of each runtime if not provided.
+    #[clap(long)]
+    pub parachain_id: Option<u32>,
+
+    /// Directory the artifacts are written to, created if missing.
+    #[clap(long, default_value = ".")]
+    pub output: PathBuf,
+
+    /// Write the artifacts as binary instead of hex.
+    #[clap(long)]
+    pub raw: bool,
+}
+
+impl ExportGenesisCmd {
+    /// Run the command.
+    pub fn run(&self) -> Result<()> {
+        let runtimes = if self.runtime.is_empty() {
+            vec![
+                GenesisRuntime::Astar,
+                GenesisRuntime::Shiden,
+                GenesisRuntime::Shibuya,
+            ]
+        } else {
+            self.runtime.clone()
+        };
+
+        std::fs::create_dir_all(&self.output)?;
+
+        for runtime in runtimes {
+            let spec = runtime.chain_spec(self.parachain_id);
+            let para_id = Extensions::try_get(&*spec)
+                .map(|e| e.para_id)
+                .ok_or("ParaId not found in chain spec extension")?;
+
+            let block: Block = generate_genesis_block(&*spec, runtime.state_version())
+                .map_err(|e| format!("{:?}", e))?;
+            let head = block.header().encode();
+            let wasm = extract_genesis_wasm(&*spec)?;
+
+            let prefix = format!("{}-{}", runtime.name(), para_id);
+            let head_path = self.write(&format!("{}-genesis-head", prefix), &head)?;
+            let wasm_path = self.write(&format!("{}-genesis-wasm", prefix), &wasm)?;
+            info!(
+                "Exported {} genesis for parachain {} to {} and {}",
+                runtime.name(),
+                para_id,
+                head_path.display(),
+                wasm_path.display()
+            );
+        }
+
+        Ok(())
+    }
+
+    fn write(&self, file_name: &str, data: &[u8]) -> Result<PathBuf> {
+        let path = self.output.join(file_name);
+        if self.raw {
+            std::fs::write(&path, data)?;
+        } else {
+            std::fs::write(&path, format!("0x{:?}", HexDisplay::from(&data)))?;
+        }
+        Ok(path)
+    }
+}
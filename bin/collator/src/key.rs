@@ -0,0 +1,285 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Account key utilities with machine-readable output.
+
+use pallet_evm::{AddressMapping, HashedAddressMapping};
+use sc_cli::{CryptoScheme, Error, OutputType, Result, SubstrateCli};
+use serde_json::{Map, Value};
+use sp_core::{
+    crypto::{AccountId32, Ss58AddressFormat, Ss58Codec},
+    ed25519, sr25519, Pair, H160,
+};
+use sp_runtime::traits::BlakeTwo256;
+
+/// Networks along with the SS58 prefix their addresses are rendered with.
+const NETWORKS: [(&str, u16); 4] = [
+    ("astar", 5),
+    ("shiden", 5),
+    ("shibuya", 5),
+    ("substrate", 42),
+];
+
+/// Key management cli utilities.
+#[derive(Debug, clap::Subcommand)]
+pub enum KeySubcommand {
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    Substrate(sc_cli::KeySubcommand),
+
+    /// Print the public key, network addresses and EVM mapping of an account.
+    InspectAccount(InspectAccountCmd),
+
+    /// Generate a new account and print its secret phrase, network addresses and EVM mapping.
+    GenerateAccount(GenerateAccountCmd),
+}
+
+impl KeySubcommand {
+    /// Run the key subcommand.
+    pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<()> {
+        match self {
+            KeySubcommand::Substrate(cmd) => cmd.run(cli),
+            KeySubcommand::InspectAccount(cmd) => cmd.run(),
+            KeySubcommand::GenerateAccount(cmd) => cmd.run(),
+        }
+    }
+}
+
+/// The `inspect-account` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct InspectAccountCmd {
+    /// A secret URI, a hex encoded public key, an SS58 address or an EVM (H160) address.
+    #[clap(value_name = "URI|ADDRESS")]
+    pub input: String,
+
+    /// Treat a hex encoded `input` as a public key rather than a raw seed.
+    #[clap(long)]
+    pub public: bool,
+
+    /// Cryptography scheme used when `input` is a secret URI.
+    #[clap(
+        long,
+        value_name = "SCHEME",
+        value_enum,
+        ignore_case = true,
+        default_value = "sr25519"
+    )]
+    pub scheme: CryptoScheme,
+
+    /// Output format, either `text` or `json`.
+    #[clap(
+        long,
+        value_name = "FORMAT",
+        value_enum,
+        ignore_case = true,
+        default_value = "text"
+    )]
+    pub output: OutputType,
+}
+
+impl InspectAccountCmd {
+    /// Run the command.
+    pub fn run(&self) -> Result<()> {
+        let input = self.input.trim();
+
+        let fields = if let Some(address) = parse_h160(input)? {
+            evm_account_fields(address)
+        } else if let Some(account) = parse_account(input, self.public)? {
+            account_fields(&account)
+        } else {
+            let public = match self.scheme {
+                CryptoScheme::Sr25519 => public_from_uri::<sr25519::Pair>(input)?,
+                CryptoScheme::Ed25519 => public_from_uri::<ed25519::Pair>(input)?,
+                CryptoScheme::Ecdsa => {
+                    return Err(Error::Input(
+                        "ecdsa accounts are not supported, use the EVM address instead".into(),
+                    ))
+                }
+            };
+            let mut fields = Map::new();
+            fields.insert("publicKey".into(), to_hex(&public));
+            fields.extend(account_fields(&AccountId32::new(public)));
+            fields
+        };
+
+        print_fields(fields, self.output);
+        Ok(())
+    }
+}
+
+/// The `generate-account` command.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct GenerateAccountCmd {
+    /// Cryptography scheme of the generated key.
+    #[clap(
+        long,
+        value_name = "SCHEME",
+        value_enum,
+        ignore_case = true,
+        default_value = "sr25519"
+    )]
+    pub scheme: CryptoScheme,
+
+    /// Output format, either `text` or `json`.
+    #[clap(
+        long,
+        value_name = "FORMAT",
+        value_enum,
+        ignore_case = true,
+        default_value = "text"
+    )]
+    pub output: OutputType,
+}
+
+impl GenerateAccountCmd {
+    /// Run the command.
+    pub fn run(&self) -> Result<()> {
+        let (phrase, public) = match self.scheme {
+            CryptoScheme::Sr25519 => {
+                let (pair, phrase, _) = sr25519::Pair::generate_with_phrase(None);
+                (phrase, pair.public().0)
+            }
+            CryptoScheme::Ed25519 => {
+                let (pair, phrase, _) = ed25519::Pair::generate_with_phrase(None);
+                (phrase, pair.public().0)
+            }
+            CryptoScheme::Ecdsa => {
+                return Err(Error::Input("ecdsa accounts are not supported".into()))
+            }
+        };
+
+        let mut fields = Map::new();
+        fields.insert("secretPhrase".into(), Value::String(phrase));
+        fields.insert("publicKey".into(), to_hex(&public));
+        fields.extend(account_fields(&AccountId32::new(public)));
+
+        print_fields(fields, self.output);
+        Ok(())
+    }
+}
+
+/// Fields describing a native account.
+///
+/// The EVM address of a native account is the first 20 bytes of its account id.
+fn account_fields(account: &AccountId32) -> Map<String, Value> {
+    let raw: &[u8; 32] = account.as_ref();
+
+    let mut fields = Map::new();
+    fields.insert("accountId".into(), to_hex(raw));
+    fields.insert("ss58".into(), ss58_addresses(account));
+    fields.insert("evmAddress".into(), to_hex(&raw[..20]));
+    fields
+}
+
+/// Fields describing an EVM address and the native account it is mapped to.
+fn evm_account_fields(address: H160) -> Map<String, Value> {
+    let account: AccountId32 =
+        <HashedAddressMapping<BlakeTwo256> as AddressMapping<AccountId32>>::into_account_id(
+            address,
+        );
+    let raw: &[u8; 32] = account.as_ref();
+
+    let mut fields = Map::new();
+    fields.insert("evmAddress".into(), to_hex(address.as_bytes()));
+    fields.insert("accountId".into(), to_hex(raw));
+    fields.insert("ss58".into(), ss58_addresses(&account));
+    fields
+}
+
+fn ss58_addresses(account: &AccountId32) -> Value {
+    let addresses = NETWORKS
+        .iter()
+        .map(|(network, prefix)| {
+            let address = account.to_ss58check_with_version(Ss58AddressFormat::custom(*prefix));
+            (network.to_string(), Value::String(address))
+        })
+        .collect::<Map<_, _>>();
+    Value::Object(addresses)
+}
+
+/// Parse `input` as a `0x` prefixed, 20 bytes long hex string.
+fn parse_h160(input: &str) -> Result<Option<H160>> {
+    match input.strip_prefix("0x") {
+        Some(hex) if hex.len() == 40 => {
+            let bytes = sp_core::bytes::from_hex(hex)
+                .map_err(|_| Error::Input("invalid EVM address".into()))?;
+            Ok(Some(H160::from_slice(&bytes)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Parse `input` as an SS58 address or, if `public` is set, a hex encoded public key.
+fn parse_account(input: &str, public: bool) -> Result<Option<AccountId32>> {
+    match input.strip_prefix("0x") {
+        Some(hex) if public && hex.len() == 64 => {
+            let bytes = sp_core::bytes::from_hex(hex)
+                .map_err(|_| Error::Input("invalid public key".into()))?;
+            Ok(AccountId32::try_from(&bytes[..]).ok())
+        }
+        _ => Ok(AccountId32::from_ss58check_with_version(input)
+            .ok()
+            .map(|(account, _)| account)),
+    }
+}
+
+fn public_from_uri<P: Pair>(uri: &str) -> Result<[u8; 32]>
+where
+    P::Public: AsRef<[u8]>,
+{
+    let pair = P::from_string(uri, None)
+        .map_err(|e| Error::Input(format!("invalid secret URI: {:?}", e)))?;
+    <[u8; 32]>::try_from(pair.public().as_ref())
+        .map_err(|_| Error::Input("unexpected public key length".into()))
+}
+
+fn to_hex(bytes: &[u8]) -> Value {
+    Value::String(sp_core::bytes::to_hex(bytes, false))
+}
+
+fn print_fields(fields: Map<String, Value>, output: OutputType) {
+    match output {
+        OutputType::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&Value::Object(fields))
+                    .expect("Json map is always serializable; qed")
+            );
+        }
+        OutputType::Text => {
+            for (name, value) in fields {
+                match value {
+                    Value::Object(addresses) => {
+                        for (network, address) in addresses {
+                            println!(
+                                "{:<24}{}",
+                                format!("{} ({}):", name, network),
+                                as_text(&address)
+                            );
+                        }
+                    }
+                    value => println!("{:<24}{}", format!("{}:", name), as_text(&value)),
+                }
+            }
+        }
+    }
+}
+
+fn as_text(value: &Value) -> &str {
+    value.as_str().unwrap_or_default()
+}
@@ -29,10 +29,15 @@ pub mod parachain;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod calibrate;
 mod cli;
 mod command;
 mod evm_tracing_types;
+mod frontier_db;
+mod genesis;
+mod key;
 mod rpc;
 
 pub use cli::*;
 pub use command::*;
+pub use key::*;
@@ -20,9 +20,9 @@
 
 use local_runtime::{
     wasm_binary_unwrap, AccountId, AuraConfig, AuraId, BalancesConfig, BaseFeeConfig,
-    BlockRewardConfig, CouncilConfig, DemocracyConfig, EVMConfig, GenesisConfig, GrandpaConfig,
-    GrandpaId, Precompiles, Signature, SudoConfig, SystemConfig, TechnicalCommitteeConfig,
-    TreasuryConfig, VestingConfig,
+    BlockRewardConfig, CouncilConfig, DemocracyConfig, EVMConfig, ExistentialDeposit,
+    GenesisConfig, GrandpaConfig, GrandpaId, Precompiles, Signature, SudoConfig, SystemConfig,
+    TechnicalCommitteeConfig, TreasuryConfig, VestingConfig, WrappedNativeAddress,
 };
 use sc_service::ChainType;
 use sp_core::{crypto::Ss58Codec, sr25519, Pair, Public};
@@ -145,7 +145,12 @@ fn testnet_genesis(
                         addr,
                         fp_evm::GenesisAccount {
                             nonce: Default::default(),
-                            balance: Default::default(),
+                            // The wrapped native vault must never be reaped.
+                            balance: if addr == WrappedNativeAddress::get() {
+                                ExistentialDeposit::get().into()
+                            } else {
+                                Default::default()
+                            },
                             storage: Default::default(),
                             code: revert_bytecode.clone(),
                         },
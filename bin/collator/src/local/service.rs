@@ -314,6 +314,12 @@ pub fn start_node(
         prometheus_registry.clone(),
     ));
 
+    let eth_rpc_metrics = prometheus_registry
+        .as_ref()
+        .map(crate::rpc::metrics::EthRpcMetrics::register)
+        .transpose()
+        .map_err(|e| ServiceError::Application(Box::new(e)))?;
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let network = network.clone();
@@ -342,6 +348,7 @@ pub fn start_node(
                 block_data_cache: block_data_cache.clone(),
                 overrides: overrides.clone(),
                 enable_evm_rpc: true, // enable EVM RPC for dev node by default
+                eth_rpc_metrics: eth_rpc_metrics.clone(),
             };
 
             crate::rpc::create_full(
@@ -588,6 +595,12 @@ pub fn start_node(config: Configuration) -> Result<TaskManager, ServiceError> {
         prometheus_registry.clone(),
     ));
 
+    let eth_rpc_metrics = prometheus_registry
+        .as_ref()
+        .map(crate::rpc::metrics::EthRpcMetrics::register)
+        .transpose()
+        .map_err(|e| ServiceError::Application(Box::new(e)))?;
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let network = network.clone();
@@ -611,6 +624,7 @@ pub fn start_node(config: Configuration) -> Result<TaskManager, ServiceError> {
                 block_data_cache: block_data_cache.clone(),
                 overrides: overrides.clone(),
                 enable_evm_rpc: true, // enable EVM RPC for dev node by default
+                eth_rpc_metrics: eth_rpc_metrics.clone(),
             };
 
             crate::rpc::create_full(deps, subscription, pubsub_notification_sinks.clone())
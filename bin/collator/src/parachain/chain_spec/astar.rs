@@ -37,8 +37,13 @@ const PARA_ID: u32 = 2006;
 /// Specialized `ChainSpec` for Astar Network.
 pub type AstarChainSpec = sc_service::GenericChainSpec<astar_runtime::GenesisConfig, Extensions>;
 
-/// Gen Astar chain specification for given parachain id.
+/// Gen Astar development chain specification with its default parachain id.
 pub fn get_chain_spec() -> AstarChainSpec {
+    get_chain_spec_with_para_id(PARA_ID)
+}
+
+/// Gen Astar development chain specification for the given parachain id.
+pub fn get_chain_spec_with_para_id(para_id: u32) -> AstarChainSpec {
     // Alice as default
     let sudo_key = get_account_id_from_seed::<sr25519::Public>("Alice");
     let endowned = vec![
@@ -60,7 +65,7 @@ pub fn get_chain_spec() -> AstarChainSpec {
         "Astar Testnet",
         "astar",
         ChainType::Development,
-        move || make_genesis(endowned.clone(), sudo_key.clone(), PARA_ID.into()),
+        move || make_genesis(endowned.clone(), sudo_key.clone(), para_id.into()),
         vec![],
         None,
         None,
@@ -69,7 +74,7 @@ pub fn get_chain_spec() -> AstarChainSpec {
         Extensions {
             bad_blocks: Default::default(),
             relay_chain: "tokyo".into(),
-            para_id: PARA_ID,
+            para_id,
         },
     )
 }
@@ -23,9 +23,9 @@ use sc_service::ChainType;
 use shibuya_runtime::{
     wasm_binary_unwrap, AccountId, AuraConfig, AuraId, Balance, BalancesConfig, BaseFeeConfig,
     BlockRewardConfig, CollatorSelectionConfig, CouncilConfig, DemocracyConfig, EVMChainIdConfig,
-    EVMConfig, GenesisConfig, ParachainInfoConfig, Precompiles, SessionConfig, SessionKeys,
-    Signature, SudoConfig, SystemConfig, TechnicalCommitteeConfig, TreasuryConfig, VestingConfig,
-    SBY,
+    EVMConfig, ExistentialDeposit, GenesisConfig, ParachainInfoConfig, Precompiles, SessionConfig,
+    SessionKeys, Signature, SudoConfig, SystemConfig, TechnicalCommitteeConfig, TreasuryConfig,
+    VestingConfig, WrappedNativeAddress, SBY,
 };
 use sp_core::{sr25519, Pair, Public};
 
@@ -41,8 +41,13 @@ const PARA_ID: u32 = 1000;
 /// Specialized `ChainSpec` for Shibuya testnet.
 pub type ShibuyaChainSpec = sc_service::GenericChainSpec<GenesisConfig, Extensions>;
 
-/// Gen Shibuya chain specification for given parachain id.
+/// Gen Shibuya development chain specification with its default parachain id.
 pub fn get_chain_spec() -> ShibuyaChainSpec {
+    get_chain_spec_with_para_id(PARA_ID)
+}
+
+/// Gen Shibuya development chain specification for the given parachain id.
+pub fn get_chain_spec_with_para_id(para_id: u32) -> ShibuyaChainSpec {
     // Alice as default
     let sudo_key = get_account_id_from_seed::<sr25519::Public>("Alice");
     let endowned = vec![
@@ -64,7 +69,7 @@ pub fn get_chain_spec() -> ShibuyaChainSpec {
         "Shibuya Testnet",
         "shibuya",
         ChainType::Development,
-        move || make_genesis(endowned.clone(), sudo_key.clone(), PARA_ID.into()),
+        move || make_genesis(endowned.clone(), sudo_key.clone(), para_id.into()),
         vec![],
         None,
         None,
@@ -73,7 +78,7 @@ pub fn get_chain_spec() -> ShibuyaChainSpec {
         Extensions {
             bad_blocks: Default::default(),
             relay_chain: "tokyo".into(),
-            para_id: PARA_ID,
+            para_id,
         },
     )
 }
@@ -150,7 +155,12 @@ fn make_genesis(
                         addr,
                         fp_evm::GenesisAccount {
                             nonce: Default::default(),
-                            balance: Default::default(),
+                            // The wrapped native vault must never be reaped.
+                            balance: if addr == WrappedNativeAddress::get() {
+                                ExistentialDeposit::get().into()
+                            } else {
+                                Default::default()
+                            },
                             storage: Default::default(),
                             code: revert_bytecode.clone(),
                         },
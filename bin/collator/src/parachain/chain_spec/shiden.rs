@@ -38,8 +38,13 @@ const PARA_ID: u32 = 2007;
 /// Specialized `ChainSpec` for Shiden Network.
 pub type ShidenChainSpec = sc_service::GenericChainSpec<shiden_runtime::GenesisConfig, Extensions>;
 
-/// Gen Shiden chain specification for given parachain id.
+/// Gen Shiden development chain specification with its default parachain id.
 pub fn get_chain_spec() -> ShidenChainSpec {
+    get_chain_spec_with_para_id(PARA_ID)
+}
+
+/// Gen Shiden development chain specification for the given parachain id.
+pub fn get_chain_spec_with_para_id(para_id: u32) -> ShidenChainSpec {
     // Alice as default
     let sudo_key = get_account_id_from_seed::<sr25519::Public>("Alice");
     let endowned = vec![
@@ -61,7 +66,7 @@ pub fn get_chain_spec() -> ShidenChainSpec {
         "Shiden Testnet",
         "shiden",
         ChainType::Development,
-        move || make_genesis(endowned.clone(), sudo_key.clone(), PARA_ID.into()),
+        move || make_genesis(endowned.clone(), sudo_key.clone(), para_id.into()),
         vec![],
         None,
         None,
@@ -70,7 +75,7 @@ pub fn get_chain_spec() -> ShidenChainSpec {
         Extensions {
             bad_blocks: Default::default(),
             relay_chain: "tokyo".into(),
-            para_id: PARA_ID,
+            para_id,
         },
     )
 }
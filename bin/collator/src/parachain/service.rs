@@ -328,6 +328,10 @@ where
         + sp_block_builder::BlockBuilder<Block>
         + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + pallet_chain_metadata_rpc::ChainMetadataRuntimeApi<Block>
+        + pallet_xc_asset_config_rpc::XcAssetsRuntimeApi<Block, AssetId>
+        + pallet_xvm_rpc::XvmRuntimeApi<Block, AccountId>
+        + pallet_dapps_staking_rpc::DappsStakingRuntimeApi<Block, BlockNumber>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + cumulus_primitives_core::CollectCollationInfo<Block>,
@@ -486,6 +490,12 @@ where
         prometheus_registry.clone(),
     ));
 
+    let eth_rpc_metrics = prometheus_registry
+        .as_ref()
+        .map(crate::rpc::metrics::EthRpcMetrics::register)
+        .transpose()
+        .map_err(|e| sc_service::Error::Application(Box::new(e)))?;
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let network = network.clone();
@@ -509,6 +519,7 @@ where
                 block_data_cache: block_data_cache.clone(),
                 overrides: overrides.clone(),
                 enable_evm_rpc: additional_config.enable_evm_rpc,
+                eth_rpc_metrics: eth_rpc_metrics.clone(),
             };
 
             crate::rpc::create_full(deps, subscription, pubsub_notification_sinks.clone())
@@ -646,6 +657,10 @@ where
         + sp_block_builder::BlockBuilder<Block>
         + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + pallet_chain_metadata_rpc::ChainMetadataRuntimeApi<Block>
+        + pallet_xc_asset_config_rpc::XcAssetsRuntimeApi<Block, AssetId>
+        + pallet_xvm_rpc::XvmRuntimeApi<Block, AccountId>
+        + pallet_dapps_staking_rpc::DappsStakingRuntimeApi<Block, BlockNumber>
         + moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
         + moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
@@ -827,6 +842,12 @@ where
         prometheus_registry.clone(),
     ));
 
+    let eth_rpc_metrics = prometheus_registry
+        .as_ref()
+        .map(crate::rpc::metrics::EthRpcMetrics::register)
+        .transpose()
+        .map_err(|e| sc_service::Error::Application(Box::new(e)))?;
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let network = network.clone();
@@ -855,6 +876,7 @@ where
                 block_data_cache: block_data_cache.clone(),
                 overrides: overrides.clone(),
                 enable_evm_rpc: additional_config.enable_evm_rpc,
+                eth_rpc_metrics: eth_rpc_metrics.clone(),
             };
 
             crate::rpc::create_full(
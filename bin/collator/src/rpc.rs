@@ -24,7 +24,11 @@ use fc_rpc::{
 };
 use fc_rpc_core::types::{FeeHistoryCache, FilterPool};
 use jsonrpsee::RpcModule;
+use pallet_chain_metadata_rpc::{ChainMetadata, ChainMetadataApiServer};
+use pallet_dapps_staking_rpc::{DappsStaking, DappsStakingApiServer};
 use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+use pallet_xc_asset_config_rpc::{XcAssets, XcAssetsApiServer};
+use pallet_xvm_rpc::{Xvm, XvmApiServer};
 use sc_client_api::{AuxStore, Backend, BlockchainEvents, StateBackend, StorageProvider};
 use sc_network::NetworkService;
 use sc_network_sync::SyncingService;
@@ -51,6 +55,7 @@ use moonbeam_rpc_txpool::{TxPool as MoonbeamTxPool, TxPoolServer};
 
 use astar_primitives::*;
 
+pub mod metrics;
 #[cfg(feature = "evm-tracing")]
 pub mod tracing;
 
@@ -115,6 +120,8 @@ pub struct FullDeps<C, P, A: ChainApi> {
     pub block_data_cache: Arc<EthBlockDataCacheTask<Block>>,
     /// Enable EVM RPC servers
     pub enable_evm_rpc: bool,
+    /// Ethereum RPC metrics, if Prometheus is enabled.
+    pub eth_rpc_metrics: Option<metrics::EthRpcMetrics>,
 }
 
 /// Instantiate all RPC extensions and Tracing RPC.
@@ -143,6 +150,10 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + pallet_chain_metadata_rpc::ChainMetadataRuntimeApi<Block>
+        + pallet_xc_asset_config_rpc::XcAssetsRuntimeApi<Block, AssetId>
+        + pallet_xvm_rpc::XvmRuntimeApi<Block, AccountId>
+        + pallet_dapps_staking_rpc::DappsStakingRuntimeApi<Block, BlockNumber>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
@@ -206,6 +217,10 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + pallet_chain_metadata_rpc::ChainMetadataRuntimeApi<Block>
+        + pallet_xc_asset_config_rpc::XcAssetsRuntimeApi<Block, AssetId>
+        + pallet_xvm_rpc::XvmRuntimeApi<Block, AccountId>
+        + pallet_dapps_staking_rpc::DappsStakingRuntimeApi<Block, BlockNumber>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>,
@@ -241,6 +256,10 @@ where
     C: sc_client_api::BlockBackend<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
+        + pallet_chain_metadata_rpc::ChainMetadataRuntimeApi<Block>
+        + pallet_xc_asset_config_rpc::XcAssetsRuntimeApi<Block, AssetId>
+        + pallet_xvm_rpc::XvmRuntimeApi<Block, AccountId>
+        + pallet_dapps_staking_rpc::DappsStakingRuntimeApi<Block, BlockNumber>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>,
@@ -266,10 +285,15 @@ where
         overrides,
         block_data_cache,
         enable_evm_rpc,
+        eth_rpc_metrics,
     } = deps;
 
     io.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    io.merge(ChainMetadata::new(client.clone()).into_rpc())?;
+    io.merge(XcAssets::new(client.clone()).into_rpc())?;
+    io.merge(Xvm::new(client.clone()).into_rpc())?;
+    io.merge(DappsStaking::<_, _, BlockNumber>::new(client.clone()).into_rpc())?;
     io.merge(sc_rpc::dev::Dev::new(client.clone(), deny_unsafe).into_rpc())?;
 
     if !enable_evm_rpc {
@@ -278,7 +302,10 @@ where
 
     let no_tx_converter: Option<fp_rpc::NoTransactionConverter> = None;
 
-    io.merge(
+    // Subscriptions are kept out of this module, so all its methods can be instrumented.
+    let mut eth_io = RpcModule::new(());
+
+    eth_io.merge(
         Eth::new(
             client.clone(),
             pool.clone(),
@@ -302,7 +329,7 @@ where
     let max_past_logs: u32 = 10_000;
     let max_stored_filters: usize = 500;
     let tx_pool = TxPool::new(client.clone(), graph);
-    io.merge(
+    eth_io.merge(
         EthFilter::new(
             client.clone(),
             frontier_backend,
@@ -315,9 +342,19 @@ where
         .into_rpc(),
     )?;
 
-    io.merge(Net::new(client.clone(), network.clone(), true).into_rpc())?;
+    eth_io.merge(Net::new(client.clone(), network.clone(), true).into_rpc())?;
 
-    io.merge(Web3::new(client.clone()).into_rpc())?;
+    eth_io.merge(Web3::new(client.clone()).into_rpc())?;
+
+    match eth_rpc_metrics {
+        Some(eth_rpc_metrics) => {
+            let client = client.clone();
+            io.merge(metrics::instrument(eth_io, eth_rpc_metrics, move || {
+                client.info().best_number.into()
+            })?)?;
+        }
+        None => io.merge(eth_io)?,
+    }
 
     io.merge(
         EthPubSub::new(
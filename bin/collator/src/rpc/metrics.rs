@@ -0,0 +1,197 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Prometheus metrics of the Ethereum RPC.
+//!
+//! Hit rates of the Ethereum block data cache are reported by `EthBlockDataCacheTask` itself
+//! when it's created with the Prometheus registry.
+
+use fc_rpc_core::types::{BlockNumber, Filter};
+use jsonrpsee::{
+    core::Error as JsonRpseeError,
+    types::error::{CallError, ErrorObject},
+    RpcModule,
+};
+use std::{sync::Arc, time::Instant};
+use substrate_prometheus_endpoint::{
+    exponential_buckets, register, CounterVec, Histogram, HistogramOpts, HistogramVec, Opts,
+    PrometheusError, Registry, U64,
+};
+
+/// Ethereum RPC metrics.
+#[derive(Clone)]
+pub struct EthRpcMetrics {
+    /// Number of requests per method and status.
+    requests: CounterVec<U64>,
+    /// Request latency per method.
+    request_duration: HistogramVec,
+    /// Number of blocks scanned by `eth_getLogs` requests.
+    get_logs_scanned_blocks: Histogram,
+    /// Number of logs returned by `eth_getLogs` requests.
+    get_logs_returned_logs: Histogram,
+}
+
+impl EthRpcMetrics {
+    /// Create and register the metrics in the given registry.
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            requests: register(
+                CounterVec::new(
+                    Opts::new(
+                        "astar_eth_rpc_requests_total",
+                        "Number of Ethereum RPC requests",
+                    ),
+                    &["method", "status"],
+                )?,
+                registry,
+            )?,
+            request_duration: register(
+                HistogramVec::new(
+                    HistogramOpts::new(
+                        "astar_eth_rpc_request_duration_seconds",
+                        "Latency of Ethereum RPC requests",
+                    )
+                    .buckets(exponential_buckets(0.001, 4.0, 9)?),
+                    &["method"],
+                )?,
+                registry,
+            )?,
+            get_logs_scanned_blocks: register(
+                Histogram::with_opts(
+                    HistogramOpts::new(
+                        "astar_eth_rpc_get_logs_scanned_blocks",
+                        "Number of blocks scanned by eth_getLogs requests",
+                    )
+                    .buckets(exponential_buckets(1.0, 4.0, 9)?),
+                )?,
+                registry,
+            )?,
+            get_logs_returned_logs: register(
+                Histogram::with_opts(
+                    HistogramOpts::new(
+                        "astar_eth_rpc_get_logs_returned_logs",
+                        "Number of logs returned by eth_getLogs requests",
+                    )
+                    .buckets(exponential_buckets(1.0, 4.0, 9)?),
+                )?,
+                registry,
+            )?,
+        })
+    }
+
+    fn observe_get_logs_filter(&self, filter: &Filter, best_number: u64) {
+        let scanned_blocks = if filter.block_hash.is_some() {
+            1
+        } else {
+            let resolve = |number: Option<BlockNumber>| match number {
+                Some(BlockNumber::Num(number)) => number.min(best_number),
+                Some(BlockNumber::Earliest) => 0,
+                _ => best_number,
+            };
+            resolve(filter.to_block.clone())
+                .saturating_sub(resolve(filter.from_block.clone()))
+                .saturating_add(1)
+        };
+        self.get_logs_scanned_blocks.observe(scanned_blocks as f64);
+    }
+}
+
+/// Wrap all methods of the `module` to record the request metrics.
+///
+/// Subscriptions aren't supported, so the module shouldn't contain them.
+pub fn instrument<F>(
+    module: RpcModule<()>,
+    metrics: EthRpcMetrics,
+    best_number: F,
+) -> Result<RpcModule<()>, JsonRpseeError>
+where
+    F: Fn() -> u64 + Clone + Send + Sync + 'static,
+{
+    let inner = Arc::new(module);
+    let mut instrumented = RpcModule::new(());
+
+    for method in inner.method_names() {
+        let inner = inner.clone();
+        let metrics = metrics.clone();
+        let best_number = best_number.clone();
+
+        instrumented.register_async_method(method, move |params, _| {
+            let inner = inner.clone();
+            let metrics = metrics.clone();
+            let best_number = best_number.clone();
+
+            async move {
+                let is_get_logs = method == "eth_getLogs";
+                if is_get_logs {
+                    if let Ok(filter) = params.one::<Filter>() {
+                        metrics.observe_get_logs_filter(&filter, best_number());
+                    }
+                }
+
+                let request = format!(
+                    r#"{{"jsonrpc":"2.0","id":0,"method":"{}","params":{}}}"#,
+                    method,
+                    params.as_str().unwrap_or("[]"),
+                );
+
+                let started = Instant::now();
+                let (response, _) = inner.raw_json_request(&request).await?;
+                metrics
+                    .request_duration
+                    .with_label_values(&[method])
+                    .observe(started.elapsed().as_secs_f64());
+                metrics
+                    .requests
+                    .with_label_values(&[method, if response.success { "ok" } else { "error" }])
+                    .inc();
+
+                let mut response: serde_json::Value = serde_json::from_str(&response.result)?;
+                if let Some(error) = response.get_mut("error").map(serde_json::Value::take) {
+                    let code = error
+                        .get("code")
+                        .and_then(|code| code.as_i64())
+                        .unwrap_or(-32603);
+                    let message = error
+                        .get("message")
+                        .and_then(|message| message.as_str())
+                        .unwrap_or_default()
+                        .to_owned();
+                    return Err(JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+                        code as i32,
+                        message,
+                        error.get("data").cloned(),
+                    ))));
+                }
+
+                let result = response
+                    .get_mut("result")
+                    .map(serde_json::Value::take)
+                    .unwrap_or_default();
+                if is_get_logs {
+                    if let Some(logs) = result.as_array() {
+                        metrics.get_logs_returned_logs.observe(logs.len() as f64);
+                    }
+                }
+
+                Ok(result)
+            }
+        })?;
+    }
+
+    Ok(instrumented)
+}
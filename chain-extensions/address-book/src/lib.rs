@@ -0,0 +1,88 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::traits::Get;
+use pallet_contracts::chain_extension::{ChainExtension, Environment, Ext, InitState, RetVal};
+use parity_scale_codec::Encode;
+use sp_runtime::DispatchError;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+enum AddressBookFuncId {
+    EvmAddressOf,
+    NativeAddressOf,
+}
+
+impl TryFrom<u16> for AddressBookFuncId {
+    type Error = DispatchError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(AddressBookFuncId::EvmAddressOf),
+            2 => Ok(AddressBookFuncId::NativeAddressOf),
+            _ => Err(DispatchError::Other(
+                "Unsupported func id in AddressBook chain extension",
+            )),
+        }
+    }
+}
+
+/// Address book chain extension.
+///
+/// Both functions take the SCALE encoded name (`Vec<u8>`) as input and
+/// write back SCALE encoded `Option` of the registered address.
+pub struct AddressBookExtension<T>(PhantomData<T>);
+
+impl<T> Default for AddressBookExtension<T> {
+    fn default() -> Self {
+        AddressBookExtension(PhantomData)
+    }
+}
+
+impl<T> ChainExtension<T> for AddressBookExtension<T>
+where
+    T: pallet_address_book::Config + pallet_contracts::Config,
+{
+    fn call<E: Ext>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+    where
+        E: Ext<T = T>,
+    {
+        let func_id = env.func_id().try_into()?;
+        let mut env = env.buf_in_buf_out();
+
+        let name: Vec<u8> = env.read_as_unbounded(env.in_len())?;
+
+        let base_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
+        env.charge_weight(base_weight)?;
+
+        match func_id {
+            AddressBookFuncId::EvmAddressOf => {
+                let address = pallet_address_book::Pallet::<T>::evm_address(&name);
+                env.write(&address.encode(), false, None)?;
+            }
+
+            AddressBookFuncId::NativeAddressOf => {
+                let account = pallet_address_book::Pallet::<T>::native_address(&name);
+                env.write(&account.encode(), false, None)?;
+            }
+        }
+
+        Ok(RetVal::Converging(0))
+    }
+}
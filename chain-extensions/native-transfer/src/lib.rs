@@ -0,0 +1,126 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::traits::{Currency, ExistenceRequirement, Get};
+use pallet_contracts::chain_extension::{ChainExtension, Environment, Ext, InitState, RetVal};
+use parity_scale_codec::{Decode, Encode};
+use precompile_utils::AddressMapper;
+use sp_core::H160;
+use sp_runtime::{traits::Zero, DispatchError};
+use sp_std::marker::PhantomData;
+
+type CurrencyOf<T> = <T as pallet_evm::Config>::Currency;
+type BalanceOf<T> = <CurrencyOf<T> as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+enum NativeTransferFuncId {
+    TransferToEvm,
+}
+
+impl TryFrom<u16> for NativeTransferFuncId {
+    type Error = DispatchError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(NativeTransferFuncId::TransferToEvm),
+            _ => Err(DispatchError::Other(
+                "Unsupported func id in NativeTransfer chain extension",
+            )),
+        }
+    }
+}
+
+/// Status code returned by the native transfer chain extension.
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, Debug)]
+pub enum NativeTransferResult {
+    /// Success
+    Ok = 0,
+    /// Destination doesn't exist and the amount is below the existential deposit.
+    BelowExistentialDeposit = 1,
+    /// Transfer failed, e.g. the contract balance is insufficient.
+    TransferFailed = 2,
+}
+
+impl From<NativeTransferResult> for u32 {
+    fn from(input: NativeTransferResult) -> Self {
+        input as u32
+    }
+}
+
+/// Native transfer chain extension.
+///
+/// `TransferToEvm` takes the SCALE encoded `(H160, Balance)` pair as input and moves the
+/// amount from the contract to the native account mapped from the EVM address, which is
+/// the account whose balance the EVM address spends, as resolved by `M`.
+pub struct NativeTransferExtension<T, M>(PhantomData<(T, M)>);
+
+impl<T, M> Default for NativeTransferExtension<T, M> {
+    fn default() -> Self {
+        NativeTransferExtension(PhantomData)
+    }
+}
+
+impl<T, M> ChainExtension<T> for NativeTransferExtension<T, M>
+where
+    T: pallet_evm::Config + pallet_contracts::Config,
+    M: AddressMapper<T::AccountId>,
+{
+    fn call<E: Ext>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+    where
+        E: Ext<T = T>,
+    {
+        let func_id = env.func_id().try_into()?;
+        let mut env = env.buf_in_buf_out();
+
+        match func_id {
+            NativeTransferFuncId::TransferToEvm => {
+                let (dest, amount): (H160, BalanceOf<T>) = env.read_as()?;
+
+                // Storage items: System::Account (contract & destination)
+                let base_weight = <T as frame_system::Config>::DbWeight::get().reads_writes(2, 2);
+                env.charge_weight(base_weight)?;
+
+                // Similar to EVM behavior, the source is the contract itself.
+                let source = env.ext().address().clone();
+                let dest = M::into_account_id(dest);
+
+                if CurrencyOf::<T>::total_balance(&dest).is_zero()
+                    && amount < CurrencyOf::<T>::minimum_balance()
+                {
+                    return Ok(RetVal::Converging(
+                        NativeTransferResult::BelowExistentialDeposit.into(),
+                    ));
+                }
+
+                // Contracts must stay alive, same as for transfers done by `pallet-contracts`.
+                let result = match CurrencyOf::<T>::transfer(
+                    &source,
+                    &dest,
+                    amount,
+                    ExistenceRequirement::KeepAlive,
+                ) {
+                    Ok(()) => NativeTransferResult::Ok,
+                    Err(_) => NativeTransferResult::TransferFailed,
+                };
+
+                Ok(RetVal::Converging(result.into()))
+            }
+        }
+    }
+}
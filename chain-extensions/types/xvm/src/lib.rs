@@ -43,6 +43,9 @@ impl From<CallError> for XvmExecutionResult {
             InputTooLarge => 4,
             ReentranceDenied => 5,
             ExecutionFailed(_) => 6,
+            QueueFull => 7,
+            WeightLimitTooHigh => 8,
+            InsufficientBalance => 9,
         };
         Self::Err(error_code)
     }
@@ -68,3 +71,19 @@ pub struct XvmCallArgs {
     /// Value to transfer
     pub value: Balance,
 }
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug)]
+pub struct XvmAsyncCallArgs {
+    /// virtual machine identifier
+    pub vm_id: u8,
+    /// Call destination (e.g. address)
+    pub to: Vec<u8>,
+    /// Encoded call params
+    pub input: Vec<u8>,
+    /// Value to transfer
+    pub value: Balance,
+    /// Reserved ref time of the call
+    pub ref_time_limit: u64,
+    /// Reserved proof size of the call
+    pub proof_size_limit: u64,
+}
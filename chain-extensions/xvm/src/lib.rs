@@ -18,15 +18,16 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use astar_primitives::xvm::{Context, VmId, XvmCall};
-use frame_support::dispatch::Encode;
+use astar_primitives::xvm::{Context, VmId, XvmAsyncCall, XvmCall};
+use frame_support::{dispatch::Encode, weights::Weight};
 use pallet_contracts::chain_extension::{ChainExtension, Environment, Ext, InitState, RetVal};
 use sp_runtime::DispatchError;
 use sp_std::marker::PhantomData;
-use xvm_chain_extension_types::{XvmCallArgs, XvmExecutionResult};
+use xvm_chain_extension_types::{XvmAsyncCallArgs, XvmCallArgs, XvmExecutionResult};
 
 enum XvmFuncId {
     Call,
+    AsyncCall,
 }
 
 impl TryFrom<u16> for XvmFuncId {
@@ -35,6 +36,7 @@ impl TryFrom<u16> for XvmFuncId {
     fn try_from(value: u16) -> Result<Self, Self::Error> {
         match value {
             1 => Ok(XvmFuncId::Call),
+            2 => Ok(XvmFuncId::AsyncCall),
             _ => Err(DispatchError::Other(
                 "Unsupported func id in Xvm chain extension",
             )),
@@ -54,7 +56,7 @@ impl<T, XC> Default for XvmExtension<T, XC> {
 impl<T, XC> ChainExtension<T> for XvmExtension<T, XC>
 where
     T: pallet_contracts::Config,
-    XC: XvmCall<T::AccountId>,
+    XC: XvmCall<T::AccountId> + XvmAsyncCall<T::AccountId>,
 {
     fn call<E: Ext>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
     where
@@ -130,6 +132,55 @@ where
                     }
                 }
             }
+
+            XvmFuncId::AsyncCall => {
+                // Only enqueueing is charged here, the price of the reserved weight is held
+                // from the contract until the call is executed.
+                env.charge_weight(XC::enqueue_call_weight())?;
+
+                let XvmAsyncCallArgs {
+                    vm_id,
+                    to,
+                    input,
+                    value,
+                    ref_time_limit,
+                    proof_size_limit,
+                } = env.read_as_unbounded(env.in_len())?;
+
+                // As for synchronous calls, the `source` is the contract address,
+                // and the result is delivered back to it.
+                let source = env.ext().address().clone();
+
+                let xvm_context = Context {
+                    source_vm_id: VmId::Wasm,
+                    weight_limit: Weight::from_parts(ref_time_limit, proof_size_limit),
+                };
+                let vm_id = {
+                    match TryInto::<VmId>::try_into(vm_id) {
+                        Ok(id) => id,
+                        Err(err) => {
+                            let result = Into::<XvmExecutionResult>::into(err);
+                            return Ok(RetVal::Converging(result.into()));
+                        }
+                    }
+                };
+                let callback = source.encode();
+                match XC::enqueue_call(xvm_context, vm_id, source, to, input, value, callback) {
+                    Ok(id) => {
+                        env.write(&id.encode(), false, None)?;
+                        Ok(RetVal::Converging(XvmExecutionResult::Ok.into()))
+                    }
+                    Err(err) => {
+                        log::trace!(
+                            target: "xvm-extension::xvm_async_call",
+                            "err: {:?}", err
+                        );
+
+                        let result = Into::<XvmExecutionResult>::into(err);
+                        Ok(RetVal::Converging(result.into()))
+                    }
+                }
+            }
         }
     }
 }
@@ -0,0 +1,59 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as AddressBook;
+
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+fn max_name<T: Config>() -> AddressName<T> {
+    vec![b'a'; T::MaxNameLength::get() as usize]
+        .try_into()
+        .expect("Length is within bounds; qed")
+}
+
+benchmarks! {
+
+    set_address {
+        let name = max_name::<T>();
+        let native: T::AccountId = account("native", 0, 0);
+        let evm = H160::repeat_byte(0x01);
+
+    }: _(RawOrigin::Root, name.clone(), Some(native.clone()), Some(evm))
+    verify {
+        assert_eq!(Entries::<T>::get(&name), Some(AddressRecord { native: Some(native), evm: Some(evm) }));
+    }
+
+    remove_address {
+        let name = max_name::<T>();
+        let native: T::AccountId = account("native", 0, 0);
+        AddressBook::<T>::set_address(RawOrigin::Root.into(), name.clone(), Some(native), None)?;
+
+    }: _(RawOrigin::Root, name.clone())
+    verify {
+        assert!(!Entries::<T>::contains_key(&name));
+    }
+}
+
+impl_benchmark_test_suite!(
+    AddressBook,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
@@ -0,0 +1,191 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Address Book Pallet
+//!
+//! ## Overview
+//!
+//! Registry of canonical, human readable address names (e.g. `treasury`, `router`, `usdt`).
+//!
+//! Each name maps to an [`AddressRecord`] which can hold a native account, an EVM address, or both.
+//! This allows on-chain programs, regardless whether they are EVM or WASM smart contracts,
+//! to reference infrastructure by name instead of hardcoding addresses which differ between networks.
+//!
+//! Entries are managed by `ManagerOrigin`, which should most likely be root or governance.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `set_address` - creates or overwrites the record for the given name
+//! - `remove_address` - removes the record for the given name
+//!
+//! ### Other
+//!
+//! - `native_address` - returns native account registered under the name, if any
+//! - `evm_address` - returns EVM address registered under the name, if any
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_core::H160;
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Name under which an address record is stored.
+pub type AddressName<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+/// Addresses registered under a single name.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AddressRecord<AccountId> {
+    /// Native (Substrate) account.
+    pub native: Option<AccountId>,
+    /// EVM address.
+    pub evm: Option<H160>,
+}
+
+impl<AccountId> AddressRecord<AccountId> {
+    /// `true` if record holds neither native nor EVM address.
+    pub fn is_empty(&self) -> bool {
+        self.native.is_none() && self.evm.is_none()
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The required origin for managing address book entries.
+        ///
+        /// Should most likely be root or governance.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Maximum length of an address name, in bytes.
+        #[pallet::constant]
+        type MaxNameLength: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Address name cannot be empty.
+        EmptyName,
+        /// Record must contain at least one address.
+        EmptyRecord,
+        /// No record is registered under the given name.
+        NotFound,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Address record was created or updated.
+        AddressSet {
+            name: AddressName<T>,
+            record: AddressRecord<T::AccountId>,
+        },
+        /// Address record was removed.
+        AddressRemoved { name: AddressName<T> },
+    }
+
+    /// Address records, keyed by name.
+    #[pallet::storage]
+    #[pallet::getter(fn entries)]
+    pub type Entries<T: Config> =
+        StorageMap<_, Blake2_128Concat, AddressName<T>, AddressRecord<T::AccountId>, OptionQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the addresses registered under `name`, overwriting any previous record.
+        ///
+        /// At least one of `native` or `evm` must be provided.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_address())]
+        pub fn set_address(
+            origin: OriginFor<T>,
+            name: AddressName<T>,
+            native: Option<T::AccountId>,
+            evm: Option<H160>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(!name.is_empty(), Error::<T>::EmptyName);
+            let record = AddressRecord { native, evm };
+            ensure!(!record.is_empty(), Error::<T>::EmptyRecord);
+
+            Entries::<T>::insert(&name, record.clone());
+
+            Self::deposit_event(Event::AddressSet { name, record });
+            Ok(())
+        }
+
+        /// Remove the record registered under `name`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_address())]
+        pub fn remove_address(origin: OriginFor<T>, name: AddressName<T>) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(Entries::<T>::contains_key(&name), Error::<T>::NotFound);
+            Entries::<T>::remove(&name);
+
+            Self::deposit_event(Event::AddressRemoved { name });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Record registered under raw `name`, if any.
+        ///
+        /// Names exceeding `MaxNameLength` can never be registered, so `None` is returned for them.
+        pub fn lookup(name: &[u8]) -> Option<AddressRecord<T::AccountId>> {
+            let name = AddressName::<T>::try_from(name.to_vec()).ok()?;
+            Entries::<T>::get(name)
+        }
+
+        /// Native account registered under `name`, if any.
+        pub fn native_address(name: &[u8]) -> Option<T::AccountId> {
+            Self::lookup(name).and_then(|record| record.native)
+        }
+
+        /// EVM address registered under `name`, if any.
+        pub fn evm_address(name: &[u8]) -> Option<H160> {
+            Self::lookup(name).and_then(|record| record.evm)
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::{pallet::Error, pallet::Event, *};
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+fn name(raw: &[u8]) -> AddressName<Test> {
+    raw.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn only_manager_origin() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            AddressBook::set_address(RuntimeOrigin::signed(1), name(b"treasury"), Some(1), None),
+            BadOrigin
+        );
+        assert_noop!(
+            AddressBook::remove_address(RuntimeOrigin::signed(1), name(b"treasury")),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn set_address_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let evm = H160::repeat_byte(0xAA);
+        assert_ok!(AddressBook::set_address(
+            RuntimeOrigin::root(),
+            name(b"router"),
+            Some(7),
+            Some(evm)
+        ));
+
+        let record = AddressRecord {
+            native: Some(7),
+            evm: Some(evm),
+        };
+        System::assert_last_event(mock::RuntimeEvent::AddressBook(Event::AddressSet {
+            name: name(b"router"),
+            record: record.clone(),
+        }));
+        assert_eq!(Entries::<Test>::get(name(b"router")), Some(record));
+        assert_eq!(AddressBook::native_address(b"router"), Some(7));
+        assert_eq!(AddressBook::evm_address(b"router"), Some(evm));
+
+        // Overwrite with native address only
+        assert_ok!(AddressBook::set_address(
+            RuntimeOrigin::root(),
+            name(b"router"),
+            Some(8),
+            None
+        ));
+        assert_eq!(AddressBook::native_address(b"router"), Some(8));
+        assert_eq!(AddressBook::evm_address(b"router"), None);
+    })
+}
+
+#[test]
+fn set_address_fails_for_invalid_input() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            AddressBook::set_address(RuntimeOrigin::root(), name(b""), Some(1), None),
+            Error::<Test>::EmptyName
+        );
+        assert_noop!(
+            AddressBook::set_address(RuntimeOrigin::root(), name(b"usdt"), None, None),
+            Error::<Test>::EmptyRecord
+        );
+    })
+}
+
+#[test]
+fn remove_address_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            AddressBook::remove_address(RuntimeOrigin::root(), name(b"usdt")),
+            Error::<Test>::NotFound
+        );
+
+        assert_ok!(AddressBook::set_address(
+            RuntimeOrigin::root(),
+            name(b"usdt"),
+            None,
+            Some(H160::repeat_byte(0x01))
+        ));
+        assert_ok!(AddressBook::remove_address(
+            RuntimeOrigin::root(),
+            name(b"usdt")
+        ));
+
+        System::assert_last_event(mock::RuntimeEvent::AddressBook(Event::AddressRemoved {
+            name: name(b"usdt"),
+        }));
+        assert!(AddressBook::lookup(b"usdt").is_none());
+    })
+}
+
+#[test]
+fn lookup_of_too_long_name_is_none() {
+    ExternalityBuilder::build().execute_with(|| {
+        let too_long = vec![b'a'; MAX_NAME_LENGTH as usize + 1];
+        assert!(AddressBook::lookup(&too_long).is_none());
+    })
+}
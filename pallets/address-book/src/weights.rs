@@ -0,0 +1,71 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_address_book
+//!
+//! TODO: these weights were not generated by the benchmark CLI. They are estimated from the storage
+//! accessed by each extrinsic and must be replaced by the output of the `pallet_address_book`
+//! benchmarks, run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_address_book.
+pub trait WeightInfo {
+	fn set_address() -> Weight;
+	fn remove_address() -> Weight;
+}
+
+/// Weights for pallet_address_book using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: AddressBook Entries (r:0 w:1)
+	// Proof: AddressBook Entries (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	fn set_address() -> Weight {
+		Weight::from_parts(11_562_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: AddressBook Entries (r:1 w:1)
+	// Proof: AddressBook Entries (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	fn remove_address() -> Weight {
+		Weight::from_parts(15_419_000, 3604)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: AddressBook Entries (r:0 w:1)
+	// Proof: AddressBook Entries (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	fn set_address() -> Weight {
+		Weight::from_parts(11_562_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: AddressBook Entries (r:1 w:1)
+	// Proof: AddressBook Entries (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	fn remove_address() -> Weight {
+		Weight::from_parts(15_419_000, 3604)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,81 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as AssetsSupplyHistory;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::Hooks;
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+
+/// Create `n` assets and start tracking them.
+fn track_assets<T: Config>(n: u32) -> Vec<T::AssetId> {
+    (0..n)
+        .map(|index| {
+            let asset_id = T::BenchmarkHelper::create_asset(index);
+            assert!(
+                AssetsSupplyHistory::<T>::track_asset(RawOrigin::Root.into(), asset_id).is_ok()
+            );
+            asset_id
+        })
+        .collect()
+}
+
+benchmarks! {
+
+    on_initialize {
+        let x in 0 .. T::MaxTrackedAssets::get();
+        let assets = track_assets::<T>(x);
+        let block_number: BlockNumberFor<T> = 2u32.into();
+
+    }: {
+        AssetsSupplyHistory::<T>::on_initialize(block_number);
+    }
+    verify {
+        for asset_id in assets {
+            assert_eq!(SupplyHistory::<T>::get(asset_id).len(), 1);
+        }
+    }
+
+    track_asset {
+        let _ = track_assets::<T>(T::MaxTrackedAssets::get().saturating_sub(1));
+        let asset_id = T::BenchmarkHelper::create_asset(T::MaxTrackedAssets::get());
+
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(TrackedAssets::<T>::get().contains(&asset_id));
+    }
+
+    untrack_asset {
+        let assets = track_assets::<T>(T::MaxTrackedAssets::get());
+        let asset_id = *assets.last().expect("At least one asset is tracked; qed");
+        AssetsSupplyHistory::<T>::on_initialize(2u32.into());
+
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(!TrackedAssets::<T>::get().contains(&asset_id));
+        assert!(SupplyHistory::<T>::get(asset_id).is_empty());
+    }
+}
+
+impl_benchmark_test_suite!(
+    AssetsSupplyHistory,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
@@ -0,0 +1,244 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Assets Supply History Pallet
+//!
+//! ## Overview
+//!
+//! Keeps a bounded history of total supply for assets selected by `ManagerOrigin`,
+//! so contracts can do checkpoint based voting with governance tokens built on top of `pallet-assets`.
+//!
+//! At the start of each block, supply of every tracked asset is compared with its latest snapshot.
+//! If it changed, a new snapshot is recorded for the previous block. Only the latest `HistoryDepth`
+//! snapshots are kept, older ones are dropped.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `track_asset` - starts recording supply history of an asset
+//! - `untrack_asset` - stops recording supply history of an asset and removes it
+//!
+//! ### Other
+//!
+//! - `total_supply_at` - returns total supply of an asset at the end of a past block, if known
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, traits::fungibles};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{One, Saturating};
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Balance type of the tracked assets.
+pub type BalanceOf<T> =
+    <<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Supply snapshots of an asset, ordered by block number.
+pub type HistoryOf<T> = BoundedVec<(BlockNumberFor<T>, BalanceOf<T>), <T as Config>::HistoryDepth>;
+
+/// Helper for creating assets in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+    /// Create a new asset, unique for the given `index`.
+    fn create_asset(index: u32) -> AssetId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Identifier of an asset.
+        type AssetId: Parameter + Member + Copy + MaxEncodedLen;
+
+        /// Assets whose supply can be tracked.
+        type Assets: fungibles::Inspect<Self::AccountId, AssetId = Self::AssetId>;
+
+        /// The required origin for selecting tracked assets.
+        ///
+        /// Should most likely be root or governance.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Maximum number of assets tracked at once.
+        #[pallet::constant]
+        type MaxTrackedAssets: Get<u32>;
+
+        /// Maximum number of supply snapshots kept per asset.
+        #[pallet::constant]
+        type HistoryDepth: Get<u32>;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Asset doesn't exist.
+        UnknownAsset,
+        /// Asset is already tracked.
+        AlreadyTracked,
+        /// Asset isn't tracked.
+        NotTracked,
+        /// Maximum number of tracked assets has been reached.
+        TooManyTrackedAssets,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Supply history of the asset is now recorded.
+        AssetTracked { asset_id: T::AssetId },
+        /// Supply history of the asset is no longer recorded and was removed.
+        AssetUntracked { asset_id: T::AssetId },
+    }
+
+    /// Assets whose supply history is recorded.
+    #[pallet::storage]
+    #[pallet::getter(fn tracked_assets)]
+    pub type TrackedAssets<T: Config> =
+        StorageValue<_, BoundedVec<T::AssetId, T::MaxTrackedAssets>, ValueQuery>;
+
+    /// Supply snapshots of tracked assets.
+    #[pallet::storage]
+    #[pallet::getter(fn supply_history)]
+    pub type SupplyHistory<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, HistoryOf<T>, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let tracked = TrackedAssets::<T>::get();
+            let previous = now.saturating_sub(One::one());
+
+            for asset_id in tracked.iter() {
+                Self::snapshot(*asset_id, previous);
+            }
+
+            T::WeightInfo::on_initialize(tracked.len() as u32)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Start recording supply history of the asset.
+        ///
+        /// The first snapshot is recorded at the start of the next block.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::track_asset())]
+        pub fn track_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                <T::Assets as fungibles::Inspect<_>>::asset_exists(asset_id),
+                Error::<T>::UnknownAsset
+            );
+
+            TrackedAssets::<T>::try_mutate(|tracked| -> DispatchResult {
+                ensure!(!tracked.contains(&asset_id), Error::<T>::AlreadyTracked);
+                tracked
+                    .try_push(asset_id)
+                    .map_err(|_| Error::<T>::TooManyTrackedAssets)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::AssetTracked { asset_id });
+
+            Ok(())
+        }
+
+        /// Stop recording supply history of the asset and remove the recorded snapshots.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::untrack_asset())]
+        pub fn untrack_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            TrackedAssets::<T>::try_mutate(|tracked| -> DispatchResult {
+                let index = tracked
+                    .iter()
+                    .position(|id| *id == asset_id)
+                    .ok_or(Error::<T>::NotTracked)?;
+                tracked.remove(index);
+                Ok(())
+            })?;
+            SupplyHistory::<T>::remove(asset_id);
+
+            Self::deposit_event(Event::AssetUntracked { asset_id });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Total supply of the asset at the end of the given block.
+        ///
+        /// `None` if the block isn't in the past, or if it precedes the recorded history.
+        pub fn total_supply_at(
+            asset_id: T::AssetId,
+            block_number: BlockNumberFor<T>,
+        ) -> Option<BalanceOf<T>> {
+            if block_number >= frame_system::Pallet::<T>::block_number() {
+                return None;
+            }
+
+            SupplyHistory::<T>::get(asset_id)
+                .iter()
+                .rev()
+                .find(|(at, _)| *at <= block_number)
+                .map(|(_, supply)| *supply)
+        }
+
+        /// Record supply of the asset for the given block, unless it didn't change.
+        fn snapshot(asset_id: T::AssetId, block_number: BlockNumberFor<T>) {
+            let supply = <T::Assets as fungibles::Inspect<_>>::total_issuance(asset_id);
+
+            let mut history = SupplyHistory::<T>::get(asset_id);
+            if history.last().map(|(_, last)| *last) == Some(supply) {
+                return;
+            }
+
+            if history.len() as u32 >= T::HistoryDepth::get() && !history.is_empty() {
+                history.remove(0);
+            }
+            if history.try_push((block_number, supply)).is_ok() {
+                SupplyHistory::<T>::insert(asset_id, history);
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::{pallet::Error, pallet::Event, *};
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+const ASSET_ID: AssetId = 7;
+
+fn create_asset(asset_id: AssetId) {
+    assert_ok!(Assets::force_create(
+        RuntimeOrigin::root(),
+        asset_id,
+        ALICE,
+        true,
+        1
+    ));
+}
+
+fn mint(amount: Balance) {
+    assert_ok!(Assets::mint(
+        RuntimeOrigin::signed(ALICE),
+        ASSET_ID,
+        ALICE,
+        amount
+    ));
+}
+
+#[test]
+fn only_manager_origin() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_asset(ASSET_ID);
+        assert_noop!(
+            AssetsSupplyHistory::track_asset(RuntimeOrigin::signed(ALICE), ASSET_ID),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetsSupplyHistory::untrack_asset(RuntimeOrigin::signed(ALICE), ASSET_ID),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn track_and_untrack_asset_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_asset(ASSET_ID);
+
+        assert_ok!(AssetsSupplyHistory::track_asset(
+            RuntimeOrigin::root(),
+            ASSET_ID
+        ));
+        System::assert_last_event(mock::RuntimeEvent::AssetsSupplyHistory(
+            Event::AssetTracked { asset_id: ASSET_ID },
+        ));
+        assert_eq!(
+            AssetsSupplyHistory::tracked_assets().into_inner(),
+            vec![ASSET_ID]
+        );
+
+        mint(100);
+        run_to_next_block();
+        assert!(!AssetsSupplyHistory::supply_history(ASSET_ID).is_empty());
+
+        assert_ok!(AssetsSupplyHistory::untrack_asset(
+            RuntimeOrigin::root(),
+            ASSET_ID
+        ));
+        System::assert_last_event(mock::RuntimeEvent::AssetsSupplyHistory(
+            Event::AssetUntracked { asset_id: ASSET_ID },
+        ));
+        assert!(AssetsSupplyHistory::tracked_assets().is_empty());
+        assert!(AssetsSupplyHistory::supply_history(ASSET_ID).is_empty());
+    })
+}
+
+#[test]
+fn track_asset_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            AssetsSupplyHistory::track_asset(RuntimeOrigin::root(), ASSET_ID),
+            Error::<Test>::UnknownAsset
+        );
+
+        for asset_id in 0..MAX_TRACKED_ASSETS {
+            create_asset(asset_id);
+            assert_ok!(AssetsSupplyHistory::track_asset(
+                RuntimeOrigin::root(),
+                asset_id
+            ));
+        }
+        assert_noop!(
+            AssetsSupplyHistory::track_asset(RuntimeOrigin::root(), 0),
+            Error::<Test>::AlreadyTracked
+        );
+
+        create_asset(ASSET_ID);
+        assert_noop!(
+            AssetsSupplyHistory::track_asset(RuntimeOrigin::root(), ASSET_ID),
+            Error::<Test>::TooManyTrackedAssets
+        );
+        assert_noop!(
+            AssetsSupplyHistory::untrack_asset(RuntimeOrigin::root(), ASSET_ID),
+            Error::<Test>::NotTracked
+        );
+    })
+}
+
+#[test]
+fn total_supply_at_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_asset(ASSET_ID);
+        assert_ok!(AssetsSupplyHistory::track_asset(
+            RuntimeOrigin::root(),
+            ASSET_ID
+        ));
+
+        // Block 1: 100
+        mint(100);
+        run_to_next_block();
+        // Block 2: unchanged, no new snapshot
+        run_to_next_block();
+        // Block 3: 150
+        mint(50);
+        run_to_next_block();
+
+        assert_eq!(
+            AssetsSupplyHistory::supply_history(ASSET_ID).into_inner(),
+            vec![(1, 100), (3, 150)]
+        );
+        assert_eq!(AssetsSupplyHistory::total_supply_at(ASSET_ID, 0), None);
+        assert_eq!(AssetsSupplyHistory::total_supply_at(ASSET_ID, 1), Some(100));
+        assert_eq!(AssetsSupplyHistory::total_supply_at(ASSET_ID, 2), Some(100));
+        assert_eq!(AssetsSupplyHistory::total_supply_at(ASSET_ID, 3), Some(150));
+
+        // Current block isn't finished yet
+        mint(50);
+        assert_eq!(AssetsSupplyHistory::total_supply_at(ASSET_ID, 4), None);
+    })
+}
+
+#[test]
+fn history_is_bounded() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_asset(ASSET_ID);
+        assert_ok!(AssetsSupplyHistory::track_asset(
+            RuntimeOrigin::root(),
+            ASSET_ID
+        ));
+
+        for _ in 0..HISTORY_DEPTH + 1 {
+            mint(10);
+            run_to_next_block();
+        }
+
+        let history = AssetsSupplyHistory::supply_history(ASSET_ID);
+        assert_eq!(history.len() as u32, HISTORY_DEPTH);
+        assert_eq!(history.first(), Some(&(2, 20)));
+
+        // Dropped snapshot is no longer available
+        assert_eq!(AssetsSupplyHistory::total_supply_at(ASSET_ID, 1), None);
+        assert_eq!(AssetsSupplyHistory::total_supply_at(ASSET_ID, 2), Some(20));
+    })
+}
@@ -0,0 +1,112 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_assets_supply_history
+//!
+//! TODO: these weights were not generated by the benchmark CLI. They are estimated from the storage
+//! accessed by each extrinsic and must be replaced by the output of the
+//! `pallet_assets_supply_history` benchmarks, run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_assets_supply_history.
+pub trait WeightInfo {
+	fn on_initialize(x: u32, ) -> Weight;
+	fn track_asset() -> Weight;
+	fn untrack_asset() -> Weight;
+}
+
+/// Weights for pallet_assets_supply_history using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: AssetsSupplyHistory TrackedAssets (r:1 w:0)
+	// Proof: AssetsSupplyHistory TrackedAssets (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:20 w:0)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: AssetsSupplyHistory SupplyHistory (r:20 w:20)
+	// Proof: AssetsSupplyHistory SupplyHistory (max_values: None, max_size: Some(5154), added: 7629, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 20]`.
+	fn on_initialize(x: u32, ) -> Weight {
+		Weight::from_parts(3_784_112, 816)
+			.saturating_add(Weight::from_parts(14_930_517, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 10326).saturating_mul(x.into()))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: AssetsSupplyHistory TrackedAssets (r:1 w:1)
+	// Proof: AssetsSupplyHistory TrackedAssets (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	fn track_asset() -> Weight {
+		Weight::from_parts(19_102_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: AssetsSupplyHistory TrackedAssets (r:1 w:1)
+	// Proof: AssetsSupplyHistory TrackedAssets (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	// Storage: AssetsSupplyHistory SupplyHistory (r:0 w:1)
+	// Proof: AssetsSupplyHistory SupplyHistory (max_values: None, max_size: Some(5154), added: 7629, mode: MaxEncodedLen)
+	fn untrack_asset() -> Weight {
+		Weight::from_parts(15_861_000, 816)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: AssetsSupplyHistory TrackedAssets (r:1 w:0)
+	// Proof: AssetsSupplyHistory TrackedAssets (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	// Storage: Assets Asset (r:20 w:0)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: AssetsSupplyHistory SupplyHistory (r:20 w:20)
+	// Proof: AssetsSupplyHistory SupplyHistory (max_values: None, max_size: Some(5154), added: 7629, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 20]`.
+	fn on_initialize(x: u32, ) -> Weight {
+		Weight::from_parts(3_784_112, 816)
+			.saturating_add(Weight::from_parts(14_930_517, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 10326).saturating_mul(x.into()))
+	}
+	// Storage: Assets Asset (r:1 w:0)
+	// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	// Storage: AssetsSupplyHistory TrackedAssets (r:1 w:1)
+	// Proof: AssetsSupplyHistory TrackedAssets (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	fn track_asset() -> Weight {
+		Weight::from_parts(19_102_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: AssetsSupplyHistory TrackedAssets (r:1 w:1)
+	// Proof: AssetsSupplyHistory TrackedAssets (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	// Storage: AssetsSupplyHistory SupplyHistory (r:0 w:1)
+	// Proof: AssetsSupplyHistory SupplyHistory (max_values: None, max_size: Some(5154), added: 7629, mode: MaxEncodedLen)
+	fn untrack_asset() -> Weight {
+		Weight::from_parts(15_861_000, 816)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
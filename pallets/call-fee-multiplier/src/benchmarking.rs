@@ -0,0 +1,48 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as CallFeeMultiplier;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+fn max_name<T: Config>() -> NameOf<T> {
+    vec![b'a'; T::MaxNameLength::get() as usize]
+        .try_into()
+        .expect("Length is within bounds; qed")
+}
+
+benchmarks! {
+
+    set_call_fee_multiplier {
+        let name = max_name::<T>();
+        let multiplier = FixedU128::saturating_from_integer(10);
+
+    }: _(RawOrigin::Root, name.clone(), name.clone(), Some(multiplier))
+    verify {
+        assert_eq!(CallFeeMultipliers::<T>::get(&name, &name), Some(multiplier));
+    }
+}
+
+impl_benchmark_test_suite!(
+    CallFeeMultiplier,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
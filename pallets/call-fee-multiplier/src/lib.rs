@@ -0,0 +1,250 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Call Fee Multiplier Pallet
+//!
+//! ## Overview
+//!
+//! Allows `ManagerOrigin` to configure fee multipliers for individual calls, keyed by
+//! `(pallet name, call name)`. This way spam-prone calls can be made more expensive
+//! without touching their benchmarked weights.
+//!
+//! Multiplier is applied by [`CallFeeMultiplierAdapter`], which wraps the runtime's
+//! `OnChargeTransaction` implementation. Only the inclusion fee is multiplied, the tip is left intact.
+//! The same multiplier is applied when the fee is corrected after dispatch.
+//!
+//! Note that fee estimation via `TransactionPaymentApi` doesn't take the multiplier into account.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `set_call_fee_multiplier` - sets or removes the fee multiplier of a call
+//!
+//! ### Other
+//!
+//! - `multiplier_for` - returns the multiplier applicable for the call, if any
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{dispatch::GetCallMetadata, pallet_prelude::*};
+use frame_system::pallet_prelude::*;
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{
+    traits::{DispatchInfoOf, PostDispatchInfoOf, Saturating, Zero},
+    transaction_validity::TransactionValidityError,
+    FixedPointNumber, FixedPointOperand, FixedU128,
+};
+use sp_std::marker::PhantomData;
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Pallet or call name, as returned by `GetCallMetadata`.
+pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The required origin for managing fee multipliers.
+        ///
+        /// Should most likely be root or governance.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Maximum length of pallet or call name, in bytes.
+        #[pallet::constant]
+        type MaxNameLength: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Multiplier must be greater than zero.
+        ZeroMultiplier,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Fee multiplier of the call was set.
+        CallFeeMultiplierSet {
+            pallet_name: NameOf<T>,
+            call_name: NameOf<T>,
+            multiplier: FixedU128,
+        },
+        /// Fee multiplier of the call was removed.
+        CallFeeMultiplierRemoved {
+            pallet_name: NameOf<T>,
+            call_name: NameOf<T>,
+        },
+    }
+
+    /// Fee multipliers, keyed by pallet and call name.
+    #[pallet::storage]
+    #[pallet::getter(fn call_fee_multipliers)]
+    pub type CallFeeMultipliers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        NameOf<T>,
+        Blake2_128Concat,
+        NameOf<T>,
+        FixedU128,
+        OptionQuery,
+    >;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the fee multiplier of the call, or remove it if `multiplier` is `None`.
+        ///
+        /// Names must match the ones returned by `GetCallMetadata`, e.g. `("DappsStaking", "unbond_and_unstake")`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_call_fee_multiplier())]
+        pub fn set_call_fee_multiplier(
+            origin: OriginFor<T>,
+            pallet_name: NameOf<T>,
+            call_name: NameOf<T>,
+            multiplier: Option<FixedU128>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            match multiplier {
+                Some(multiplier) => {
+                    ensure!(!multiplier.is_zero(), Error::<T>::ZeroMultiplier);
+                    CallFeeMultipliers::<T>::insert(&pallet_name, &call_name, multiplier);
+                    Self::deposit_event(Event::CallFeeMultiplierSet {
+                        pallet_name,
+                        call_name,
+                        multiplier,
+                    });
+                }
+                None => {
+                    CallFeeMultipliers::<T>::remove(&pallet_name, &call_name);
+                    Self::deposit_event(Event::CallFeeMultiplierRemoved {
+                        pallet_name,
+                        call_name,
+                    });
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Fee multiplier configured for the call, if any.
+        pub fn multiplier_for<C: GetCallMetadata>(call: &C) -> Option<FixedU128> {
+            let metadata = call.get_call_metadata();
+            let pallet_name =
+                NameOf::<T>::try_from(metadata.pallet_name.as_bytes().to_vec()).ok()?;
+            let call_name =
+                NameOf::<T>::try_from(metadata.function_name.as_bytes().to_vec()).ok()?;
+
+            CallFeeMultipliers::<T>::get(pallet_name, call_name)
+        }
+    }
+}
+
+/// Wrapper around `OnChargeTransaction` implementation which applies the configured call fee multipliers.
+pub struct CallFeeMultiplierAdapter<T, OCT>(PhantomData<(T, OCT)>);
+
+impl<T, OCT> CallFeeMultiplierAdapter<T, OCT>
+where
+    T: Config + pallet_transaction_payment::Config,
+    OCT: OnChargeTransaction<T>,
+    OCT::Balance: FixedPointOperand,
+{
+    /// Apply `multiplier` to the inclusion fee part of the `fee`.
+    fn apply(multiplier: FixedU128, fee: OCT::Balance, tip: OCT::Balance) -> OCT::Balance {
+        let inclusion_fee = fee.saturating_sub(tip);
+        multiplier
+            .saturating_mul_int(inclusion_fee)
+            .saturating_add(tip)
+    }
+}
+
+impl<T, OCT> OnChargeTransaction<T> for CallFeeMultiplierAdapter<T, OCT>
+where
+    T: Config + pallet_transaction_payment::Config,
+    T::RuntimeCall: GetCallMetadata,
+    OCT: OnChargeTransaction<T>,
+    OCT::Balance: FixedPointOperand,
+{
+    type Balance = OCT::Balance;
+    /// Liquidity info of the wrapped implementation, together with the applied multiplier.
+    type LiquidityInfo = (OCT::LiquidityInfo, Option<FixedU128>);
+
+    fn withdraw_fee(
+        who: &T::AccountId,
+        call: &T::RuntimeCall,
+        dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        fee: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let multiplier = Pallet::<T>::multiplier_for(call);
+        let fee = match multiplier {
+            Some(multiplier) => Self::apply(multiplier, fee, tip),
+            None => fee,
+        };
+
+        OCT::withdraw_fee(who, call, dispatch_info, fee, tip).map(|info| (info, multiplier))
+    }
+
+    fn correct_and_deposit_fee(
+        who: &T::AccountId,
+        dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+        corrected_fee: Self::Balance,
+        tip: Self::Balance,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        let (already_withdrawn, multiplier) = already_withdrawn;
+        let corrected_fee = match multiplier {
+            Some(multiplier) => Self::apply(multiplier, corrected_fee, tip),
+            None => corrected_fee,
+        };
+
+        OCT::correct_and_deposit_fee(
+            who,
+            dispatch_info,
+            post_info,
+            corrected_fee,
+            tip,
+            already_withdrawn,
+        )
+    }
+}
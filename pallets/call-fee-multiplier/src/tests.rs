@@ -0,0 +1,157 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::{pallet::Error, pallet::Event, *};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchInfo};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+type Adapter = <Test as pallet_transaction_payment::Config>::OnChargeTransaction;
+
+fn name(raw: &[u8]) -> NameOf<Test> {
+    raw.to_vec().try_into().unwrap()
+}
+
+fn remark_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+#[test]
+fn only_manager_origin() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            CallFeeMultiplier::set_call_fee_multiplier(
+                RuntimeOrigin::signed(1),
+                name(b"System"),
+                name(b"remark"),
+                Some(FixedU128::saturating_from_integer(2))
+            ),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn set_and_remove_multiplier_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let multiplier = FixedU128::saturating_from_integer(3);
+        assert_ok!(CallFeeMultiplier::set_call_fee_multiplier(
+            RuntimeOrigin::root(),
+            name(b"System"),
+            name(b"remark"),
+            Some(multiplier)
+        ));
+        System::assert_last_event(mock::RuntimeEvent::CallFeeMultiplier(
+            Event::CallFeeMultiplierSet {
+                pallet_name: name(b"System"),
+                call_name: name(b"remark"),
+                multiplier,
+            },
+        ));
+        assert_eq!(
+            CallFeeMultiplier::multiplier_for(&remark_call()),
+            Some(multiplier)
+        );
+
+        assert_ok!(CallFeeMultiplier::set_call_fee_multiplier(
+            RuntimeOrigin::root(),
+            name(b"System"),
+            name(b"remark"),
+            None
+        ));
+        System::assert_last_event(mock::RuntimeEvent::CallFeeMultiplier(
+            Event::CallFeeMultiplierRemoved {
+                pallet_name: name(b"System"),
+                call_name: name(b"remark"),
+            },
+        ));
+        assert_eq!(CallFeeMultiplier::multiplier_for(&remark_call()), None);
+    })
+}
+
+#[test]
+fn zero_multiplier_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            CallFeeMultiplier::set_call_fee_multiplier(
+                RuntimeOrigin::root(),
+                name(b"System"),
+                name(b"remark"),
+                Some(FixedU128::zero())
+            ),
+            Error::<Test>::ZeroMultiplier
+        );
+    })
+}
+
+#[test]
+fn fee_without_multiplier_is_unchanged() {
+    ExternalityBuilder::build().execute_with(|| {
+        let info = DispatchInfo::default();
+        let liquidity =
+            Adapter::withdraw_fee(&ALICE, &remark_call(), &info, 100, 10).expect("Enough funds");
+
+        assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 100);
+        assert_eq!(liquidity.1, None);
+    })
+}
+
+#[test]
+fn fee_multiplier_is_applied_to_inclusion_fee() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(CallFeeMultiplier::set_call_fee_multiplier(
+            RuntimeOrigin::root(),
+            name(b"System"),
+            name(b"remark"),
+            Some(FixedU128::saturating_from_integer(3))
+        ));
+
+        // Inclusion fee of 90 is tripled, tip of 10 is left as is.
+        let info = DispatchInfo::default();
+        let liquidity =
+            Adapter::withdraw_fee(&ALICE, &remark_call(), &info, 100, 10).expect("Enough funds");
+        assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 280);
+
+        // Corrected fee is multiplied as well, difference is refunded.
+        assert_ok!(Adapter::correct_and_deposit_fee(
+            &ALICE,
+            &info,
+            &Default::default(),
+            60,
+            10,
+            liquidity
+        ));
+        assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 160);
+    })
+}
+
+#[test]
+fn unrelated_call_is_not_affected() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(CallFeeMultiplier::set_call_fee_multiplier(
+            RuntimeOrigin::root(),
+            name(b"System"),
+            name(b"remark_with_event"),
+            Some(FixedU128::saturating_from_integer(3))
+        ));
+
+        let info = DispatchInfo::default();
+        assert_ok!(Adapter::withdraw_fee(&ALICE, &remark_call(), &info, 100, 0));
+        assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 100);
+    })
+}
@@ -0,0 +1,172 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for querying EVM chain metadata.
+//!
+//! Chain metadata is returned in the form of EIP-3085 `AddEthereumChainParameter`,
+//! so it can be passed to `wallet_addEthereumChain` as is.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_chain_metadata_runtime_api::{
+    ChainMetadataApi as ChainMetadataRuntimeApi, EvmChainMetadata,
+};
+
+/// Native currency, as defined by EIP-3085.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NativeCurrency {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+/// `wallet_addEthereumChain` parameter, as defined by EIP-3085.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddEthereumChainParameter {
+    /// Hex encoded chain Id, e.g. `0x51`.
+    pub chain_id: String,
+    pub chain_name: String,
+    pub native_currency: NativeCurrency,
+    pub rpc_urls: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_explorer_urls: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_urls: Option<Vec<String>>,
+}
+
+impl From<EvmChainMetadata> for AddEthereumChainParameter {
+    fn from(metadata: EvmChainMetadata) -> Self {
+        let into_string = |bytes: Vec<u8>| String::from_utf8_lossy(&bytes).into_owned();
+        let into_strings =
+            |urls: Vec<Vec<u8>>| -> Vec<String> { urls.into_iter().map(into_string).collect() };
+        let non_empty = |urls: Vec<String>| if urls.is_empty() { None } else { Some(urls) };
+
+        Self {
+            chain_id: format!("0x{:x}", metadata.chain_id),
+            chain_name: into_string(metadata.chain_name),
+            native_currency: NativeCurrency {
+                name: into_string(metadata.currency_name),
+                symbol: into_string(metadata.currency_symbol),
+                decimals: metadata.currency_decimals,
+            },
+            rpc_urls: into_strings(metadata.rpc_urls),
+            block_explorer_urls: non_empty(into_strings(metadata.block_explorer_urls)),
+            icon_urls: non_empty(into_strings(metadata.icon_urls)),
+        }
+    }
+}
+
+#[rpc(client, server)]
+pub trait ChainMetadataApi<BlockHash> {
+    /// Returns the governance approved chain metadata as EIP-3085 `AddEthereumChainParameter`,
+    /// or `null` if it hasn't been set.
+    #[method(name = "chainMetadata_addEthereumChainParameter")]
+    fn add_ethereum_chain_parameter(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<AddEthereumChainParameter>>;
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Provides RPC methods to query EVM chain metadata.
+pub struct ChainMetadata<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> ChainMetadata<C, Block> {
+    /// Creates a new instance of the `ChainMetadata` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block> ChainMetadataApiServer<<Block as BlockT>::Hash> for ChainMetadata<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ChainMetadataRuntimeApi<Block>,
+{
+    fn add_ethereum_chain_parameter(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<AddEthereumChainParameter>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let metadata = api.chain_metadata(at_hash).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query chain metadata.",
+                Some(e.to_string()),
+            ))
+        })?;
+
+        Ok(metadata.map(Into::into))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_ethereum_chain_parameter_is_eip3085_compliant() {
+        let metadata = EvmChainMetadata {
+            chain_id: 81,
+            chain_name: b"Shibuya Testnet".to_vec(),
+            currency_name: b"Shibuya".to_vec(),
+            currency_symbol: b"SBY".to_vec(),
+            currency_decimals: 18,
+            rpc_urls: vec![b"https://evm.shibuya.astar.network".to_vec()],
+            block_explorer_urls: vec![],
+            icon_urls: vec![b"https://astar.network/icon.svg".to_vec()],
+        };
+
+        assert_eq!(
+            serde_json::to_value(AddEthereumChainParameter::from(metadata)).unwrap(),
+            serde_json::json!({
+                "chainId": "0x51",
+                "chainName": "Shibuya Testnet",
+                "nativeCurrency": {
+                    "name": "Shibuya",
+                    "symbol": "SBY",
+                    "decimals": 18
+                },
+                "rpcUrls": ["https://evm.shibuya.astar.network"],
+                "iconUrls": ["https://astar.network/icon.svg"]
+            })
+        );
+    }
+}
@@ -0,0 +1,31 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for querying EVM chain metadata.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_chain_metadata::EvmChainMetadata;
+
+sp_api::decl_runtime_apis! {
+    /// API for querying governance approved EVM chain metadata.
+    pub trait ChainMetadataApi {
+        /// EIP-3085 chain metadata, or `None` if it hasn't been set.
+        fn chain_metadata() -> Option<EvmChainMetadata>;
+    }
+}
@@ -0,0 +1,73 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as ChainMetadata;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+fn max_text<T: Config>() -> TextOf<T> {
+    vec![b'a'; T::MaxTextLength::get() as usize]
+        .try_into()
+        .expect("Length is within bounds; qed")
+}
+
+fn max_urls<T: Config>() -> UrlsOf<T> {
+    vec![max_text::<T>(); T::MaxUrls::get() as usize]
+        .try_into()
+        .expect("Length is within bounds; qed")
+}
+
+fn max_record<T: Config>() -> ChainMetadataRecord<T> {
+    ChainMetadataRecord {
+        chain_name: max_text::<T>(),
+        currency_name: max_text::<T>(),
+        currency_symbol: max_text::<T>(),
+        currency_decimals: 18,
+        rpc_urls: max_urls::<T>(),
+        block_explorer_urls: max_urls::<T>(),
+        icon_urls: max_urls::<T>(),
+    }
+}
+
+benchmarks! {
+
+    set_chain_metadata {
+        let record = max_record::<T>();
+
+    }: _(RawOrigin::Root, record.clone())
+    verify {
+        assert_eq!(Metadata::<T>::get(), Some(record));
+    }
+
+    clear_chain_metadata {
+        Metadata::<T>::put(max_record::<T>());
+
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(Metadata::<T>::get().is_none());
+    }
+}
+
+impl_benchmark_test_suite!(
+    ChainMetadata,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
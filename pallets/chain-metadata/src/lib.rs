@@ -0,0 +1,250 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Chain Metadata Pallet
+//!
+//! ## Overview
+//!
+//! Stores the canonical EVM chain metadata, as approved by `ManagerOrigin` (e.g. governance).
+//! The record contains everything a wallet needs to build an EIP-3085 `wallet_addEthereumChain`
+//! payload: chain name, native currency metadata and recommended RPC, block explorer & icon URLs.
+//! The chain Id isn't stored, it's always taken from the runtime's EVM configuration.
+//!
+//! This way wallets can configure the network from the chain itself, instead of relying on
+//! third-party lists.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `set_chain_metadata` - sets the chain metadata record
+//! - `clear_chain_metadata` - removes the chain metadata record
+//!
+//! ### Other
+//!
+//! - `evm_chain_metadata` - returns the full chain metadata, including the chain Id, used by the runtime API
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::*;
+use sp_std::vec::Vec;
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// UTF-8 encoded text, e.g. chain name or URL.
+pub type TextOf<T> = BoundedVec<u8, <T as Config>::MaxTextLength>;
+
+/// List of URLs.
+pub type UrlsOf<T> = BoundedVec<TextOf<T>, <T as Config>::MaxUrls>;
+
+/// Chain metadata record, as approved by `ManagerOrigin`.
+#[derive(
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    RuntimeDebugNoBound,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct ChainMetadataRecord<T: Config> {
+    /// Human readable chain name.
+    pub chain_name: TextOf<T>,
+    /// Native currency name.
+    pub currency_name: TextOf<T>,
+    /// Native currency symbol.
+    pub currency_symbol: TextOf<T>,
+    /// Native currency decimals.
+    pub currency_decimals: u8,
+    /// Recommended RPC endpoints, in order of preference.
+    pub rpc_urls: UrlsOf<T>,
+    /// Recommended block explorers.
+    pub block_explorer_urls: UrlsOf<T>,
+    /// Chain icons.
+    pub icon_urls: UrlsOf<T>,
+}
+
+/// EIP-3085 compatible chain metadata, as exposed via runtime API.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EvmChainMetadata {
+    /// EVM chain Id.
+    pub chain_id: u64,
+    /// Human readable chain name.
+    pub chain_name: Vec<u8>,
+    /// Native currency name.
+    pub currency_name: Vec<u8>,
+    /// Native currency symbol.
+    pub currency_symbol: Vec<u8>,
+    /// Native currency decimals.
+    pub currency_decimals: u8,
+    /// Recommended RPC endpoints, in order of preference.
+    pub rpc_urls: Vec<Vec<u8>>,
+    /// Recommended block explorers.
+    pub block_explorer_urls: Vec<Vec<u8>>,
+    /// Chain icons.
+    pub icon_urls: Vec<Vec<u8>>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// EVM chain Id.
+        type ChainId: Get<u64>;
+
+        /// The required origin for managing chain metadata.
+        ///
+        /// Should most likely be root or governance.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Maximum length of a single text field (name, symbol or URL), in bytes.
+        #[pallet::constant]
+        type MaxTextLength: Get<u32>;
+
+        /// Maximum number of URLs per URL list.
+        #[pallet::constant]
+        type MaxUrls: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Chain name is empty.
+        EmptyChainName,
+        /// Native currency name or symbol is empty.
+        EmptyCurrency,
+        /// At least one RPC URL is required.
+        NoRpcUrls,
+        /// Text field or URL isn't valid UTF-8 or is empty.
+        InvalidText,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Chain metadata record was set.
+        ChainMetadataSet,
+        /// Chain metadata record was removed.
+        ChainMetadataCleared,
+    }
+
+    /// Governance approved chain metadata record.
+    #[pallet::storage]
+    #[pallet::getter(fn chain_metadata)]
+    pub type Metadata<T: Config> = StorageValue<_, ChainMetadataRecord<T>, OptionQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the chain metadata record, replacing the existing one.
+        ///
+        /// All text fields must be valid UTF-8 and at least one RPC URL must be provided.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_chain_metadata())]
+        pub fn set_chain_metadata(
+            origin: OriginFor<T>,
+            metadata: ChainMetadataRecord<T>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(!metadata.chain_name.is_empty(), Error::<T>::EmptyChainName);
+            ensure!(
+                !metadata.currency_name.is_empty() && !metadata.currency_symbol.is_empty(),
+                Error::<T>::EmptyCurrency
+            );
+            ensure!(!metadata.rpc_urls.is_empty(), Error::<T>::NoRpcUrls);
+
+            let texts = [
+                &metadata.chain_name,
+                &metadata.currency_name,
+                &metadata.currency_symbol,
+            ];
+            let urls = metadata
+                .rpc_urls
+                .iter()
+                .chain(metadata.block_explorer_urls.iter())
+                .chain(metadata.icon_urls.iter());
+            for text in texts.into_iter().chain(urls) {
+                ensure!(
+                    !text.is_empty() && sp_std::str::from_utf8(text).is_ok(),
+                    Error::<T>::InvalidText
+                );
+            }
+
+            Metadata::<T>::put(metadata);
+
+            Self::deposit_event(Event::<T>::ChainMetadataSet);
+            Ok(())
+        }
+
+        /// Remove the chain metadata record.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::clear_chain_metadata())]
+        pub fn clear_chain_metadata(origin: OriginFor<T>) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            Metadata::<T>::kill();
+
+            Self::deposit_event(Event::<T>::ChainMetadataCleared);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Full chain metadata, including the EVM chain Id, or `None` if the record isn't set.
+        pub fn evm_chain_metadata() -> Option<EvmChainMetadata> {
+            let into_vecs =
+                |urls: UrlsOf<T>| urls.into_iter().map(|url| url.into_inner()).collect();
+
+            Metadata::<T>::get().map(|record| EvmChainMetadata {
+                chain_id: T::ChainId::get(),
+                chain_name: record.chain_name.into_inner(),
+                currency_name: record.currency_name.into_inner(),
+                currency_symbol: record.currency_symbol.into_inner(),
+                currency_decimals: record.currency_decimals,
+                rpc_urls: into_vecs(record.rpc_urls),
+                block_explorer_urls: into_vecs(record.block_explorer_urls),
+                icon_urls: into_vecs(record.icon_urls),
+            })
+        }
+    }
+}
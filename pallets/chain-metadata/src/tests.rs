@@ -0,0 +1,157 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::{pallet::Error, pallet::Event, *};
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+fn text(value: &str) -> TextOf<Test> {
+    value.as_bytes().to_vec().try_into().unwrap()
+}
+
+fn urls(values: &[&str]) -> UrlsOf<Test> {
+    values
+        .iter()
+        .map(|value| text(value))
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn record() -> ChainMetadataRecord<Test> {
+    ChainMetadataRecord {
+        chain_name: text("Shibuya Testnet"),
+        currency_name: text("Shibuya"),
+        currency_symbol: text("SBY"),
+        currency_decimals: 18,
+        rpc_urls: urls(&["https://evm.shibuya.astar.network"]),
+        block_explorer_urls: urls(&["https://shibuya.subscan.io"]),
+        icon_urls: urls(&[]),
+    }
+}
+
+#[test]
+fn set_and_clear_chain_metadata_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert!(ChainMetadata::evm_chain_metadata().is_none());
+
+        assert_ok!(ChainMetadata::set_chain_metadata(
+            RuntimeOrigin::root(),
+            record()
+        ));
+        System::assert_last_event(mock::RuntimeEvent::ChainMetadata(Event::ChainMetadataSet));
+        assert_eq!(ChainMetadata::chain_metadata(), Some(record()));
+
+        // Chain Id is taken from the config
+        assert_eq!(
+            ChainMetadata::evm_chain_metadata(),
+            Some(EvmChainMetadata {
+                chain_id: CHAIN_ID,
+                chain_name: b"Shibuya Testnet".to_vec(),
+                currency_name: b"Shibuya".to_vec(),
+                currency_symbol: b"SBY".to_vec(),
+                currency_decimals: 18,
+                rpc_urls: vec![b"https://evm.shibuya.astar.network".to_vec()],
+                block_explorer_urls: vec![b"https://shibuya.subscan.io".to_vec()],
+                icon_urls: vec![],
+            })
+        );
+
+        assert_ok!(ChainMetadata::clear_chain_metadata(RuntimeOrigin::root()));
+        System::assert_last_event(mock::RuntimeEvent::ChainMetadata(
+            Event::ChainMetadataCleared,
+        ));
+        assert!(ChainMetadata::chain_metadata().is_none());
+        assert!(ChainMetadata::evm_chain_metadata().is_none());
+    })
+}
+
+#[test]
+fn only_manager_origin_is_allowed() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            ChainMetadata::set_chain_metadata(RuntimeOrigin::signed(1), record()),
+            BadOrigin
+        );
+        assert_noop!(
+            ChainMetadata::clear_chain_metadata(RuntimeOrigin::signed(1)),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn set_chain_metadata_with_invalid_record_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            ChainMetadata::set_chain_metadata(
+                RuntimeOrigin::root(),
+                ChainMetadataRecord {
+                    chain_name: text(""),
+                    ..record()
+                }
+            ),
+            Error::<Test>::EmptyChainName
+        );
+        assert_noop!(
+            ChainMetadata::set_chain_metadata(
+                RuntimeOrigin::root(),
+                ChainMetadataRecord {
+                    currency_symbol: text(""),
+                    ..record()
+                }
+            ),
+            Error::<Test>::EmptyCurrency
+        );
+        assert_noop!(
+            ChainMetadata::set_chain_metadata(
+                RuntimeOrigin::root(),
+                ChainMetadataRecord {
+                    rpc_urls: urls(&[]),
+                    ..record()
+                }
+            ),
+            Error::<Test>::NoRpcUrls
+        );
+
+        // Invalid UTF-8
+        assert_noop!(
+            ChainMetadata::set_chain_metadata(
+                RuntimeOrigin::root(),
+                ChainMetadataRecord {
+                    currency_name: vec![0xff, 0xfe].try_into().unwrap(),
+                    ..record()
+                }
+            ),
+            Error::<Test>::InvalidText
+        );
+
+        // Empty URL
+        assert_noop!(
+            ChainMetadata::set_chain_metadata(
+                RuntimeOrigin::root(),
+                ChainMetadataRecord {
+                    icon_urls: urls(&[""]),
+                    ..record()
+                }
+            ),
+            Error::<Test>::InvalidText
+        );
+    })
+}
@@ -0,0 +1,75 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as CollatorOffences;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
+use sp_runtime::{traits::Hash, Digest, DigestItem};
+
+fn sealed_header<T: Config>(
+    number: BlockNumberFor<T>,
+    state_root: T::Hash,
+    slot: Slot,
+    author: &T::AuthorityId,
+) -> T::Header {
+    let mut header = T::Header::new(
+        number,
+        Default::default(),
+        state_root,
+        Default::default(),
+        Digest {
+            logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())],
+        },
+    );
+    let signature = author
+        .sign(&header.hash().as_ref())
+        .expect("authority was just generated; qed");
+    header
+        .digest_mut()
+        .push(DigestItem::Seal(AURA_ENGINE_ID, signature.encode()));
+    header
+}
+
+benchmarks! {
+
+    report_equivocation {
+        let authority = T::AuthorityId::generate_pair(None);
+        let offender = T::BenchmarkHelper::register_offender(&authority);
+        let slot = Slot::from(42);
+        let number = frame_system::Pallet::<T>::block_number();
+        let equivocation_proof = EquivocationProof {
+            offender: authority.clone(),
+            slot,
+            first_header: sealed_header::<T>(number, Default::default(), slot, &authority),
+            second_header: sealed_header::<T>(number, T::Hashing::hash(b"fork"), slot, &authority),
+        };
+        let caller: T::AccountId = whitelisted_caller();
+
+    }: _(RawOrigin::Signed(caller), Box::new(equivocation_proof))
+    verify {
+        assert!(Reports::<T>::contains_key(&offender, slot));
+    }
+}
+
+impl_benchmark_test_suite!(
+    CollatorOffences,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
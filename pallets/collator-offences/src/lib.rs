@@ -0,0 +1,246 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Collator Offences Pallet
+//!
+//! ## Overview
+//!
+//! Handles equivocation reports of collators, i.e. proofs that a collator has authored
+//! two different blocks on top of the same parent for the same aura slot.
+//!
+//! A report is verified on-chain by checking that both headers were built on the same parent,
+//! were produced in the reported slot and that both are sealed by the offender. Blocks built on
+//! different parents in the same slot, e.g. after a reorg, aren't an equivocation. Equivocations
+//! older than `ReportLongevity` blocks can no longer be reported. The offender's authority key is resolved to an account
+//! via `KeyOwner`, after which the offence is passed to `OnEquivocation`, which is expected to
+//! slash the offender's bond, e.g. the candidacy bond held by the collator selection pallet.
+//!
+//! Every offence can be reported only once.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `report_equivocation` - reports an equivocating collator
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+use sp_runtime::{
+    traits::{Convert, Header as HeaderT},
+    RuntimeAppPublic,
+};
+use sp_std::prelude::*;
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Proof that `offender` has authored two different blocks on the same parent in the same `slot`.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EquivocationProof<Header, AuthorityId> {
+    /// Authority which has equivocated.
+    pub offender: AuthorityId,
+    /// Slot in which the equivocation happened.
+    pub slot: Slot,
+    /// The first header authored by the offender.
+    pub first_header: Header,
+    /// The second header authored by the offender.
+    pub second_header: Header,
+}
+
+/// Handler of a verified equivocation.
+pub trait OnEquivocation<AccountId> {
+    /// Punish the `offender`.
+    fn on_equivocation(offender: &AccountId);
+}
+
+impl<AccountId> OnEquivocation<AccountId> for () {
+    fn on_equivocation(_offender: &AccountId) {}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+/// Helper for preparing an offender in benchmarks.
+pub trait BenchmarkHelper<AuthorityId, AccountId> {
+    /// Make `authority` resolvable via `Config::KeyOwner`, returning the owning account.
+    fn register_offender(authority: &AuthorityId) -> AccountId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Authority identifier used to seal blocks, same as `pallet_aura::Config::AuthorityId`.
+        type AuthorityId: Member + Parameter + RuntimeAppPublic + MaxEncodedLen;
+
+        /// Resolves the account owning the authority key, e.g. from the session keys.
+        type KeyOwner: Convert<Self::AuthorityId, Option<Self::AccountId>>;
+
+        /// Handler of verified equivocations.
+        type OnEquivocation: OnEquivocation<Self::AccountId>;
+
+        /// Number of blocks during which an equivocation can be reported, e.g. a session.
+        #[pallet::constant]
+        type ReportLongevity: Get<BlockNumberFor<Self>>;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AuthorityId, Self::AccountId>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Equivocation proof is invalid.
+        InvalidEquivocationProof,
+        /// Offender's authority key isn't owned by any account.
+        UnknownOffender,
+        /// The offence has already been reported.
+        DuplicateOffenceReport,
+        /// The equivocation is older than `ReportLongevity` blocks.
+        OutdatedReport,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Collator has equivocated and was punished.
+        EquivocationReported {
+            offender: T::AccountId,
+            slot: Slot,
+            reporter: T::AccountId,
+        },
+    }
+
+    /// Reported offences, with the block number of the report.
+    #[pallet::storage]
+    #[pallet::getter(fn reports)]
+    pub type Reports<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        Slot,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Report a collator which has authored two different blocks on the same parent
+        /// in the same slot, at most `ReportLongevity` blocks ago.
+        ///
+        /// The offender is passed to `OnEquivocation` once the proof has been verified.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::report_equivocation())]
+        pub fn report_equivocation(
+            origin: OriginFor<T>,
+            equivocation_proof: Box<EquivocationProof<T::Header, T::AuthorityId>>,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+
+            ensure!(
+                Self::check_equivocation_proof(&equivocation_proof),
+                Error::<T>::InvalidEquivocationProof
+            );
+            ensure!(
+                frame_system::Pallet::<T>::block_number()
+                    .saturating_sub(*equivocation_proof.first_header.number())
+                    <= T::ReportLongevity::get(),
+                Error::<T>::OutdatedReport
+            );
+
+            let EquivocationProof { offender, slot, .. } = *equivocation_proof;
+            let offender = T::KeyOwner::convert(offender).ok_or(Error::<T>::UnknownOffender)?;
+            ensure!(
+                !Reports::<T>::contains_key(&offender, slot),
+                Error::<T>::DuplicateOffenceReport
+            );
+
+            Reports::<T>::insert(&offender, slot, frame_system::Pallet::<T>::block_number());
+            T::OnEquivocation::on_equivocation(&offender);
+
+            Self::deposit_event(Event::EquivocationReported {
+                offender,
+                slot,
+                reporter,
+            });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Verify that both headers are different, were built on the same parent,
+        /// were produced in the reported slot and are sealed by the offender.
+        pub fn check_equivocation_proof(
+            proof: &EquivocationProof<T::Header, T::AuthorityId>,
+        ) -> bool {
+            proof.first_header.hash() != proof.second_header.hash()
+                && proof.first_header.parent_hash() == proof.second_header.parent_hash()
+                && Self::check_header(&proof.first_header, proof.slot, &proof.offender)
+                && Self::check_header(&proof.second_header, proof.slot, &proof.offender)
+        }
+
+        fn check_header(header: &T::Header, slot: Slot, offender: &T::AuthorityId) -> bool {
+            let mut header = header.clone();
+            let seal = match header.digest_mut().pop() {
+                Some(item) => item,
+                None => return false,
+            };
+            let signature = match seal
+                .seal_try_to::<<T::AuthorityId as RuntimeAppPublic>::Signature>(&AURA_ENGINE_ID)
+            {
+                Some(signature) => signature,
+                None => return false,
+            };
+
+            let pre_digest_slot = header
+                .digest()
+                .logs()
+                .iter()
+                .find_map(|item| item.pre_runtime_try_to::<Slot>(&AURA_ENGINE_ID));
+            if pre_digest_slot != Some(slot) {
+                return false;
+            }
+
+            // Seal signs the hash of the header without the seal itself.
+            let pre_hash = header.hash();
+            offender.verify(&pre_hash.as_ref(), &signature)
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_collator_offences, EquivocationProof, OnEquivocation};
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU64, Everything},
+    weights::Weight,
+};
+use parity_scale_codec::Encode;
+use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+use sp_core::H256;
+
+use sp_io::TestExternalities;
+use sp_runtime::{
+    testing::{Header, UintAuthorityId},
+    traits::{BlakeTwo256, Convert, Header as HeaderT, IdentityLookup},
+    Digest, DigestItem, RuntimeAppPublic,
+};
+
+type BlockNumber = u64;
+pub(crate) type AccountId = u64;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// Authorities with an id at or above this one have no owner.
+pub(crate) const UNKNOWN_AUTHORITY: u64 = 100;
+
+pub(crate) const REPORT_LONGEVITY: BlockNumber = 20;
+
+construct_runtime!(
+    pub struct Test
+    where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        CollatorOffences: pallet_collator_offences,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1024, 0));
+    pub static Punished: Vec<AccountId> = vec![];
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = BlockWeights;
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Index = u64;
+    type RuntimeCall = RuntimeCall;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub struct MockKeyOwner;
+impl Convert<UintAuthorityId, Option<AccountId>> for MockKeyOwner {
+    fn convert(authority: UintAuthorityId) -> Option<AccountId> {
+        if authority.0 < UNKNOWN_AUTHORITY {
+            Some(authority.0)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct MockOnEquivocation;
+impl OnEquivocation<AccountId> for MockOnEquivocation {
+    fn on_equivocation(offender: &AccountId) {
+        Punished::mutate(|punished| punished.push(*offender));
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<UintAuthorityId, AccountId> for BenchmarkHelper {
+    fn register_offender(authority: &UintAuthorityId) -> AccountId {
+        authority.0
+    }
+}
+
+impl pallet_collator_offences::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = UintAuthorityId;
+    type KeyOwner = MockKeyOwner;
+    type OnEquivocation = MockOnEquivocation;
+    type ReportLongevity = ConstU64<REPORT_LONGEVITY>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
+    type WeightInfo = ();
+}
+
+/// Header of block `number` built on `parent_hash`, with an aura pre-digest for `slot`,
+/// sealed by `author`. `fork` tells apart different blocks built on the same parent.
+pub(crate) fn sealed_header(
+    number: BlockNumber,
+    parent_hash: H256,
+    fork: u8,
+    slot: u64,
+    author: u64,
+) -> Header {
+    let mut header = Header::new(
+        number,
+        Default::default(),
+        H256::repeat_byte(fork),
+        parent_hash,
+        Digest {
+            logs: vec![DigestItem::PreRuntime(
+                AURA_ENGINE_ID,
+                Slot::from(slot).encode(),
+            )],
+        },
+    );
+    let signature = UintAuthorityId(author)
+        .sign(&header.hash().as_ref())
+        .expect("test authority can always sign; qed");
+    header
+        .digest_mut()
+        .push(DigestItem::Seal(AURA_ENGINE_ID, signature.encode()));
+    header
+}
+
+/// Proof of `offender` authoring two different blocks `1` on top of genesis in the same `slot`.
+pub(crate) fn equivocation_proof(
+    offender: u64,
+    slot: u64,
+) -> Box<EquivocationProof<Header, UintAuthorityId>> {
+    Box::new(EquivocationProof {
+        offender: UintAuthorityId(offender),
+        slot: slot.into(),
+        first_header: sealed_header(1, H256::zero(), 1, slot, offender),
+        second_header: sealed_header(1, H256::zero(), 2, slot, offender),
+    })
+}
+
+pub struct ExternalityBuilder;
+
+impl ExternalityBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
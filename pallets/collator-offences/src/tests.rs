@@ -0,0 +1,178 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::{pallet::Error, pallet::Event, *};
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_core::H256;
+use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin};
+
+#[test]
+fn report_equivocation_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(CollatorOffences::report_equivocation(
+            RuntimeOrigin::signed(7),
+            equivocation_proof(3, 42)
+        ));
+        System::assert_last_event(mock::RuntimeEvent::CollatorOffences(
+            Event::EquivocationReported {
+                offender: 3,
+                slot: 42.into(),
+                reporter: 7,
+            },
+        ));
+        assert_eq!(Punished::get(), vec![3]);
+        assert_eq!(CollatorOffences::reports(3, Slot::from(42)), Some(1));
+    })
+}
+
+#[test]
+fn report_equivocation_requires_signed_origin() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            CollatorOffences::report_equivocation(RuntimeOrigin::root(), equivocation_proof(3, 42)),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn duplicate_report_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(CollatorOffences::report_equivocation(
+            RuntimeOrigin::signed(7),
+            equivocation_proof(3, 42)
+        ));
+        assert_noop!(
+            CollatorOffences::report_equivocation(
+                RuntimeOrigin::signed(8),
+                equivocation_proof(3, 42)
+            ),
+            Error::<Test>::DuplicateOffenceReport
+        );
+
+        // same offender in another slot is a separate offence
+        assert_ok!(CollatorOffences::report_equivocation(
+            RuntimeOrigin::signed(7),
+            equivocation_proof(3, 43)
+        ));
+        assert_eq!(Punished::get(), vec![3, 3]);
+    })
+}
+
+#[test]
+fn unknown_offender_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            CollatorOffences::report_equivocation(
+                RuntimeOrigin::signed(7),
+                equivocation_proof(UNKNOWN_AUTHORITY, 42)
+            ),
+            Error::<Test>::UnknownOffender
+        );
+    })
+}
+
+#[test]
+fn invalid_equivocation_proof_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        let header = sealed_header(1, H256::zero(), 1, 42, 3);
+        let invalid_proofs = vec![
+            // same header twice
+            EquivocationProof {
+                offender: UintAuthorityId(3),
+                slot: 42.into(),
+                first_header: header.clone(),
+                second_header: header.clone(),
+            },
+            // headers from different slots
+            EquivocationProof {
+                offender: UintAuthorityId(3),
+                slot: 42.into(),
+                first_header: header.clone(),
+                second_header: sealed_header(1, H256::zero(), 2, 43, 3),
+            },
+            // second header sealed by another authority
+            EquivocationProof {
+                offender: UintAuthorityId(3),
+                slot: 42.into(),
+                first_header: header.clone(),
+                second_header: sealed_header(1, H256::zero(), 2, 42, 4),
+            },
+            // unsealed header
+            EquivocationProof {
+                offender: UintAuthorityId(3),
+                slot: 42.into(),
+                first_header: header,
+                second_header: {
+                    let mut header = sealed_header(1, H256::zero(), 2, 42, 3);
+                    header.digest.pop();
+                    header
+                },
+            },
+        ];
+
+        for proof in invalid_proofs {
+            assert_noop!(
+                CollatorOffences::report_equivocation(RuntimeOrigin::signed(7), Box::new(proof)),
+                Error::<Test>::InvalidEquivocationProof
+            );
+        }
+        assert!(Punished::get().is_empty());
+    })
+}
+
+#[test]
+fn blocks_on_different_parents_are_not_an_equivocation() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Same slot on two forks, e.g. the collator rebuilt its block after a reorg.
+        let proof = EquivocationProof {
+            offender: UintAuthorityId(3),
+            slot: 42.into(),
+            first_header: sealed_header(1, H256::repeat_byte(1), 1, 42, 3),
+            second_header: sealed_header(1, H256::repeat_byte(2), 1, 42, 3),
+        };
+        assert_noop!(
+            CollatorOffences::report_equivocation(RuntimeOrigin::signed(7), Box::new(proof)),
+            Error::<Test>::InvalidEquivocationProof
+        );
+        assert!(Punished::get().is_empty());
+    })
+}
+
+#[test]
+fn outdated_report_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Still reportable at the end of the window
+        System::set_block_number(1 + REPORT_LONGEVITY);
+        assert_ok!(CollatorOffences::report_equivocation(
+            RuntimeOrigin::signed(7),
+            equivocation_proof(3, 42)
+        ));
+
+        System::set_block_number(2 + REPORT_LONGEVITY);
+        assert_noop!(
+            CollatorOffences::report_equivocation(
+                RuntimeOrigin::signed(7),
+                equivocation_proof(4, 42)
+            ),
+            Error::<Test>::OutdatedReport
+        );
+        assert_eq!(Punished::get(), vec![3]);
+    })
+}
@@ -0,0 +1,83 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! Placeholder weights for pallet_collator_offences
+//!
+//! TODO: these weights were not generated by the benchmark CLI. They are estimated from the storage
+//! accessed by each extrinsic and must be replaced by the output of the `pallet_collator_offences`
+//! benchmarks, run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_collator_offences.
+pub trait WeightInfo {
+	fn report_equivocation() -> Weight;
+}
+
+/// Weights for pallet_collator_offences using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Session KeyOwner (r:1 w:0)
+	// Proof Skipped: Session KeyOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorOffences Reports (r:1 w:1)
+	// Proof: CollatorOffences Reports (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: CollatorSelection Candidates (r:1 w:1)
+	// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: CollatorSelection SlashDestination (r:1 w:0)
+	// Proof Skipped: CollatorSelection SlashDestination (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection LastAuthoredBlock (r:0 w:1)
+	// Proof Skipped: CollatorSelection LastAuthoredBlock (max_values: None, max_size: None, mode: Measured)
+	fn report_equivocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1024`
+		//  Estimated: `23448`
+		Weight::from_parts(120_913_000, 23448)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Session KeyOwner (r:1 w:0)
+	// Proof Skipped: Session KeyOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: CollatorOffences Reports (r:1 w:1)
+	// Proof: CollatorOffences Reports (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: CollatorSelection Candidates (r:1 w:1)
+	// Proof Skipped: CollatorSelection Candidates (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: CollatorSelection SlashDestination (r:1 w:0)
+	// Proof Skipped: CollatorSelection SlashDestination (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: CollatorSelection LastAuthoredBlock (r:0 w:1)
+	// Proof Skipped: CollatorSelection LastAuthoredBlock (max_values: None, max_size: None, mode: Measured)
+	fn report_equivocation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1024`
+		//  Estimated: `23448`
+		Weight::from_parts(120_913_000, 23448)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
@@ -109,6 +109,37 @@ pub mod pallet {
         }
     }
 
+    /// Sets the session keys of an account, usually implemented by `pallet_session`.
+    pub trait SetSessionKeys<AccountId> {
+        /// Session keys type.
+        type Keys: Member + Parameter;
+
+        /// Weight of `set_session_keys`.
+        fn set_session_keys_weight() -> Weight;
+
+        /// Set the session keys of `who`, checking the ownership `proof` of the keys.
+        fn set_session_keys(who: AccountId, keys: Self::Keys, proof: Vec<u8>) -> DispatchResult;
+    }
+
+    impl<T: pallet_session::Config> SetSessionKeys<T::AccountId> for pallet_session::Pallet<T> {
+        type Keys = T::Keys;
+
+        fn set_session_keys_weight() -> Weight {
+            <<T as pallet_session::Config>::WeightInfo as pallet_session::WeightInfo>::set_keys()
+        }
+
+        fn set_session_keys(who: T::AccountId, keys: T::Keys, proof: Vec<u8>) -> DispatchResult {
+            pallet_session::Pallet::<T>::set_keys(
+                frame_system::RawOrigin::Signed(who).into(),
+                keys,
+                proof,
+            )
+        }
+    }
+
+    type SessionKeysOf<T> =
+        <<T as Config>::SessionKeys as SetSessionKeys<<T as SystemConfig>::AccountId>>::Keys;
+
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -157,6 +188,9 @@ pub mod pallet {
         /// How many in perc kicked collators should be slashed (set 0 to disable)
         type SlashRatio: Get<Perbill>;
 
+        /// Session keys setter, used by `set_session_keys`.
+        type SessionKeys: SetSessionKeys<Self::AccountId>;
+
         /// The weight information of this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -264,6 +298,7 @@ pub mod pallet {
         CandidateAdded(T::AccountId, BalanceOf<T>),
         CandidateRemoved(T::AccountId),
         CandidateSlashed(T::AccountId),
+        SessionKeysSet(T::AccountId),
     }
 
     // Errors inform users that something went wrong.
@@ -427,6 +462,31 @@ pub mod pallet {
 
             Ok(Some(T::WeightInfo::leave_intent(current_count as u32)).into())
         }
+
+        /// Set the session keys of a collator.
+        ///
+        /// The caller must be a candidate or an invulnerable, so the call can be safely
+        /// dispatched through a proxy to rotate session keys without using the collator key.
+        #[pallet::call_index(5)]
+        #[pallet::weight(
+            T::SessionKeys::set_session_keys_weight().saturating_add(T::DbWeight::get().reads(2))
+        )]
+        pub fn set_session_keys(
+            origin: OriginFor<T>,
+            keys: SessionKeysOf<T>,
+            proof: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::invulnerables().contains(&who)
+                    || Self::candidates().iter().any(|c| c.who == who),
+                Error::<T>::NotCandidate
+            );
+
+            T::SessionKeys::set_session_keys(who.clone(), keys, proof)?;
+            Self::deposit_event(Event::SessionKeysSet(who));
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -468,6 +528,12 @@ pub mod pallet {
             Ok(current_count)
         }
 
+        /// Slash a candidate by `SlashRatio` of its deposit and remove it from the candidates,
+        /// e.g. as a punishment for an offence.
+        pub fn slash_candidate(who: &T::AccountId) -> DispatchResult {
+            Self::try_remove_candidate(who, true).map(|_| ())
+        }
+
         /// Assemble the current set of candidates and invulnerables into the next collator set.
         ///
         /// This is done on the fly, as frequent as we are told to do so, as the session manager.
@@ -225,6 +225,7 @@ impl Config for Test {
     type ValidatorIdOf = IdentityCollator;
     type ValidatorRegistration = IsRegistered;
     type SlashRatio = SlashRatio;
+    type SessionKeys = Session;
     type WeightInfo = ();
 }
 
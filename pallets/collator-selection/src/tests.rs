@@ -22,7 +22,7 @@ use frame_support::{
     traits::{Currency, GenesisBuild, OnInitialize},
 };
 use pallet_balances::Error as BalancesError;
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin};
 
 #[test]
 fn basic_setup_works() {
@@ -401,6 +401,67 @@ fn kick_and_slash_mechanism() {
     });
 }
 
+#[test]
+fn slash_candidate_works() {
+    new_test_ext().execute_with(|| {
+        <crate::SlashDestination<Test>>::put(5);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_eq!(Balances::free_balance(3), 90);
+
+        assert_ok!(CollatorSelection::slash_candidate(&3));
+        assert!(CollatorSelection::candidates().is_empty());
+        // slashed 10% (of 10 bond)
+        assert_eq!(Balances::free_balance(3), 99);
+        assert_eq!(Balances::free_balance(5), 101);
+
+        // invulnerables and unknown accounts have no bond to slash
+        assert_noop!(
+            CollatorSelection::slash_candidate(&1),
+            Error::<Test>::NotCandidate
+        );
+    });
+}
+
+#[test]
+fn set_session_keys_works() {
+    new_test_ext().execute_with(|| {
+        let keys = MockSessionKeys {
+            aura: UintAuthorityId(10),
+        };
+
+        // only collators can set their session keys
+        assert_noop!(
+            CollatorSelection::set_session_keys(RuntimeOrigin::signed(3), keys.clone(), vec![]),
+            Error::<Test>::NotCandidate
+        );
+
+        // invulnerable can set session keys
+        assert_ok!(CollatorSelection::set_session_keys(
+            RuntimeOrigin::signed(1),
+            keys.clone(),
+            vec![]
+        ));
+
+        // candidate can set session keys, but not the ones already in use
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_noop!(
+            CollatorSelection::set_session_keys(RuntimeOrigin::signed(3), keys, vec![]),
+            pallet_session::Error::<Test>::DuplicatedKey
+        );
+        assert_ok!(CollatorSelection::set_session_keys(
+            RuntimeOrigin::signed(3),
+            MockSessionKeys {
+                aura: UintAuthorityId(11),
+            },
+            vec![]
+        ));
+    });
+}
+
 #[test]
 fn should_not_kick_mechanism_too_few() {
     new_test_ext().execute_with(|| {
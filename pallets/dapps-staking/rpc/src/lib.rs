@@ -0,0 +1,175 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for querying dapps staking era details.
+//!
+//! Gives dashboards a single stable endpoint for era rewards and stakes,
+//! instead of decoding storage entries whose layout may change between runtime upgrades.
+
+use std::sync::{Arc, Mutex};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_dapps_staking_runtime_api::{
+    DappsStakingApi as DappsStakingRuntimeApi, EraIndex, EraInfo, EraStatus, RewardInfo,
+};
+
+/// Details of a dapps staking era.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EraDetails<BlockNumber> {
+    pub era: EraIndex,
+    /// Rewards of stakers, zero until the era is over.
+    pub stakers_reward: u128,
+    /// Rewards of dapps, zero until the era is over.
+    pub dapps_reward: u128,
+    pub staked: u128,
+    pub locked: u128,
+    /// Block at which the next era starts, `null` unless this is the current era.
+    pub next_era_starting_block: Option<BlockNumber>,
+}
+
+impl<BlockNumber> From<EraStatus<BlockNumber>> for EraDetails<BlockNumber> {
+    fn from(status: EraStatus<BlockNumber>) -> Self {
+        Self {
+            era: status.era,
+            stakers_reward: status.info.rewards.stakers,
+            dapps_reward: status.info.rewards.dapps,
+            staked: status.info.staked,
+            locked: status.info.locked,
+            next_era_starting_block: status.next_era_starting_block,
+        }
+    }
+}
+
+#[rpc(client, server)]
+pub trait DappsStakingApi<BlockHash, BlockNumber> {
+    /// Returns details of `era`, or of the current era if not provided.
+    ///
+    /// `null` is returned for future eras and for eras whose history was already pruned.
+    #[method(name = "astar_getEraInfo")]
+    fn era_info(
+        &self,
+        era: Option<EraIndex>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<EraDetails<BlockNumber>>>;
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Last answered query, era details only change from one block to another.
+type CachedEraInfo<Hash, BlockNumber> = ((Hash, Option<EraIndex>), Option<EraDetails<BlockNumber>>);
+
+/// Provides RPC methods to query dapps staking eras.
+pub struct DappsStaking<C, Block: BlockT, BlockNumber> {
+    client: Arc<C>,
+    cache: Mutex<Option<CachedEraInfo<Block::Hash, BlockNumber>>>,
+}
+
+impl<C, Block: BlockT, BlockNumber> DappsStaking<C, Block, BlockNumber> {
+    /// Creates a new instance of the `DappsStaking` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            cache: Mutex::new(None),
+        }
+    }
+}
+
+impl<C, Block, BlockNumber> DappsStakingApiServer<<Block as BlockT>::Hash, BlockNumber>
+    for DappsStaking<C, Block, BlockNumber>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DappsStakingRuntimeApi<Block, BlockNumber>,
+    BlockNumber: Codec + Clone + Serialize + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    fn era_info(
+        &self,
+        era: Option<EraIndex>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<EraDetails<BlockNumber>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let key = (at_hash, era);
+
+        if let Some((cached_key, details)) = &*self.cache.lock().expect("lock is not poisoned") {
+            if *cached_key == key {
+                return Ok(details.clone());
+            }
+        }
+
+        let details = self
+            .client
+            .runtime_api()
+            .era_status(at_hash, era)
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    RUNTIME_ERROR,
+                    "Unable to query dapps staking era.",
+                    Some(e.to_string()),
+                ))
+            })?
+            .map(EraDetails::from);
+
+        *self.cache.lock().expect("lock is not poisoned") = Some((key, details.clone()));
+        Ok(details)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn era_details_are_serialized_as_expected() {
+        let status = EraStatus {
+            era: 7,
+            info: EraInfo {
+                rewards: RewardInfo {
+                    stakers: 20,
+                    dapps: 30,
+                },
+                staked: 100,
+                locked: 150,
+            },
+            next_era_starting_block: Some(1_000_u32),
+        };
+
+        assert_eq!(
+            serde_json::to_value(EraDetails::from(status)).unwrap(),
+            serde_json::json!({
+                "era": 7,
+                "stakersReward": 20,
+                "dappsReward": 30,
+                "staked": 100,
+                "locked": 150,
+                "nextEraStartingBlock": 1_000
+            })
+        );
+    }
+}
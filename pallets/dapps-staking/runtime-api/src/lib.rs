@@ -0,0 +1,35 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for querying dapps staking era details.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+
+pub use pallet_dapps_staking::{EraIndex, EraInfo, EraStatus, RewardInfo};
+
+sp_api::decl_runtime_apis! {
+    /// API for querying dapps staking eras.
+    pub trait DappsStakingApi<BlockNumber> where BlockNumber: Codec {
+        /// Details of `era`, or of the current era if `None`.
+        ///
+        /// Returns `None` for future eras and for eras whose history was already pruned.
+        fn era_status(era: Option<EraIndex>) -> Option<EraStatus<BlockNumber>>;
+    }
+}
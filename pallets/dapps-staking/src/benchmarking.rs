@@ -42,6 +42,9 @@ fn initialize<T: Config>() {
     let _ = GeneralEraInfo::<T>::clear(u32::MAX, None);
     let _ = ContractEraStake::<T>::clear(u32::MAX, None);
     let _ = GeneralStakerInfo::<T>::clear(u32::MAX, None);
+    let _ = ContractStakeCap::<T>::clear(u32::MAX, None);
+    let _ = PendingInfractions::<T>::clear(u32::MAX, None);
+    let _ = ClaimForTipOptIn::<T>::clear(u32::MAX, None);
     CurrentEra::<T>::kill();
     BlockRewardAccumulator::<T>::kill();
 
@@ -301,6 +304,57 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::RewardDestination(staker, option).into());
     }
 
+    set_contract_stake_cap {
+        initialize::<T>();
+        let (_, contract_id) = register_contract::<T>(1)?;
+        let stake_cap = Some(T::MinimumStakingAmount::get());
+    }: _(RawOrigin::Root, contract_id.clone(), stake_cap)
+    verify {
+        assert_last_event::<T>(Event::<T>::ContractStakeCapSet(contract_id, stake_cap).into());
+    }
+
+    report_infraction {
+        initialize::<T>();
+        let (_, contract_id) = register_contract::<T>(1)?;
+
+        let era = DappsStaking::<T>::current_era();
+        advance_to_era::<T>(era + 1u32);
+
+        let origin = T::InfractionReportOrigin::try_successful_origin()
+            .map_err(|_| "InfractionReportOrigin has no successful origin")?;
+    }: _<T::RuntimeOrigin>(origin, contract_id.clone(), InfractionKind::Abandoned, era, era)
+    verify {
+        assert!(PendingInfractions::<T>::contains_key(&contract_id));
+    }
+
+    cancel_infraction {
+        initialize::<T>();
+        let (_, contract_id) = register_contract::<T>(1)?;
+
+        let era = DappsStaking::<T>::current_era();
+        advance_to_era::<T>(era + 1u32);
+        DappsStaking::<T>::do_report_infraction(
+            contract_id.clone(),
+            InfractionKind::Abandoned,
+            era,
+            era,
+        )?;
+    }: _(RawOrigin::Root, contract_id.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::InfractionCancelled(contract_id).into());
+    }
+
+    set_claim_for_tip {
+        initialize::<T>();
+        let (_, contract_id) = register_contract::<T>(1)?;
+
+        let stakers = prepare_bond_and_stake::<T>(1, &contract_id, SEED)?;
+        let staker = stakers[0].clone();
+    }: _(RawOrigin::Signed(staker.clone()), true)
+    verify {
+        assert!(ClaimForTipOptIn::<T>::get(&staker));
+    }
+
 }
 
 #[cfg(test)]
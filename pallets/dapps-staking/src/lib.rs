@@ -29,7 +29,8 @@
 //! Stakers are also rewarded, based on the total amount they've locked (invariant of the dapp they staked on).
 //!
 //! Rewards are accumulated throughout an **era** and when **era** finishes, both stakers and developers can claim their rewards for that era.
-//! This is a continous process. Rewards can be claimed even for eras which are older than the last one (no limit at the moment).
+//! This is a continous process. Rewards can be claimed even for eras which are older than the last one,
+//! as long as they're within the era history retention. Historical data of older eras is pruned.
 //!
 //! Reward claiming isn't automated since the whole process is done **on-chain** and is fully decentralized.
 //! Both stakers and developers are responsible for claiming their own rewards.
@@ -53,16 +54,21 @@
 //! - `set_reward_destination` - sets reward destination for the staker rewards
 //! - `set_contract_stake_info` - root-only call to set storage value (used for fixing corrupted data)
 //! - `burn_stale_reward` - root-only call to burn unclaimed, stale rewards from unregistered contracts
+//! - `report_infraction` - reports a rule violation of a contract, deferring the loss of its dApp rewards for the affected eras
+//! - `cancel_infraction` - root-only call to cancel a pending infraction
+//! - `apply_infraction` - burns the dApp rewards forfeited by a pending infraction, by anyone once its delay has passed or by root at any time
 //!
 //! User is encouraged to refer to specific function implementations for more comprehensive documentation.
 //!
 //! ### Other
 //!
 //! - `on_initialize` - part of `Hooks` trait, it's important to call this per block since it handles reward snapshots and era advancement.
+//! - `on_idle` - part of `Hooks` trait, prunes historical era data which is outside of the history retention.
 //! - `account_id` - returns pallet's account Id
 //! - `ensure_pallet_enabled` - checks whether pallet is in maintenance mode or not and returns appropriate `Result`
 //! - `rewards` - used to deposit staker and dapps rewards into dApps staking reward pool
 //! - `tvl` - total value locked in dApps staking (might differ from total staked value)
+//! - `era_status` - details of the current or a past era, used by the `DappsStakingApi` runtime API
 //!
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -71,6 +77,7 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{traits::Zero, RuntimeDebug};
 use sp_std::prelude::*;
+pub mod migrations;
 pub mod pallet;
 pub mod weights;
 
@@ -184,6 +191,61 @@ pub struct EraInfo {
     pub locked: Balance,
 }
 
+/// Details of an era, as exposed via runtime API.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EraStatus<BlockNumber> {
+    /// Era index.
+    pub era: EraIndex,
+    /// Rewards, staked and locked amounts of the era.
+    /// Rewards are only known once the era is over.
+    pub info: EraInfo,
+    /// Block at which the next era starts, only set for the current era.
+    pub next_era_starting_block: Option<BlockNumber>,
+}
+
+/// Progress of the historical era data pruning.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EraPruningCursor<SmartContract> {
+    /// Era which is being pruned.
+    #[codec(compact)]
+    pub era: EraIndex,
+    /// Last contract whose `ContractEraStake` entry was pruned for the `era`.
+    pub last_contract: Option<SmartContract>,
+}
+
+/// Kind of a dApp staking rule violation.
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum InfractionKind {
+    /// Developer has abandoned the registered contract.
+    Abandoned,
+    /// Violation backed by evidence from outside of the pallet, e.g. fed by an oracle,
+    /// identified by its hash.
+    Evidence([u8; 32]),
+}
+
+/// Reported dApp staking rule violation of a contract, pending until it's applied or cancelled.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Infraction {
+    /// Kind of the violation.
+    pub kind: InfractionKind,
+    /// First era whose dApp reward is forfeited.
+    #[codec(compact)]
+    pub from_era: EraIndex,
+    /// Last era whose dApp reward is forfeited, inclusive.
+    #[codec(compact)]
+    pub to_era: EraIndex,
+    /// Era from which anyone can apply the infraction.
+    #[codec(compact)]
+    pub applicable_era: EraIndex,
+}
+
+impl Infraction {
+    /// `true` if dApp reward for the `era` is forfeited by the infraction, `false` otherwise
+    fn covers(&self, era: EraIndex) -> bool {
+        self.from_era <= era && era <= self.to_era
+    }
+}
+
 /// Used to split total EraPayout among contracts.
 /// Each tuple (contract, era) has this structure.
 /// This will be used to reward contracts developer and his stakers.
@@ -415,6 +477,36 @@ impl StakerInfo {
         }
     }
 
+    /// Skips all eras before `era`, so the next `claim` starts from `era`.
+    ///
+    /// The amount staked in the last skipped era carries over to `era`.
+    /// Does nothing if there are no stakes before `era`.
+    ///
+    /// # Example
+    ///
+    /// `stakes: [<5, 1000>, <7, 1300>, <8, 0>, <15, 3000>]`
+    ///
+    /// 1. `skip_to(6)` modifies the internal vector to `[<6, 1000>, <7, 1300>, <8, 0>, <15, 3000>]`
+    ///
+    /// 2. `skip_to(10)` modifies the internal vector to `[<15, 3000>]`
+    ///    Note that `0` staked period is discarded since nothing can be claimed there.
+    ///
+    fn skip_to(&mut self, era: EraIndex) {
+        if let Some(index) = self
+            .stakes
+            .iter()
+            .rposition(|era_stake| era_stake.era <= era)
+        {
+            self.stakes.drain(..index);
+            self.stakes[0].era = era;
+
+            // Removes unstaked values if they're no longer valid for comprehension
+            if self.stakes[0].staked.is_zero() {
+                self.stakes.remove(0);
+            }
+        }
+    }
+
     /// Latest staked value.
     /// E.g. if staker is fully unstaked, this will return `Zero`.
     /// Otherwise returns a non-zero balance.
@@ -483,6 +575,11 @@ impl UnbondingInfo {
             .unwrap_or_default()
     }
 
+    /// Returns the earliest unlock era of all chunks, if there are any.
+    fn earliest_unlock_era(&self) -> Option<EraIndex> {
+        self.unlocking_chunks.first().map(|chunk| chunk.unlock_era)
+    }
+
     /// Adds a new unlocking chunk to the vector, preserving the unlock_era based ordering.
     fn add(&mut self, chunk: UnlockingChunk) {
         // It is possible that the unbonding period changes so we need to account for that
@@ -0,0 +1,38 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+use sp_std::marker::PhantomData;
+
+/// Starts the history retention from the current era.
+///
+/// Without it, all eras outside of `EraHistoryRetention` expire as soon as the retention is enabled,
+/// forfeiting unclaimed rewards. With it, stakers get a full retention window to claim them first.
+/// Does nothing if the retention start has already been set.
+pub struct EnableEraHistoryRetention<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for EnableEraHistoryRetention<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if EraHistoryRetentionStart::<T>::exists() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        EraHistoryRetentionStart::<T>::put(Pallet::<T>::current_era());
+        T::DbWeight::get().reads_writes(2, 1)
+    }
+}
@@ -21,7 +21,7 @@ use crate::{self as pallet_dapps_staking, weights};
 use frame_support::{
     construct_runtime, parameter_types,
     traits::{Currency, OnFinalize, OnInitialize},
-    weights::Weight,
+    weights::{RuntimeDbWeight, Weight},
     PalletId,
 };
 use sp_core::{H160, H256};
@@ -30,7 +30,8 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_io::TestExternalities;
 use sp_runtime::{
     testing::Header,
-    traits::{BlakeTwo256, ConstU32, IdentityLookup},
+    traits::{BlakeTwo256, ConstU128, ConstU32, IdentityLookup},
+    Perbill,
 };
 
 pub(crate) type AccountId = u64;
@@ -51,6 +52,12 @@ pub(crate) const MAX_UNLOCKING_CHUNKS: u32 = 4;
 pub(crate) const UNBONDING_PERIOD: EraIndex = 3;
 pub(crate) const MAX_ERA_STAKE_VALUES: u32 = 8;
 pub(crate) const REWARD_RETENTION_PERIOD: u32 = 2;
+pub(crate) const ERA_HISTORY_RETENTION: u32 = 32;
+pub(crate) const FEELESS_CLAIM_THRESHOLD: Balance = 1000;
+pub(crate) const FEELESS_CLAIM_DELAY: u32 = 2;
+pub(crate) const CLAIM_FOR_TIP_PERCENT: u32 = 10;
+pub(crate) const INFRACTION_DELAY: u32 = 2;
+pub(crate) const MAX_INFRACTION_ERAS: u32 = 3;
 
 // Do note that this needs to at least be 3 for tests to be valid. It can be greater but not smaller.
 pub(crate) const BLOCKS_PER_ERA: BlockNumber = 3;
@@ -78,6 +85,7 @@ parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub BlockWeights: frame_system::limits::BlockWeights =
         frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1024, 0));
+    pub static DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 0, write: 0 };
 }
 
 impl frame_system::Config for TestRuntime {
@@ -95,7 +103,7 @@ impl frame_system::Config for TestRuntime {
     type Header = Header;
     type RuntimeEvent = RuntimeEvent;
     type BlockHashCount = BlockHashCount;
-    type DbWeight = ();
+    type DbWeight = DbWeight;
     type Version = ();
     type PalletInfo = PalletInfo;
     type AccountData = pallet_balances::AccountData<Balance>;
@@ -149,6 +157,7 @@ parameter_types! {
     pub const MaxUnlockingChunks: u32 = MAX_UNLOCKING_CHUNKS;
     pub const UnbondingPeriod: EraIndex = UNBONDING_PERIOD;
     pub const MaxEraStakeValues: u32 = MAX_ERA_STAKE_VALUES;
+    pub const ClaimForTip: Perbill = Perbill::from_percent(CLAIM_FOR_TIP_PERCENT);
 }
 
 impl pallet_dapps_staking::Config for TestRuntime {
@@ -166,6 +175,13 @@ impl pallet_dapps_staking::Config for TestRuntime {
     type UnbondingPeriod = UnbondingPeriod;
     type MaxEraStakeValues = MaxEraStakeValues;
     type UnregisteredDappRewardRetention = ConstU32<REWARD_RETENTION_PERIOD>;
+    type EraHistoryRetention = ConstU32<ERA_HISTORY_RETENTION>;
+    type FeelessClaimThreshold = ConstU128<FEELESS_CLAIM_THRESHOLD>;
+    type FeelessClaimDelay = ConstU32<FEELESS_CLAIM_DELAY>;
+    type ClaimForTip = ClaimForTip;
+    type InfractionReportOrigin = frame_system::EnsureRoot<AccountId>;
+    type InfractionDelay = ConstU32<INFRACTION_DELAY>;
+    type MaxInfractionEras = ConstU32<MAX_INFRACTION_ERAS>;
 }
 
 #[derive(
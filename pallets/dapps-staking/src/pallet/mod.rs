@@ -20,7 +20,7 @@
 
 use super::*;
 use frame_support::{
-    dispatch::DispatchResult,
+    dispatch::{DispatchResult, Pays, PostDispatchInfo},
     ensure,
     pallet_prelude::*,
     traits::{
@@ -30,9 +30,9 @@ use frame_support::{
     weights::Weight,
     PalletId,
 };
-use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
+use frame_system::{ensure_root, ensure_signed, ensure_signed_or_root, pallet_prelude::*};
 use sp_runtime::{
-    traits::{AccountIdConversion, Zero},
+    traits::{AccountIdConversion, Saturating, Zero},
     Perbill,
 };
 use sp_std::{convert::From, mem};
@@ -115,6 +115,49 @@ pub mod pallet {
         #[pallet::constant]
         type UnregisteredDappRewardRetention: Get<u32>;
 
+        /// Number of past eras for which historical era data (`GeneralEraInfo` & `ContractEraStake`) is retained.
+        /// Rewards for eras older than this can no longer be claimed and their data is pruned in `on_idle`.
+        ///
+        /// For example, if retention is set to `30` and current era is `100`, it means that all data bellow era `70` is pruned.
+        /// Setting it to `u32::MAX` keeps the entire history.
+        ///
+        /// When enabling it on a live network, [`crate::migrations::EnableEraHistoryRetention`] should be run
+        /// so that existing history isn't pruned right after the upgrade.
+        #[pallet::constant]
+        type EraHistoryRetention: Get<u32>;
+
+        /// Minimum amount that has to be claimed via `claim_staker` or `withdraw_unbonded` for the call to be fee-less.
+        /// The fee is still paid upfront but is refunded after a successful dispatch.
+        ///
+        /// Setting it to `Balance::MAX` disables fee-less claims.
+        #[pallet::constant]
+        type FeelessClaimThreshold: Get<Balance>;
+
+        /// Number of eras that need to pass after a reward or unbonded chunk became claimable before claiming it can be fee-less.
+        ///
+        /// For example, if delay is set to `5`, reward for era `10` (claimable from era `11`) can be claimed without fees starting from era `16`.
+        #[pallet::constant]
+        type FeelessClaimDelay: Get<u32>;
+
+        /// Share of the staker reward paid to the caller of `claim_staker_for`, when claiming on behalf of another account
+        /// which has opted in with `set_claim_for_tip`.
+        ///
+        /// Setting it to zero makes claiming on behalf of others a purely altruistic action.
+        #[pallet::constant]
+        type ClaimForTip: Get<Perbill>;
+
+        /// Origin which can report dApp staking rule violations, e.g. a protocol pallet or an oracle.
+        type InfractionReportOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Number of eras a reported infraction is deferred before anyone can apply it.
+        /// Until then, root can either apply it early or cancel it.
+        #[pallet::constant]
+        type InfractionDelay: Get<u32>;
+
+        /// Max number of eras whose dApp reward can be forfeited by a single infraction.
+        #[pallet::constant]
+        type MaxInfractionEras: Get<u32>;
+
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -204,6 +247,41 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Maximum total amount which can be staked on a contract.
+    /// Contracts without an entry are not capped.
+    #[pallet::storage]
+    #[pallet::getter(fn contract_stake_cap)]
+    pub type ContractStakeCap<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, Balance>;
+
+    /// Stakers who agreed to pay the `ClaimForTip` share of their rewards to accounts claiming on their behalf.
+    #[pallet::storage]
+    #[pallet::getter(fn claim_for_tip_opt_in)]
+    pub type ClaimForTipOptIn<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    /// Era from which the history retention is applied.
+    ///
+    /// Eras before it are treated as if they were this era, so that stakers have a full retention
+    /// window to claim older rewards after the retention is enabled on a live network.
+    /// Set by [`crate::migrations::EnableEraHistoryRetention`].
+    #[pallet::storage]
+    #[pallet::getter(fn era_history_retention_start)]
+    pub type EraHistoryRetentionStart<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
+    /// Progress of the historical era data pruning.
+    #[pallet::storage]
+    #[pallet::getter(fn era_pruning_cursor)]
+    pub type EraPruningProgress<T: Config> =
+        StorageValue<_, EraPruningCursor<T::SmartContract>, ValueQuery>;
+
+    /// Reported rule violations of contracts, pending until they are applied or cancelled.
+    /// dApp rewards of the eras covered by a pending infraction can't be claimed.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_infraction)]
+    pub type PendingInfractions<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::SmartContract, Infraction>;
+
     /// Stores the current pallet storage version.
     #[pallet::storage]
     #[pallet::getter(fn storage_version)]
@@ -240,6 +318,59 @@ pub mod pallet {
         ///
         /// \(developer account, smart contract, era, amount burned\)
         StaleRewardBurned(T::AccountId, T::SmartContract, EraIndex, Balance),
+        /// Staker rewards for all eras outside of the history retention have been skipped.
+        ///
+        /// \(staker account, smart contract, first skipped era\)
+        ExpiredRewardSkipped(T::AccountId, T::SmartContract, EraIndex),
+        /// Historical data of an era has been pruned.
+        ///
+        /// Only `GeneralEraInfo` of the era is included. `ContractEraStake` entries are removed
+        /// without being exported, they have to be read from an archive node before pruning.
+        ///
+        /// \(era, pruned era info\)
+        EraHistoryPruned(EraIndex, EraInfo),
+        /// Account has bonded and staked funds on a smart contract, tagged with a memo.
+        ///
+        /// \(staker account, smart contract, amount, memo\)
+        BondAndStakeWithMemo(T::AccountId, T::SmartContract, Balance, [u8; 32]),
+        /// Account has unbonded & unstaked some funds, tagged with a memo.
+        ///
+        /// \(staker account, smart contract, amount, memo\)
+        UnbondAndUnstakeWithMemo(T::AccountId, T::SmartContract, Balance, [u8; 32]),
+        /// Stake cap of a contract has been set or removed.
+        ///
+        /// \(smart contract, new stake cap\)
+        ContractStakeCapSet(T::SmartContract, Option<Balance>),
+        /// Staker reward has been claimed by another account on behalf of the staker.
+        ///
+        /// \(claimer account, staker account, smart contract, era, tip paid to the claimer\)
+        RewardClaimedFor(
+            T::AccountId,
+            T::AccountId,
+            T::SmartContract,
+            EraIndex,
+            Balance,
+        ),
+        /// Rule violation of a contract has been reported.
+        ///
+        /// \(smart contract, kind, first era, last era, era from which anyone can apply it\)
+        InfractionReported(
+            T::SmartContract,
+            InfractionKind,
+            EraIndex,
+            EraIndex,
+            EraIndex,
+        ),
+        /// Pending infraction of a contract has been cancelled.
+        InfractionCancelled(T::SmartContract),
+        /// Pending infraction of a contract has been applied, burning its unclaimed dApp rewards.
+        ///
+        /// \(smart contract, amount burned\)
+        InfractionApplied(T::SmartContract, Balance),
+        /// Staker has opted in or out of paying a tip to accounts claiming on their behalf.
+        ///
+        /// \(staker account, opted in\)
+        ClaimForTipSet(T::AccountId, bool),
     }
 
     #[pallet::error]
@@ -292,6 +423,20 @@ pub mod pallet {
         NotActiveStaker,
         /// Transfering nomination to the same contract
         NominationTransferToSameContract,
+        /// Era is outside of the history retention so its reward can no longer be claimed.
+        RewardExpired,
+        /// Stake would exceed the maximum total stake allowed on the contract.
+        StakeCapExceeded,
+        /// Infraction covers more eras than allowed.
+        TooManyInfractionEras,
+        /// Contract already has a pending infraction.
+        InfractionAlreadyPending,
+        /// Contract has no pending infraction.
+        NoPendingInfraction,
+        /// Infraction can't be applied by anyone before its delay has passed.
+        InfractionDeferred,
+        /// dApp reward for this era is withheld by a pending infraction.
+        RewardWithheld,
     }
 
     #[pallet::hooks]
@@ -333,6 +478,10 @@ pub mod pallet {
                 T::DbWeight::get().reads(4)
             }
         }
+
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_era_history(remaining_weight)
+        }
     }
 
     #[pallet::call]
@@ -480,46 +629,7 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let staker = ensure_signed(origin)?;
 
-            // Check that contract is ready for staking.
-            ensure!(
-                Self::is_active(&contract_id),
-                Error::<T>::NotOperatedContract
-            );
-
-            // Get the staking ledger or create an entry if it doesn't exist.
-            let mut ledger = Self::ledger(&staker);
-            let available_balance = Self::available_staking_balance(&staker, &ledger);
-            let value_to_stake = value.min(available_balance);
-            ensure!(
-                value_to_stake > Zero::zero(),
-                Error::<T>::StakingWithNoValue
-            );
-
-            let current_era = Self::current_era();
-            let mut staking_info =
-                Self::contract_stake_info(&contract_id, current_era).unwrap_or_default();
-            let mut staker_info = Self::staker_info(&staker, &contract_id);
-
-            Self::stake_on_contract(
-                &mut staker_info,
-                &mut staking_info,
-                value_to_stake,
-                current_era,
-            )?;
-
-            ledger.locked = ledger.locked.saturating_add(value_to_stake);
-
-            // Update storage
-            GeneralEraInfo::<T>::mutate(&current_era, |value| {
-                if let Some(x) = value {
-                    x.staked = x.staked.saturating_add(value_to_stake);
-                    x.locked = x.locked.saturating_add(value_to_stake);
-                }
-            });
-
-            Self::update_ledger(&staker, ledger);
-            Self::update_staker_info(&staker, &contract_id, staker_info);
-            ContractEraStake::<T>::insert(&contract_id, current_era, staking_info);
+            let value_to_stake = Self::do_bond_and_stake(&staker, &contract_id, value)?;
 
             Self::deposit_event(Event::<T>::BondAndStake(
                 staker,
@@ -547,46 +657,7 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let staker = ensure_signed(origin)?;
 
-            ensure!(value > Zero::zero(), Error::<T>::UnstakingWithNoValue);
-            ensure!(
-                Self::is_active(&contract_id),
-                Error::<T>::NotOperatedContract,
-            );
-
-            let current_era = Self::current_era();
-            let mut staker_info = Self::staker_info(&staker, &contract_id);
-            let mut contract_stake_info =
-                Self::contract_stake_info(&contract_id, current_era).unwrap_or_default();
-
-            let value_to_unstake = Self::unstake_from_contract(
-                &mut staker_info,
-                &mut contract_stake_info,
-                value,
-                current_era,
-            )?;
-
-            // Update the chunks and write them to storage
-            let mut ledger = Self::ledger(&staker);
-            ledger.unbonding_info.add(UnlockingChunk {
-                amount: value_to_unstake,
-                unlock_era: current_era + T::UnbondingPeriod::get(),
-            });
-            // This should be done AFTER insertion since it's possible for chunks to merge
-            ensure!(
-                ledger.unbonding_info.len() <= T::MaxUnlockingChunks::get(),
-                Error::<T>::TooManyUnlockingChunks
-            );
-
-            Self::update_ledger(&staker, ledger);
-
-            // Update total staked value in era.
-            GeneralEraInfo::<T>::mutate(&current_era, |value| {
-                if let Some(x) = value {
-                    x.staked = x.staked.saturating_sub(value_to_unstake)
-                }
-            });
-            Self::update_staker_info(&staker, &contract_id, staker_info);
-            ContractEraStake::<T>::insert(&contract_id, current_era, contract_stake_info);
+            let value_to_unstake = Self::do_unbond_and_unstake(&staker, &contract_id, value)?;
 
             Self::deposit_event(Event::<T>::UnbondAndUnstake(
                 staker,
@@ -601,6 +672,10 @@ pub mod pallet {
         ///
         /// If there are unbonding chunks which will be fully unbonded in future eras,
         /// they will remain and can be withdrawn later.
+        ///
+        /// Withdrawal is fee-less if at least `FeelessClaimThreshold` is withdrawn and it has been withdrawable
+        /// for at least `FeelessClaimDelay` eras. The fee is still charged upfront, like for any other call,
+        /// and refunded by returning `Pays::No` once the withdrawal succeeds.
         #[pallet::call_index(5)]
         #[pallet::weight(T::WeightInfo::withdraw_unbonded())]
         pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
@@ -615,6 +690,12 @@ pub mod pallet {
 
             ensure!(!withdraw_amount.is_zero(), Error::<T>::NothingToWithdraw);
 
+            let pays_fee = Self::claim_pays_fee(
+                withdraw_amount,
+                valid_chunks.earliest_unlock_era().unwrap_or(current_era),
+                current_era,
+            );
+
             // Get the staking ledger and update it
             ledger.locked = ledger.locked.saturating_sub(withdraw_amount);
             ledger.unbonding_info = future_chunks;
@@ -628,7 +709,10 @@ pub mod pallet {
 
             Self::deposit_event(Event::<T>::Withdrawn(staker, withdraw_amount));
 
-            Ok(().into())
+            Ok(PostDispatchInfo {
+                actual_weight: None,
+                pays_fee,
+            })
         }
 
         /// Transfer nomination from one contract to another.
@@ -681,6 +765,7 @@ pub mod pallet {
                 Self::contract_stake_info(&target_contract_id, current_era).unwrap_or_default();
 
             Self::stake_on_contract(
+                &target_contract_id,
                 &mut target_staker_info,
                 &mut target_staking_info,
                 origin_to_target_transfer_value,
@@ -705,13 +790,15 @@ pub mod pallet {
             Ok(().into())
         }
 
-        // TODO: do we need to add force methods or at least methods that allow others to claim for someone else?
-
         /// Claim earned staker rewards for the oldest unclaimed era.
         /// In order to claim multiple eras, this call has to be called multiple times.
         ///
         /// The rewards are always added to the staker's free balance (account) but depending on the reward destination configuration,
         /// they might be immediately re-staked.
+        ///
+        /// Claim is fee-less if the reward is at least `FeelessClaimThreshold` and it has been left unclaimed
+        /// for at least `FeelessClaimDelay` eras. The fee is still charged upfront, like for any other call,
+        /// and refunded by returning `Pays::No` once the claim succeeds.
         #[pallet::call_index(7)]
         #[pallet::weight(T::WeightInfo::claim_staker_with_restake().max(T::WeightInfo::claim_staker_without_restake()))]
         pub fn claim_staker(
@@ -721,94 +808,7 @@ pub mod pallet {
             Self::ensure_pallet_enabled()?;
             let staker = ensure_signed(origin)?;
 
-            // Ensure we have something to claim
-            let mut staker_info = Self::staker_info(&staker, &contract_id);
-            let (era, staked) = staker_info.claim();
-            ensure!(staked > Zero::zero(), Error::<T>::NotStakedContract);
-
-            let dapp_info =
-                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
-
-            if let DAppState::Unregistered(unregister_era) = dapp_info.state {
-                ensure!(era < unregister_era, Error::<T>::NotOperatedContract);
-            }
-
-            let current_era = Self::current_era();
-            ensure!(era < current_era, Error::<T>::EraOutOfBounds);
-
-            let staking_info = Self::contract_stake_info(&contract_id, era).unwrap_or_default();
-            let reward_and_stake =
-                Self::general_era_info(era).ok_or(Error::<T>::UnknownEraReward)?;
-
-            let (_, stakers_joint_reward) =
-                Self::dev_stakers_split(&staking_info, &reward_and_stake);
-            let staker_reward =
-                Perbill::from_rational(staked, staking_info.total) * stakers_joint_reward;
-
-            let mut ledger = Self::ledger(&staker);
-
-            let should_restake_reward = Self::should_restake_reward(
-                ledger.reward_destination,
-                dapp_info.state,
-                staker_info.latest_staked_value(),
-            );
-
-            if should_restake_reward {
-                staker_info
-                    .stake(current_era, staker_reward)
-                    .map_err(|_| Error::<T>::UnexpectedStakeInfoEra)?;
-
-                // Restaking will, in the worst case, remove one, and add one record,
-                // so it's fine if the vector is full
-                ensure!(
-                    staker_info.len() <= T::MaxEraStakeValues::get(),
-                    Error::<T>::TooManyEraStakeValues
-                );
-            }
-
-            // Withdraw reward funds from the dapps staking pot
-            let reward_imbalance = T::Currency::withdraw(
-                &Self::account_id(),
-                staker_reward,
-                WithdrawReasons::TRANSFER,
-                ExistenceRequirement::AllowDeath,
-            )?;
-
-            if should_restake_reward {
-                ledger.locked = ledger.locked.saturating_add(staker_reward);
-                Self::update_ledger(&staker, ledger);
-
-                // Update storage
-                GeneralEraInfo::<T>::mutate(&current_era, |value| {
-                    if let Some(x) = value {
-                        x.staked = x.staked.saturating_add(staker_reward);
-                        x.locked = x.locked.saturating_add(staker_reward);
-                    }
-                });
-
-                ContractEraStake::<T>::mutate(contract_id.clone(), current_era, |staking_info| {
-                    if let Some(x) = staking_info {
-                        x.total = x.total.saturating_add(staker_reward);
-                    }
-                });
-
-                Self::deposit_event(Event::<T>::BondAndStake(
-                    staker.clone(),
-                    contract_id.clone(),
-                    staker_reward,
-                ));
-            }
-
-            T::Currency::resolve_creating(&staker, reward_imbalance);
-            Self::update_staker_info(&staker, &contract_id, staker_info);
-            Self::deposit_event(Event::<T>::Reward(staker, contract_id, era, staker_reward));
-
-            Ok(Some(if should_restake_reward {
-                T::WeightInfo::claim_staker_with_restake()
-            } else {
-                T::WeightInfo::claim_staker_without_restake()
-            })
-            .into())
+            Self::do_claim_staker(&staker, contract_id, None)
         }
 
         /// Claim earned dapp rewards for the specified era.
@@ -830,7 +830,8 @@ pub mod pallet {
             let mut contract_stake_info =
                 Self::contract_stake_info(&contract_id, era).unwrap_or_default();
 
-            let dapp_reward = Self::calculate_dapp_reward(&contract_stake_info, &dapp_info, era)?;
+            let dapp_reward =
+                Self::calculate_dapp_reward(&contract_id, &contract_stake_info, &dapp_info, era)?;
 
             // Withdraw reward funds from the dapps staking
             let reward_imbalance = T::Currency::withdraw(
@@ -960,7 +961,8 @@ pub mod pallet {
             let mut contract_stake_info =
                 Self::contract_stake_info(&contract_id, era).unwrap_or_default();
 
-            let dapp_reward = Self::calculate_dapp_reward(&contract_stake_info, &dapp_info, era)?;
+            let dapp_reward =
+                Self::calculate_dapp_reward(&contract_id, &contract_stake_info, &dapp_info, era)?;
 
             // Withdraw reward funds from the dapps staking pot and burn them
             let imbalance_to_burn = T::Currency::withdraw(
@@ -984,48 +986,583 @@ pub mod pallet {
 
             Ok(().into())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Calculate the dApp reward for the specified era.
-        /// If successfull, returns reward amount.
-        /// In case reward cannot be claimed or was already claimed, an error is raised.
-        fn calculate_dapp_reward(
-            contract_stake_info: &ContractStakeInfo,
-            dapp_info: &DAppInfo<T::AccountId>,
-            era: EraIndex,
-        ) -> Result<Balance, Error<T>> {
-            let current_era = Self::current_era();
-            if let DAppState::Unregistered(unregister_era) = dapp_info.state {
-                ensure!(era < unregister_era, Error::<T>::NotOperatedContract);
-            }
-            ensure!(era < current_era, Error::<T>::EraOutOfBounds);
+        /// Same as `bond_and_stake`, but tags the stake with a 32-byte `memo`.
+        ///
+        /// Intended for liquid staking protocols staking from a pot account, so they can
+        /// correlate on-chain stakes with their internal share accounting.
+        /// Emits `BondAndStakeWithMemo` instead of `BondAndStake`.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::bond_and_stake())]
+        pub fn bond_and_stake_with_memo(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            #[pallet::compact] value: Balance,
+            memo: [u8; 32],
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
 
-            ensure!(
-                !contract_stake_info.contract_reward_claimed,
-                Error::<T>::AlreadyClaimedInThisEra,
-            );
-            ensure!(
-                contract_stake_info.total > Zero::zero(),
-                Error::<T>::NotStakedContract,
-            );
+            let value_to_stake = Self::do_bond_and_stake(&staker, &contract_id, value)?;
 
-            let reward_and_stake =
-                Self::general_era_info(era).ok_or(Error::<T>::UnknownEraReward)?;
+            Self::deposit_event(Event::<T>::BondAndStakeWithMemo(
+                staker,
+                contract_id,
+                value_to_stake,
+                memo,
+            ));
+            Ok(().into())
+        }
 
-            // Calculate the contract reward for this era.
-            let (dapp_reward, _) = Self::dev_stakers_split(&contract_stake_info, &reward_and_stake);
+        /// Same as `unbond_and_unstake`, but tags the unstake with a 32-byte `memo`.
+        ///
+        /// Emits `UnbondAndUnstakeWithMemo` instead of `UnbondAndUnstake`.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::unbond_and_unstake())]
+        pub fn unbond_and_unstake_with_memo(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            #[pallet::compact] value: Balance,
+            memo: [u8; 32],
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
 
-            Ok(dapp_reward)
+            let value_to_unstake = Self::do_unbond_and_unstake(&staker, &contract_id, value)?;
+
+            Self::deposit_event(Event::<T>::UnbondAndUnstakeWithMemo(
+                staker,
+                contract_id,
+                value_to_unstake,
+                memo,
+            ));
+            Ok(().into())
         }
 
-        /// An utility method used to stake specified amount on an arbitrary contract.
+        /// Set the maximum total amount which can be staked on a contract, or remove it with `None`.
         ///
-        /// `StakerInfo` and `ContractStakeInfo` are provided and all checks are made to ensure that it's possible to
-        /// complete staking operation.
+        /// Existing stake above the new cap is left as is, but no more stake can be added
+        /// until the total falls below the cap. Restaked rewards are not limited by the cap.
+        ///
+        /// The dispatch origin must be Root.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::set_contract_stake_cap())]
+        pub fn set_contract_stake_cap(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            stake_cap: Option<Balance>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ContractStakeCap::<T>::set(&contract_id, stake_cap);
+
+            Self::deposit_event(Event::<T>::ContractStakeCapSet(contract_id, stake_cap));
+            Ok(().into())
+        }
+
+        /// Claim earned staker rewards of `staker` for the oldest unclaimed era, on their behalf.
+        ///
+        /// Can be called by anyone. The reward is paid out exactly as if `staker` had called `claim_staker`,
+        /// respecting their reward destination, except for the `ClaimForTip` share which is paid to the caller
+        /// if `staker` has opted in with `set_claim_for_tip`.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::claim_staker_with_restake().max(T::WeightInfo::claim_staker_without_restake())
+            .saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn claim_staker_for(
+            origin: OriginFor<T>,
+            staker: T::AccountId,
+            contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let caller = ensure_signed(origin)?;
+
+            let claimer = if caller == staker { None } else { Some(caller) };
+            Self::do_claim_staker(&staker, contract_id, claimer)
+        }
+
+        /// Report a rule violation of a registered contract, forfeiting its dApp rewards
+        /// from `from_era` to `to_era`, inclusive.
+        ///
+        /// The infraction is deferred by `InfractionDelay` eras, during which root can cancel it.
+        /// Until it's applied or cancelled, dApp rewards of the affected eras can't be claimed.
+        ///
+        /// The dispatch origin must be `InfractionReportOrigin`.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::report_infraction())]
+        pub fn report_infraction(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+            kind: InfractionKind,
+            #[pallet::compact] from_era: EraIndex,
+            #[pallet::compact] to_era: EraIndex,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            T::InfractionReportOrigin::ensure_origin(origin)?;
+
+            Self::do_report_infraction(contract_id, kind, from_era, to_era)?;
+
+            Ok(().into())
+        }
+
+        /// Cancel the pending infraction of a contract.
+        ///
+        /// The dispatch origin must be Root.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::cancel_infraction())]
+        pub fn cancel_infraction(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            PendingInfractions::<T>::take(&contract_id).ok_or(Error::<T>::NoPendingInfraction)?;
+
+            Self::deposit_event(Event::<T>::InfractionCancelled(contract_id));
+            Ok(().into())
+        }
+
+        /// Apply the pending infraction of a contract, burning the unclaimed dApp rewards of the affected eras.
+        /// Eras whose rewards were claimed before the infraction was reported, or which were pruned, are skipped.
+        ///
+        /// Can be called by anyone once the infraction delay has passed, or by root at any time.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::claim_dapp().saturating_mul(T::MaxInfractionEras::get().into()))]
+        pub fn apply_infraction(
+            origin: OriginFor<T>,
+            contract_id: T::SmartContract,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let caller = ensure_signed_or_root(origin)?;
+
+            let infraction =
+                Self::pending_infraction(&contract_id).ok_or(Error::<T>::NoPendingInfraction)?;
+            ensure!(
+                caller.is_none() || Self::current_era() >= infraction.applicable_era,
+                Error::<T>::InfractionDeferred
+            );
+
+            let mut forfeited_reward: Balance = Zero::zero();
+            for era in infraction.from_era..=infraction.to_era {
+                let (mut contract_stake_info, era_info) = match (
+                    Self::contract_stake_info(&contract_id, era),
+                    Self::general_era_info(era),
+                ) {
+                    (Some(contract_stake_info), Some(era_info))
+                        if !contract_stake_info.contract_reward_claimed =>
+                    {
+                        (contract_stake_info, era_info)
+                    }
+                    _ => continue,
+                };
+
+                let (dapp_reward, _) = Self::dev_stakers_split(&contract_stake_info, &era_info);
+                forfeited_reward.saturating_accrue(dapp_reward);
+
+                // mark entry as `claimed`, same as when burning stale rewards.
+                contract_stake_info.contract_reward_claimed = true;
+                ContractEraStake::<T>::insert(&contract_id, era, contract_stake_info);
+            }
+
+            // Withdraw forfeited reward funds from the dapps staking pot and burn them
+            let imbalance_to_burn = T::Currency::withdraw(
+                &Self::account_id(),
+                forfeited_reward,
+                WithdrawReasons::TRANSFER,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            mem::drop(imbalance_to_burn);
+
+            PendingInfractions::<T>::remove(&contract_id);
+
+            Self::deposit_event(Event::<T>::InfractionApplied(contract_id, forfeited_reward));
+
+            let affected_eras = infraction.to_era.saturating_sub(infraction.from_era) + 1;
+            Ok(Some(T::WeightInfo::claim_dapp().saturating_mul(affected_eras.into())).into())
+        }
+
+        /// Opt in or out of paying the `ClaimForTip` share of staker rewards to accounts
+        /// claiming them on the caller's behalf with `claim_staker_for`.
+        ///
+        /// Stakers are opted out by default. Caller must be an active staker in order to opt in.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_claim_for_tip())]
+        pub fn set_claim_for_tip(origin: OriginFor<T>, opt_in: bool) -> DispatchResultWithPostInfo {
+            Self::ensure_pallet_enabled()?;
+            let staker = ensure_signed(origin)?;
+
+            if opt_in {
+                ensure!(
+                    !Self::ledger(&staker).is_empty(),
+                    Error::<T>::NotActiveStaker
+                );
+                ClaimForTipOptIn::<T>::insert(&staker, true);
+            } else {
+                ClaimForTipOptIn::<T>::remove(&staker);
+            }
+
+            Self::deposit_event(Event::<T>::ClaimForTipSet(staker, opt_in));
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Calculate the dApp reward for the specified era.
+        /// If successfull, returns reward amount.
+        /// In case reward cannot be claimed or was already claimed, an error is raised.
+        fn calculate_dapp_reward(
+            contract_id: &T::SmartContract,
+            contract_stake_info: &ContractStakeInfo,
+            dapp_info: &DAppInfo<T::AccountId>,
+            era: EraIndex,
+        ) -> Result<Balance, Error<T>> {
+            let current_era = Self::current_era();
+            if let DAppState::Unregistered(unregister_era) = dapp_info.state {
+                ensure!(era < unregister_era, Error::<T>::NotOperatedContract);
+            }
+            ensure!(era < current_era, Error::<T>::EraOutOfBounds);
+            ensure!(
+                !Self::is_era_expired(era, current_era),
+                Error::<T>::RewardExpired
+            );
+
+            ensure!(
+                !contract_stake_info.contract_reward_claimed,
+                Error::<T>::AlreadyClaimedInThisEra,
+            );
+            ensure!(
+                contract_stake_info.total > Zero::zero(),
+                Error::<T>::NotStakedContract,
+            );
+            ensure!(
+                !Self::pending_infraction(contract_id)
+                    .map_or(false, |infraction| infraction.covers(era)),
+                Error::<T>::RewardWithheld,
+            );
+
+            let reward_and_stake =
+                Self::general_era_info(era).ok_or(Error::<T>::UnknownEraReward)?;
+
+            // Calculate the contract reward for this era.
+            let (dapp_reward, _) = Self::dev_stakers_split(&contract_stake_info, &reward_and_stake);
+
+            Ok(dapp_reward)
+        }
+
+        /// Report a rule violation of a registered contract, forfeiting its dApp rewards
+        /// from `from_era` to `to_era`, inclusive, once applied.
+        ///
+        /// Can be used by other pallets to report violations they detect. Reported eras must be over
+        /// and within the history retention.
+        pub fn do_report_infraction(
+            contract_id: T::SmartContract,
+            kind: InfractionKind,
+            from_era: EraIndex,
+            to_era: EraIndex,
+        ) -> DispatchResult {
+            ensure!(
+                RegisteredDapps::<T>::contains_key(&contract_id),
+                Error::<T>::NotOperatedContract
+            );
+            ensure!(
+                !PendingInfractions::<T>::contains_key(&contract_id),
+                Error::<T>::InfractionAlreadyPending
+            );
+
+            let current_era = Self::current_era();
+            ensure!(
+                from_era <= to_era && to_era < current_era,
+                Error::<T>::EraOutOfBounds
+            );
+            ensure!(
+                !Self::is_era_expired(from_era, current_era),
+                Error::<T>::RewardExpired
+            );
+            ensure!(
+                to_era - from_era < T::MaxInfractionEras::get(),
+                Error::<T>::TooManyInfractionEras
+            );
+
+            let applicable_era = current_era.saturating_add(T::InfractionDelay::get());
+            PendingInfractions::<T>::insert(
+                &contract_id,
+                Infraction {
+                    kind,
+                    from_era,
+                    to_era,
+                    applicable_era,
+                },
+            );
+
+            Self::deposit_event(Event::<T>::InfractionReported(
+                contract_id,
+                kind,
+                from_era,
+                to_era,
+                applicable_era,
+            ));
+
+            Ok(())
+        }
+
+        /// Claim staker rewards of `staker` for the oldest unclaimed era on `contract_id`.
+        ///
+        /// If `claimer` is set, the claim is made on behalf of the staker and the claimer
+        /// receives the `ClaimForTip` share of the reward, if the staker has opted in.
+        fn do_claim_staker(
+            staker: &T::AccountId,
+            contract_id: T::SmartContract,
+            claimer: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            // Ensure we have something to claim
+            let mut staker_info = Self::staker_info(staker, &contract_id);
+            let (era, staked) = staker_info.claim();
+            ensure!(staked > Zero::zero(), Error::<T>::NotStakedContract);
+
+            let dapp_info =
+                RegisteredDapps::<T>::get(&contract_id).ok_or(Error::<T>::NotOperatedContract)?;
+
+            if let DAppState::Unregistered(unregister_era) = dapp_info.state {
+                ensure!(era < unregister_era, Error::<T>::NotOperatedContract);
+            }
+
+            let current_era = Self::current_era();
+            ensure!(era < current_era, Error::<T>::EraOutOfBounds);
+
+            // History of the era might already be pruned so reward is forfeited.
+            // Claim is still processed, skipping all expired eras at once,
+            // to allow staker to move on to the next eras.
+            if Self::is_era_expired(era, current_era) {
+                staker_info.skip_to(Self::first_retained_era(current_era));
+                Self::update_staker_info(staker, &contract_id, staker_info);
+                Self::deposit_event(Event::<T>::ExpiredRewardSkipped(
+                    staker.clone(),
+                    contract_id,
+                    era,
+                ));
+
+                return Ok(Some(T::WeightInfo::claim_staker_without_restake()).into());
+            }
+
+            let staking_info = Self::contract_stake_info(&contract_id, era).unwrap_or_default();
+            let reward_and_stake =
+                Self::general_era_info(era).ok_or(Error::<T>::UnknownEraReward)?;
+
+            let (_, stakers_joint_reward) =
+                Self::dev_stakers_split(&staking_info, &reward_and_stake);
+            let total_reward =
+                Perbill::from_rational(staked, staking_info.total) * stakers_joint_reward;
+
+            // Part of the reward goes to the claimer when claiming on behalf of the staker,
+            // if the staker has agreed to it.
+            let tip = match claimer {
+                Some(_) if Self::claim_for_tip_opt_in(staker) => {
+                    T::ClaimForTip::get() * total_reward
+                }
+                _ => Zero::zero(),
+            };
+            let staker_reward = total_reward.saturating_sub(tip);
+
+            let mut ledger = Self::ledger(staker);
+
+            let should_restake_reward = Self::should_restake_reward(
+                ledger.reward_destination,
+                dapp_info.state,
+                staker_info.latest_staked_value(),
+            );
+
+            if should_restake_reward {
+                staker_info
+                    .stake(current_era, staker_reward)
+                    .map_err(|_| Error::<T>::UnexpectedStakeInfoEra)?;
+
+                // Restaking will, in the worst case, remove one, and add one record,
+                // so it's fine if the vector is full
+                ensure!(
+                    staker_info.len() <= T::MaxEraStakeValues::get(),
+                    Error::<T>::TooManyEraStakeValues
+                );
+            }
+
+            // Withdraw reward funds from the dapps staking pot
+            let reward_imbalance = T::Currency::withdraw(
+                &Self::account_id(),
+                total_reward,
+                WithdrawReasons::TRANSFER,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            let (tip_imbalance, reward_imbalance) = reward_imbalance.split(tip);
+
+            if should_restake_reward {
+                ledger.locked = ledger.locked.saturating_add(staker_reward);
+                Self::update_ledger(staker, ledger);
+
+                // Update storage
+                GeneralEraInfo::<T>::mutate(&current_era, |value| {
+                    if let Some(x) = value {
+                        x.staked = x.staked.saturating_add(staker_reward);
+                        x.locked = x.locked.saturating_add(staker_reward);
+                    }
+                });
+
+                ContractEraStake::<T>::mutate(contract_id.clone(), current_era, |staking_info| {
+                    if let Some(x) = staking_info {
+                        x.total = x.total.saturating_add(staker_reward);
+                    }
+                });
+
+                Self::deposit_event(Event::<T>::BondAndStake(
+                    staker.clone(),
+                    contract_id.clone(),
+                    staker_reward,
+                ));
+            }
+
+            T::Currency::resolve_creating(staker, reward_imbalance);
+            Self::update_staker_info(staker, &contract_id, staker_info);
+
+            Self::deposit_event(Event::<T>::Reward(
+                staker.clone(),
+                contract_id.clone(),
+                era,
+                staker_reward,
+            ));
+
+            if let Some(claimer) = claimer {
+                T::Currency::resolve_creating(&claimer, tip_imbalance);
+                Self::deposit_event(Event::<T>::RewardClaimedFor(
+                    claimer,
+                    staker.clone(),
+                    contract_id,
+                    era,
+                    tip,
+                ));
+            }
+
+            Ok(PostDispatchInfo {
+                actual_weight: Some(if should_restake_reward {
+                    T::WeightInfo::claim_staker_with_restake()
+                } else {
+                    T::WeightInfo::claim_staker_without_restake()
+                }),
+                pays_fee: Self::claim_pays_fee(total_reward, era.saturating_add(1), current_era),
+            })
+        }
+
+        /// Bond and stake `value` from the `staker` on the `contract_id`.
+        ///
+        /// Returns the value which was actually staked, which can be less than `value`
+        /// in case the staker doesn't have enough available balance.
+        fn do_bond_and_stake(
+            staker: &T::AccountId,
+            contract_id: &T::SmartContract,
+            value: Balance,
+        ) -> Result<Balance, DispatchError> {
+            // Check that contract is ready for staking.
+            ensure!(
+                Self::is_active(contract_id),
+                Error::<T>::NotOperatedContract
+            );
+
+            // Get the staking ledger or create an entry if it doesn't exist.
+            let mut ledger = Self::ledger(staker);
+            let available_balance = Self::available_staking_balance(staker, &ledger);
+            let value_to_stake = value.min(available_balance);
+            ensure!(
+                value_to_stake > Zero::zero(),
+                Error::<T>::StakingWithNoValue
+            );
+
+            let current_era = Self::current_era();
+            let mut staking_info =
+                Self::contract_stake_info(contract_id, current_era).unwrap_or_default();
+            let mut staker_info = Self::staker_info(staker, contract_id);
+
+            Self::stake_on_contract(
+                contract_id,
+                &mut staker_info,
+                &mut staking_info,
+                value_to_stake,
+                current_era,
+            )?;
+
+            ledger.locked = ledger.locked.saturating_add(value_to_stake);
+
+            // Update storage
+            GeneralEraInfo::<T>::mutate(&current_era, |value| {
+                if let Some(x) = value {
+                    x.staked = x.staked.saturating_add(value_to_stake);
+                    x.locked = x.locked.saturating_add(value_to_stake);
+                }
+            });
+
+            Self::update_ledger(staker, ledger);
+            Self::update_staker_info(staker, contract_id, staker_info);
+            ContractEraStake::<T>::insert(contract_id, current_era, staking_info);
+
+            Ok(value_to_stake)
+        }
+
+        /// Unbond and unstake `value` of the `staker` from the `contract_id`.
+        ///
+        /// Returns the value which was actually unstaked, which can be more than `value`
+        /// in case the remaining stake would fall below `MinimumStakingAmount`.
+        fn do_unbond_and_unstake(
+            staker: &T::AccountId,
+            contract_id: &T::SmartContract,
+            value: Balance,
+        ) -> Result<Balance, DispatchError> {
+            ensure!(value > Zero::zero(), Error::<T>::UnstakingWithNoValue);
+            ensure!(
+                Self::is_active(contract_id),
+                Error::<T>::NotOperatedContract,
+            );
+
+            let current_era = Self::current_era();
+            let mut staker_info = Self::staker_info(staker, contract_id);
+            let mut contract_stake_info =
+                Self::contract_stake_info(contract_id, current_era).unwrap_or_default();
+
+            let value_to_unstake = Self::unstake_from_contract(
+                &mut staker_info,
+                &mut contract_stake_info,
+                value,
+                current_era,
+            )?;
+
+            // Update the chunks and write them to storage
+            let mut ledger = Self::ledger(staker);
+            ledger.unbonding_info.add(UnlockingChunk {
+                amount: value_to_unstake,
+                unlock_era: current_era + T::UnbondingPeriod::get(),
+            });
+            // This should be done AFTER insertion since it's possible for chunks to merge
+            ensure!(
+                ledger.unbonding_info.len() <= T::MaxUnlockingChunks::get(),
+                Error::<T>::TooManyUnlockingChunks
+            );
+
+            Self::update_ledger(staker, ledger);
+
+            // Update total staked value in era.
+            GeneralEraInfo::<T>::mutate(&current_era, |value| {
+                if let Some(x) = value {
+                    x.staked = x.staked.saturating_sub(value_to_unstake)
+                }
+            });
+            Self::update_staker_info(staker, contract_id, staker_info);
+            ContractEraStake::<T>::insert(contract_id, current_era, contract_stake_info);
+
+            Ok(value_to_unstake)
+        }
+
+        /// An utility method used to stake specified amount on an arbitrary contract.
+        ///
+        /// `StakerInfo` and `ContractStakeInfo` are provided and all checks are made to ensure that it's possible to
+        /// complete staking operation.
         ///
         /// # Arguments
         ///
+        /// * `contract_id` - contract which is being staked on, used to check the stake cap
         /// * `staker_info` - info about staker's stakes on the contract up to current moment
         /// * `staking_info` - general info about contract stakes up to current moment
         /// * `value` - value which is being bonded & staked
@@ -1037,11 +1574,16 @@ pub mod pallet {
         /// If not, an error is returned and structs are left in an undefined state.
         ///
         fn stake_on_contract(
+            contract_id: &T::SmartContract,
             staker_info: &mut StakerInfo,
             staking_info: &mut ContractStakeInfo,
             value: Balance,
             current_era: EraIndex,
         ) -> Result<(), Error<T>> {
+            ensure!(
+                !Self::exceeds_stake_cap(contract_id, staking_info.total, value),
+                Error::<T>::StakeCapExceeded
+            );
             ensure!(
                 !staker_info.latest_staked_value().is_zero()
                     || staking_info.number_of_stakers < T::MaxNumberOfStakersPerContract::get(),
@@ -1225,6 +1767,121 @@ pub mod pallet {
             consumed_weight
         }
 
+        /// Fee-less if at least `FeelessClaimThreshold` is claimed and it has been claimable
+        /// since `claimable_era` for at least `FeelessClaimDelay` eras, regular fee otherwise.
+        pub(crate) fn claim_pays_fee(
+            amount: Balance,
+            claimable_era: EraIndex,
+            current_era: EraIndex,
+        ) -> Pays {
+            if amount >= T::FeelessClaimThreshold::get()
+                && current_era.saturating_sub(claimable_era) >= T::FeelessClaimDelay::get()
+            {
+                Pays::No
+            } else {
+                Pays::Yes
+            }
+        }
+
+        /// `true` if staking `value` on top of `total` would exceed the contract stake cap, `false` otherwise.
+        pub(crate) fn exceeds_stake_cap(
+            contract_id: &T::SmartContract,
+            total: Balance,
+            value: Balance,
+        ) -> bool {
+            match ContractStakeCap::<T>::get(contract_id) {
+                Some(cap) => total.saturating_add(value) > cap,
+                None => false,
+            }
+        }
+
+        /// `true` if era is older than the history retention, `false` otherwise.
+        pub(crate) fn is_era_expired(era: EraIndex, current_era: EraIndex) -> bool {
+            era.max(Self::era_history_retention_start())
+                .saturating_add(T::EraHistoryRetention::get())
+                < current_era
+        }
+
+        /// First era after the expired ones.
+        fn first_retained_era(current_era: EraIndex) -> EraIndex {
+            current_era
+                .saturating_sub(T::EraHistoryRetention::get())
+                .max(Self::era_history_retention_start())
+        }
+
+        /// Prunes historical era data which is outside of the history retention.
+        ///
+        /// Eras are pruned one by one, starting with the `ContractEraStake` entries of all contracts
+        /// and finishing with the `GeneralEraInfo` entry. Progress is stored so pruning
+        /// can continue in the next block if the weight limit has been reached.
+        pub(crate) fn prune_era_history(weight_limit: Weight) -> Weight {
+            // Pallet disabled flag, current era, retention start & cursor reads, cursor write
+            let mut consumed_weight = T::DbWeight::get().reads_writes(4, 1);
+            if consumed_weight.any_gt(weight_limit) {
+                return Weight::zero();
+            }
+
+            if PalletDisabled::<T>::get() {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let current_era = Self::current_era();
+            let mut cursor = EraPruningProgress::<T>::get();
+            if !Self::is_era_expired(cursor.era, current_era) {
+                return T::DbWeight::get().reads(4);
+            }
+
+            let step_weight = T::DbWeight::get().reads_writes(1, 1);
+
+            'eras: while Self::is_era_expired(cursor.era, current_era) {
+                let mut contracts = match cursor.last_contract {
+                    Some(ref contract_id) => RegisteredDapps::<T>::iter_keys_from(
+                        RegisteredDapps::<T>::hashed_key_for(contract_id),
+                    ),
+                    None => RegisteredDapps::<T>::iter_keys(),
+                };
+
+                loop {
+                    if consumed_weight
+                        .saturating_add(step_weight)
+                        .any_gt(weight_limit)
+                    {
+                        break 'eras;
+                    }
+
+                    match contracts.next() {
+                        Some(contract_id) => {
+                            ContractEraStake::<T>::remove(&contract_id, cursor.era);
+                            cursor.last_contract = Some(contract_id);
+                            consumed_weight.saturating_accrue(step_weight);
+                        }
+                        None => break,
+                    }
+                }
+
+                if consumed_weight
+                    .saturating_add(step_weight)
+                    .any_gt(weight_limit)
+                {
+                    break;
+                }
+
+                if let Some(era_info) = GeneralEraInfo::<T>::take(cursor.era) {
+                    Self::deposit_event(Event::<T>::EraHistoryPruned(cursor.era, era_info));
+                }
+                consumed_weight.saturating_accrue(step_weight);
+
+                cursor = EraPruningCursor {
+                    era: cursor.era.saturating_add(1),
+                    last_contract: None,
+                };
+            }
+
+            EraPruningProgress::<T>::put(cursor);
+
+            consumed_weight
+        }
+
         /// Returns available staking balance for the potential staker
         fn available_staking_balance(staker: &T::AccountId, ledger: &AccountLedger) -> Balance {
             // Ensure that staker has enough balance to bond & stake.
@@ -1294,5 +1951,29 @@ pub mod pallet {
                 Zero::zero()
             }
         }
+
+        /// Returns details of `era`, or of the current era if `None`.
+        ///
+        /// `None` is returned for future eras and for eras whose history was already pruned.
+        pub fn era_status(era: Option<EraIndex>) -> Option<EraStatus<T::BlockNumber>> {
+            let current_era = Self::current_era();
+            let era = era.unwrap_or(current_era);
+            if era > current_era {
+                return None;
+            }
+
+            let info = Self::general_era_info(era)?;
+            let next_era_starting_block = if era == current_era {
+                Some(Self::next_era_starting_block())
+            } else {
+                None
+            };
+
+            Some(EraStatus {
+                era,
+                info,
+                next_era_starting_block,
+            })
+        }
     }
 }
@@ -19,9 +19,11 @@
 use super::{pallet::pallet::Error, pallet::pallet::Event, *};
 use frame_support::{
     assert_noop, assert_ok,
-    traits::{Currency, OnInitialize},
-    weights::Weight,
+    dispatch::Pays,
+    traits::{Currency, OnIdle, OnInitialize, OnRuntimeUpgrade},
+    weights::{RuntimeDbWeight, Weight},
 };
+use migrations::EnableEraHistoryRetention;
 use mock::{Balance, Balances, MockSmartContract, *};
 use sp_core::H160;
 use sp_runtime::{
@@ -2342,3 +2344,840 @@ fn burn_stale_reward_negative_checks() {
         );
     })
 }
+
+#[test]
+fn era_history_pruning_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        // Nothing should be pruned while all eras are within the history retention
+        advance_to_era(start_era + ERA_HISTORY_RETENTION);
+        DappsStaking::on_idle(System::block_number(), Weight::MAX);
+        assert!(GeneralEraInfo::<TestRuntime>::get(start_era).is_some());
+        assert!(ContractEraStake::<TestRuntime>::get(&contract_id, start_era).is_some());
+
+        // Oldest era falls out of the history retention and should be pruned
+        advance_to_era(start_era + ERA_HISTORY_RETENTION + 1);
+        let era_info = GeneralEraInfo::<TestRuntime>::get(start_era).unwrap();
+        DappsStaking::on_idle(System::block_number(), Weight::MAX);
+
+        assert!(GeneralEraInfo::<TestRuntime>::get(start_era).is_none());
+        assert!(ContractEraStake::<TestRuntime>::get(&contract_id, start_era).is_none());
+        assert!(GeneralEraInfo::<TestRuntime>::get(start_era + 1).is_some());
+        assert!(ContractEraStake::<TestRuntime>::get(&contract_id, start_era + 1).is_some());
+        assert_eq!(
+            DappsStaking::era_pruning_cursor(),
+            EraPruningCursor {
+                era: start_era + 1,
+                last_contract: None,
+            }
+        );
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::EraHistoryPruned(
+            start_era, era_info,
+        )));
+    })
+}
+
+#[test]
+fn era_history_pruning_respects_weight_limit() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = 3;
+        let first_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let second_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(1, &first_contract_id);
+        assert_register(2, &second_contract_id);
+        assert_bond_and_stake(staker, &first_contract_id, 100);
+        assert_bond_and_stake(staker, &second_contract_id, 100);
+        advance_to_era(start_era + ERA_HISTORY_RETENTION + 1);
+
+        let db_weight = RuntimeDbWeight {
+            read: 10,
+            write: 100,
+        };
+        DbWeight::set(db_weight);
+        let base_weight = db_weight.reads_writes(4, 1);
+        let step_weight = db_weight.reads_writes(1, 1);
+
+        // Not enough weight to do anything
+        assert!(DappsStaking::on_idle(System::block_number(), Weight::from_parts(1, 0)).is_zero());
+        assert_eq!(DappsStaking::era_pruning_cursor(), Default::default());
+
+        // Prune era `0` completely (two contracts & general info) and a single contract of the next era
+        let weight_limit = base_weight.saturating_add(step_weight.saturating_mul(4));
+        assert_eq!(
+            DappsStaking::on_idle(System::block_number(), weight_limit),
+            weight_limit
+        );
+
+        let cursor = DappsStaking::era_pruning_cursor();
+        assert_eq!(cursor.era, start_era);
+        let pruned_contract_id = cursor.last_contract.unwrap();
+        let remaining_contract_id = if pruned_contract_id == first_contract_id {
+            second_contract_id
+        } else {
+            first_contract_id
+        };
+        assert!(ContractEraStake::<TestRuntime>::get(&pruned_contract_id, start_era).is_none());
+        assert!(ContractEraStake::<TestRuntime>::get(&remaining_contract_id, start_era).is_some());
+        assert!(GeneralEraInfo::<TestRuntime>::get(start_era).is_some());
+
+        // Pruning continues from where it stopped
+        assert_eq!(
+            DappsStaking::on_idle(System::block_number(), Weight::MAX),
+            base_weight.saturating_add(step_weight.saturating_mul(2))
+        );
+        assert!(ContractEraStake::<TestRuntime>::get(&remaining_contract_id, start_era).is_none());
+        assert!(GeneralEraInfo::<TestRuntime>::get(start_era).is_none());
+        assert_eq!(DappsStaking::era_pruning_cursor().era, start_era + 1);
+    })
+}
+
+#[test]
+fn claim_expired_reward() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + ERA_HISTORY_RETENTION + 1);
+
+        // dApp reward for the expired era can no longer be claimed
+        assert_noop!(
+            DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, start_era),
+            Error::<TestRuntime>::RewardExpired
+        );
+        assert_claim_dapp(&contract_id, start_era + 1);
+
+        // Expired staker reward is skipped without payout so the next era can be claimed
+        let free_balance = Balances::free_balance(&staker);
+        assert_ok!(DappsStaking::claim_staker(
+            RuntimeOrigin::signed(staker),
+            contract_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+            Event::ExpiredRewardSkipped(staker, contract_id, start_era),
+        ));
+        assert_eq!(Balances::free_balance(&staker), free_balance);
+
+        assert_claim_staker(staker, &contract_id);
+    })
+}
+
+#[test]
+fn claim_skips_all_expired_rewards() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(1, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        let expired_eras = 5;
+        advance_to_era(start_era + ERA_HISTORY_RETENTION + expired_eras);
+
+        // A single claim skips all the expired eras
+        assert_ok!(DappsStaking::claim_staker(
+            RuntimeOrigin::signed(staker),
+            contract_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+            Event::ExpiredRewardSkipped(staker, contract_id, start_era),
+        ));
+        let (next_era, _) = DappsStaking::staker_info(&staker, &contract_id).claim();
+        assert_eq!(next_era, start_era + expired_eras);
+
+        // and the next one is paid out
+        assert_claim_staker(staker, &contract_id);
+    })
+}
+
+#[test]
+fn era_history_retention_start_gives_grace_period() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(1, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+
+        // Retention is enabled a few eras later
+        let enabled_era = start_era + 3;
+        advance_to_era(enabled_era);
+        EnableEraHistoryRetention::<TestRuntime>::on_runtime_upgrade();
+        assert_eq!(DappsStaking::era_history_retention_start(), enabled_era);
+
+        // Running the migration again doesn't move the start
+        advance_to_era(enabled_era + 1);
+        EnableEraHistoryRetention::<TestRuntime>::on_runtime_upgrade();
+        assert_eq!(DappsStaking::era_history_retention_start(), enabled_era);
+
+        // Eras before the start are retained for a full retention window after it
+        advance_to_era(enabled_era + ERA_HISTORY_RETENTION);
+        DappsStaking::on_idle(System::block_number(), Weight::MAX);
+        assert!(GeneralEraInfo::<TestRuntime>::get(start_era).is_some());
+        assert_claim_staker(staker, &contract_id);
+
+        // and are pruned afterwards
+        advance_to_era(enabled_era + ERA_HISTORY_RETENTION + 1);
+        DappsStaking::on_idle(System::block_number(), Weight::MAX);
+        for era in start_era..=enabled_era {
+            assert!(GeneralEraInfo::<TestRuntime>::get(era).is_none());
+        }
+        assert!(GeneralEraInfo::<TestRuntime>::get(enabled_era + 1).is_some());
+    })
+}
+
+#[test]
+fn bond_and_stake_with_memo_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let staker = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let memo = [0xAB; 32];
+        assert_register(20, &contract_id);
+
+        assert_ok!(DappsStaking::bond_and_stake_with_memo(
+            RuntimeOrigin::signed(staker),
+            contract_id,
+            100,
+            memo
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+            Event::BondAndStakeWithMemo(staker, contract_id, 100, memo),
+        ));
+        let current_era = DappsStaking::current_era();
+        assert_eq!(DappsStaking::ledger(&staker).locked, 100);
+        assert_eq!(
+            DappsStaking::staker_info(&staker, &contract_id).latest_staked_value(),
+            100
+        );
+        assert_eq!(
+            DappsStaking::contract_stake_info(&contract_id, current_era)
+                .unwrap()
+                .total,
+            100
+        );
+
+        assert_ok!(DappsStaking::unbond_and_unstake_with_memo(
+            RuntimeOrigin::signed(staker),
+            contract_id,
+            40,
+            memo
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+            Event::UnbondAndUnstakeWithMemo(staker, contract_id, 40, memo),
+        ));
+        assert_eq!(
+            DappsStaking::staker_info(&staker, &contract_id).latest_staked_value(),
+            60
+        );
+        assert_eq!(DappsStaking::ledger(&staker).unbonding_info.sum(), 40);
+    })
+}
+
+#[test]
+fn bond_and_stake_with_memo_on_inactive_contract_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        assert_noop!(
+            DappsStaking::bond_and_stake_with_memo(
+                RuntimeOrigin::signed(1),
+                contract_id,
+                100,
+                [0xAB; 32]
+            ),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+    })
+}
+
+#[test]
+fn claim_staker_is_feeless_after_delay() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 1 + FEELESS_CLAIM_DELAY);
+
+        // Reward for the start era has been claimable for long enough
+        let post_info =
+            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+
+        // Reward for the next era hasn't been
+        let post_info =
+            DappsStaking::claim_staker(RuntimeOrigin::signed(staker), contract_id).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::Yes);
+
+        // Amount below the threshold is never fee-less
+        assert_eq!(
+            DappsStaking::claim_pays_fee(FEELESS_CLAIM_THRESHOLD - 1, 1, 1 + FEELESS_CLAIM_DELAY),
+            Pays::Yes
+        );
+        assert_eq!(
+            DappsStaking::claim_pays_fee(FEELESS_CLAIM_THRESHOLD, 1, 1 + FEELESS_CLAIM_DELAY),
+            Pays::No
+        );
+    })
+}
+
+#[test]
+fn claim_staker_for_pays_tip_to_caller() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let caller = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        assert_set_reward_destination(staker, RewardDestination::FreeBalance);
+        assert_ok!(DappsStaking::set_claim_for_tip(
+            RuntimeOrigin::signed(staker),
+            true
+        ));
+        advance_to_era(start_era + 1);
+
+        // Staker is the only one on the contract so they get the whole stakers reward
+        let (_, stakers_joint_reward) = DappsStaking::dev_stakers_split(
+            &DappsStaking::contract_stake_info(&contract_id, start_era).unwrap(),
+            &DappsStaking::general_era_info(start_era).unwrap(),
+        );
+        let tip = Perbill::from_percent(CLAIM_FOR_TIP_PERCENT) * stakers_joint_reward;
+        let staker_reward = stakers_joint_reward - tip;
+
+        let staker_balance = Balances::free_balance(&staker);
+        let caller_balance = Balances::free_balance(&caller);
+
+        assert_ok!(DappsStaking::claim_staker_for(
+            RuntimeOrigin::signed(caller),
+            staker,
+            contract_id,
+        ));
+        System::assert_has_event(mock::RuntimeEvent::DappsStaking(Event::Reward(
+            staker,
+            contract_id,
+            start_era,
+            staker_reward,
+        )));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::RewardClaimedFor(
+            caller,
+            staker,
+            contract_id,
+            start_era,
+            tip,
+        )));
+
+        assert_eq!(
+            Balances::free_balance(&staker),
+            staker_balance + staker_reward
+        );
+        assert_eq!(Balances::free_balance(&caller), caller_balance + tip);
+        assert_eq!(
+            DappsStaking::staker_info(&staker, &contract_id).claim().0,
+            start_era + 1
+        );
+
+        // Nothing left to claim
+        assert_noop!(
+            DappsStaking::claim_staker_for(RuntimeOrigin::signed(caller), staker, contract_id),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+    })
+}
+
+#[test]
+fn claim_staker_for_pays_no_tip_without_opt_in() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let caller = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        assert_set_reward_destination(staker, RewardDestination::FreeBalance);
+
+        // Opting out again restores the default
+        assert_ok!(DappsStaking::set_claim_for_tip(
+            RuntimeOrigin::signed(staker),
+            true
+        ));
+        assert_ok!(DappsStaking::set_claim_for_tip(
+            RuntimeOrigin::signed(staker),
+            false
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::ClaimForTipSet(
+            staker, false,
+        )));
+        assert!(!DappsStaking::claim_for_tip_opt_in(&staker));
+        advance_to_era(start_era + 1);
+
+        let (_, stakers_joint_reward) = DappsStaking::dev_stakers_split(
+            &DappsStaking::contract_stake_info(&contract_id, start_era).unwrap(),
+            &DappsStaking::general_era_info(start_era).unwrap(),
+        );
+        let staker_balance = Balances::free_balance(&staker);
+        let caller_balance = Balances::free_balance(&caller);
+
+        assert_ok!(DappsStaking::claim_staker_for(
+            RuntimeOrigin::signed(caller),
+            staker,
+            contract_id,
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::RewardClaimedFor(
+            caller,
+            staker,
+            contract_id,
+            start_era,
+            0,
+        )));
+
+        assert_eq!(
+            Balances::free_balance(&staker),
+            staker_balance + stakers_joint_reward
+        );
+        assert_eq!(Balances::free_balance(&caller), caller_balance);
+    })
+}
+
+#[test]
+fn set_claim_for_tip_requires_active_staker() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        assert_noop!(
+            DappsStaking::set_claim_for_tip(RuntimeOrigin::signed(2), true),
+            Error::<TestRuntime>::NotActiveStaker
+        );
+    })
+}
+
+#[test]
+fn claim_staker_for_self_pays_no_tip() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 1);
+
+        System::reset_events();
+        assert_ok!(DappsStaking::claim_staker_for(
+            RuntimeOrigin::signed(staker),
+            staker,
+            contract_id,
+        ));
+        assert!(!dapps_staking_events()
+            .iter()
+            .any(|event| matches!(event, Event::RewardClaimedFor(..))));
+    })
+}
+
+#[test]
+fn contract_stake_cap_is_enforced() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let other_developer = 2;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let other_contract_id = MockSmartContract::Evm(H160::repeat_byte(0x02));
+        let stake_cap = MINIMUM_STAKING_AMOUNT * 3;
+
+        assert_register(developer, &contract_id);
+        assert_register(other_developer, &other_contract_id);
+
+        assert_noop!(
+            DappsStaking::set_contract_stake_cap(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                Some(stake_cap)
+            ),
+            BadOrigin
+        );
+        assert_ok!(DappsStaking::set_contract_stake_cap(
+            RuntimeOrigin::root(),
+            contract_id,
+            Some(stake_cap)
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+            Event::ContractStakeCapSet(contract_id, Some(stake_cap)),
+        ));
+
+        // Staking up to the cap is fine, anything above it isn't
+        assert_bond_and_stake(staker, &contract_id, MINIMUM_STAKING_AMOUNT * 2);
+        assert_noop!(
+            DappsStaking::bond_and_stake(
+                RuntimeOrigin::signed(staker),
+                contract_id,
+                MINIMUM_STAKING_AMOUNT * 2
+            ),
+            Error::<TestRuntime>::StakeCapExceeded
+        );
+        assert_bond_and_stake(staker, &contract_id, MINIMUM_STAKING_AMOUNT);
+
+        // Same applies to nomination transfers
+        assert_bond_and_stake(staker, &other_contract_id, MINIMUM_STAKING_AMOUNT * 2);
+        assert_noop!(
+            DappsStaking::nomination_transfer(
+                RuntimeOrigin::signed(staker),
+                other_contract_id,
+                MINIMUM_STAKING_AMOUNT,
+                contract_id,
+            ),
+            Error::<TestRuntime>::StakeCapExceeded
+        );
+
+        // Once the cap is removed, staking is possible again
+        assert_ok!(DappsStaking::set_contract_stake_cap(
+            RuntimeOrigin::root(),
+            contract_id,
+            None
+        ));
+        assert!(DappsStaking::contract_stake_cap(&contract_id).is_none());
+        assert_nomination_transfer(
+            staker,
+            &other_contract_id,
+            MINIMUM_STAKING_AMOUNT,
+            &contract_id,
+        );
+    })
+}
+
+#[test]
+fn era_status_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 2;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, MINIMUM_STAKING_AMOUNT);
+
+        let current_era = DappsStaking::current_era();
+        let status = DappsStaking::era_status(None).expect("current era info must exist");
+        assert_eq!(status.era, current_era);
+        assert_eq!(status.info.staked, MINIMUM_STAKING_AMOUNT);
+        assert_eq!(
+            status.next_era_starting_block,
+            Some(DappsStaking::next_era_starting_block())
+        );
+
+        // Future eras are unknown
+        assert!(DappsStaking::era_status(Some(current_era + 1)).is_none());
+
+        // Once the era is over, its rewards are known and it has no next era block
+        advance_to_era(current_era + 1);
+        let status = DappsStaking::era_status(Some(current_era)).unwrap();
+        assert_eq!(status.era, current_era);
+        assert_eq!(
+            status.info,
+            DappsStaking::general_era_info(current_era).unwrap()
+        );
+        assert!(status.info.rewards.stakers > 0);
+        assert!(status.next_era_starting_block.is_none());
+    })
+}
+
+/// dApp reward of `contract_id` for `era`, as it would be claimed.
+fn dapp_reward(contract_id: &MockSmartContract<AccountId>, era: EraIndex) -> Balance {
+    let (dapp_reward, _) = DappsStaking::dev_stakers_split(
+        &DappsStaking::contract_stake_info(contract_id, era).unwrap(),
+        &DappsStaking::general_era_info(era).unwrap(),
+    );
+    dapp_reward
+}
+
+#[test]
+fn report_and_apply_infraction_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 3);
+
+        // Report first two eras
+        let kind = InfractionKind::Evidence([7; 32]);
+        let applicable_era = start_era + 3 + INFRACTION_DELAY;
+        assert_ok!(DappsStaking::report_infraction(
+            RuntimeOrigin::root(),
+            contract_id,
+            kind,
+            start_era,
+            start_era + 1,
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::InfractionReported(
+            contract_id,
+            kind,
+            start_era,
+            start_era + 1,
+            applicable_era,
+        )));
+
+        // Rewards of the reported eras are withheld, others can still be claimed
+        assert_noop!(
+            DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, start_era),
+            Error::<TestRuntime>::RewardWithheld
+        );
+        assert_claim_dapp(&contract_id, start_era + 2);
+
+        // Anyone can apply the infraction, but only once the delay has passed
+        assert_noop!(
+            DappsStaking::apply_infraction(RuntimeOrigin::signed(staker), contract_id),
+            Error::<TestRuntime>::InfractionDeferred
+        );
+        advance_to_era(applicable_era);
+
+        let forfeited_reward =
+            dapp_reward(&contract_id, start_era) + dapp_reward(&contract_id, start_era + 1);
+        let issuance_before = <TestRuntime as Config>::Currency::total_issuance();
+        let developer_balance_before = Balances::free_balance(developer);
+
+        assert_ok!(DappsStaking::apply_infraction(
+            RuntimeOrigin::signed(staker),
+            contract_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::InfractionApplied(
+            contract_id,
+            forfeited_reward,
+        )));
+
+        assert_eq!(
+            <TestRuntime as Config>::Currency::total_issuance(),
+            issuance_before - forfeited_reward
+        );
+        assert_eq!(Balances::free_balance(developer), developer_balance_before);
+        assert!(DappsStaking::pending_infraction(&contract_id).is_none());
+
+        // Forfeited rewards can no longer be claimed
+        for era in start_era..=start_era + 1 {
+            assert_noop!(
+                DappsStaking::claim_dapp(RuntimeOrigin::signed(developer), contract_id, era),
+                Error::<TestRuntime>::AlreadyClaimedInThisEra
+            );
+        }
+    })
+}
+
+#[test]
+fn apply_infraction_by_root_skips_claimed_eras() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 2);
+
+        // Reward claimed before the infraction was reported is kept
+        assert_claim_dapp(&contract_id, start_era);
+        assert_ok!(DappsStaking::report_infraction(
+            RuntimeOrigin::root(),
+            contract_id,
+            InfractionKind::Abandoned,
+            start_era,
+            start_era + 1,
+        ));
+
+        // Root doesn't have to wait for the delay
+        let forfeited_reward = dapp_reward(&contract_id, start_era + 1);
+        assert_ok!(DappsStaking::apply_infraction(
+            RuntimeOrigin::root(),
+            contract_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(Event::InfractionApplied(
+            contract_id,
+            forfeited_reward,
+        )));
+    })
+}
+
+#[test]
+fn cancel_infraction_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let staker = 3;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        assert_bond_and_stake(staker, &contract_id, 100);
+        advance_to_era(start_era + 1);
+
+        assert_noop!(
+            DappsStaking::cancel_infraction(RuntimeOrigin::root(), contract_id),
+            Error::<TestRuntime>::NoPendingInfraction
+        );
+
+        assert_ok!(DappsStaking::report_infraction(
+            RuntimeOrigin::root(),
+            contract_id,
+            InfractionKind::Abandoned,
+            start_era,
+            start_era,
+        ));
+        assert_noop!(
+            DappsStaking::cancel_infraction(RuntimeOrigin::signed(staker), contract_id),
+            BadOrigin
+        );
+        assert_ok!(DappsStaking::cancel_infraction(
+            RuntimeOrigin::root(),
+            contract_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::DappsStaking(
+            Event::InfractionCancelled(contract_id),
+        ));
+
+        // Reward is no longer withheld
+        assert_noop!(
+            DappsStaking::apply_infraction(RuntimeOrigin::root(), contract_id),
+            Error::<TestRuntime>::NoPendingInfraction
+        );
+        assert_claim_dapp(&contract_id, start_era);
+    })
+}
+
+#[test]
+fn report_infraction_negative_checks() {
+    ExternalityBuilder::build().execute_with(|| {
+        initialize_first_block();
+
+        let developer = 1;
+        let contract_id = MockSmartContract::Evm(H160::repeat_byte(0x01));
+        let kind = InfractionKind::Abandoned;
+
+        // Only registered contracts can be reported
+        assert_noop!(
+            DappsStaking::report_infraction(RuntimeOrigin::root(), contract_id, kind, 1, 1),
+            Error::<TestRuntime>::NotOperatedContract
+        );
+
+        let start_era = DappsStaking::current_era();
+        assert_register(developer, &contract_id);
+        advance_to_era(start_era + MAX_INFRACTION_ERAS + 1);
+        let current_era = DappsStaking::current_era();
+
+        // Only `InfractionReportOrigin` can report
+        assert_noop!(
+            DappsStaking::report_infraction(
+                RuntimeOrigin::signed(developer),
+                contract_id,
+                kind,
+                start_era,
+                start_era
+            ),
+            BadOrigin
+        );
+
+        // Reported eras must be over and in order
+        assert_noop!(
+            DappsStaking::report_infraction(
+                RuntimeOrigin::root(),
+                contract_id,
+                kind,
+                start_era,
+                current_era
+            ),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+        assert_noop!(
+            DappsStaking::report_infraction(
+                RuntimeOrigin::root(),
+                contract_id,
+                kind,
+                start_era + 1,
+                start_era
+            ),
+            Error::<TestRuntime>::EraOutOfBounds
+        );
+
+        // Too many eras
+        assert_noop!(
+            DappsStaking::report_infraction(
+                RuntimeOrigin::root(),
+                contract_id,
+                kind,
+                start_era,
+                start_era + MAX_INFRACTION_ERAS
+            ),
+            Error::<TestRuntime>::TooManyInfractionEras
+        );
+
+        // Only one pending infraction per contract
+        assert_ok!(DappsStaking::report_infraction(
+            RuntimeOrigin::root(),
+            contract_id,
+            kind,
+            start_era,
+            start_era + MAX_INFRACTION_ERAS - 1,
+        ));
+        assert_noop!(
+            DappsStaking::report_infraction(
+                RuntimeOrigin::root(),
+                contract_id,
+                kind,
+                start_era,
+                start_era
+            ),
+            Error::<TestRuntime>::InfractionAlreadyPending
+        );
+    })
+}
@@ -308,3 +308,38 @@ fn staker_info_claim_ops_advanced() {
         assert_eq!(staker_info.latest_staked_value(), fourth_stake_value);
     }
 }
+
+#[test]
+fn staker_info_skip_to() {
+    let mut staker_info = StakerInfo::default();
+
+    // Nothing to skip in empty staker info
+    staker_info.skip_to(5);
+    assert!(staker_info.is_empty());
+
+    // `[<2, 100>, <4, 300>, <5, 0>, <8, 400>]`
+    assert_ok!(staker_info.stake(2, 100));
+    assert_ok!(staker_info.stake(4, 200));
+    assert_ok!(staker_info.unstake(5, 300));
+    assert_ok!(staker_info.stake(8, 400));
+    assert_eq!(staker_info.len(), 4);
+
+    // Skipping to an era before the first stake does nothing
+    staker_info.skip_to(1);
+    assert_eq!(staker_info.len(), 4);
+
+    // Stake of the last skipped era carries over
+    staker_info.skip_to(3);
+    assert_eq!(staker_info.len(), 4);
+    assert_eq!(staker_info.clone().claim(), (3, 100));
+
+    // Unstaked period is discarded
+    staker_info.skip_to(6);
+    assert_eq!(staker_info.len(), 1);
+    assert_eq!(staker_info.clone().claim(), (8, 400));
+
+    // Skipping past the last stake
+    staker_info.skip_to(20);
+    assert_eq!(staker_info.len(), 1);
+    assert_eq!(staker_info.claim(), (20, 400));
+}
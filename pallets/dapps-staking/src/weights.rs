@@ -38,6 +38,9 @@
 // --output=./benchmark-results/dapps_staking_weights.rs
 // --template=./scripts/templates/weight-template.hbs
 
+// TODO: weights marked as placeholders below were written or updated by hand for extrinsics
+// added after the benchmarks were last run, and must be regenerated by running the benchmarks.
+
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -60,6 +63,10 @@ pub trait WeightInfo {
 	fn force_new_era() -> Weight;
 	fn maintenance_mode() -> Weight;
 	fn set_reward_destination() -> Weight;
+	fn set_contract_stake_cap() -> Weight;
+	fn report_infraction() -> Weight;
+	fn cancel_infraction() -> Weight;
+	fn set_claim_for_tip() -> Weight;
 }
 
 /// Weights for pallet_dapps_staking using the Substrate node and recommended hardware.
@@ -118,11 +125,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: DappsStaking GeneralEraInfo (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: DappsStaking ContractStakeCap (r:1 w:0)
 	fn bond_and_stake() -> Weight {
-		// Minimum execution time: 43_099 nanoseconds.
+		// TODO: placeholder weight, not generated by the benchmark CLI.
 		Weight::from_parts(43_939_000, 0)
 			.saturating_add(Weight::from_parts(0, 16957))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
@@ -163,11 +171,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: DappsStaking GeneralStakerInfo (max_values: None, max_size: Some(298), added: 2773, mode: MaxEncodedLen)
 	// Storage: DappsStaking ContractEraStake (r:2 w:2)
 	// Proof: DappsStaking ContractEraStake (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	// Storage: DappsStaking ContractStakeCap (r:1 w:0)
 	fn nomination_transfer() -> Weight {
-		// Minimum execution time: 38_247 nanoseconds.
+		// TODO: placeholder weight, not generated by the benchmark CLI.
 		Weight::from_parts(38_832_000, 0)
 			.saturating_add(Weight::from_parts(0, 15782))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
@@ -214,11 +223,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: DappsStaking ContractEraStake (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
 	// Storage: DappsStaking GeneralEraInfo (r:1 w:0)
 	// Proof: DappsStaking GeneralEraInfo (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: DappsStaking PendingInfractions (r:1 w:0)
 	fn claim_dapp() -> Weight {
-		// Minimum execution time: 25_126 nanoseconds.
+		// TODO: placeholder weight, not generated by the benchmark CLI.
 		Weight::from_parts(25_489_000, 0)
 			.saturating_add(Weight::from_parts(0, 7669))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	fn force_new_era() -> Weight {
@@ -240,6 +250,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: DappsStaking ContractStakeCap (r:0 w:1)
+	fn set_contract_stake_cap() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking PendingInfractions (r:1 w:1)
+	// Storage: DappsStaking CurrentEra (r:1 w:0)
+	fn report_infraction() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DappsStaking PendingInfractions (r:1 w:1)
+	fn cancel_infraction() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DappsStaking Ledger (r:1 w:0)
+	// Storage: DappsStaking ClaimForTipOptIn (r:0 w:1)
+	fn set_claim_for_tip() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -297,11 +337,12 @@ impl WeightInfo for () {
 	// Proof: DappsStaking GeneralEraInfo (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: DappsStaking ContractStakeCap (r:1 w:0)
 	fn bond_and_stake() -> Weight {
-		// Minimum execution time: 43_099 nanoseconds.
+		// TODO: placeholder weight, not generated by the benchmark CLI.
 		Weight::from_parts(43_939_000, 0)
 			.saturating_add(Weight::from_parts(0, 16957))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
@@ -342,11 +383,12 @@ impl WeightInfo for () {
 	// Proof: DappsStaking GeneralStakerInfo (max_values: None, max_size: Some(298), added: 2773, mode: MaxEncodedLen)
 	// Storage: DappsStaking ContractEraStake (r:2 w:2)
 	// Proof: DappsStaking ContractEraStake (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
+	// Storage: DappsStaking ContractStakeCap (r:1 w:0)
 	fn nomination_transfer() -> Weight {
-		// Minimum execution time: 38_247 nanoseconds.
+		// TODO: placeholder weight, not generated by the benchmark CLI.
 		Weight::from_parts(38_832_000, 0)
 			.saturating_add(Weight::from_parts(0, 15782))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	// Storage: DappsStaking GeneralStakerInfo (r:1 w:1)
@@ -393,11 +435,12 @@ impl WeightInfo for () {
 	// Proof: DappsStaking ContractEraStake (max_values: None, max_size: Some(82), added: 2557, mode: MaxEncodedLen)
 	// Storage: DappsStaking GeneralEraInfo (r:1 w:0)
 	// Proof: DappsStaking GeneralEraInfo (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: DappsStaking PendingInfractions (r:1 w:0)
 	fn claim_dapp() -> Weight {
-		// Minimum execution time: 25_126 nanoseconds.
+		// TODO: placeholder weight, not generated by the benchmark CLI.
 		Weight::from_parts(25_489_000, 0)
 			.saturating_add(Weight::from_parts(0, 7669))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	fn force_new_era() -> Weight {
@@ -419,4 +462,34 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: DappsStaking ContractStakeCap (r:0 w:1)
+	fn set_contract_stake_cap() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DappsStaking RegisteredDapps (r:1 w:0)
+	// Storage: DappsStaking PendingInfractions (r:1 w:1)
+	// Storage: DappsStaking CurrentEra (r:1 w:0)
+	fn report_infraction() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DappsStaking PendingInfractions (r:1 w:1)
+	fn cancel_infraction() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: DappsStaking Ledger (r:1 w:0)
+	// Storage: DappsStaking ClaimForTipOptIn (r:0 w:1)
+	fn set_claim_for_tip() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -21,8 +21,8 @@
 //! ## Overview
 //!
 //! A `pallet-ethereum like pallet that execute transactions from checked source,
-//! like XCM remote call, cross-VM call, etc. Only `Call` transactions are supported
-//! (no `Create`).
+//! like XCM remote call, cross-VM call, etc. Contract creation from checked sources
+//! is only allowed for source addresses accepted by `Config::CreateFilter`.
 //!
 //! The checked source guarantees that transactions are valid with prior checks, so these
 //! transactions are not required to include valid signatures. Instead, `pallet-ethereum-checked`
@@ -35,6 +35,11 @@
 //!
 //! - `transact`: transact an Ethereum transaction. Similar to `pallet_ethereum::Transact`,
 //! but is only for XCM remote call.
+//! - `multisig_transact`: transact an Ethereum transaction from the mapped H160 address of
+//! the origin account. Meant to be wrapped in `pallet_multisig::as_multi`, so a multisig
+//! account can control EVM-side admin keys.
+//! - `create`: deploy a contract with an Ethereum create transaction. Similar to `transact`,
+//! but the mapped source address must be allowed by `Config::CreateFilter`.
 //!
 //! ### Implementation
 //!
@@ -47,16 +52,17 @@ use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
 use ethereum_types::{H160, U256};
-use fp_ethereum::{TransactionData, ValidatedTransaction};
+use fp_ethereum::{Transaction, TransactionData, ValidatedTransaction};
 use fp_evm::{
-    CallInfo, CallOrCreateInfo, CheckEvmTransaction, CheckEvmTransactionConfig, ExitReason,
-    ExitSucceed, InvalidEvmTransactionError,
+    CallInfo, CallOrCreateInfo, CheckEvmTransaction, CheckEvmTransactionConfig, CreateInfo,
+    ExitReason, ExitSucceed, InvalidEvmTransactionError,
 };
 use pallet_evm::GasWeightMapping;
 
 use frame_support::{
     dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
     pallet_prelude::*,
+    traits::Contains,
 };
 use frame_system::pallet_prelude::*;
 #[cfg(feature = "runtime-benchmarks")]
@@ -65,7 +71,7 @@ use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::{marker::PhantomData, result::Result};
 
 use astar_primitives::ethereum_checked::{
-    AccountMapping, CheckedEthereumTransact, CheckedEthereumTx,
+    AccountMapping, CheckedEthereumCreateTx, CheckedEthereumTransact, CheckedEthereumTx,
 };
 
 pub use pallet::*;
@@ -117,6 +123,8 @@ pub enum CheckedEthereumTxKind {
     Xcm,
     /// The tx is from cross-VM call.
     Xvm,
+    /// The tx is from a multisig account.
+    Multisig,
 }
 
 #[frame_support::pallet]
@@ -146,6 +154,12 @@ pub mod pallet {
         /// Origin for `transact` call.
         type XcmTransactOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
+        /// Origin for `multisig_transact` call.
+        type MultisigTransactOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// Source addresses allowed to deploy contracts, via `create` call or cross-VM.
+        type CreateFilter: Contains<H160>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -155,6 +169,12 @@ pub mod pallet {
 
     /// Global nonce for all transactions to avoid hash collision, which is
     /// caused by the same dummy signatures for all transactions.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The source address is not allowed to create contracts.
+        CreateNotAllowed,
+    }
+
     #[pallet::storage]
     pub type Nonce<T: Config> = StorageValue<_, U256, ValueQuery>;
 
@@ -177,21 +197,102 @@ pub mod pallet {
             )
             .map(|(post_info, _)| post_info)
         }
+
+        /// Transact an Ethereum transaction from the mapped H160 address of the origin account.
+        ///
+        /// Meant to be dispatched by `pallet_multisig` once the approval threshold is reached,
+        /// giving the multisig account native control over its EVM-side address.
+        #[pallet::call_index(1)]
+        #[pallet::weight({
+            let weight_limit = T::GasWeightMapping::gas_to_weight(tx.gas_limit.unique_saturated_into(), false);
+            weight_limit.saturating_add(WeightInfoOf::<T>::transact_without_apply())
+        })]
+        pub fn multisig_transact(
+            origin: OriginFor<T>,
+            tx: CheckedEthereumTx,
+        ) -> DispatchResultWithPostInfo {
+            let source = T::MultisigTransactOrigin::ensure_origin(origin)?;
+            Self::do_transact(
+                T::AccountMapping::into_h160(source),
+                tx,
+                CheckedEthereumTxKind::Multisig,
+                false,
+            )
+            .map(|(post_info, _)| post_info)
+        }
+
+        /// Deploy a contract with an Ethereum create transaction. Similar to `transact`,
+        /// the source address must be allowed by `CreateFilter`.
+        #[pallet::call_index(2)]
+        #[pallet::weight({
+            let weight_limit = T::GasWeightMapping::gas_to_weight(tx.gas_limit.unique_saturated_into(), false);
+            weight_limit.saturating_add(WeightInfoOf::<T>::transact_without_apply())
+        })]
+        pub fn create(
+            origin: OriginFor<T>,
+            tx: CheckedEthereumCreateTx,
+        ) -> DispatchResultWithPostInfo {
+            let source = T::XcmTransactOrigin::ensure_origin(origin)?;
+            Self::do_create(
+                T::AccountMapping::into_h160(source),
+                tx,
+                CheckedEthereumTxKind::Xcm,
+            )
+            .map(|(post_info, _)| post_info)
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
-    /// Validate and execute the checked tx. Only `Call` transaction action is allowed.
+    /// Validate and execute the checked call tx.
     fn do_transact(
         source: H160,
         checked_tx: CheckedEthereumTx,
         tx_kind: CheckedEthereumTxKind,
         skip_apply: bool,
     ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo> {
+        let tx = checked_tx.into_ethereum_tx(Nonce::<T>::get(), T::ChainId::get());
+        match Self::validate_and_apply(source, tx, tx_kind, skip_apply)? {
+            (post_info, CallOrCreateInfo::Call(info)) => Ok((post_info, info)),
+            // It is not possible to have a `Create` transaction via `CheckedEthereumTx`.
+            (_, CallOrCreateInfo::Create(_)) => {
+                unreachable!("Cannot create a 'Create' transaction; qed")
+            }
+        }
+    }
+
+    /// Validate and execute the checked create tx, if the source is allowed to create contracts.
+    fn do_create(
+        source: H160,
+        checked_tx: CheckedEthereumCreateTx,
+        tx_kind: CheckedEthereumTxKind,
+    ) -> Result<(PostDispatchInfo, CreateInfo), DispatchErrorWithPostInfo> {
+        ensure!(
+            T::CreateFilter::contains(&source),
+            Error::<T>::CreateNotAllowed
+        );
+
+        let tx = checked_tx.into_ethereum_tx(Nonce::<T>::get(), T::ChainId::get());
+        match Self::validate_and_apply(source, tx, tx_kind, false)? {
+            (post_info, CallOrCreateInfo::Create(info)) => Ok((post_info, info)),
+            // It is not possible to have a `Call` transaction via `CheckedEthereumCreateTx`.
+            (_, CallOrCreateInfo::Call(_)) => {
+                unreachable!("Cannot create a 'Call' transaction; qed")
+            }
+        }
+    }
+
+    /// Validate and execute the tx built from a checked source.
+    fn validate_and_apply(
+        source: H160,
+        tx: Transaction,
+        tx_kind: CheckedEthereumTxKind,
+        skip_apply: bool,
+    ) -> Result<(PostDispatchInfo, CallOrCreateInfo), DispatchErrorWithPostInfo> {
         let chain_id = T::ChainId::get();
         let nonce = Nonce::<T>::get();
-        let tx = checked_tx.into_ethereum_tx(Nonce::<T>::get(), chain_id);
         let tx_data: TransactionData = (&tx).into();
+        let gas_limit = tx_data.gas_limit;
 
         let (weight_limit, proof_size_base_cost) =
             match <T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(
@@ -241,37 +342,35 @@ impl<T: Config> Pallet<T> {
                     actual_weight: Some(WeightInfoOf::<T>::transact_without_apply()),
                     pays_fee: Pays::Yes,
                 },
-                CallInfo {
+                CallOrCreateInfo::Call(CallInfo {
                     exit_reason: ExitReason::Succeed(ExitSucceed::Returned),
                     value: Default::default(),
                     used_gas: fp_evm::UsedGas {
-                        standard: checked_tx.gas_limit,
-                        effective: checked_tx.gas_limit,
+                        standard: gas_limit,
+                        effective: gas_limit,
                     },
                     weight_info: None,
                     logs: Default::default(),
-                },
+                }),
             ));
         }
 
         // Execute the tx.
-        let (post_info, apply_info) = T::ValidatedTransaction::apply(source, tx)?;
-        match apply_info {
-            CallOrCreateInfo::Call(info) => Ok((post_info, info)),
-            // It is not possible to have a `Create` transaction via `CheckedEthereumTx`.
-            CallOrCreateInfo::Create(_) => {
-                unreachable!("Cannot create a 'Create' transaction; qed")
-            }
-        }
+        T::ValidatedTransaction::apply(source, tx)
     }
 
     /// Block gas limit calculation based on the tx kind.
     fn block_gas_limit(tx_kind: &CheckedEthereumTxKind) -> u64 {
-        let weight_limit = match tx_kind {
-            CheckedEthereumTxKind::Xcm => T::ReservedXcmpWeight::get(),
-            CheckedEthereumTxKind::Xvm => T::XvmTxWeightLimit::get(),
-        };
-        T::GasWeightMapping::weight_to_gas(weight_limit)
+        match tx_kind {
+            CheckedEthereumTxKind::Xcm => {
+                T::GasWeightMapping::weight_to_gas(T::ReservedXcmpWeight::get())
+            }
+            CheckedEthereumTxKind::Xvm => {
+                T::GasWeightMapping::weight_to_gas(T::XvmTxWeightLimit::get())
+            }
+            // Dispatched as a regular extrinsic, so the regular block gas limit applies.
+            CheckedEthereumTxKind::Multisig => T::BlockGasLimit::get().unique_saturated_into(),
+        }
     }
 
     /// Similar to `transact` dispatch-able call that transacts an Ethereum transaction,
@@ -299,4 +398,11 @@ impl<T: Config> CheckedEthereumTransact for Pallet<T> {
     ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo> {
         Self::do_transact(source, checked_tx, CheckedEthereumTxKind::Xvm, false)
     }
+
+    fn xvm_create(
+        source: H160,
+        checked_tx: CheckedEthereumCreateTx,
+    ) -> Result<(PostDispatchInfo, CreateInfo), DispatchErrorWithPostInfo> {
+        Self::do_create(source, checked_tx, CheckedEthereumTxKind::Xvm)
+    }
 }
@@ -26,9 +26,10 @@ use ethereum_types::H256;
 use frame_support::{
     assert_ok, construct_runtime, parameter_types,
     sp_io::TestExternalities,
-    traits::{ConstU128, ConstU64, FindAuthor},
+    traits::{ConstU128, ConstU64, Contains, FindAuthor},
     weights::Weight,
 };
+use frame_system::EnsureSigned;
 use pallet_ethereum::PostLogContent;
 use pallet_evm::{AddressMapping, FeeCalculator};
 use sp_io::hashing::blake2_256;
@@ -145,6 +146,13 @@ impl AccountMapping<AccountId32> for MockAccountMapping {
     }
 }
 
+pub struct MockCreateFilter;
+impl Contains<H160> for MockCreateFilter {
+    fn contains(address: &H160) -> bool {
+        *address == ALICE_H160
+    }
+}
+
 parameter_types! {
     pub WeightPerGas: Weight = Weight::from_parts(1, 0);
     pub const BlockGasLimit: U256 = U256::MAX;
@@ -195,6 +203,8 @@ impl pallet_ethereum_checked::Config for TestRuntime {
     type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
     type AccountMapping = MockAccountMapping;
     type XcmTransactOrigin = EnsureXcmEthereumTx<AccountId32>;
+    type MultisigTransactOrigin = EnsureSigned<AccountId32>;
+    type CreateFilter = MockCreateFilter;
     type WeightInfo = ();
 }
 
@@ -253,7 +263,7 @@ contract Storage {
     }
 }
 */
-const STORAGE_CONTRACT: &str = "608060405234801561001057600080fd5b50610150806100206000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c80632e64cec11461003b5780636057361d14610059575b600080fd5b610043610075565b60405161005091906100a1565b60405180910390f35b610073600480360381019061006e91906100ed565b61007e565b005b60008054905090565b8060008190555050565b6000819050919050565b61009b81610088565b82525050565b60006020820190506100b66000830184610092565b92915050565b600080fd5b6100ca81610088565b81146100d557600080fd5b50565b6000813590506100e7816100c1565b92915050565b600060208284031215610103576101026100bc565b5b6000610111848285016100d8565b9150509291505056fea2646970667358221220322c78243e61b783558509c9cc22cb8493dde6925aa5e89a08cdf6e22f279ef164736f6c63430008120033";
+pub const STORAGE_CONTRACT: &str = "608060405234801561001057600080fd5b50610150806100206000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c80632e64cec11461003b5780636057361d14610059575b600080fd5b610043610075565b60405161005091906100a1565b60405180910390f35b610073600480360381019061006e91906100ed565b61007e565b005b60008054905090565b8060008190555050565b6000819050919050565b61009b81610088565b82525050565b60006020820190506100b66000830184610092565b92915050565b600080fd5b6100ca81610088565b81146100d557600080fd5b50565b6000813590506100e7816100c1565b92915050565b600060208284031215610103576101026100bc565b5b6000610111848285016100d8565b9150509291505056fea2646970667358221220322c78243e61b783558509c9cc22cb8493dde6925aa5e89a08cdf6e22f279ef164736f6c63430008120033";
 
 pub fn contract_address() -> H160 {
     H160::from_slice(&hex::decode("dfb975d018f03994a3b943808e3aa0964bd78463").unwrap())
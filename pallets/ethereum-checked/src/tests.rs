@@ -22,7 +22,7 @@ use super::*;
 use mock::*;
 
 use astar_primitives::ethereum_checked::EthereumTxInput;
-use ethereum::{ReceiptV3, TransactionV2 as Transaction};
+use ethereum::{ReceiptV3, TransactionAction, TransactionV2 as Transaction};
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::DispatchError;
 
@@ -115,6 +115,55 @@ fn origin_check_works() {
     });
 }
 
+#[test]
+fn multisig_transact_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `store(5)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000005",
+            ),
+            maybe_access_list: None,
+        };
+        assert_noop!(
+            EthereumChecked::multisig_transact(
+                RawOrigin::XcmEthereumTx(BOB).into(),
+                store_tx.clone()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            EthereumChecked::multisig_transact(RuntimeOrigin::root(), store_tx.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(EthereumChecked::multisig_transact(
+            RuntimeOrigin::signed(BOB),
+            store_tx
+        ));
+
+        // Executed from the mapped address of the origin account
+        let pending = pallet_ethereum::Pending::<TestRuntime>::get();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].1.from, BOB_H160);
+        assert_eq!(Nonce::<TestRuntime>::get(), U256::one());
+
+        let retrieve_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `retrieve`
+            input: bounded_input("2e64cec1"),
+            maybe_access_list: None,
+        };
+        let (_, call_info) =
+            EthereumChecked::xvm_transact(ALICE_H160, retrieve_tx).expect("failed to retrieve");
+        assert_eq!(U256::from_big_endian(&(call_info.value)), 5.into());
+    });
+}
+
 #[test]
 fn no_hash_collision() {
     ExtBuilder::default().build().execute_with(|| {
@@ -151,3 +200,73 @@ fn no_hash_collision() {
         assert_eq!(tx_hashes.len(), 15);
     });
 }
+
+#[test]
+fn create_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let create_tx = CheckedEthereumCreateTx {
+            gas_limit: U256::from(1_000_000),
+            value: U256::zero(),
+            init: bounded_input(STORAGE_CONTRACT),
+            maybe_access_list: None,
+        };
+        assert_ok!(EthereumChecked::create(
+            RawOrigin::XcmEthereumTx(ALICE).into(),
+            create_tx.clone()
+        ));
+        let pending = pallet_ethereum::Pending::<TestRuntime>::get();
+        assert_eq!(pending.len(), 1);
+        match pending[0] {
+            (Transaction::EIP1559(ref t), _, ReceiptV3::EIP1559(ref r)) => {
+                assert_eq!(t.action, TransactionAction::Create);
+                assert_eq!(r.status_code, 1);
+            }
+            _ => panic!("unexpected transaction type"),
+        }
+
+        // Deployed contract is callable.
+        let (_, create_info) =
+            EthereumChecked::xvm_create(ALICE_H160, create_tx).expect("failed to create");
+        assert!(pallet_evm::AccountCodes::<TestRuntime>::contains_key(
+            create_info.value
+        ));
+        let retrieve_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: create_info.value,
+            value: U256::zero(),
+            // Calling `retrieve`
+            input: bounded_input("2e64cec1"),
+            maybe_access_list: None,
+        };
+        let (_, call_info) =
+            EthereumChecked::xvm_transact(ALICE_H160, retrieve_tx).expect("failed to retrieve");
+        assert_eq!(U256::from_big_endian(&(call_info.value)), U256::zero());
+    });
+}
+
+#[test]
+fn create_filter_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let create_tx = CheckedEthereumCreateTx {
+            gas_limit: U256::from(1_000_000),
+            value: U256::zero(),
+            init: bounded_input(STORAGE_CONTRACT),
+            maybe_access_list: None,
+        };
+        assert_noop!(
+            EthereumChecked::create(RawOrigin::XcmEthereumTx(BOB).into(), create_tx.clone()),
+            Error::<TestRuntime>::CreateNotAllowed
+        );
+        assert_noop!(
+            EthereumChecked::create(RuntimeOrigin::signed(ALICE), create_tx.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_eq!(
+            EthereumChecked::xvm_create(CHARLIE_H160, create_tx)
+                .map(|_| ())
+                .map_err(|e| e.error),
+            Err(Error::<TestRuntime>::CreateNotAllowed.into())
+        );
+        assert_eq!(Nonce::<TestRuntime>::get(), U256::zero());
+    });
+}
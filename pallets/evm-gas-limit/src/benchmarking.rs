@@ -0,0 +1,40 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as EvmGasLimit;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+
+benchmarks! {
+
+    set_block_gas_limit {
+        let gas_limit = EvmGasLimit::<T>::max_block_gas_limit();
+
+    }: _(RawOrigin::Root, gas_limit)
+    verify {
+        assert_eq!(BlockGasLimit::<T>::get(), gas_limit);
+    }
+}
+
+impl_benchmark_test_suite!(
+    EvmGasLimit,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
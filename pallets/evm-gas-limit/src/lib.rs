@@ -0,0 +1,148 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # EVM Gas Limit Pallet
+//!
+//! ## Overview
+//!
+//! Keeps the EVM block gas limit in storage, so it can be adjusted by `ManagerOrigin`
+//! without a runtime upgrade. Until it's set, `DefaultBlockGasLimit` is used.
+//!
+//! The new limit is validated against the maximum weight of normal dispatches, i.e. a block
+//! completely filled with EVM transactions, which are normal extrinsics, must not be able to
+//! exceed it.
+//!
+//! Runtime should use the pallet as `pallet_evm::Config::BlockGasLimit`, since it implements `Get<U256>`.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `set_block_gas_limit` - sets the EVM block gas limit
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, weights::Weight};
+use frame_system::pallet_prelude::*;
+use sp_core::U256;
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The required origin for changing the block gas limit.
+        ///
+        /// Should most likely be root or governance.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Weight of a single unit of gas, same as `pallet_evm::Config::WeightPerGas`.
+        type WeightPerGas: Get<Weight>;
+
+        /// Block gas limit used until one is set by `ManagerOrigin`.
+        #[pallet::constant]
+        type DefaultBlockGasLimit: Get<u64>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Block gas limit must be greater than zero.
+        ZeroBlockGasLimit,
+        /// Block gas limit would allow exceeding the maximum weight of normal dispatches.
+        BlockGasLimitTooHigh,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// EVM block gas limit was changed.
+        BlockGasLimitSet { gas_limit: u64 },
+    }
+
+    /// EVM block gas limit.
+    #[pallet::storage]
+    #[pallet::getter(fn block_gas_limit)]
+    pub type BlockGasLimit<T: Config> = StorageValue<_, u64, ValueQuery, T::DefaultBlockGasLimit>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the EVM block gas limit.
+        ///
+        /// `gas_limit` converted to weight must fit into the maximum weight of normal dispatches.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_block_gas_limit())]
+        pub fn set_block_gas_limit(origin: OriginFor<T>, gas_limit: u64) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(gas_limit > 0, Error::<T>::ZeroBlockGasLimit);
+            ensure!(
+                gas_limit <= Self::max_block_gas_limit(),
+                Error::<T>::BlockGasLimitTooHigh
+            );
+
+            BlockGasLimit::<T>::put(gas_limit);
+            Self::deposit_event(Event::BlockGasLimitSet { gas_limit });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Highest gas limit which still fits into the maximum weight of normal dispatches,
+        /// the class of Ethereum transactions.
+        pub fn max_block_gas_limit() -> u64 {
+            let block_weights = T::BlockWeights::get();
+            let max_normal = block_weights
+                .get(DispatchClass::Normal)
+                .max_total
+                .unwrap_or(block_weights.max_block)
+                .ref_time();
+            max_normal
+                .checked_div(T::WeightPerGas::get().ref_time())
+                .unwrap_or(u64::MAX)
+        }
+    }
+
+    impl<T: Config> Get<U256> for Pallet<T> {
+        fn get() -> U256 {
+            BlockGasLimit::<T>::get().into()
+        }
+    }
+}
@@ -0,0 +1,126 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_evm_gas_limit};
+
+use frame_support::{
+    construct_runtime,
+    dispatch::DispatchClass,
+    parameter_types,
+    traits::{ConstU64, Everything},
+    weights::Weight,
+};
+use sp_core::H256;
+
+use sp_io::TestExternalities;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+    Perbill,
+};
+
+type BlockNumber = u64;
+pub(crate) type AccountId = u64;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) const MAX_BLOCK_REF_TIME: u64 = 1_000_000;
+pub(crate) const WEIGHT_PER_GAS: u64 = 20;
+pub(crate) const DEFAULT_BLOCK_GAS_LIMIT: u64 = 30_000;
+pub(crate) const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
+
+construct_runtime!(
+    pub struct Test
+    where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        EvmGasLimit: pallet_evm_gas_limit,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::builder()
+            .base_block(Weight::zero())
+            .for_class(DispatchClass::all(), |weights| {
+                weights.base_extrinsic = Weight::zero();
+            })
+            .for_class(DispatchClass::Normal, |weights| {
+                weights.max_total =
+                    Some(NORMAL_DISPATCH_RATIO * Weight::from_parts(MAX_BLOCK_REF_TIME, 0));
+            })
+            .for_class(DispatchClass::Operational, |weights| {
+                weights.max_total = Some(Weight::from_parts(MAX_BLOCK_REF_TIME, 0));
+            })
+            .build_or_panic();
+    pub WeightPerGas: Weight = Weight::from_parts(WEIGHT_PER_GAS, 0);
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = BlockWeights;
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Index = u64;
+    type RuntimeCall = RuntimeCall;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_evm_gas_limit::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightPerGas = WeightPerGas;
+    type DefaultBlockGasLimit = ConstU64<DEFAULT_BLOCK_GAS_LIMIT>;
+    type WeightInfo = ();
+}
+
+pub struct ExternalityBuilder;
+
+impl ExternalityBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
@@ -0,0 +1,101 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::{pallet::Error, pallet::Event, *};
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn default_block_gas_limit_is_used() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_eq!(EvmGasLimit::block_gas_limit(), DEFAULT_BLOCK_GAS_LIMIT);
+        assert_eq!(
+            <EvmGasLimit as Get<U256>>::get(),
+            U256::from(DEFAULT_BLOCK_GAS_LIMIT)
+        );
+    })
+}
+
+#[test]
+fn only_manager_origin() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            EvmGasLimit::set_block_gas_limit(RuntimeOrigin::signed(1), 10_000),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn set_block_gas_limit_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let max_gas_limit = NORMAL_DISPATCH_RATIO * MAX_BLOCK_REF_TIME / WEIGHT_PER_GAS;
+        assert_eq!(EvmGasLimit::max_block_gas_limit(), max_gas_limit);
+
+        assert_ok!(EvmGasLimit::set_block_gas_limit(
+            RuntimeOrigin::root(),
+            max_gas_limit
+        ));
+        System::assert_last_event(mock::RuntimeEvent::EvmGasLimit(Event::BlockGasLimitSet {
+            gas_limit: max_gas_limit,
+        }));
+        assert_eq!(<EvmGasLimit as Get<U256>>::get(), U256::from(max_gas_limit));
+    })
+}
+
+#[test]
+fn invalid_block_gas_limit_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            EvmGasLimit::set_block_gas_limit(RuntimeOrigin::root(), 0),
+            Error::<Test>::ZeroBlockGasLimit
+        );
+        assert_noop!(
+            EvmGasLimit::set_block_gas_limit(
+                RuntimeOrigin::root(),
+                EvmGasLimit::max_block_gas_limit() + 1
+            ),
+            Error::<Test>::BlockGasLimitTooHigh
+        );
+    })
+}
+
+#[test]
+fn block_gas_limit_must_fit_into_normal_dispatches() {
+    ExternalityBuilder::build().execute_with(|| {
+        let max_normal_gas_limit = NORMAL_DISPATCH_RATIO * MAX_BLOCK_REF_TIME / WEIGHT_PER_GAS;
+        let max_block_gas_limit = MAX_BLOCK_REF_TIME / WEIGHT_PER_GAS;
+
+        // Fits into the block, but not into the weight left to normal extrinsics.
+        assert_noop!(
+            EvmGasLimit::set_block_gas_limit(RuntimeOrigin::root(), max_normal_gas_limit + 1),
+            Error::<Test>::BlockGasLimitTooHigh
+        );
+        assert_noop!(
+            EvmGasLimit::set_block_gas_limit(RuntimeOrigin::root(), max_block_gas_limit),
+            Error::<Test>::BlockGasLimitTooHigh
+        );
+
+        assert_ok!(EvmGasLimit::set_block_gas_limit(
+            RuntimeOrigin::root(),
+            max_normal_gas_limit
+        ));
+        assert_eq!(EvmGasLimit::block_gas_limit(), max_normal_gas_limit);
+    })
+}
@@ -0,0 +1,56 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_evm_gas_limit
+//!
+//! TODO: these weights were not generated by the benchmark CLI. They are estimated from the storage
+//! accessed by each extrinsic and must be replaced by the output of the `pallet_evm_gas_limit`
+//! benchmarks, run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_evm_gas_limit.
+pub trait WeightInfo {
+	fn set_block_gas_limit() -> Weight;
+}
+
+/// Weights for pallet_evm_gas_limit using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: EvmGasLimit BlockGasLimit (r:0 w:1)
+	// Proof: EvmGasLimit BlockGasLimit (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn set_block_gas_limit() -> Weight {
+		Weight::from_parts(9_413_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: EvmGasLimit BlockGasLimit (r:0 w:1)
+	// Proof: EvmGasLimit BlockGasLimit (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	fn set_block_gas_limit() -> Weight {
+		Weight::from_parts(9_413_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,49 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use super::*;
+use crate::Pallet as GovernanceExecutor;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use sp_runtime::traits::Bounded;
+
+benchmarks! {
+
+    delegate {
+        let executor = H160::repeat_byte(0x01);
+        let expires_at = T::BlockNumber::max_value();
+
+    }: _(RawOrigin::Root, executor, expires_at)
+    verify {
+        assert_eq!(Executors::<T>::get(executor), Some(expires_at));
+    }
+
+    revoke {
+        let executor = H160::repeat_byte(0x01);
+        Executors::<T>::insert(executor, T::BlockNumber::max_value());
+
+    }: _(RawOrigin::Root, executor)
+    verify {
+        assert!(!Executors::<T>::contains_key(executor));
+    }
+}
+
+impl_benchmark_test_suite!(
+    GovernanceExecutor,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
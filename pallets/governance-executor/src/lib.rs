@@ -0,0 +1,200 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! # Governance Executor Pallet
+//!
+//! ## Overview
+//!
+//! Lets governance delegate a narrow set of runtime calls to EVM contracts, e.g. a DAO deployed on
+//! the chain, so EVM-native governance can be experimented with without handing out root.
+//!
+//! `ManagerOrigin` delegates to an EVM address until a given block. Until the delegation expires
+//! or is revoked, the address can dispatch calls with the root origin through [`Pallet::execute`],
+//! which is exposed to contracts by the governance executor precompile.
+//! Only calls allowed by `CallFilter` can be dispatched, regardless of the delegation.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `delegate` - delegates the allowed calls to an EVM address, until the given block
+//! - `revoke` - revokes the delegation of an EVM address
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    dispatch::{GetDispatchInfo, UnfilteredDispatchable},
+    pallet_prelude::*,
+    traits::Contains,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use sp_core::H160;
+use sp_runtime::traits::Hash;
+use sp_std::boxed::Box;
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// A call which can be dispatched by executors.
+        type RuntimeCall: Parameter
+            + UnfilteredDispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+            + GetDispatchInfo;
+
+        /// The required origin for delegating to and revoking executors.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Calls which executors are allowed to dispatch.
+        type CallFilter: Contains<<Self as Config>::RuntimeCall>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Address isn't an executor.
+        NotDelegated,
+        /// Delegation of the executor has expired.
+        DelegationExpired,
+        /// Call isn't allowed to be dispatched by executors.
+        CallFiltered,
+        /// Delegation must expire after the current block.
+        ExpiryInPast,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Allowed calls were delegated to the executor until `expires_at`.
+        Delegated {
+            executor: H160,
+            expires_at: T::BlockNumber,
+        },
+        /// Delegation of the executor was revoked.
+        Revoked { executor: H160 },
+        /// Executor dispatched a call.
+        Executed { executor: H160, call_hash: T::Hash },
+    }
+
+    /// Block at which the delegation expires, per executor.
+    #[pallet::storage]
+    #[pallet::getter(fn executors)]
+    pub type Executors<T: Config> = StorageMap<_, Blake2_128Concat, H160, T::BlockNumber>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Delegate the allowed calls to `executor` until block `expires_at`, exclusive.
+        ///
+        /// Replaces the expiry of an existing delegation.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::delegate())]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            executor: H160,
+            expires_at: T::BlockNumber,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(
+                expires_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ExpiryInPast
+            );
+
+            Executors::<T>::insert(executor, expires_at);
+            Self::deposit_event(Event::Delegated {
+                executor,
+                expires_at,
+            });
+
+            Ok(())
+        }
+
+        /// Revoke the delegation of `executor`, expired or not.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::revoke())]
+        pub fn revoke(origin: OriginFor<T>, executor: H160) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(
+                Executors::<T>::contains_key(executor),
+                Error::<T>::NotDelegated
+            );
+
+            Executors::<T>::remove(executor);
+            Self::deposit_event(Event::Revoked { executor });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// `true` if `executor` has a delegation which hasn't expired.
+        pub fn is_executor(executor: &H160) -> bool {
+            Self::ensure_executor(executor).is_ok()
+        }
+
+        /// Dispatch `call` with the root origin on behalf of `executor`.
+        ///
+        /// Fails if `executor` has no active delegation or if `call` isn't allowed by `CallFilter`.
+        /// The caller is responsible for charging the weight of `call`.
+        pub fn execute(
+            executor: H160,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_executor(&executor)?;
+            ensure!(T::CallFilter::contains(&call), Error::<T>::CallFiltered);
+
+            let call_hash = T::Hashing::hash_of(&call);
+            let post_info = call.dispatch_bypass_filter(RawOrigin::Root.into())?;
+            Self::deposit_event(Event::Executed {
+                executor,
+                call_hash,
+            });
+
+            Ok(post_info)
+        }
+
+        fn ensure_executor(executor: &H160) -> Result<(), Error<T>> {
+            match Executors::<T>::get(executor) {
+                Some(expires_at) if frame_system::Pallet::<T>::block_number() < expires_at => {
+                    Ok(())
+                }
+                Some(_) => Err(Error::<T>::DelegationExpired),
+                None => Err(Error::<T>::NotDelegated),
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use super::{pallet::Error, pallet::Event, *};
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::{BadOrigin, BlakeTwo256};
+
+const EXECUTOR: H160 = H160::repeat_byte(0x01);
+
+fn set_storage(value: &[u8]) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::System(frame_system::Call::set_storage {
+        items: vec![(b"key".to_vec(), value.to_vec())],
+    }))
+}
+
+#[test]
+fn delegate_and_revoke() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            GovernanceExecutor::delegate(RuntimeOrigin::signed(1), EXECUTOR, 10),
+            BadOrigin
+        );
+        assert_noop!(
+            GovernanceExecutor::delegate(RuntimeOrigin::root(), EXECUTOR, 1),
+            Error::<Test>::ExpiryInPast
+        );
+
+        assert_ok!(GovernanceExecutor::delegate(
+            RuntimeOrigin::root(),
+            EXECUTOR,
+            10
+        ));
+        System::assert_last_event(mock::RuntimeEvent::GovernanceExecutor(Event::Delegated {
+            executor: EXECUTOR,
+            expires_at: 10,
+        }));
+        assert_eq!(GovernanceExecutor::executors(EXECUTOR), Some(10));
+        assert!(GovernanceExecutor::is_executor(&EXECUTOR));
+
+        assert_noop!(
+            GovernanceExecutor::revoke(RuntimeOrigin::signed(1), EXECUTOR),
+            BadOrigin
+        );
+        assert_ok!(GovernanceExecutor::revoke(RuntimeOrigin::root(), EXECUTOR));
+        System::assert_last_event(mock::RuntimeEvent::GovernanceExecutor(Event::Revoked {
+            executor: EXECUTOR,
+        }));
+        assert!(!GovernanceExecutor::is_executor(&EXECUTOR));
+        assert_noop!(
+            GovernanceExecutor::revoke(RuntimeOrigin::root(), EXECUTOR),
+            Error::<Test>::NotDelegated
+        );
+    })
+}
+
+#[test]
+fn executor_dispatches_allowed_calls_as_root() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            GovernanceExecutor::execute(EXECUTOR, set_storage(b"value")),
+            Error::<Test>::NotDelegated
+        );
+
+        assert_ok!(GovernanceExecutor::delegate(
+            RuntimeOrigin::root(),
+            EXECUTOR,
+            10
+        ));
+        let call = set_storage(b"value");
+        let call_hash = BlakeTwo256::hash_of(&call);
+        assert_ok!(GovernanceExecutor::execute(EXECUTOR, call));
+        assert_eq!(sp_io::storage::get(b"key").as_deref(), Some(&b"value"[..]));
+        System::assert_last_event(mock::RuntimeEvent::GovernanceExecutor(Event::Executed {
+            executor: EXECUTOR,
+            call_hash,
+        }));
+
+        // Other addresses are still not allowed
+        assert_noop!(
+            GovernanceExecutor::execute(H160::repeat_byte(0x02), set_storage(b"other")),
+            Error::<Test>::NotDelegated
+        );
+    })
+}
+
+#[test]
+fn calls_are_filtered() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(GovernanceExecutor::delegate(
+            RuntimeOrigin::root(),
+            EXECUTOR,
+            10
+        ));
+
+        let call = Box::new(RuntimeCall::System(frame_system::Call::remark {
+            remark: vec![1],
+        }));
+        assert_noop!(
+            GovernanceExecutor::execute(EXECUTOR, call),
+            Error::<Test>::CallFiltered
+        );
+
+        // Allowed calls can still fail when dispatched as root
+        let call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+            remark: vec![1],
+        }));
+        assert!(GovernanceExecutor::execute(EXECUTOR, call).is_err());
+    })
+}
+
+#[test]
+fn delegation_expires() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(GovernanceExecutor::delegate(
+            RuntimeOrigin::root(),
+            EXECUTOR,
+            10
+        ));
+
+        System::set_block_number(9);
+        assert_ok!(GovernanceExecutor::execute(EXECUTOR, set_storage(b"value")));
+
+        System::set_block_number(10);
+        assert!(!GovernanceExecutor::is_executor(&EXECUTOR));
+        assert_noop!(
+            GovernanceExecutor::execute(EXECUTOR, set_storage(b"value")),
+            Error::<Test>::DelegationExpired
+        );
+
+        // Expired delegations can be renewed or revoked
+        assert_ok!(GovernanceExecutor::delegate(
+            RuntimeOrigin::root(),
+            EXECUTOR,
+            20
+        ));
+        assert!(GovernanceExecutor::is_executor(&EXECUTOR));
+    })
+}
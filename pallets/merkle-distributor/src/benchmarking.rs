@@ -0,0 +1,94 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as MerkleDistributor;
+
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
+
+fn funded_source<T: Config>(total: BalanceOf<T>) -> T::AccountId {
+    let source: T::AccountId = account("source", 0, 0);
+    T::Currency::make_free_balance_be(
+        &source,
+        total.saturating_add(T::Currency::minimum_balance()),
+    );
+    source
+}
+
+fn create_with_root<T: Config>(merkle_root: H256, total: BalanceOf<T>) -> DistributionId {
+    let source = funded_source::<T>(total);
+    let id = NextDistributionId::<T>::get();
+    MerkleDistributor::<T>::create_distribution(
+        RawOrigin::Root.into(),
+        T::Lookup::unlookup(source),
+        merkle_root,
+        total,
+    )
+    .expect("Source is funded; qed");
+    id
+}
+
+benchmarks! {
+
+    create_distribution {
+        let total = T::Currency::minimum_balance().saturating_mul(1000_u32.into());
+        let source = funded_source::<T>(total);
+        let merkle_root = H256::repeat_byte(0x01);
+        let id = NextDistributionId::<T>::get();
+
+    }: _(RawOrigin::Root, T::Lookup::unlookup(source), merkle_root, total)
+    verify {
+        assert!(Distributions::<T>::contains_key(id));
+    }
+
+    claim {
+        let x in 0 .. T::MaxProofLength::get();
+
+        let amount = T::Currency::minimum_balance().saturating_mul(10_u32.into());
+        let claimant = Claimant::Evm(H160::repeat_byte(0xAA));
+        let proof: Vec<H256> = (0..x).map(|i| H256::from_low_u64_be(i as u64)).collect();
+        let merkle_root = MerkleDistributor::<T>::compute_root(
+            MerkleDistributor::<T>::leaf_hash(&claimant, amount),
+            &proof,
+        );
+        let id = create_with_root::<T>(merkle_root, amount.saturating_mul(2_u32.into()));
+        let caller: T::AccountId = whitelisted_caller();
+        let proof: BoundedVec<H256, T::MaxProofLength> = proof.try_into().expect("Within bounds; qed");
+
+    }: _(RawOrigin::Signed(caller), id, claimant.clone(), amount, proof)
+    verify {
+        assert!(MerkleDistributor::<T>::is_claimed(id, &claimant, amount));
+    }
+
+    close_distribution {
+        let total = T::Currency::minimum_balance().saturating_mul(1000_u32.into());
+        let id = create_with_root::<T>(H256::repeat_byte(0x01), total);
+        let dest: T::AccountId = account("dest", 0, 0);
+
+    }: _(RawOrigin::Root, id, T::Lookup::unlookup(dest))
+    verify {
+        assert!(!Distributions::<T>::contains_key(id));
+    }
+}
+
+impl_benchmark_test_suite!(
+    MerkleDistributor,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
@@ -0,0 +1,377 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Merkle Distributor Pallet
+//!
+//! ## Overview
+//!
+//! Pallet for large scale token distributions (e.g. snapshots or airdrops) which doesn't require
+//! a transfer extrinsic per recipient. Instead, `ManagerOrigin` registers a Merkle root of all
+//! `(claimant, amount)` entries and each recipient claims its own entry by providing a Merkle proof.
+//!
+//! Both native and EVM recipients are supported. Leaf is computed as
+//! `keccak256(claimant ++ amount)`, where:
+//! - `claimant` is either the SCALE encoded native account (raw 32 bytes for `AccountId32`) or the raw 20 byte EVM address
+//! - `amount` is encoded as 32 byte big endian integer (same as Solidity `uint256`)
+//!
+//! Proof is verified using sorted pair hashing (`keccak256(min(a, b) ++ max(a, b))`), which is compatible
+//! with commonly used Merkle tree tooling, e.g. OpenZeppelin `MerkleProof`.
+//!
+//! Rewards for EVM claimants are paid out to the native account derived via `EvmAddressMapping`.
+//!
+//! Each distribution is funded upfront, into its own account derived from `PalletId` and distribution Id.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `create_distribution` - registers a new distribution and funds it from the given source account
+//! - `claim` - claims an entry of the distribution, can be called by anyone on behalf of the claimant
+//! - `close_distribution` - removes the distribution and transfers the unclaimed remainder to the given account
+//!
+//! ### Other
+//!
+//! - `leaf_hash` - computes the leaf for the given claimant and amount
+//! - `verify_proof` - verifies the Merkle proof of the given leaf
+//! - `compute_root` - computes the Merkle root implied by the given leaf and proof
+//! - `is_claimed` - checks whether the entry has already been claimed
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Currency, ExistenceRequirement, Get},
+    PalletId,
+};
+use frame_system::pallet_prelude::*;
+use pallet_evm::AddressMapping;
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::{
+    AccountIdConversion, Saturating, StaticLookup, UniqueSaturatedInto, Zero,
+};
+use sp_std::prelude::*;
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Distribution identifier.
+pub type DistributionId = u32;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+/// Recipient of a distribution entry.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Claimant<AccountId> {
+    /// Native account.
+    Native(AccountId),
+    /// EVM address.
+    Evm(H160),
+}
+
+/// Registered distribution.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DistributionInfo<Balance> {
+    /// Merkle root of all `(claimant, amount)` entries.
+    pub merkle_root: H256,
+    /// Total amount funded for the distribution.
+    pub total: Balance,
+    /// Amount claimed so far.
+    pub claimed: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Currency used for the distributions.
+        type Currency: Currency<Self::AccountId>;
+
+        /// Used to derive accounts holding the distribution funds.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Mapping used to derive native account of the EVM claimant.
+        type EvmAddressMapping: AddressMapping<Self::AccountId>;
+
+        /// The required origin for managing distributions.
+        ///
+        /// Should most likely be root or governance.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Maximum length of the Merkle proof, i.e. maximum depth of the tree.
+        #[pallet::constant]
+        type MaxProofLength: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Distribution with the given Id doesn't exist.
+        DistributionNotFound,
+        /// Merkle proof doesn't match the distribution root.
+        InvalidProof,
+        /// Entry has already been claimed.
+        AlreadyClaimed,
+        /// Claim would exceed the total amount funded for the distribution.
+        InsufficientDistributionFunds,
+        /// Distribution must distribute a non-zero amount.
+        ZeroTotal,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// New distribution was created and funded.
+        DistributionCreated {
+            id: DistributionId,
+            merkle_root: H256,
+            total: BalanceOf<T>,
+        },
+        /// Distribution entry was claimed.
+        Claimed {
+            id: DistributionId,
+            claimant: Claimant<T::AccountId>,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Distribution was closed, unclaimed remainder was transferred out.
+        DistributionClosed {
+            id: DistributionId,
+            remainder: BalanceOf<T>,
+        },
+    }
+
+    /// Id which will be assigned to the next distribution.
+    #[pallet::storage]
+    pub type NextDistributionId<T: Config> = StorageValue<_, DistributionId, ValueQuery>;
+
+    /// Registered distributions.
+    #[pallet::storage]
+    #[pallet::getter(fn distributions)]
+    pub type Distributions<T: Config> =
+        StorageMap<_, Twox64Concat, DistributionId, DistributionInfo<BalanceOf<T>>, OptionQuery>;
+
+    /// Claimed leaves, per distribution.
+    #[pallet::storage]
+    pub type ClaimedLeaves<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, DistributionId, Identity, H256, (), OptionQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register a new distribution with the given Merkle root.
+        ///
+        /// `total` is transferred from `source` into the distribution account.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_distribution())]
+        pub fn create_distribution(
+            origin: OriginFor<T>,
+            source: AccountIdLookupOf<T>,
+            merkle_root: H256,
+            total: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let source = T::Lookup::lookup(source)?;
+
+            ensure!(!total.is_zero(), Error::<T>::ZeroTotal);
+
+            let id = NextDistributionId::<T>::get();
+            T::Currency::transfer(
+                &source,
+                &Self::account_id(id),
+                total,
+                ExistenceRequirement::AllowDeath,
+            )?;
+
+            Distributions::<T>::insert(
+                id,
+                DistributionInfo {
+                    merkle_root,
+                    total,
+                    claimed: Zero::zero(),
+                },
+            );
+            NextDistributionId::<T>::put(id.saturating_add(1));
+
+            Self::deposit_event(Event::DistributionCreated {
+                id,
+                merkle_root,
+                total,
+            });
+            Ok(())
+        }
+
+        /// Claim the `(claimant, amount)` entry of the distribution.
+        ///
+        /// Can be called by anyone since the funds are always paid out to the claimant.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::claim(proof.len() as u32))]
+        pub fn claim(
+            origin: OriginFor<T>,
+            id: DistributionId,
+            claimant: Claimant<T::AccountId>,
+            amount: BalanceOf<T>,
+            proof: BoundedVec<H256, T::MaxProofLength>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut distribution =
+                Distributions::<T>::get(id).ok_or(Error::<T>::DistributionNotFound)?;
+
+            let leaf = Self::leaf_hash(&claimant, amount);
+            ensure!(
+                !ClaimedLeaves::<T>::contains_key(id, leaf),
+                Error::<T>::AlreadyClaimed
+            );
+            ensure!(
+                Self::verify_proof(leaf, &proof, distribution.merkle_root),
+                Error::<T>::InvalidProof
+            );
+
+            let claimed = distribution.claimed.saturating_add(amount);
+            ensure!(
+                claimed <= distribution.total,
+                Error::<T>::InsufficientDistributionFunds
+            );
+
+            let beneficiary = match &claimant {
+                Claimant::Native(account) => account.clone(),
+                Claimant::Evm(address) => T::EvmAddressMapping::into_account_id(*address),
+            };
+            T::Currency::transfer(
+                &Self::account_id(id),
+                &beneficiary,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+
+            distribution.claimed = claimed;
+            Distributions::<T>::insert(id, distribution);
+            ClaimedLeaves::<T>::insert(id, leaf, ());
+
+            Self::deposit_event(Event::Claimed {
+                id,
+                claimant,
+                beneficiary,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Close the distribution and transfer the unclaimed remainder to `dest`.
+        ///
+        /// Claimed leaves of the distribution are not cleaned up.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::close_distribution())]
+        pub fn close_distribution(
+            origin: OriginFor<T>,
+            id: DistributionId,
+            dest: AccountIdLookupOf<T>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let dest = T::Lookup::lookup(dest)?;
+
+            ensure!(
+                Distributions::<T>::contains_key(id),
+                Error::<T>::DistributionNotFound
+            );
+
+            let account = Self::account_id(id);
+            let remainder = T::Currency::free_balance(&account);
+            T::Currency::transfer(&account, &dest, remainder, ExistenceRequirement::AllowDeath)?;
+            Distributions::<T>::remove(id);
+
+            Self::deposit_event(Event::DistributionClosed { id, remainder });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Account holding the funds of the distribution.
+        pub fn account_id(id: DistributionId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(id)
+        }
+
+        /// Leaf of the `(claimant, amount)` entry.
+        pub fn leaf_hash(claimant: &Claimant<T::AccountId>, amount: BalanceOf<T>) -> H256 {
+            let mut data = match claimant {
+                Claimant::Native(account) => account.encode(),
+                Claimant::Evm(address) => address.as_bytes().to_vec(),
+            };
+
+            let mut amount_bytes = [0_u8; 32];
+            U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(amount))
+                .to_big_endian(&mut amount_bytes);
+            data.extend_from_slice(&amount_bytes);
+
+            keccak_256(&data).into()
+        }
+
+        /// `true` if `proof` proves that `leaf` is part of the tree with `root`.
+        pub fn verify_proof(leaf: H256, proof: &[H256], root: H256) -> bool {
+            Self::compute_root(leaf, proof) == root
+        }
+
+        /// Root of the tree as implied by `leaf` and its `proof`.
+        pub fn compute_root(leaf: H256, proof: &[H256]) -> H256 {
+            proof.iter().fold(leaf, |acc, node| {
+                let (first, second) = if acc <= *node {
+                    (acc, *node)
+                } else {
+                    (*node, acc)
+                };
+
+                let mut data = [0_u8; 64];
+                data[..32].copy_from_slice(first.as_bytes());
+                data[32..].copy_from_slice(second.as_bytes());
+                keccak_256(&data).into()
+            })
+        }
+
+        /// `true` if the `(claimant, amount)` entry of the distribution was already claimed.
+        pub fn is_claimed(
+            id: DistributionId,
+            claimant: &Claimant<T::AccountId>,
+            amount: BalanceOf<T>,
+        ) -> bool {
+            ClaimedLeaves::<T>::contains_key(id, Self::leaf_hash(claimant, amount))
+        }
+    }
+}
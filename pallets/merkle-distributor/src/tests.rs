@@ -0,0 +1,334 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::{pallet::Error, pallet::Event, *};
+use frame_support::{assert_noop, assert_ok, traits::ConstU32};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+const EVM_CLAIMANT: H160 = H160::repeat_byte(0x07);
+
+/// Simple 4 leaf tree, with helpers to get leaf proofs.
+struct Tree {
+    leaves: [H256; 4],
+}
+
+impl Tree {
+    fn new(entries: [(Claimant<AccountId>, Balance); 4]) -> Self {
+        Self {
+            leaves: entries
+                .map(|(claimant, amount)| MerkleDistributor::leaf_hash(&claimant, amount)),
+        }
+    }
+
+    fn node(a: H256, b: H256) -> H256 {
+        MerkleDistributor::compute_root(a, &[b])
+    }
+
+    fn root(&self) -> H256 {
+        Self::node(
+            Self::node(self.leaves[0], self.leaves[1]),
+            Self::node(self.leaves[2], self.leaves[3]),
+        )
+    }
+
+    fn proof(&self, index: usize) -> BoundedVec<H256, ConstU32<MAX_PROOF_LENGTH>> {
+        let sibling = self.leaves[index ^ 1];
+        let other_pair = if index < 2 {
+            Self::node(self.leaves[2], self.leaves[3])
+        } else {
+            Self::node(self.leaves[0], self.leaves[1])
+        };
+        vec![sibling, other_pair].try_into().unwrap()
+    }
+}
+
+fn default_tree() -> Tree {
+    Tree::new([
+        (Claimant::Native(BOB), 100),
+        (Claimant::Evm(EVM_CLAIMANT), 200),
+        (Claimant::Native(3), 300),
+        (Claimant::Native(4), 400),
+    ])
+}
+
+fn create_default_distribution(tree: &Tree) -> DistributionId {
+    let id = NextDistributionId::<Test>::get();
+    assert_ok!(MerkleDistributor::create_distribution(
+        RuntimeOrigin::root(),
+        ALICE,
+        tree.root(),
+        1000
+    ));
+    id
+}
+
+#[test]
+fn only_manager_origin() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            MerkleDistributor::create_distribution(
+                RuntimeOrigin::signed(ALICE),
+                ALICE,
+                H256::zero(),
+                1000
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            MerkleDistributor::close_distribution(RuntimeOrigin::signed(ALICE), 0, ALICE),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn create_distribution_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let tree = default_tree();
+        let id = create_default_distribution(&tree);
+
+        System::assert_last_event(mock::RuntimeEvent::MerkleDistributor(
+            Event::DistributionCreated {
+                id,
+                merkle_root: tree.root(),
+                total: 1000,
+            },
+        ));
+        assert_eq!(
+            Distributions::<Test>::get(id),
+            Some(DistributionInfo {
+                merkle_root: tree.root(),
+                total: 1000,
+                claimed: 0,
+            })
+        );
+        assert_eq!(NextDistributionId::<Test>::get(), id + 1);
+        assert_eq!(
+            Balances::free_balance(MerkleDistributor::account_id(id)),
+            1000
+        );
+        assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 1000);
+    })
+}
+
+#[test]
+fn create_distribution_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            MerkleDistributor::create_distribution(RuntimeOrigin::root(), ALICE, H256::zero(), 0),
+            Error::<Test>::ZeroTotal
+        );
+        assert!(MerkleDistributor::create_distribution(
+            RuntimeOrigin::root(),
+            ALICE,
+            H256::zero(),
+            INITIAL_BALANCE + 1
+        )
+        .is_err());
+    })
+}
+
+#[test]
+fn claim_native_and_evm_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let tree = default_tree();
+        let id = create_default_distribution(&tree);
+
+        // Anyone can claim on behalf of the native claimant
+        assert_ok!(MerkleDistributor::claim(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            Claimant::Native(BOB),
+            100,
+            tree.proof(0)
+        ));
+        System::assert_last_event(mock::RuntimeEvent::MerkleDistributor(Event::Claimed {
+            id,
+            claimant: Claimant::Native(BOB),
+            beneficiary: BOB,
+            amount: 100,
+        }));
+        assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE + 100);
+
+        // EVM claimant is paid out to the mapped account
+        let mapped = MockAddressMapping::into_account_id(EVM_CLAIMANT);
+        assert_ok!(MerkleDistributor::claim(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            Claimant::Evm(EVM_CLAIMANT),
+            200,
+            tree.proof(1)
+        ));
+        assert_eq!(Balances::free_balance(mapped), 200);
+
+        assert!(MerkleDistributor::is_claimed(
+            id,
+            &Claimant::Native(BOB),
+            100
+        ));
+        assert!(MerkleDistributor::is_claimed(
+            id,
+            &Claimant::Evm(EVM_CLAIMANT),
+            200
+        ));
+        assert!(!MerkleDistributor::is_claimed(
+            id,
+            &Claimant::Native(3),
+            300
+        ));
+        assert_eq!(Distributions::<Test>::get(id).unwrap().claimed, 300);
+    })
+}
+
+#[test]
+fn claim_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        let tree = default_tree();
+
+        assert_noop!(
+            MerkleDistributor::claim(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                Claimant::Native(BOB),
+                100,
+                tree.proof(0)
+            ),
+            Error::<Test>::DistributionNotFound
+        );
+
+        let id = create_default_distribution(&tree);
+
+        // Wrong amount
+        assert_noop!(
+            MerkleDistributor::claim(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Claimant::Native(BOB),
+                101,
+                tree.proof(0)
+            ),
+            Error::<Test>::InvalidProof
+        );
+        // Wrong proof
+        assert_noop!(
+            MerkleDistributor::claim(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Claimant::Native(BOB),
+                100,
+                tree.proof(2)
+            ),
+            Error::<Test>::InvalidProof
+        );
+        // Same address in a different leaf format
+        assert_noop!(
+            MerkleDistributor::claim(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Claimant::Native(MockAddressMapping::into_account_id(EVM_CLAIMANT)),
+                200,
+                tree.proof(1)
+            ),
+            Error::<Test>::InvalidProof
+        );
+
+        assert_ok!(MerkleDistributor::claim(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            Claimant::Native(BOB),
+            100,
+            tree.proof(0)
+        ));
+        assert_noop!(
+            MerkleDistributor::claim(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Claimant::Native(BOB),
+                100,
+                tree.proof(0)
+            ),
+            Error::<Test>::AlreadyClaimed
+        );
+    })
+}
+
+#[test]
+fn claim_cannot_exceed_total() {
+    ExternalityBuilder::build().execute_with(|| {
+        let tree = default_tree();
+        let id = NextDistributionId::<Test>::get();
+        // Underfunded distribution
+        assert_ok!(MerkleDistributor::create_distribution(
+            RuntimeOrigin::root(),
+            ALICE,
+            tree.root(),
+            500
+        ));
+
+        assert_ok!(MerkleDistributor::claim(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            Claimant::Native(4),
+            400,
+            tree.proof(3)
+        ));
+        assert_noop!(
+            MerkleDistributor::claim(
+                RuntimeOrigin::signed(ALICE),
+                id,
+                Claimant::Native(3),
+                300,
+                tree.proof(2)
+            ),
+            Error::<Test>::InsufficientDistributionFunds
+        );
+    })
+}
+
+#[test]
+fn close_distribution_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let tree = default_tree();
+        let id = create_default_distribution(&tree);
+
+        assert_ok!(MerkleDistributor::claim(
+            RuntimeOrigin::signed(ALICE),
+            id,
+            Claimant::Native(BOB),
+            100,
+            tree.proof(0)
+        ));
+
+        assert_ok!(MerkleDistributor::close_distribution(
+            RuntimeOrigin::root(),
+            id,
+            ALICE
+        ));
+        System::assert_last_event(mock::RuntimeEvent::MerkleDistributor(
+            Event::DistributionClosed { id, remainder: 900 },
+        ));
+        assert!(!Distributions::<Test>::contains_key(id));
+        assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 100);
+
+        assert_noop!(
+            MerkleDistributor::close_distribution(RuntimeOrigin::root(), id, ALICE),
+            Error::<Test>::DistributionNotFound
+        );
+    })
+}
@@ -0,0 +1,112 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_merkle_distributor
+//!
+//! TODO: these weights were not generated by the benchmark CLI. They are estimated from the storage
+//! accessed by each extrinsic and must be replaced by the output of the `pallet_merkle_distributor`
+//! benchmarks, run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_merkle_distributor.
+pub trait WeightInfo {
+	fn create_distribution() -> Weight;
+	fn claim(x: u32, ) -> Weight;
+	fn close_distribution() -> Weight;
+}
+
+/// Weights for pallet_merkle_distributor using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: MerkleDistributor NextDistributionId (r:1 w:1)
+	// Proof: MerkleDistributor NextDistributionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: MerkleDistributor Distributions (r:0 w:1)
+	// Proof: MerkleDistributor Distributions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn create_distribution() -> Weight {
+		Weight::from_parts(42_512_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: MerkleDistributor Distributions (r:1 w:1)
+	// Proof: MerkleDistributor Distributions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: MerkleDistributor ClaimedLeaves (r:1 w:1)
+	// Proof: MerkleDistributor ClaimedLeaves (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 32]`.
+	fn claim(x: u32, ) -> Weight {
+		Weight::from_parts(53_118_000, 6196)
+			.saturating_add(Weight::from_parts(1_372_511, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: MerkleDistributor Distributions (r:1 w:1)
+	// Proof: MerkleDistributor Distributions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn close_distribution() -> Weight {
+		Weight::from_parts(40_785_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: MerkleDistributor NextDistributionId (r:1 w:1)
+	// Proof: MerkleDistributor NextDistributionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: MerkleDistributor Distributions (r:0 w:1)
+	// Proof: MerkleDistributor Distributions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	fn create_distribution() -> Weight {
+		Weight::from_parts(42_512_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: MerkleDistributor Distributions (r:1 w:1)
+	// Proof: MerkleDistributor Distributions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: MerkleDistributor ClaimedLeaves (r:1 w:1)
+	// Proof: MerkleDistributor ClaimedLeaves (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 32]`.
+	fn claim(x: u32, ) -> Weight {
+		Weight::from_parts(53_118_000, 6196)
+			.saturating_add(Weight::from_parts(1_372_511, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	// Storage: MerkleDistributor Distributions (r:1 w:1)
+	// Proof: MerkleDistributor Distributions (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn close_distribution() -> Weight {
+		Weight::from_parts(40_785_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
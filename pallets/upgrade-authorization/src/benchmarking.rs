@@ -0,0 +1,52 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as UpgradeAuthorization;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+
+benchmarks! {
+
+    authorize_upgrade {
+        let code_hash = T::Hashing::hash(&[1_u8; 32]);
+
+    }: _(RawOrigin::Root, code_hash)
+    verify {
+        assert_eq!(
+            AuthorizedUpgrade::<T>::get().map(|authorization| authorization.code_hash),
+            Some(code_hash)
+        );
+    }
+
+    cancel_authorized_upgrade {
+        let code_hash = T::Hashing::hash(&[1_u8; 32]);
+        UpgradeAuthorization::<T>::authorize_upgrade(RawOrigin::Root.into(), code_hash)?;
+
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(AuthorizedUpgrade::<T>::get().is_none());
+    }
+}
+
+impl_benchmark_test_suite!(
+    UpgradeAuthorization,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
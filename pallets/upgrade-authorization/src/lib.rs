@@ -0,0 +1,253 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Upgrade Authorization Pallet
+//!
+//! ## Overview
+//!
+//! Two-step runtime upgrade process. `ManagerOrigin` first authorizes the hash of the new
+//! runtime code, after which anyone can enact the upgrade by submitting code matching the hash.
+//!
+//! The authorization is valid for `EnactmentPeriod` blocks, after which it automatically expires.
+//! It can also be cancelled by `ManagerOrigin` at any time before it's enacted.
+//! Only one upgrade can be authorized at a time.
+//!
+//! Enactment goes through `frame_system::Pallet::set_code`, so the new runtime's version
+//! is checked and the configured `OnSetCode` is used, e.g. to schedule a parachain validation
+//! function upgrade.
+//!
+//! Wrapping the runtime's `OnSetCode` in [`AuthorizedSetCode`] makes `frame_system`'s `set_code`
+//! and `set_code_without_checks` also require the code to be authorized, even when dispatched
+//! by root, which bypasses the `BaseCallFilter`.
+//!
+//! `cumulus_pallet_parachain_system` has its own `authorize_upgrade` and `enact_authorized_upgrade`
+//! calls which schedule the upgrade directly, without going through `OnSetCode`. Runtimes using this
+//! pallet should reject them in their `BaseCallFilter`. Since root bypasses the filter, a root origin
+//! can still use them, but only by dispatching both calls itself.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `authorize_upgrade` - authorizes an upgrade to the runtime with the given code hash
+//! - `enact_authorized_upgrade` - enacts the authorized upgrade
+//! - `cancel_authorized_upgrade` - cancels the authorized upgrade
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    dispatch::{DispatchClass, Pays},
+    pallet_prelude::*,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use sp_runtime::traits::{Hash, Saturating};
+use sp_std::prelude::*;
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Authorized runtime upgrade.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct UpgradeAuthorization<Hash, BlockNumber> {
+    /// Hash of the authorized runtime code.
+    pub code_hash: Hash,
+    /// Last block in which the upgrade can be enacted.
+    pub expires_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The required origin for authorizing and cancelling upgrades.
+        ///
+        /// Should most likely be root or governance.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Number of blocks after the authorization in which the upgrade can be enacted.
+        #[pallet::constant]
+        type EnactmentPeriod: Get<BlockNumberFor<Self>>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Another upgrade is already authorized, it must be enacted or cancelled first.
+        UpgradeAlreadyAuthorized,
+        /// There is no authorized upgrade.
+        NothingAuthorized,
+        /// The authorized upgrade has expired.
+        AuthorizationExpired,
+        /// Hash of the provided code doesn't match the authorized code hash.
+        UnauthorizedCode,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Runtime upgrade was authorized.
+        UpgradeAuthorized {
+            code_hash: T::Hash,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// Authorized runtime upgrade was enacted.
+        UpgradeEnacted { code_hash: T::Hash },
+        /// Authorized runtime upgrade was cancelled.
+        UpgradeAuthorizationCancelled { code_hash: T::Hash },
+        /// Authorized runtime upgrade has expired without being enacted.
+        UpgradeAuthorizationExpired { code_hash: T::Hash },
+    }
+
+    /// Currently authorized runtime upgrade.
+    #[pallet::storage]
+    #[pallet::getter(fn authorized_upgrade)]
+    pub type AuthorizedUpgrade<T: Config> =
+        StorageValue<_, UpgradeAuthorization<T::Hash, BlockNumberFor<T>>, OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            match AuthorizedUpgrade::<T>::get() {
+                Some(authorization) if now > authorization.expires_at => {
+                    AuthorizedUpgrade::<T>::kill();
+                    Self::deposit_event(Event::UpgradeAuthorizationExpired {
+                        code_hash: authorization.code_hash,
+                    });
+                    T::DbWeight::get().reads_writes(1, 1)
+                }
+                _ => T::DbWeight::get().reads(1),
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Authorize an upgrade to the runtime with `code_hash`.
+        ///
+        /// The upgrade must be enacted within `EnactmentPeriod` blocks.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::authorize_upgrade())]
+        pub fn authorize_upgrade(origin: OriginFor<T>, code_hash: T::Hash) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !AuthorizedUpgrade::<T>::exists(),
+                Error::<T>::UpgradeAlreadyAuthorized
+            );
+
+            let expires_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::EnactmentPeriod::get());
+            AuthorizedUpgrade::<T>::put(UpgradeAuthorization {
+                code_hash,
+                expires_at,
+            });
+            Self::deposit_event(Event::UpgradeAuthorized {
+                code_hash,
+                expires_at,
+            });
+
+            Ok(())
+        }
+
+        /// Enact the authorized upgrade by providing the runtime `code`.
+        ///
+        /// Can be called by anyone, the caller doesn't pay a fee if the upgrade succeeds.
+        #[pallet::call_index(1)]
+        #[pallet::weight((T::BlockWeights::get().max_block, DispatchClass::Operational))]
+        pub fn enact_authorized_upgrade(
+            origin: OriginFor<T>,
+            code: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let code_hash = Self::ensure_authorized(&code)?;
+
+            frame_system::Pallet::<T>::set_code(RawOrigin::Root.into(), code)
+                .map_err(|e| e.error)?;
+
+            AuthorizedUpgrade::<T>::kill();
+            Self::deposit_event(Event::UpgradeEnacted { code_hash });
+
+            Ok(Pays::No.into())
+        }
+
+        /// Cancel the authorized upgrade.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::cancel_authorized_upgrade())]
+        pub fn cancel_authorized_upgrade(origin: OriginFor<T>) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let authorization =
+                AuthorizedUpgrade::<T>::take().ok_or(Error::<T>::NothingAuthorized)?;
+            Self::deposit_event(Event::UpgradeAuthorizationCancelled {
+                code_hash: authorization.code_hash,
+            });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Ensure `code` is authorized and its authorization hasn't expired, returning its hash.
+        pub(crate) fn ensure_authorized(code: &[u8]) -> Result<T::Hash, Error<T>> {
+            let authorization =
+                AuthorizedUpgrade::<T>::get().ok_or(Error::<T>::NothingAuthorized)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= authorization.expires_at,
+                Error::<T>::AuthorizationExpired
+            );
+            ensure!(
+                T::Hashing::hash(code) == authorization.code_hash,
+                Error::<T>::UnauthorizedCode
+            );
+
+            Ok(authorization.code_hash)
+        }
+    }
+}
+
+/// `OnSetCode` implementation which only lets the authorized code through to `Inner`.
+pub struct AuthorizedSetCode<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T: Config, Inner: frame_system::SetCode<T>> frame_system::SetCode<T>
+    for AuthorizedSetCode<T, Inner>
+{
+    fn set_code(code: Vec<u8>) -> DispatchResult {
+        Pallet::<T>::ensure_authorized(&code)?;
+        Inner::set_code(code)
+    }
+}
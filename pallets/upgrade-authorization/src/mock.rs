@@ -0,0 +1,129 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_upgrade_authorization};
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU64, Everything},
+    weights::Weight,
+};
+use parity_scale_codec::Encode;
+use sp_core::{
+    traits::{ReadRuntimeVersion, ReadRuntimeVersionExt},
+    H256,
+};
+
+use sp_io::TestExternalities;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+use sp_version::RuntimeVersion;
+
+type BlockNumber = u64;
+pub(crate) type AccountId = u64;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) const ENACTMENT_PERIOD: BlockNumber = 10;
+
+construct_runtime!(
+    pub struct Test
+    where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        UpgradeAuthorization: pallet_upgrade_authorization,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1024, 0));
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = BlockWeights;
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Index = u64;
+    type RuntimeCall = RuntimeCall;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = pallet_upgrade_authorization::AuthorizedSetCode<Test, ()>;
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_upgrade_authorization::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type EnactmentPeriod = ConstU64<ENACTMENT_PERIOD>;
+    type WeightInfo = ();
+}
+
+/// Reports a runtime version with a higher spec version than the current one for any code,
+/// so the code passes `frame_system::Pallet::can_set_code`.
+struct UpgradedRuntimeVersion;
+impl ReadRuntimeVersion for UpgradedRuntimeVersion {
+    fn read_runtime_version(
+        &self,
+        _wasm_code: &[u8],
+        _ext: &mut dyn sp_externalities::Externalities,
+    ) -> Result<Vec<u8>, String> {
+        Ok(RuntimeVersion {
+            spec_version: 1,
+            ..Default::default()
+        }
+        .encode())
+    }
+}
+
+pub struct ExternalityBuilder;
+
+impl ExternalityBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.register_extension(ReadRuntimeVersionExt::new(UpgradedRuntimeVersion));
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
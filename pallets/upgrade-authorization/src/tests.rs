@@ -0,0 +1,220 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::{pallet::Error, pallet::Event, UpgradeAuthorization as Authorization};
+use crate::mock::{self, *};
+use frame_support::{assert_noop, assert_ok, dispatch::Pays, traits::Hooks};
+use sp_core::H256;
+use sp_runtime::traits::{BadOrigin, BlakeTwo256, Hash};
+
+fn runtime_code() -> Vec<u8> {
+    b"new runtime code".to_vec()
+}
+
+fn runtime_code_hash() -> H256 {
+    BlakeTwo256::hash(&runtime_code())
+}
+
+#[test]
+fn only_manager_origin() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            UpgradeAuthorization::authorize_upgrade(RuntimeOrigin::signed(1), runtime_code_hash()),
+            BadOrigin
+        );
+        assert_noop!(
+            UpgradeAuthorization::cancel_authorized_upgrade(RuntimeOrigin::signed(1)),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn authorize_upgrade_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(UpgradeAuthorization::authorize_upgrade(
+            RuntimeOrigin::root(),
+            runtime_code_hash()
+        ));
+        System::assert_last_event(mock::RuntimeEvent::UpgradeAuthorization(
+            Event::UpgradeAuthorized {
+                code_hash: runtime_code_hash(),
+                expires_at: 1 + ENACTMENT_PERIOD,
+            },
+        ));
+        assert_eq!(
+            UpgradeAuthorization::authorized_upgrade(),
+            Some(Authorization {
+                code_hash: runtime_code_hash(),
+                expires_at: 1 + ENACTMENT_PERIOD,
+            })
+        );
+
+        // Only one upgrade can be authorized at a time
+        assert_noop!(
+            UpgradeAuthorization::authorize_upgrade(RuntimeOrigin::root(), H256::repeat_byte(1)),
+            Error::<Test>::UpgradeAlreadyAuthorized
+        );
+    })
+}
+
+#[test]
+fn enact_authorized_upgrade_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(UpgradeAuthorization::authorize_upgrade(
+            RuntimeOrigin::root(),
+            runtime_code_hash()
+        ));
+
+        let post_info = UpgradeAuthorization::enact_authorized_upgrade(
+            RuntimeOrigin::signed(1),
+            runtime_code(),
+        )
+        .unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+
+        System::assert_has_event(mock::RuntimeEvent::System(frame_system::Event::CodeUpdated));
+        System::assert_last_event(mock::RuntimeEvent::UpgradeAuthorization(
+            Event::UpgradeEnacted {
+                code_hash: runtime_code_hash(),
+            },
+        ));
+        assert_eq!(
+            sp_io::storage::get(sp_core::storage::well_known_keys::CODE),
+            Some(runtime_code().into())
+        );
+        assert!(UpgradeAuthorization::authorized_upgrade().is_none());
+    })
+}
+
+#[test]
+fn enact_authorized_upgrade_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            UpgradeAuthorization::enact_authorized_upgrade(
+                RuntimeOrigin::signed(1),
+                runtime_code()
+            ),
+            Error::<Test>::NothingAuthorized
+        );
+
+        assert_ok!(UpgradeAuthorization::authorize_upgrade(
+            RuntimeOrigin::root(),
+            runtime_code_hash()
+        ));
+
+        assert_noop!(
+            UpgradeAuthorization::enact_authorized_upgrade(RuntimeOrigin::none(), runtime_code()),
+            BadOrigin
+        );
+        assert_noop!(
+            UpgradeAuthorization::enact_authorized_upgrade(
+                RuntimeOrigin::signed(1),
+                b"other runtime code".to_vec()
+            ),
+            Error::<Test>::UnauthorizedCode
+        );
+
+        System::set_block_number(2 + ENACTMENT_PERIOD);
+        assert_noop!(
+            UpgradeAuthorization::enact_authorized_upgrade(
+                RuntimeOrigin::signed(1),
+                runtime_code()
+            ),
+            Error::<Test>::AuthorizationExpired
+        );
+    })
+}
+
+#[test]
+fn cancel_authorized_upgrade_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            UpgradeAuthorization::cancel_authorized_upgrade(RuntimeOrigin::root()),
+            Error::<Test>::NothingAuthorized
+        );
+
+        assert_ok!(UpgradeAuthorization::authorize_upgrade(
+            RuntimeOrigin::root(),
+            runtime_code_hash()
+        ));
+        assert_ok!(UpgradeAuthorization::cancel_authorized_upgrade(
+            RuntimeOrigin::root()
+        ));
+        System::assert_last_event(mock::RuntimeEvent::UpgradeAuthorization(
+            Event::UpgradeAuthorizationCancelled {
+                code_hash: runtime_code_hash(),
+            },
+        ));
+        assert!(UpgradeAuthorization::authorized_upgrade().is_none());
+    })
+}
+
+#[test]
+fn authorization_expires_on_initialize() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(UpgradeAuthorization::authorize_upgrade(
+            RuntimeOrigin::root(),
+            runtime_code_hash()
+        ));
+
+        // Still valid at the last block of the enactment period
+        UpgradeAuthorization::on_initialize(1 + ENACTMENT_PERIOD);
+        assert!(UpgradeAuthorization::authorized_upgrade().is_some());
+
+        UpgradeAuthorization::on_initialize(2 + ENACTMENT_PERIOD);
+        System::assert_last_event(mock::RuntimeEvent::UpgradeAuthorization(
+            Event::UpgradeAuthorizationExpired {
+                code_hash: runtime_code_hash(),
+            },
+        ));
+        assert!(UpgradeAuthorization::authorized_upgrade().is_none());
+    })
+}
+
+#[test]
+fn set_code_requires_authorization() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            System::set_code(RuntimeOrigin::root(), runtime_code()),
+            Error::<Test>::NothingAuthorized
+        );
+        assert_noop!(
+            System::set_code_without_checks(RuntimeOrigin::root(), runtime_code()),
+            Error::<Test>::NothingAuthorized
+        );
+
+        assert_ok!(UpgradeAuthorization::authorize_upgrade(
+            RuntimeOrigin::root(),
+            runtime_code_hash()
+        ));
+        assert_noop!(
+            System::set_code_without_checks(RuntimeOrigin::root(), b"other runtime code".to_vec()),
+            Error::<Test>::UnauthorizedCode
+        );
+
+        assert_ok!(System::set_code_without_checks(
+            RuntimeOrigin::root(),
+            runtime_code()
+        ));
+        assert_eq!(
+            sp_io::storage::get(sp_core::storage::well_known_keys::CODE),
+            Some(runtime_code().into())
+        );
+    })
+}
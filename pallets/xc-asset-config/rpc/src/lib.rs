@@ -0,0 +1,170 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for querying the cross-chain asset registry.
+//!
+//! Returns, in a single call, everything wallets and bridges need to know about registered assets:
+//! their location, whether they can be used to pay for execution, sufficiency and metadata.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::{Codec, Encode};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_xc_asset_config_runtime_api::{XcAssetInfo, XcAssetsApi as XcAssetsRuntimeApi};
+
+/// Registry entry of a cross-chain asset.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XcAsset<AssetId> {
+    pub asset_id: AssetId,
+    /// SCALE encoded `VersionedMultiLocation`.
+    pub location: Bytes,
+    /// `units per second` of execution, `null` if the asset isn't a supported payment asset.
+    pub units_per_second: Option<u128>,
+    pub is_sufficient: bool,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl<AssetId> From<XcAssetInfo<AssetId>> for XcAsset<AssetId> {
+    fn from(info: XcAssetInfo<AssetId>) -> Self {
+        let into_string = |bytes: Vec<u8>| String::from_utf8_lossy(&bytes).into_owned();
+
+        Self {
+            asset_id: info.asset_id,
+            location: info.location.encode().into(),
+            units_per_second: info.units_per_second,
+            is_sufficient: info.is_sufficient,
+            name: into_string(info.name),
+            symbol: into_string(info.symbol),
+            decimals: info.decimals,
+        }
+    }
+}
+
+#[rpc(client, server)]
+pub trait XcAssetsApi<BlockHash, AssetId> {
+    /// Returns registered cross-chain assets, starting after `start_after` if provided.
+    ///
+    /// At most `limit` assets are returned, further capped by the runtime.
+    /// Pass the Id of the last returned asset as `start_after` to get the next page,
+    /// an empty page means there are no more assets.
+    #[method(name = "xcAssets_assets")]
+    fn assets(
+        &self,
+        start_after: Option<AssetId>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<XcAsset<AssetId>>>;
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Provides RPC methods to query the cross-chain asset registry.
+pub struct XcAssets<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> XcAssets<C, Block> {
+    /// Creates a new instance of the `XcAssets` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AssetId> XcAssetsApiServer<<Block as BlockT>::Hash, AssetId> for XcAssets<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: XcAssetsRuntimeApi<Block, AssetId>,
+    AssetId: Codec + Serialize + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    fn assets(
+        &self,
+        start_after: Option<AssetId>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<XcAsset<AssetId>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let assets = api
+            .assets(at_hash, start_after, limit.unwrap_or(u32::MAX))
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    RUNTIME_ERROR,
+                    "Unable to query cross-chain assets.",
+                    Some(e.to_string()),
+                ))
+            })?;
+
+        Ok(assets.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xcm::{
+        latest::{Junction::Parachain, Junctions::X1, MultiLocation},
+        VersionedMultiLocation,
+    };
+
+    #[test]
+    fn xc_asset_is_serialized_as_expected() {
+        let location = VersionedMultiLocation::V3(MultiLocation::new(1, X1(Parachain(1000))));
+        let info = XcAssetInfo {
+            asset_id: 42_u128,
+            location: location.clone(),
+            units_per_second: Some(1_000_000),
+            is_sufficient: true,
+            name: b"Tether USD".to_vec(),
+            symbol: b"USDT".to_vec(),
+            decimals: 6,
+        };
+
+        assert_eq!(
+            serde_json::to_value(XcAsset::from(info)).unwrap(),
+            serde_json::json!({
+                "assetId": 42,
+                "location": Bytes(location.encode()),
+                "unitsPerSecond": 1_000_000,
+                "isSufficient": true,
+                "name": "Tether USD",
+                "symbol": "USDT",
+                "decimals": 6
+            })
+        );
+    }
+}
@@ -0,0 +1,37 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for querying the cross-chain asset registry.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_xc_asset_config::XcAssetInfo;
+
+sp_api::decl_runtime_apis! {
+    /// API for querying registered cross-chain assets.
+    pub trait XcAssetsApi<AssetId> where AssetId: Codec {
+        /// Registered assets with their location, payment status, sufficiency and metadata.
+        ///
+        /// Assets are returned in storage order, starting after `start_after` if provided.
+        /// The runtime may return less than `limit` entries, an empty page means there are no more assets.
+        fn assets(start_after: Option<AssetId>, limit: u32) -> Vec<XcAssetInfo<AssetId>>;
+    }
+}
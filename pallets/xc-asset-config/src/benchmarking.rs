@@ -23,10 +23,20 @@ use crate::Pallet as XcAssetConfig;
 
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
 use frame_system::RawOrigin;
+use sp_runtime::Perbill;
 use sp_std::boxed::Box;
-use xcm::v3::MultiLocation;
+use xcm::v3::{Junction, Junctions, MultiLocation};
+
+fn auto_params() -> AutoUnitsPerSecondParams {
+    AutoUnitsPerSecondParams {
+        min_units_per_second: 1,
+        max_units_per_second: u128::MAX,
+        deadband: Perbill::from_percent(5),
+    }
+}
 
 benchmarks! {
+    where_clause { where T::AssetId: From<u32> }
 
     register_asset_location {
         let asset_location = MultiLocation::parent();
@@ -94,6 +104,47 @@ benchmarks! {
         assert!(!AssetLocationUnitsPerSecond::<T>::contains_key(&asset_location.into_versioned()));
     }
 
+    enable_auto_units_per_second {
+        let asset_location = MultiLocation::parent();
+        let asset_id = T::AssetId::default();
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.into_versioned()), asset_id)?;
+        XcAssetConfig::<T>::set_asset_units_per_second(RawOrigin::Root.into(), Box::new(asset_location.into_versioned()), 1)?;
+
+    }: _(RawOrigin::Root, asset_id, auto_params())
+    verify {
+        assert_eq!(AutoUnitsPerSecond::<T>::get(&asset_id), Some(auto_params()));
+    }
+
+    disable_auto_units_per_second {
+        let asset_location = MultiLocation::parent();
+        let asset_id = T::AssetId::default();
+
+        XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.into_versioned()), asset_id)?;
+        XcAssetConfig::<T>::set_asset_units_per_second(RawOrigin::Root.into(), Box::new(asset_location.into_versioned()), 1)?;
+        XcAssetConfig::<T>::enable_auto_units_per_second(RawOrigin::Root.into(), asset_id, auto_params())?;
+
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert!(!AutoUnitsPerSecond::<T>::contains_key(&asset_id));
+    }
+
+    recalculate_units_per_second {
+        let x in 0 .. T::MaxAutoUnitsPerSecondAssets::get();
+
+        for i in 0..x {
+            let asset_location = MultiLocation::new(0, Junctions::X1(Junction::GeneralIndex(i.into())));
+            let asset_id: T::AssetId = i.into();
+
+            XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.into_versioned()), asset_id)?;
+            XcAssetConfig::<T>::set_asset_units_per_second(RawOrigin::Root.into(), Box::new(asset_location.into_versioned()), 1)?;
+            XcAssetConfig::<T>::enable_auto_units_per_second(RawOrigin::Root.into(), asset_id, auto_params())?;
+        }
+
+    }: {
+        XcAssetConfig::<T>::recalculate_units_per_second();
+    }
+
 }
 
 #[cfg(test)]
@@ -37,6 +37,8 @@
 //! - `change_existing_asset_location` - changes the remote location of an existing local asset Id
 //! - `remove_payment_asset` - removes asset from the set of supported payment assets
 //! - `remove_asset` - removes all information related to this asset
+//! - `enable_auto_units_per_second` - enables periodic derivation of `units per second` from the asset price
+//! - `disable_auto_units_per_second` - disables periodic derivation of `units per second`
 //!
 //! User is encouraged to refer to specific function implementations for more comprehensive documentation.
 //!
@@ -49,12 +51,38 @@
 //! `ExecutionPaymentRate` interface for fetching `units per second` if asset is supported payment asset
 //! - `get_units_per_second`
 //!
+//! `AssetPriceProvider` interface used to fetch the asset price, expressed in native currency,
+//! when deriving `units per second` automatically
+//! - `price_in_native`
+//!
+//! `registered_assets` helper used by the `XcAssetsApi` runtime API to page through the asset registry.
+//!
+//! ### Automatic `units per second`
+//!
+//! Assets can optionally be put into automatic mode. Every `RecalculationPeriod` blocks (e.g. each era)
+//! the `units per second` value is derived from the native `units per second` and the asset price.
+//! The derived value is clamped to the configured bounds and is only written if it deviates from the
+//! current value by more than the configured deadband, to avoid churn on small price movements.
+//!
+//! ### Rate limited fee changes
+//!
+//! Besides `ManagerOrigin`, `units per second` of supported payment assets can be adjusted by `FeeManagerOrigin`
+//! (e.g. a technical committee) so urgent fee fixes don't require a full governance process.
+//! Within each `FeeChangePeriod`, such changes can deviate at most `MaxUnitsPerSecondChange`
+//! from the value at the start of the period.
+//!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::pallet;
 pub use pallet::*;
 
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+use xcm::VersionedMultiLocation;
+
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 mod benchmarking;
 
@@ -68,6 +96,25 @@ pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
+/// Registry entry of a cross-chain asset, as exposed via runtime API.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct XcAssetInfo<AssetId> {
+    /// Local asset Id.
+    pub asset_id: AssetId,
+    /// Cross-chain asset location.
+    pub location: VersionedMultiLocation,
+    /// `units per second` of execution, or `None` if the asset isn't a supported payment asset.
+    pub units_per_second: Option<u128>,
+    /// Whether the asset can be held by accounts without any native balance.
+    pub is_sufficient: bool,
+    /// Asset name.
+    pub name: Vec<u8>,
+    /// Asset symbol.
+    pub symbol: Vec<u8>,
+    /// Asset decimals.
+    pub decimals: u8,
+}
+
 #[pallet]
 pub mod pallet {
 
@@ -75,7 +122,11 @@ pub mod pallet {
     use frame_support::{pallet_prelude::*, traits::EnsureOrigin};
     use frame_system::pallet_prelude::*;
     use parity_scale_codec::HasCompact;
-    use sp_std::boxed::Box;
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        FixedPointNumber, FixedU128, Perbill,
+    };
+    use sp_std::{boxed::Box, vec::Vec};
     use xcm::{v3::MultiLocation, VersionedMultiLocation};
 
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
@@ -100,6 +151,30 @@ pub mod pallet {
         fn get_units_per_second(asset_location: MultiLocation) -> Option<u128>;
     }
 
+    /// Used to fetch the price of an asset, expressed in native currency.
+    pub trait AssetPriceProvider<AssetId> {
+        /// Returns amount of native currency which is worth a single unit of the asset,
+        /// or `None` if price isn't available.
+        fn price_in_native(asset_id: AssetId) -> Option<FixedU128>;
+    }
+
+    impl<AssetId> AssetPriceProvider<AssetId> for () {
+        fn price_in_native(_asset_id: AssetId) -> Option<FixedU128> {
+            None
+        }
+    }
+
+    /// Parameters used when automatically deriving `units per second` for an asset.
+    #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct AutoUnitsPerSecondParams {
+        /// Lower bound of the derived `units per second`.
+        pub min_units_per_second: u128,
+        /// Upper bound of the derived `units per second`.
+        pub max_units_per_second: u128,
+        /// Relative change below which the current value is kept as is.
+        pub deadband: Perbill,
+    }
+
     impl<T: Config> XcAssetLocation<T::AssetId> for Pallet<T> {
         fn get_xc_asset_location(asset_id: T::AssetId) -> Option<MultiLocation> {
             AssetIdToLocation::<T>::get(asset_id).and_then(|x| x.try_into().ok())
@@ -129,6 +204,35 @@ pub mod pallet {
         /// Should most likely be root.
         type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+        /// Provides asset prices used to derive `units per second` for assets in automatic mode.
+        type PriceProvider: AssetPriceProvider<Self::AssetId>;
+
+        /// Amount of native currency charged per second of execution time.
+        #[pallet::constant]
+        type NativeUnitsPerSecond: Get<u128>;
+
+        /// Number of blocks between two automatic `units per second` recalculations.
+        #[pallet::constant]
+        type RecalculationPeriod: Get<Self::BlockNumber>;
+
+        /// Maximum number of assets which can be in automatic `units per second` mode.
+        #[pallet::constant]
+        type MaxAutoUnitsPerSecondAssets: Get<u32>;
+
+        /// The origin allowed to adjust `units per second` of assets which are already supported for payment.
+        ///
+        /// Unlike `ManagerOrigin`, changes made by this origin are rate limited. Within each `FeeChangePeriod`,
+        /// the value can deviate at most `MaxUnitsPerSecondChange` from the value at the start of the period.
+        type FeeManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Number of blocks in which `units per second` changes made by `FeeManagerOrigin` are accumulated.
+        #[pallet::constant]
+        type FeeChangePeriod: Get<Self::BlockNumber>;
+
+        /// Maximum relative change of `units per second` which `FeeManagerOrigin` can make within a single period.
+        #[pallet::constant]
+        type MaxUnitsPerSecondChange: Get<Perbill>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -140,6 +244,18 @@ pub mod pallet {
         AssetDoesNotExist,
         /// Failed to convert to latest versioned MultiLocation
         MultiLocationNotSupported,
+        /// Lower bound of automatic `units per second` is greater than the upper bound.
+        InvalidUnitsPerSecondBounds,
+        /// Too many assets are in automatic `units per second` mode.
+        TooManyAutoUnitsPerSecondAssets,
+        /// Asset isn't in automatic `units per second` mode.
+        AutoUnitsPerSecondNotEnabled,
+        /// Asset isn't supported for payment so its `units per second` can only be set by the manager origin.
+        UnitsPerSecondNotSet,
+        /// `units per second` change exceeds the limit allowed within the current period.
+        UnitsPerSecondChangeTooLarge,
+        /// Asset isn't supported for payment, automatic `units per second` can't enable it.
+        NotPaymentAsset,
     }
 
     #[pallet::event]
@@ -170,6 +286,13 @@ pub mod pallet {
             asset_location: VersionedMultiLocation,
             asset_id: T::AssetId,
         },
+        /// Enabled automatic `units per second` derivation for an asset
+        AutoUnitsPerSecondEnabled {
+            asset_id: T::AssetId,
+            params: AutoUnitsPerSecondParams,
+        },
+        /// Disabled automatic `units per second` derivation for an asset
+        AutoUnitsPerSecondDisabled { asset_id: T::AssetId },
     }
 
     /// Mapping from an asset id to asset type.
@@ -197,6 +320,31 @@ pub mod pallet {
     pub type AssetLocationUnitsPerSecond<T: Config> =
         StorageMap<_, Twox64Concat, VersionedMultiLocation, u128>;
 
+    /// Assets for which `units per second` is periodically derived from the asset price.
+    #[pallet::storage]
+    #[pallet::getter(fn auto_units_per_second)]
+    pub type AutoUnitsPerSecond<T: Config> =
+        CountedStorageMap<_, Twox64Concat, T::AssetId, AutoUnitsPerSecondParams>;
+
+    /// Block at which the current fee change period started and the `units per second` value at that moment.
+    /// Used to rate limit changes made by `FeeManagerOrigin`.
+    #[pallet::storage]
+    #[pallet::getter(fn units_per_second_change_reference)]
+    pub type UnitsPerSecondChangeReference<T: Config> =
+        StorageMap<_, Twox64Concat, VersionedMultiLocation, (T::BlockNumber, u128)>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let period = T::RecalculationPeriod::get();
+            if period.is_zero() || !(now % period).is_zero() {
+                return Weight::zero();
+            }
+
+            Self::recalculate_units_per_second()
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register new asset location to asset Id mapping.
@@ -233,6 +381,9 @@ pub mod pallet {
 
         /// Change the amount of units we are charging per execution second
         /// for a given AssetLocation.
+        ///
+        /// Can be called by `ManagerOrigin` without restrictions, or by `FeeManagerOrigin`
+        /// for already supported payment assets, within the `MaxUnitsPerSecondChange` limit.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::set_asset_units_per_second())]
         pub fn set_asset_units_per_second(
@@ -240,7 +391,13 @@ pub mod pallet {
             asset_location: Box<VersionedMultiLocation>,
             #[pallet::compact] units_per_second: u128,
         ) -> DispatchResult {
-            T::ManagerOrigin::ensure_origin(origin)?;
+            let is_rate_limited = match T::ManagerOrigin::ensure_origin(origin.clone()) {
+                Ok(_) => false,
+                Err(_) => {
+                    T::FeeManagerOrigin::ensure_origin(origin)?;
+                    true
+                }
+            };
 
             let v3_asset_loc = MultiLocation::try_from(*asset_location)
                 .map_err(|_| Error::<T>::MultiLocationNotSupported)?;
@@ -251,6 +408,13 @@ pub mod pallet {
                 Error::<T>::AssetDoesNotExist
             );
 
+            if is_rate_limited {
+                Self::ensure_units_per_second_change_allowed(&asset_location, units_per_second)?;
+            } else {
+                // Manager sets a new baseline for the rate limited changes
+                UnitsPerSecondChangeReference::<T>::remove(&asset_location);
+            }
+
             AssetLocationUnitsPerSecond::<T>::insert(&asset_location, units_per_second);
 
             Self::deposit_event(Event::UnitsPerSecondChanged {
@@ -289,6 +453,11 @@ pub mod pallet {
             if let Some(units) = AssetLocationUnitsPerSecond::<T>::take(&previous_asset_location) {
                 AssetLocationUnitsPerSecond::<T>::insert(&new_asset_location, units);
             }
+            if let Some(reference) =
+                UnitsPerSecondChangeReference::<T>::take(&previous_asset_location)
+            {
+                UnitsPerSecondChangeReference::<T>::insert(&new_asset_location, reference);
+            }
 
             Self::deposit_event(Event::AssetLocationChanged {
                 previous_asset_location,
@@ -301,6 +470,7 @@ pub mod pallet {
         /// Removes asset from the set of supported payment assets.
         ///
         /// The asset can still be interacted with via XCM but it cannot be used to pay for execution time.
+        /// Automatic `units per second` derivation is disabled for the asset, if it was enabled.
         #[pallet::call_index(3)]
         #[pallet::weight(T::WeightInfo::remove_payment_asset())]
         pub fn remove_payment_asset(
@@ -314,6 +484,10 @@ pub mod pallet {
             let asset_location = VersionedMultiLocation::V3(v3_asset_loc);
 
             AssetLocationUnitsPerSecond::<T>::remove(&asset_location);
+            UnitsPerSecondChangeReference::<T>::remove(&asset_location);
+            if let Some(asset_id) = AssetLocationToId::<T>::get(&asset_location) {
+                AutoUnitsPerSecond::<T>::remove(asset_id);
+            }
 
             Self::deposit_event(Event::SupportedAssetRemoved { asset_location });
             Ok(())
@@ -334,6 +508,8 @@ pub mod pallet {
             AssetIdToLocation::<T>::remove(&asset_id);
             AssetLocationToId::<T>::remove(&asset_location);
             AssetLocationUnitsPerSecond::<T>::remove(&asset_location);
+            UnitsPerSecondChangeReference::<T>::remove(&asset_location);
+            AutoUnitsPerSecond::<T>::remove(&asset_id);
 
             Self::deposit_event(Event::AssetRemoved {
                 asset_id,
@@ -341,5 +517,171 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Enable automatic `units per second` derivation for an asset.
+        ///
+        /// Each `RecalculationPeriod`, `units per second` is derived from the asset price, clamped to
+        /// the provided bounds and updated if it deviates from the current value by more than `deadband`.
+        /// Calling this for an asset already in automatic mode updates its parameters.
+        ///
+        /// The asset must already be supported for payment, i.e. have its `units per second` set.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::enable_auto_units_per_second())]
+        pub fn enable_auto_units_per_second(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            params: AutoUnitsPerSecondParams,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            let asset_location =
+                AssetIdToLocation::<T>::get(&asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+            ensure!(
+                AssetLocationUnitsPerSecond::<T>::contains_key(&asset_location),
+                Error::<T>::NotPaymentAsset
+            );
+            ensure!(
+                params.min_units_per_second <= params.max_units_per_second,
+                Error::<T>::InvalidUnitsPerSecondBounds
+            );
+            ensure!(
+                AutoUnitsPerSecond::<T>::contains_key(&asset_id)
+                    || AutoUnitsPerSecond::<T>::count() < T::MaxAutoUnitsPerSecondAssets::get(),
+                Error::<T>::TooManyAutoUnitsPerSecondAssets
+            );
+
+            AutoUnitsPerSecond::<T>::insert(&asset_id, params);
+
+            Self::deposit_event(Event::AutoUnitsPerSecondEnabled { asset_id, params });
+            Ok(())
+        }
+
+        /// Disable automatic `units per second` derivation for an asset.
+        ///
+        /// The last derived `units per second` value is kept.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::disable_auto_units_per_second())]
+        pub fn disable_auto_units_per_second(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                AutoUnitsPerSecond::<T>::contains_key(&asset_id),
+                Error::<T>::AutoUnitsPerSecondNotEnabled
+            );
+            AutoUnitsPerSecond::<T>::remove(&asset_id);
+
+            Self::deposit_event(Event::AutoUnitsPerSecondDisabled { asset_id });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Registered assets, with their location and `units per second` if they are supported payment assets.
+        ///
+        /// At most `limit` assets are returned, in storage order, starting after `start_after` if provided.
+        pub fn registered_assets(
+            start_after: Option<T::AssetId>,
+            limit: u32,
+        ) -> Vec<(T::AssetId, VersionedMultiLocation, Option<u128>)> {
+            let assets = match start_after {
+                Some(asset_id) => AssetIdToLocation::<T>::iter_from(
+                    AssetIdToLocation::<T>::hashed_key_for(asset_id),
+                ),
+                None => AssetIdToLocation::<T>::iter(),
+            };
+
+            assets
+                .take(limit as usize)
+                .map(|(asset_id, asset_location)| {
+                    let units_per_second = AssetLocationUnitsPerSecond::<T>::get(&asset_location);
+                    (asset_id, asset_location, units_per_second)
+                })
+                .collect()
+        }
+
+        /// Ensure that changing `units per second` to the new value stays within the limit of the current period.
+        ///
+        /// Starts a new period, with the current value as reference, if the previous one has elapsed.
+        fn ensure_units_per_second_change_allowed(
+            asset_location: &VersionedMultiLocation,
+            units_per_second: u128,
+        ) -> DispatchResult {
+            let current = AssetLocationUnitsPerSecond::<T>::get(asset_location)
+                .ok_or(Error::<T>::UnitsPerSecondNotSet)?;
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let (period_start, reference) =
+                match UnitsPerSecondChangeReference::<T>::get(asset_location) {
+                    Some((period_start, reference))
+                        if now < period_start.saturating_add(T::FeeChangePeriod::get()) =>
+                    {
+                        (period_start, reference)
+                    }
+                    _ => (now, current),
+                };
+
+            ensure!(
+                units_per_second.abs_diff(reference)
+                    <= T::MaxUnitsPerSecondChange::get() * reference,
+                Error::<T>::UnitsPerSecondChangeTooLarge
+            );
+
+            UnitsPerSecondChangeReference::<T>::insert(asset_location, (period_start, reference));
+            Ok(())
+        }
+
+        /// Derive `units per second` from the native `units per second` and the asset price.
+        ///
+        /// Returns `None` if price isn't available or is zero.
+        pub fn derive_units_per_second(
+            asset_id: T::AssetId,
+            params: &AutoUnitsPerSecondParams,
+        ) -> Option<u128> {
+            let price = T::PriceProvider::price_in_native(asset_id)?;
+            let units_per_second = price
+                .reciprocal()?
+                .saturating_mul_int(T::NativeUnitsPerSecond::get());
+
+            Some(units_per_second.clamp(params.min_units_per_second, params.max_units_per_second))
+        }
+
+        /// Recalculate `units per second` for all assets in automatic mode.
+        pub(crate) fn recalculate_units_per_second() -> Weight {
+            let mut counter = 0_u32;
+
+            for (asset_id, params) in AutoUnitsPerSecond::<T>::iter() {
+                counter.saturating_inc();
+
+                let asset_location = match AssetIdToLocation::<T>::get(&asset_id) {
+                    Some(asset_location) => asset_location,
+                    None => continue,
+                };
+                let units_per_second = match Self::derive_units_per_second(asset_id, &params) {
+                    Some(units_per_second) => units_per_second,
+                    None => continue,
+                };
+
+                // Only payment assets are updated, an asset mustn't become one as a side effect.
+                let current = match AssetLocationUnitsPerSecond::<T>::get(&asset_location) {
+                    Some(current) => current,
+                    None => continue,
+                };
+                // Ignore small deviations to avoid constant updates
+                if current.abs_diff(units_per_second) <= params.deadband * current {
+                    continue;
+                }
+
+                AssetLocationUnitsPerSecond::<T>::insert(&asset_location, units_per_second);
+                Self::deposit_event(Event::UnitsPerSecondChanged {
+                    asset_location,
+                    units_per_second,
+                });
+            }
+
+            T::WeightInfo::recalculate_units_per_second(counter)
+        }
     }
 }
@@ -18,14 +18,20 @@
 
 use crate::{self as pallet_xc_asset_config};
 
-use frame_support::{construct_runtime, parameter_types, traits::ConstU32, weights::Weight};
+use frame_support::{
+    construct_runtime, ord_parameter_types, parameter_types,
+    traits::{ConstU32, ConstU64},
+    weights::Weight,
+};
 use sp_core::H256;
 
 use sp_io::TestExternalities;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
+    FixedU128, Perbill,
 };
+use sp_std::cell::RefCell;
 
 type BlockNumber = u64;
 type Balance = u128;
@@ -105,10 +111,49 @@ impl pallet_balances::Config for Test {
 
 type AssetId = u128;
 
+pub(crate) const NATIVE_UNITS_PER_SECOND: u128 = 1_000_000;
+pub(crate) const RECALCULATION_PERIOD: BlockNumber = 10;
+pub(crate) const MAX_AUTO_UNITS_PER_SECOND_ASSETS: u32 = 2;
+pub(crate) const FEE_MANAGER: AccountId = 7;
+pub(crate) const FEE_CHANGE_PERIOD: BlockNumber = 5;
+
+thread_local! {
+    static PRICE: RefCell<Option<FixedU128>> = RefCell::new(None);
+}
+
+/// Price provider which returns the same configurable price for all assets.
+pub struct DummyPriceProvider;
+impl DummyPriceProvider {
+    pub(crate) fn set_price(price: Option<FixedU128>) {
+        PRICE.with(|v| *v.borrow_mut() = price);
+    }
+}
+impl pallet_xc_asset_config::AssetPriceProvider<AssetId> for DummyPriceProvider {
+    fn price_in_native(_asset_id: AssetId) -> Option<FixedU128> {
+        PRICE.with(|v| *v.borrow())
+    }
+}
+
+parameter_types! {
+    pub const NativeUnitsPerSecond: u128 = NATIVE_UNITS_PER_SECOND;
+    pub const MaxUnitsPerSecondChange: Perbill = Perbill::from_percent(10);
+}
+
+ord_parameter_types! {
+    pub const FeeManager: AccountId = FEE_MANAGER;
+}
+
 impl pallet_xc_asset_config::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type PriceProvider = DummyPriceProvider;
+    type NativeUnitsPerSecond = NativeUnitsPerSecond;
+    type RecalculationPeriod = ConstU64<RECALCULATION_PERIOD>;
+    type MaxAutoUnitsPerSecondAssets = ConstU32<MAX_AUTO_UNITS_PER_SECOND_ASSETS>;
+    type FeeManagerOrigin = frame_system::EnsureSignedBy<FeeManager, AccountId>;
+    type FeeChangePeriod = ConstU64<FEE_CHANGE_PERIOD>;
+    type MaxUnitsPerSecondChange = MaxUnitsPerSecondChange;
     type WeightInfo = ();
 }
 
@@ -17,9 +17,9 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::{pallet::Error, pallet::Event, *};
-use frame_support::{assert_noop, assert_ok, WeakBoundedVec};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, WeakBoundedVec};
 use mock::*;
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::{traits::BadOrigin, FixedPointNumber, FixedU128, Perbill};
 use xcm::latest::prelude::*;
 
 use xcm::{v3::MultiLocation, VersionedMultiLocation};
@@ -127,6 +127,35 @@ fn register_asset_location_and_units_per_sec_is_ok() {
     })
 }
 
+#[test]
+fn bridged_asset_location_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Snowbridge asset, e.g. WETH, as seen from a Polkadot parachain
+        let asset_location = MultiLocation::new(
+            2,
+            Junctions::X2(
+                GlobalConsensus(NetworkId::Ethereum { chain_id: 1 }),
+                AccountKey20 {
+                    network: None,
+                    key: [0xc0; 20],
+                },
+            ),
+        );
+        let asset_id = 17;
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone().into_versioned()),
+            asset_id
+        ));
+        assert_eq!(
+            XcAssetConfig::get_xc_asset_location(asset_id),
+            Some(asset_location)
+        );
+        assert_eq!(XcAssetConfig::get_asset_id(asset_location), Some(asset_id));
+    })
+}
+
 #[test]
 fn asset_is_already_registered() {
     ExternalityBuilder::build().execute_with(|| {
@@ -449,3 +478,482 @@ fn incompatible_versioned_multilocations_are_not_ok() {
         );
     })
 }
+
+#[test]
+fn enable_and_disable_auto_units_per_second_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = MultiLocation::parent();
+        let asset_id = 17;
+        let params = AutoUnitsPerSecondParams {
+            min_units_per_second: 10,
+            max_units_per_second: 1_000,
+            deadband: Perbill::from_percent(5),
+        };
+
+        // Only root can enable or disable automatic mode
+        assert_noop!(
+            XcAssetConfig::enable_auto_units_per_second(RuntimeOrigin::signed(1), asset_id, params),
+            BadOrigin
+        );
+        assert_noop!(
+            XcAssetConfig::disable_auto_units_per_second(RuntimeOrigin::signed(1), asset_id),
+            BadOrigin
+        );
+
+        // Asset must be registered first
+        assert_noop!(
+            XcAssetConfig::enable_auto_units_per_second(RuntimeOrigin::root(), asset_id, params),
+            Error::<Test>::AssetDoesNotExist
+        );
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            asset_id
+        ));
+
+        // Asset must be supported for payment
+        assert_noop!(
+            XcAssetConfig::enable_auto_units_per_second(RuntimeOrigin::root(), asset_id, params),
+            Error::<Test>::NotPaymentAsset
+        );
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            100
+        ));
+
+        assert_ok!(XcAssetConfig::enable_auto_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id,
+            params
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::AutoUnitsPerSecondEnabled { asset_id, params },
+        ));
+        assert_eq!(XcAssetConfig::auto_units_per_second(asset_id), Some(params));
+
+        assert_ok!(XcAssetConfig::disable_auto_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::AutoUnitsPerSecondDisabled { asset_id },
+        ));
+        assert!(XcAssetConfig::auto_units_per_second(asset_id).is_none());
+
+        // Cannot disable twice
+        assert_noop!(
+            XcAssetConfig::disable_auto_units_per_second(RuntimeOrigin::root(), asset_id),
+            Error::<Test>::AutoUnitsPerSecondNotEnabled
+        );
+    })
+}
+
+#[test]
+fn enable_auto_units_per_second_fails_with_invalid_params() {
+    ExternalityBuilder::build().execute_with(|| {
+        let params = AutoUnitsPerSecondParams {
+            min_units_per_second: 10,
+            max_units_per_second: 1_000,
+            deadband: Perbill::zero(),
+        };
+
+        for asset_id in 0..=MAX_AUTO_UNITS_PER_SECOND_ASSETS as u128 {
+            let asset_location = MultiLocation::new(0, X1(GeneralIndex(asset_id))).into_versioned();
+            assert_ok!(XcAssetConfig::register_asset_location(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone()),
+                asset_id
+            ));
+            assert_ok!(XcAssetConfig::set_asset_units_per_second(
+                RuntimeOrigin::root(),
+                Box::new(asset_location),
+                100
+            ));
+        }
+
+        // Lower bound must not exceed the upper bound
+        assert_noop!(
+            XcAssetConfig::enable_auto_units_per_second(
+                RuntimeOrigin::root(),
+                0,
+                AutoUnitsPerSecondParams {
+                    min_units_per_second: 1_001,
+                    ..params
+                }
+            ),
+            Error::<Test>::InvalidUnitsPerSecondBounds
+        );
+
+        // Fill up the limit, updating existing entries is still allowed
+        for asset_id in 0..MAX_AUTO_UNITS_PER_SECOND_ASSETS as u128 {
+            assert_ok!(XcAssetConfig::enable_auto_units_per_second(
+                RuntimeOrigin::root(),
+                asset_id,
+                params
+            ));
+        }
+        assert_ok!(XcAssetConfig::enable_auto_units_per_second(
+            RuntimeOrigin::root(),
+            0,
+            params
+        ));
+        assert_noop!(
+            XcAssetConfig::enable_auto_units_per_second(
+                RuntimeOrigin::root(),
+                MAX_AUTO_UNITS_PER_SECOND_ASSETS as u128,
+                params
+            ),
+            Error::<Test>::TooManyAutoUnitsPerSecondAssets
+        );
+    })
+}
+
+#[test]
+fn auto_units_per_second_is_recalculated_periodically() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = MultiLocation::parent();
+        let asset_id = 17;
+        let params = AutoUnitsPerSecondParams {
+            min_units_per_second: 1_000,
+            max_units_per_second: 10_000_000,
+            deadband: Perbill::from_percent(10),
+        };
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            asset_id
+        ));
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            NATIVE_UNITS_PER_SECOND
+        ));
+        assert_ok!(XcAssetConfig::enable_auto_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id,
+            params
+        ));
+
+        // No price, the current rate is kept
+        XcAssetConfig::on_initialize(RECALCULATION_PERIOD);
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location),
+            Some(NATIVE_UNITS_PER_SECOND)
+        );
+
+        // Asset is worth 4 native tokens, hence 4 times less units per second
+        DummyPriceProvider::set_price(Some(FixedU128::saturating_from_integer(4)));
+
+        // Nothing happens outside of the recalculation period
+        XcAssetConfig::on_initialize(RECALCULATION_PERIOD + 1);
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location),
+            Some(NATIVE_UNITS_PER_SECOND)
+        );
+
+        XcAssetConfig::on_initialize(2 * RECALCULATION_PERIOD);
+        let expected = NATIVE_UNITS_PER_SECOND / 4;
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location),
+            Some(expected)
+        );
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::UnitsPerSecondChanged {
+                asset_location: asset_location.into_versioned(),
+                units_per_second: expected,
+            },
+        ));
+
+        // Small price change is within the deadband and is ignored
+        DummyPriceProvider::set_price(Some(FixedU128::saturating_from_rational(42, 10)));
+        XcAssetConfig::on_initialize(3 * RECALCULATION_PERIOD);
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location),
+            Some(expected)
+        );
+
+        // Large price change is applied
+        DummyPriceProvider::set_price(Some(FixedU128::saturating_from_integer(2)));
+        XcAssetConfig::on_initialize(4 * RECALCULATION_PERIOD);
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location),
+            Some(NATIVE_UNITS_PER_SECOND / 2)
+        );
+
+        // Derived value is clamped to the configured bounds
+        DummyPriceProvider::set_price(Some(FixedU128::saturating_from_integer(10_000)));
+        XcAssetConfig::on_initialize(5 * RECALCULATION_PERIOD);
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location),
+            Some(params.min_units_per_second)
+        );
+        DummyPriceProvider::set_price(Some(FixedU128::saturating_from_rational(1, 1_000)));
+        XcAssetConfig::on_initialize(6 * RECALCULATION_PERIOD);
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location),
+            Some(params.max_units_per_second)
+        );
+
+        // Once disabled, the last value is kept
+        assert_ok!(XcAssetConfig::disable_auto_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        DummyPriceProvider::set_price(Some(FixedU128::saturating_from_integer(4)));
+        XcAssetConfig::on_initialize(7 * RECALCULATION_PERIOD);
+        assert_eq!(
+            XcAssetConfig::get_units_per_second(asset_location),
+            Some(params.max_units_per_second)
+        );
+    })
+}
+
+#[test]
+fn removing_asset_disables_auto_units_per_second() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = MultiLocation::parent();
+        let asset_id = 17;
+        let params = AutoUnitsPerSecondParams {
+            min_units_per_second: 0,
+            max_units_per_second: u128::MAX,
+            deadband: Perbill::zero(),
+        };
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            asset_id
+        ));
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            100
+        ));
+        assert_ok!(XcAssetConfig::enable_auto_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id,
+            params
+        ));
+
+        // Removing payment asset disables automatic mode
+        assert_ok!(XcAssetConfig::remove_payment_asset(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+        ));
+        assert!(XcAssetConfig::auto_units_per_second(asset_id).is_none());
+
+        // Same for removing the asset altogether
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            100
+        ));
+        assert_ok!(XcAssetConfig::enable_auto_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id,
+            params
+        ));
+        assert_ok!(XcAssetConfig::remove_asset(RuntimeOrigin::root(), asset_id));
+        assert!(XcAssetConfig::auto_units_per_second(asset_id).is_none());
+        assert_eq!(AutoUnitsPerSecond::<Test>::count(), 0);
+    })
+}
+
+#[test]
+fn fee_manager_can_change_units_per_second_within_limit() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = MultiLocation::here().into_versioned();
+        let asset_id = 17;
+        let units = 1_000;
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone()),
+            asset_id
+        ));
+
+        // Fee manager cannot add a new payment asset
+        assert_noop!(
+            XcAssetConfig::set_asset_units_per_second(
+                RuntimeOrigin::signed(FEE_MANAGER),
+                Box::new(asset_location.clone()),
+                units
+            ),
+            Error::<Test>::UnitsPerSecondNotSet
+        );
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone()),
+            units
+        ));
+
+        // Changes within 10% of the value at the start of the period are allowed
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::signed(FEE_MANAGER),
+            Box::new(asset_location.clone()),
+            1_100
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+            Event::UnitsPerSecondChanged {
+                asset_location: asset_location.clone(),
+                units_per_second: 1_100,
+            },
+        ));
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::signed(FEE_MANAGER),
+            Box::new(asset_location.clone()),
+            900
+        ));
+        assert_eq!(
+            XcAssetConfig::units_per_second_change_reference(&asset_location),
+            Some((1, units))
+        );
+
+        // Accumulated change cannot exceed the limit within the same period
+        assert_noop!(
+            XcAssetConfig::set_asset_units_per_second(
+                RuntimeOrigin::signed(FEE_MANAGER),
+                Box::new(asset_location.clone()),
+                899
+            ),
+            Error::<Test>::UnitsPerSecondChangeTooLarge
+        );
+
+        // New period uses the current value as reference
+        System::set_block_number(1 + FEE_CHANGE_PERIOD);
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::signed(FEE_MANAGER),
+            Box::new(asset_location.clone()),
+            810
+        ));
+        assert_eq!(
+            XcAssetConfig::units_per_second_change_reference(&asset_location),
+            Some((1 + FEE_CHANGE_PERIOD, 900))
+        );
+
+        // Manager isn't limited and resets the reference
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone()),
+            5_000
+        ));
+        assert!(XcAssetConfig::units_per_second_change_reference(&asset_location).is_none());
+    })
+}
+
+#[test]
+fn fee_manager_cannot_manage_assets() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = MultiLocation::here().into_versioned();
+        let asset_id = 17;
+
+        assert_noop!(
+            XcAssetConfig::register_asset_location(
+                RuntimeOrigin::signed(FEE_MANAGER),
+                Box::new(asset_location.clone()),
+                asset_id
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone()),
+            asset_id
+        ));
+        assert_ok!(XcAssetConfig::set_asset_units_per_second(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.clone()),
+            1_000
+        ));
+
+        assert_noop!(
+            XcAssetConfig::remove_payment_asset(
+                RuntimeOrigin::signed(FEE_MANAGER),
+                Box::new(asset_location)
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            XcAssetConfig::remove_asset(RuntimeOrigin::signed(FEE_MANAGER), asset_id),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn registered_assets_are_paginated() {
+    ExternalityBuilder::build().execute_with(|| {
+        for asset_id in 1..=5 {
+            let asset_location =
+                MultiLocation::new(1, Junctions::X1(Parachain(asset_id))).into_versioned();
+            assert_ok!(XcAssetConfig::register_asset_location(
+                RuntimeOrigin::root(),
+                Box::new(asset_location.clone()),
+                asset_id.into()
+            ));
+
+            // Only odd assets are supported payment assets
+            if asset_id % 2 == 1 {
+                assert_ok!(XcAssetConfig::set_asset_units_per_second(
+                    RuntimeOrigin::root(),
+                    Box::new(asset_location),
+                    asset_id.into()
+                ));
+            }
+        }
+
+        let first_page = XcAssetConfig::registered_assets(None, 3);
+        assert_eq!(first_page.len(), 3);
+
+        let (last_id, _, _) = first_page.last().cloned().unwrap();
+        let second_page = XcAssetConfig::registered_assets(Some(last_id), 3);
+        assert_eq!(second_page.len(), 2);
+
+        // Nothing left after the last asset
+        let (last_id, _, _) = second_page.last().cloned().unwrap();
+        assert!(XcAssetConfig::registered_assets(Some(last_id), 3).is_empty());
+
+        // Each asset is returned exactly once, with its location and payment status
+        let mut assets: Vec<_> = first_page.into_iter().chain(second_page).collect();
+        assets.sort_by_key(|(asset_id, _, _)| *asset_id);
+        assert_eq!(
+            assets,
+            (1..=5u32)
+                .map(|asset_id| (
+                    u128::from(asset_id),
+                    MultiLocation::new(1, Junctions::X1(Parachain(asset_id))).into_versioned(),
+                    (asset_id % 2 == 1).then_some(u128::from(asset_id))
+                ))
+                .collect::<Vec<_>>()
+        );
+    })
+}
+
+#[test]
+fn recalculation_skips_assets_not_supported_for_payment() {
+    ExternalityBuilder::build().execute_with(|| {
+        let asset_location = MultiLocation::parent();
+        let asset_id = 17;
+        let params = AutoUnitsPerSecondParams {
+            min_units_per_second: 0,
+            max_units_per_second: u128::MAX,
+            deadband: Perbill::zero(),
+        };
+
+        assert_ok!(XcAssetConfig::register_asset_location(
+            RuntimeOrigin::root(),
+            Box::new(asset_location.into_versioned()),
+            asset_id
+        ));
+        // Automatic mode set without going through `enable_auto_units_per_second`,
+        // e.g. by a migration.
+        AutoUnitsPerSecond::<Test>::insert(asset_id, params);
+        DummyPriceProvider::set_price(Some(FixedU128::saturating_from_integer(4)));
+
+        XcAssetConfig::on_initialize(RECALCULATION_PERIOD);
+        assert!(XcAssetConfig::get_units_per_second(asset_location).is_none());
+    })
+}
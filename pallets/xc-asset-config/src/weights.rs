@@ -38,6 +38,9 @@
 // --output=./benchmark-results/xc_asset_config_weights.rs
 // --template=./scripts/templates/weight-template.hbs
 
+// TODO: weights marked as placeholders below were updated by hand after the storage accessed by
+// the extrinsics changed, and must be regenerated by running the benchmarks.
+
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -52,6 +55,9 @@ pub trait WeightInfo {
 	fn change_existing_asset_location() -> Weight;
 	fn remove_payment_asset() -> Weight;
 	fn remove_asset() -> Weight;
+	fn enable_auto_units_per_second() -> Weight;
+	fn disable_auto_units_per_second() -> Weight;
+	fn recalculate_units_per_second(x: u32, ) -> Weight;
 }
 
 /// Weights for pallet_xc_asset_config using the Substrate node and recommended hardware.
@@ -72,14 +78,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
-	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig UnitsPerSecondChangeReference (r:1 w:1)
+	// Proof Skipped: XcAssetConfig UnitsPerSecondChangeReference (max_values: None, max_size: None, mode: Measured)
 	fn set_asset_units_per_second() -> Weight {
-		// Minimum execution time: 15_297 nanoseconds.
+		// TODO: placeholder weight, not generated by the benchmark CLI.
 		Weight::from_parts(15_551_000, 0)
 			.saturating_add(Weight::from_parts(0, 2661))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
@@ -87,20 +95,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:2)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig UnitsPerSecondChangeReference (r:1 w:2)
+	// Proof Skipped: XcAssetConfig UnitsPerSecondChangeReference (max_values: None, max_size: None, mode: Measured)
 	fn change_existing_asset_location() -> Weight {
-		// Minimum execution time: 22_357 nanoseconds.
+		// TODO: placeholder weight, not generated by the benchmark CLI.
 		Weight::from_parts(22_572_000, 0)
 			.saturating_add(Weight::from_parts(0, 5373))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAutoUnitsPerSecond (r:1 w:1)
+	// Proof: XcAssetConfig CounterForAutoUnitsPerSecond (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AutoUnitsPerSecond (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AutoUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig UnitsPerSecondChangeReference (r:0 w:1)
+	// Proof Skipped: XcAssetConfig UnitsPerSecondChangeReference (max_values: None, max_size: None, mode: Measured)
 	fn remove_payment_asset() -> Weight {
-		// Minimum execution time: 9_707 nanoseconds.
-		Weight::from_parts(10_005_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(16_603_000, 0)
+			.saturating_add(Weight::from_parts(0, 3109))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
@@ -110,12 +129,61 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAutoUnitsPerSecond (r:1 w:1)
+	// Proof: XcAssetConfig CounterForAutoUnitsPerSecond (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AutoUnitsPerSecond (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AutoUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig UnitsPerSecondChangeReference (r:0 w:1)
+	// Proof Skipped: XcAssetConfig UnitsPerSecondChangeReference (max_values: None, max_size: None, mode: Measured)
 	fn remove_asset() -> Weight {
-		// Minimum execution time: 18_645 nanoseconds.
-		Weight::from_parts(18_878_000, 0)
-			.saturating_add(Weight::from_parts(0, 2987))
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(23_377_000, 0)
+			.saturating_add(Weight::from_parts(0, 4463))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AutoUnitsPerSecond (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AutoUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAutoUnitsPerSecond (r:1 w:1)
+	// Proof: XcAssetConfig CounterForAutoUnitsPerSecond (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn enable_auto_units_per_second() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(17_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 5415))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: XcAssetConfig AutoUnitsPerSecond (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AutoUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAutoUnitsPerSecond (r:1 w:1)
+	// Proof: XcAssetConfig CounterForAutoUnitsPerSecond (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn disable_auto_units_per_second() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(14_392_000, 0)
+			.saturating_add(Weight::from_parts(0, 3019))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: XcAssetConfig AutoUnitsPerSecond (r:17 w:0)
+	// Proof Skipped: XcAssetConfig AutoUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetIdToLocation (r:16 w:0)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:16 w:16)
+	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 16]`.
+	fn recalculate_units_per_second(x: u32, ) -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(5_071_362, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(9_832_451, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 7842).saturating_mul(x.into()))
 	}
 }
 
@@ -136,14 +204,16 @@ impl WeightInfo for () {
 	}
 	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
-	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig UnitsPerSecondChangeReference (r:1 w:1)
+	// Proof Skipped: XcAssetConfig UnitsPerSecondChangeReference (max_values: None, max_size: None, mode: Measured)
 	fn set_asset_units_per_second() -> Weight {
-		// Minimum execution time: 15_297 nanoseconds.
+		// TODO: placeholder weight, not generated by the benchmark CLI.
 		Weight::from_parts(15_551_000, 0)
 			.saturating_add(Weight::from_parts(0, 2661))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
@@ -151,20 +221,31 @@ impl WeightInfo for () {
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:2)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig UnitsPerSecondChangeReference (r:1 w:2)
+	// Proof Skipped: XcAssetConfig UnitsPerSecondChangeReference (max_values: None, max_size: None, mode: Measured)
 	fn change_existing_asset_location() -> Weight {
-		// Minimum execution time: 22_357 nanoseconds.
+		// TODO: placeholder weight, not generated by the benchmark CLI.
 		Weight::from_parts(22_572_000, 0)
 			.saturating_add(Weight::from_parts(0, 5373))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAutoUnitsPerSecond (r:1 w:1)
+	// Proof: XcAssetConfig CounterForAutoUnitsPerSecond (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AutoUnitsPerSecond (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AutoUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig UnitsPerSecondChangeReference (r:0 w:1)
+	// Proof Skipped: XcAssetConfig UnitsPerSecondChangeReference (max_values: None, max_size: None, mode: Measured)
 	fn remove_payment_asset() -> Weight {
-		// Minimum execution time: 9_707 nanoseconds.
-		Weight::from_parts(10_005_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(16_603_000, 0)
+			.saturating_add(Weight::from_parts(0, 3109))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
@@ -174,11 +255,60 @@ impl WeightInfo for () {
 	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAutoUnitsPerSecond (r:1 w:1)
+	// Proof: XcAssetConfig CounterForAutoUnitsPerSecond (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: XcAssetConfig AutoUnitsPerSecond (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AutoUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig UnitsPerSecondChangeReference (r:0 w:1)
+	// Proof Skipped: XcAssetConfig UnitsPerSecondChangeReference (max_values: None, max_size: None, mode: Measured)
 	fn remove_asset() -> Weight {
-		// Minimum execution time: 18_645 nanoseconds.
-		Weight::from_parts(18_878_000, 0)
-			.saturating_add(Weight::from_parts(0, 2987))
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(23_377_000, 0)
+			.saturating_add(Weight::from_parts(0, 4463))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AutoUnitsPerSecond (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AutoUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAutoUnitsPerSecond (r:1 w:1)
+	// Proof: XcAssetConfig CounterForAutoUnitsPerSecond (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn enable_auto_units_per_second() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(17_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 5415))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: XcAssetConfig AutoUnitsPerSecond (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AutoUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAutoUnitsPerSecond (r:1 w:1)
+	// Proof: XcAssetConfig CounterForAutoUnitsPerSecond (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn disable_auto_units_per_second() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(14_392_000, 0)
+			.saturating_add(Weight::from_parts(0, 3019))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: XcAssetConfig AutoUnitsPerSecond (r:17 w:0)
+	// Proof Skipped: XcAssetConfig AutoUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetIdToLocation (r:16 w:0)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:16 w:16)
+	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 16]`.
+	fn recalculate_units_per_second(x: u32, ) -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(5_071_362, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(9_832_451, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 7842).saturating_mul(x.into()))
 	}
 }
\ No newline at end of file
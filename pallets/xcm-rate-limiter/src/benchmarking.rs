@@ -0,0 +1,57 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use super::*;
+use crate::Pallet as XcmRateLimiter;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::RawOrigin;
+
+benchmarks! {
+
+    set_rate_limit {
+        let channel: MultiLocation = (Parent, Parachain(2000)).into();
+        let rate_limit = RateLimit {
+            period: 10_u32.into(),
+            max_messages: 100,
+            max_amount: u128::MAX,
+        };
+
+    }: _(RawOrigin::Root, Box::new(channel.into()), Some(rate_limit))
+    verify {
+        assert_eq!(RateLimitOverrides::<T>::get(channel), Some(rate_limit));
+    }
+
+    reset_usage {
+        let channel: MultiLocation = (Parent, Parachain(2000)).into();
+        Usage::<T>::insert(channel, ChannelUsage {
+            window_start: 1_u32.into(),
+            messages: 1,
+            amount: 1,
+        });
+
+    }: _(RawOrigin::Root, Box::new(channel.into()))
+    verify {
+        assert!(!Usage::<T>::contains_key(channel));
+    }
+}
+
+impl_benchmark_test_suite!(
+    XcmRateLimiter,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
@@ -0,0 +1,323 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! # XCM Rate Limiter Pallet
+//!
+//! ## Overview
+//!
+//! Limits outbound XCM messages per channel, i.e. per relay chain or sibling parachain.
+//! If a local contract or precompile is exploited into sending assets cross-chain, this bounds
+//! what can leave the chain before governance is able to react.
+//!
+//! Limits are enforced by [`RateLimitedRouter`], which wraps the runtime XCM router and rejects
+//! messages before they are enqueued. In each window of `period` blocks, a channel can be sent at most
+//! `max_messages` messages, carrying a total amount of the native currency of at most `max_amount`.
+//! The amount of a message is the amount of the native currency it withdraws, deposits as reserve
+//! or teleports. Amounts of other assets aren't comparable with it, so they're only limited by
+//! the number of messages.
+//!
+//! `DefaultRateLimit` applies to all channels, unless it is overridden by `ManagerOrigin`.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `set_rate_limit` - overrides the rate limit of a channel, or removes the override
+//! - `reset_usage` - resets the usage of a channel in its current window
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::{boxed::Box, marker::PhantomData};
+use xcm::{
+    v3::{prelude::*, MultiLocation},
+    VersionedMultiLocation,
+};
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Outbound rate limit of a channel.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RateLimit<BlockNumber> {
+    /// Length of a window, in blocks.
+    pub period: BlockNumber,
+    /// Maximum number of messages sent in a window.
+    pub max_messages: u32,
+    /// Maximum total amount of the native currency carried by the messages sent in a window.
+    pub max_amount: u128,
+}
+
+/// Outbound usage of a channel in its current window.
+#[derive(
+    Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ChannelUsage<BlockNumber> {
+    /// Block at which the window started.
+    pub window_start: BlockNumber,
+    /// Number of messages sent in the window.
+    pub messages: u32,
+    /// Total amount of the native currency carried by the messages sent in the window.
+    pub amount: u128,
+}
+
+/// Channel through which messages to `dest` are sent, i.e. the relay chain or a sibling parachain.
+pub fn channel_of(dest: &MultiLocation) -> MultiLocation {
+    match dest.first_interior() {
+        Some(Parachain(id)) => MultiLocation::new(dest.parents, X1(Parachain(*id))),
+        _ => MultiLocation::new(dest.parents, Here),
+    }
+}
+
+/// Total amount of the `native` currency withdrawn, deposited as reserve or teleported by `message`.
+///
+/// `native` must be the location of the native currency as seen by the message destination.
+pub fn transferred_amount<Call>(message: &Xcm<Call>, native: &MultiLocation) -> u128 {
+    message
+        .0
+        .iter()
+        .flat_map(|instruction| match instruction {
+            WithdrawAsset(assets)
+            | ReserveAssetDeposited(assets)
+            | ReceiveTeleportedAsset(assets) => assets.inner().as_slice(),
+            _ => &[][..],
+        })
+        .filter_map(|asset| match asset {
+            MultiAsset {
+                id: Concrete(location),
+                fun: Fungible(amount),
+            } if location == native => Some(*amount),
+            _ => None,
+        })
+        .fold(0, |total, amount| total.saturating_add(amount))
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The required origin for overriding rate limits and resetting usages.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Rate limit of channels without an override, `None` to not limit them.
+        #[pallet::constant]
+        type DefaultRateLimit: Get<Option<RateLimit<Self::BlockNumber>>>;
+
+        /// Location of the native currency, as seen by this chain.
+        #[pallet::constant]
+        type NativeAssetLocation: Get<MultiLocation>;
+
+        /// Universal location of this chain, used to reanchor the native currency location
+        /// to the message destination.
+        #[pallet::constant]
+        type UniversalLocation: Get<InteriorMultiLocation>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Channel location can't be converted into the supported XCM version.
+        BadVersion,
+        /// Rate limit period must be at least one block.
+        ZeroPeriod,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Rate limit of the channel was overridden, or the override was removed.
+        RateLimitSet {
+            channel: MultiLocation,
+            rate_limit: Option<RateLimit<T::BlockNumber>>,
+        },
+        /// Usage of the channel was reset.
+        UsageReset { channel: MultiLocation },
+    }
+
+    /// Rate limits overriding `DefaultRateLimit`, per channel.
+    #[pallet::storage]
+    #[pallet::getter(fn rate_limit_overrides)]
+    pub type RateLimitOverrides<T: Config> =
+        StorageMap<_, Blake2_128Concat, MultiLocation, RateLimit<T::BlockNumber>>;
+
+    /// Outbound usage of rate limited channels.
+    #[pallet::storage]
+    #[pallet::getter(fn usage)]
+    pub type Usage<T: Config> =
+        StorageMap<_, Blake2_128Concat, MultiLocation, ChannelUsage<T::BlockNumber>, ValueQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Override the rate limit of a channel, or remove the override with `None`.
+        ///
+        /// A channel is the relay chain or a sibling parachain. Any other location is reduced to
+        /// the channel it is reached through.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_rate_limit())]
+        pub fn set_rate_limit(
+            origin: OriginFor<T>,
+            channel: Box<VersionedMultiLocation>,
+            rate_limit: Option<RateLimit<T::BlockNumber>>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let channel = Self::v3_channel(*channel)?;
+
+            match rate_limit {
+                Some(rate_limit) => {
+                    ensure!(!rate_limit.period.is_zero(), Error::<T>::ZeroPeriod);
+                    RateLimitOverrides::<T>::insert(channel, rate_limit);
+                }
+                None => RateLimitOverrides::<T>::remove(channel),
+            }
+            Self::deposit_event(Event::RateLimitSet {
+                channel,
+                rate_limit,
+            });
+
+            Ok(())
+        }
+
+        /// Reset the usage of a channel, allowing it to be used up to its full rate limit again.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::reset_usage())]
+        pub fn reset_usage(
+            origin: OriginFor<T>,
+            channel: Box<VersionedMultiLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let channel = Self::v3_channel(*channel)?;
+
+            Usage::<T>::remove(channel);
+            Self::deposit_event(Event::UsageReset { channel });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Rate limit applying to `channel`, if any.
+        pub fn rate_limit(channel: &MultiLocation) -> Option<RateLimit<T::BlockNumber>> {
+            RateLimitOverrides::<T>::get(channel).or_else(T::DefaultRateLimit::get)
+        }
+
+        /// `true` if a message carrying `amount` can be sent through `channel` without exceeding
+        /// its rate limit.
+        pub fn can_send(channel: &MultiLocation, amount: u128) -> bool {
+            match Self::rate_limit(channel) {
+                Some(rate_limit) => {
+                    let usage = Self::current_usage(channel, &rate_limit);
+                    usage.messages < rate_limit.max_messages
+                        && usage.amount.saturating_add(amount) <= rate_limit.max_amount
+                }
+                None => true,
+            }
+        }
+
+        /// Account a message carrying `amount` sent through `channel`.
+        pub(crate) fn note_sent(channel: &MultiLocation, amount: u128) {
+            if let Some(rate_limit) = Self::rate_limit(channel) {
+                let mut usage = Self::current_usage(channel, &rate_limit);
+                usage.messages.saturating_inc();
+                usage.amount.saturating_accrue(amount);
+                Usage::<T>::insert(channel, usage);
+            }
+        }
+
+        /// Usage of `channel`, starting a new window if the previous one has ended.
+        fn current_usage(
+            channel: &MultiLocation,
+            rate_limit: &RateLimit<T::BlockNumber>,
+        ) -> ChannelUsage<T::BlockNumber> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let usage = Usage::<T>::get(channel);
+            if now >= usage.window_start.saturating_add(rate_limit.period) {
+                ChannelUsage {
+                    window_start: now,
+                    ..Default::default()
+                }
+            } else {
+                usage
+            }
+        }
+
+        fn v3_channel(location: VersionedMultiLocation) -> Result<MultiLocation, Error<T>> {
+            MultiLocation::try_from(location)
+                .map(|location| channel_of(&location))
+                .map_err(|_| Error::<T>::BadVersion)
+        }
+    }
+}
+
+/// XCM router enforcing the outbound rate limits before passing messages on to `Router`.
+///
+/// Messages exceeding the rate limit of their channel are rejected with a transport error,
+/// so no other router gets to send them.
+pub struct RateLimitedRouter<T, Router>(PhantomData<(T, Router)>);
+impl<T: Config, Router: SendXcm> SendXcm for RateLimitedRouter<T, Router> {
+    type Ticket = (Router::Ticket, Option<(MultiLocation, u128)>);
+
+    fn validate(
+        dest: &mut Option<MultiLocation>,
+        msg: &mut Option<Xcm<()>>,
+    ) -> SendResult<Self::Ticket> {
+        let sent = match (dest.as_ref(), msg.as_ref()) {
+            (Some(dest), Some(msg)) => {
+                let channel = channel_of(dest);
+                let amount = T::NativeAssetLocation::get()
+                    .reanchored(dest, T::UniversalLocation::get())
+                    .map_or(0, |native| transferred_amount(msg, &native));
+                if !Pallet::<T>::can_send(&channel, amount) {
+                    return Err(SendError::Transport("outbound rate limit exceeded"));
+                }
+                Some((channel, amount))
+            }
+            _ => None,
+        };
+
+        let (ticket, price) = Router::validate(dest, msg)?;
+        Ok(((ticket, sent), price))
+    }
+
+    fn deliver((ticket, sent): Self::Ticket) -> Result<XcmHash, SendError> {
+        let hash = Router::deliver(ticket)?;
+        if let Some((channel, amount)) = sent {
+            Pallet::<T>::note_sent(&channel, amount);
+        }
+        Ok(hash)
+    }
+}
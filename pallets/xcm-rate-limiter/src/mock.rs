@@ -0,0 +1,147 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use crate::{self as pallet_xcm_rate_limiter, RateLimit};
+
+use frame_support::{construct_runtime, parameter_types, traits::Everything, weights::Weight};
+use parity_scale_codec::Encode;
+use sp_core::H256;
+use sp_io::{hashing::blake2_256, TestExternalities};
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+use std::cell::RefCell;
+use xcm::v3::prelude::*;
+
+type BlockNumber = u64;
+pub(crate) type AccountId = u64;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) const PERIOD: BlockNumber = 10;
+pub(crate) const MAX_MESSAGES: u32 = 3;
+pub(crate) const MAX_AMOUNT: u128 = 1_000;
+pub(crate) const SELF_PARA_ID: u32 = 1000;
+
+construct_runtime!(
+    pub struct Test
+    where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        XcmRateLimiter: pallet_xcm_rate_limiter,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1_000_000, 0));
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = BlockWeights;
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Index = u64;
+    type RuntimeCall = RuntimeCall;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+thread_local! {
+    pub static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+}
+
+pub(crate) fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+    SENT_XCM.with(|q| (*q.borrow()).clone())
+}
+
+/// Sender which stores sent messages.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+    type Ticket = (MultiLocation, Xcm<()>);
+    fn validate(
+        dest: &mut Option<MultiLocation>,
+        msg: &mut Option<Xcm<()>>,
+    ) -> SendResult<(MultiLocation, Xcm<()>)> {
+        let pair = (dest.take().unwrap(), msg.take().unwrap());
+        Ok((pair, MultiAssets::new()))
+    }
+    fn deliver(pair: (MultiLocation, Xcm<()>)) -> Result<XcmHash, SendError> {
+        let hash = pair.1.using_encoded(blake2_256);
+        SENT_XCM.with(|q| q.borrow_mut().push(pair));
+        Ok(hash)
+    }
+}
+
+parameter_types! {
+    pub const DefaultRateLimit: Option<RateLimit<BlockNumber>> = Some(RateLimit {
+        period: PERIOD,
+        max_messages: MAX_MESSAGES,
+        max_amount: MAX_AMOUNT,
+    });
+    pub const NativeAssetLocation: MultiLocation = Here.into_location();
+    pub UniversalLocation: InteriorMultiLocation =
+        X2(GlobalConsensus(NetworkId::Rococo), Parachain(SELF_PARA_ID));
+}
+
+impl pallet_xcm_rate_limiter::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type DefaultRateLimit = DefaultRateLimit;
+    type NativeAssetLocation = NativeAssetLocation;
+    type UniversalLocation = UniversalLocation;
+    type WeightInfo = ();
+}
+
+pub(crate) type Router = crate::RateLimitedRouter<Test, TestSendXcm>;
+
+pub struct ExternalityBuilder;
+
+impl ExternalityBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
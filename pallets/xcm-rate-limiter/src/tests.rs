@@ -0,0 +1,276 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use super::{pallet::Error, pallet::Event, *};
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+fn para(id: u32) -> MultiLocation {
+    (Parent, Parachain(id)).into()
+}
+
+/// Native currency, as seen by a sibling parachain.
+fn native() -> MultiLocation {
+    para(SELF_PARA_ID)
+}
+
+/// Reserve transfer of `amount` of the native currency to an account on a sibling parachain.
+fn transfer(amount: u128) -> Xcm<()> {
+    transfer_asset(native(), amount)
+}
+
+/// Reserve transfer of `amount` of `asset` to an account on the destination.
+fn transfer_asset(asset: MultiLocation, amount: u128) -> Xcm<()> {
+    Xcm(vec![
+        ReserveAssetDeposited((asset, amount).into()),
+        ClearOrigin,
+        BuyExecution {
+            fees: (asset, amount).into(),
+            weight_limit: Unlimited,
+        },
+        DepositAsset {
+            assets: AllCounted(1).into(),
+            beneficiary: AccountId32 {
+                network: None,
+                id: [1; 32],
+            }
+            .into(),
+        },
+    ])
+}
+
+#[test]
+fn channel_and_amount_of_messages() {
+    let account: MultiLocation = (
+        Parent,
+        Parachain(2000),
+        AccountId32 {
+            network: None,
+            id: [1; 32],
+        },
+    )
+        .into();
+    assert_eq!(channel_of(&account), para(2000));
+    assert_eq!(channel_of(&Parent.into()), Parent.into());
+    assert_eq!(
+        channel_of(
+            &(
+                Parent,
+                AccountId32 {
+                    network: None,
+                    id: [1; 32]
+                }
+            )
+                .into()
+        ),
+        Parent.into()
+    );
+
+    assert_eq!(transferred_amount(&transfer(100), &native()), 100);
+    let message = Xcm::<()>(vec![
+        WithdrawAsset(MultiAssets::from(vec![
+            MultiAsset::from((native(), 100u128)),
+            (Parent, 20u128).into(),
+            (Here, 5u128).into(),
+        ])),
+        ReceiveTeleportedAsset((native(), 3u128).into()),
+        ClearOrigin,
+    ]);
+    assert_eq!(transferred_amount(&message, &native()), 103);
+}
+
+#[test]
+fn messages_are_limited_per_channel() {
+    ExternalityBuilder::build().execute_with(|| {
+        for _ in 0..MAX_MESSAGES {
+            assert_ok!(send_xcm::<Router>(para(2000), transfer(1)));
+        }
+        assert_eq!(
+            send_xcm::<Router>(para(2000), transfer(1)),
+            Err(SendError::Transport("outbound rate limit exceeded"))
+        );
+        assert_eq!(sent_xcm().len(), MAX_MESSAGES as usize);
+
+        // Other channels are not affected
+        assert_ok!(send_xcm::<Router>(para(2001), transfer(1)));
+        assert_ok!(send_xcm::<Router>(Parent.into(), transfer(1)));
+
+        // Limit is lifted once the window ends
+        System::set_block_number(1 + PERIOD);
+        assert_ok!(send_xcm::<Router>(para(2000), transfer(1)));
+        assert_eq!(XcmRateLimiter::usage(para(2000)).messages, 1);
+    })
+}
+
+#[test]
+fn amount_is_limited_per_channel() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(send_xcm::<Router>(para(2000), transfer(MAX_AMOUNT - 10)));
+        assert_eq!(
+            send_xcm::<Router>(para(2000), transfer(11)),
+            Err(SendError::Transport("outbound rate limit exceeded"))
+        );
+        assert_ok!(send_xcm::<Router>(para(2000), transfer(10)));
+
+        assert_eq!(
+            XcmRateLimiter::usage(para(2000)),
+            ChannelUsage {
+                window_start: 1,
+                messages: 2,
+                amount: MAX_AMOUNT,
+            }
+        );
+    })
+}
+
+#[test]
+fn only_native_currency_amount_is_limited() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Other assets only count as messages
+        assert_ok!(send_xcm::<Router>(
+            para(2000),
+            transfer_asset(Parent.into(), MAX_AMOUNT * 2)
+        ));
+        assert_ok!(send_xcm::<Router>(
+            para(2000),
+            transfer_asset(para(2000), MAX_AMOUNT * 2)
+        ));
+        assert_eq!(XcmRateLimiter::usage(para(2000)).amount, 0);
+
+        // Native currency is recognized as seen by the relay chain
+        assert_eq!(
+            send_xcm::<Router>(
+                Parent.into(),
+                transfer_asset(Parachain(SELF_PARA_ID).into(), MAX_AMOUNT + 1)
+            ),
+            Err(SendError::Transport("outbound rate limit exceeded"))
+        );
+        assert_ok!(send_xcm::<Router>(
+            Parent.into(),
+            transfer_asset(Parachain(SELF_PARA_ID).into(), MAX_AMOUNT)
+        ));
+        assert_eq!(
+            XcmRateLimiter::usage(MultiLocation::parent()).amount,
+            MAX_AMOUNT
+        );
+    })
+}
+
+#[test]
+fn rate_limit_can_be_overridden() {
+    ExternalityBuilder::build().execute_with(|| {
+        let rate_limit = RateLimit {
+            period: 5,
+            max_messages: 1,
+            max_amount: u128::MAX,
+        };
+        assert_ok!(XcmRateLimiter::set_rate_limit(
+            RuntimeOrigin::root(),
+            Box::new(para(2000).into()),
+            Some(rate_limit),
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcmRateLimiter(Event::RateLimitSet {
+            channel: para(2000),
+            rate_limit: Some(rate_limit),
+        }));
+
+        assert_ok!(send_xcm::<Router>(para(2000), transfer(MAX_AMOUNT * 2)));
+        assert!(send_xcm::<Router>(para(2000), transfer(1)).is_err());
+
+        // Removing the override restores the default limit
+        assert_ok!(XcmRateLimiter::set_rate_limit(
+            RuntimeOrigin::root(),
+            Box::new(para(2000).into()),
+            None,
+        ));
+        assert_eq!(
+            XcmRateLimiter::rate_limit(&para(2000)),
+            DefaultRateLimit::get()
+        );
+
+        // Overrides are set for the whole channel
+        assert_ok!(XcmRateLimiter::set_rate_limit(
+            RuntimeOrigin::root(),
+            Box::new(
+                (
+                    Parent,
+                    Parachain(2001),
+                    AccountId32 {
+                        network: None,
+                        id: [1; 32]
+                    }
+                )
+                    .into()
+            ),
+            Some(rate_limit),
+        ));
+        assert_eq!(
+            XcmRateLimiter::rate_limit_overrides(para(2001)),
+            Some(rate_limit)
+        );
+
+        assert_noop!(
+            XcmRateLimiter::set_rate_limit(
+                RuntimeOrigin::root(),
+                Box::new(para(2000).into()),
+                Some(RateLimit {
+                    period: 0,
+                    ..rate_limit
+                }),
+            ),
+            Error::<Test>::ZeroPeriod
+        );
+    })
+}
+
+#[test]
+fn usage_can_be_reset() {
+    ExternalityBuilder::build().execute_with(|| {
+        for _ in 0..MAX_MESSAGES {
+            assert_ok!(send_xcm::<Router>(para(2000), transfer(1)));
+        }
+        assert!(send_xcm::<Router>(para(2000), transfer(1)).is_err());
+
+        assert_ok!(XcmRateLimiter::reset_usage(
+            RuntimeOrigin::root(),
+            Box::new(para(2000).into()),
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcmRateLimiter(Event::UsageReset {
+            channel: para(2000),
+        }));
+        assert_ok!(send_xcm::<Router>(para(2000), transfer(1)));
+    })
+}
+
+#[test]
+fn only_manager_origin() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            XcmRateLimiter::set_rate_limit(
+                RuntimeOrigin::signed(1),
+                Box::new(para(2000).into()),
+                None,
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            XcmRateLimiter::reset_usage(RuntimeOrigin::signed(1), Box::new(para(2000).into())),
+            BadOrigin
+        );
+    })
+}
@@ -0,0 +1,69 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_xcm_rate_limiter
+//!
+//! TODO: these weights were not generated by the benchmark CLI. They are estimated from the storage
+//! accessed by each extrinsic and must be replaced by the output of the `pallet_xcm_rate_limiter`
+//! benchmarks, run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_xcm_rate_limiter.
+pub trait WeightInfo {
+	fn set_rate_limit() -> Weight;
+	fn reset_usage() -> Weight;
+}
+
+/// Weights for pallet_xcm_rate_limiter using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: XcmRateLimiter RateLimitOverrides (r:0 w:1)
+	// Proof: XcmRateLimiter RateLimitOverrides (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	fn set_rate_limit() -> Weight {
+		Weight::from_parts(14_387_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcmRateLimiter Usage (r:0 w:1)
+	// Proof: XcmRateLimiter Usage (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	fn reset_usage() -> Weight {
+		Weight::from_parts(13_244_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: XcmRateLimiter RateLimitOverrides (r:0 w:1)
+	// Proof: XcmRateLimiter RateLimitOverrides (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	fn set_rate_limit() -> Weight {
+		Weight::from_parts(14_387_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcmRateLimiter Usage (r:0 w:1)
+	// Proof: XcmRateLimiter Usage (max_values: None, max_size: Some(631), added: 3106, mode: MaxEncodedLen)
+	fn reset_usage() -> Weight {
+		Weight::from_parts(13_244_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
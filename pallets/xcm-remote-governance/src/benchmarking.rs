@@ -0,0 +1,86 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as XcmRemoteGovernance;
+
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+
+fn allowed_calls<T: Config>() -> BoundedVec<CallPrefix, T::MaxAllowedCalls> {
+    (0..T::MaxAllowedCalls::get())
+        .map(|i| [i as u8, 0])
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("Size is equal to the maximum; qed")
+}
+
+benchmarks! {
+
+    set_destination {
+        let destination: MultiLocation = (Parent, Parachain(2000)).into();
+        let allowed_calls = allowed_calls::<T>();
+
+    }: _(RawOrigin::Root, Box::new(destination.into()), OriginKind::SovereignAccount, allowed_calls.clone())
+    verify {
+        assert_eq!(
+            Destinations::<T>::get(destination).map(|config| config.allowed_calls),
+            Some(allowed_calls)
+        );
+    }
+
+    remove_destination {
+        let destination: MultiLocation = (Parent, Parachain(2000)).into();
+        XcmRemoteGovernance::<T>::set_destination(
+            RawOrigin::Root.into(),
+            Box::new(destination.into()),
+            OriginKind::SovereignAccount,
+            allowed_calls::<T>(),
+        )?;
+
+    }: _(RawOrigin::Root, Box::new(destination.into()))
+    verify {
+        assert!(!Destinations::<T>::contains_key(destination));
+    }
+
+    send_transact {
+        let destination = T::ReachableDest::get().ok_or(BenchmarkError::Skip)?;
+        let allowed_calls = allowed_calls::<T>();
+        // The last allowed call is matched, so the whole filter is checked.
+        let prefix = allowed_calls[allowed_calls.len() - 1];
+        XcmRemoteGovernance::<T>::set_destination(
+            RawOrigin::Root.into(),
+            Box::new(destination.into()),
+            OriginKind::SovereignAccount,
+            allowed_calls,
+        )?;
+
+        let mut call = vec![0_u8; T::MaxCallLength::get() as usize];
+        call[..2].copy_from_slice(&prefix);
+        let call: BoundedVec<u8, T::MaxCallLength> =
+            call.try_into().expect("Size is equal to the maximum; qed");
+
+    }: _(RawOrigin::Root, Box::new(destination.into()), call, Weight::from_parts(1_000_000_000, 64 * 1024))
+}
+
+impl_benchmark_test_suite!(
+    XcmRemoteGovernance,
+    crate::mock::ExternalityBuilder::build(),
+    crate::mock::Test,
+);
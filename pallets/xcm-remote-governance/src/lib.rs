@@ -0,0 +1,262 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! # XCM Remote Governance Pallet
+//!
+//! ## Overview
+//!
+//! Lets the result of a referendum (or any other `DispatchOrigin`) be executed on another chain.
+//! The call is sent as an XCM `Transact` instruction, and the remote chain converts this chain's
+//! location into a local origin according to the `OriginKind` configured for the destination.
+//! With `OriginKind::SovereignAccount`, the call is dispatched by this chain's sovereign account
+//! on the destination, e.g. an account owning a settlement contract on another chain.
+//!
+//! Only destinations registered by `ManagerOrigin` can be used, and each destination has its own
+//! list of allowed calls. An allowed call is identified by its `[pallet_index, call_index]` prefix,
+//! which is how every FRAME based chain encodes its runtime calls.
+//!
+//! Messages are sent with `UnpaidExecution`, so the destination must be configured to execute
+//! messages from this chain for free.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Function
+//!
+//! - `set_destination` - registers or updates a destination, its origin kind and allowed calls
+//! - `remove_destination` - removes a destination
+//! - `send_transact` - sends an allowed encoded call to a registered destination
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, weights::Weight};
+use frame_system::pallet_prelude::*;
+use sp_std::{boxed::Box, vec};
+use xcm::{
+    v3::{prelude::*, MultiLocation},
+    VersionedMultiLocation,
+};
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Call prefix, i.e. `[pallet_index, call_index]` of an encoded runtime call.
+pub type CallPrefix = [u8; 2];
+
+/// Configuration of a destination chain.
+#[derive(
+    Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxAllowedCalls))]
+pub struct DestinationConfig<MaxAllowedCalls: Get<u32>> {
+    /// How the destination converts this chain's location into its local origin.
+    pub origin_kind: OriginKind,
+    /// Calls which can be dispatched on the destination.
+    pub allowed_calls: BoundedVec<CallPrefix, MaxAllowedCalls>,
+}
+
+impl<MaxAllowedCalls: Get<u32>> DestinationConfig<MaxAllowedCalls> {
+    /// `true` if the encoded call starts with one of the allowed prefixes.
+    pub fn allows(&self, encoded_call: &[u8]) -> bool {
+        self.allowed_calls
+            .iter()
+            .any(|prefix| encoded_call.starts_with(prefix))
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The required origin for sending calls to destinations.
+        ///
+        /// Should be root or the origin of approved referenda.
+        type DispatchOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// The required origin for managing destinations and their allowed calls.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Router used to send XCM messages.
+        type XcmRouter: SendXcm;
+
+        /// Maximum length of an encoded call.
+        #[pallet::constant]
+        type MaxCallLength: Get<u32>;
+
+        /// Maximum number of allowed calls per destination.
+        #[pallet::constant]
+        type MaxAllowedCalls: Get<u32>;
+
+        /// A destination which is reachable through `XcmRouter`, used for benchmarking.
+        #[cfg(feature = "runtime-benchmarks")]
+        type ReachableDest: Get<Option<MultiLocation>>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Destination location can't be converted into the supported XCM version.
+        BadVersion,
+        /// Destination isn't registered.
+        UnknownDestination,
+        /// Call isn't allowed on the destination.
+        CallFiltered,
+        /// Sending the XCM message failed.
+        SendFailure,
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Destination was registered or updated.
+        DestinationSet {
+            destination: MultiLocation,
+            origin_kind: OriginKind,
+        },
+        /// Destination was removed.
+        DestinationRemoved { destination: MultiLocation },
+        /// Call was sent to the destination.
+        TransactSent {
+            destination: MultiLocation,
+            message_id: XcmHash,
+        },
+    }
+
+    /// Registered destinations and their configuration.
+    #[pallet::storage]
+    #[pallet::getter(fn destinations)]
+    pub type Destinations<T: Config> =
+        StorageMap<_, Blake2_128Concat, MultiLocation, DestinationConfig<T::MaxAllowedCalls>>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register a destination or update an existing one.
+        ///
+        /// `allowed_calls` fully replaces the previously allowed calls.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_destination())]
+        pub fn set_destination(
+            origin: OriginFor<T>,
+            destination: Box<VersionedMultiLocation>,
+            origin_kind: OriginKind,
+            allowed_calls: BoundedVec<CallPrefix, T::MaxAllowedCalls>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let destination = Self::v3_location(*destination)?;
+
+            Destinations::<T>::insert(
+                destination,
+                DestinationConfig {
+                    origin_kind,
+                    allowed_calls,
+                },
+            );
+            Self::deposit_event(Event::DestinationSet {
+                destination,
+                origin_kind,
+            });
+
+            Ok(())
+        }
+
+        /// Remove a registered destination.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_destination())]
+        pub fn remove_destination(
+            origin: OriginFor<T>,
+            destination: Box<VersionedMultiLocation>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let destination = Self::v3_location(*destination)?;
+
+            ensure!(
+                Destinations::<T>::contains_key(destination),
+                Error::<T>::UnknownDestination
+            );
+            Destinations::<T>::remove(destination);
+            Self::deposit_event(Event::DestinationRemoved { destination });
+
+            Ok(())
+        }
+
+        /// Send an encoded call to a registered destination.
+        ///
+        /// The call must be allowed for the destination, and is executed there with at most
+        /// `require_weight_at_most` weight.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::send_transact())]
+        pub fn send_transact(
+            origin: OriginFor<T>,
+            destination: Box<VersionedMultiLocation>,
+            call: BoundedVec<u8, T::MaxCallLength>,
+            require_weight_at_most: Weight,
+        ) -> DispatchResult {
+            T::DispatchOrigin::ensure_origin(origin)?;
+            let destination = Self::v3_location(*destination)?;
+
+            let config =
+                Destinations::<T>::get(destination).ok_or(Error::<T>::UnknownDestination)?;
+            ensure!(config.allows(&call), Error::<T>::CallFiltered);
+
+            let message = Xcm(vec![
+                UnpaidExecution {
+                    weight_limit: Unlimited,
+                    check_origin: None,
+                },
+                Transact {
+                    origin_kind: config.origin_kind,
+                    require_weight_at_most,
+                    call: call.into_inner().into(),
+                },
+            ]);
+            let (message_id, _) = send_xcm::<T::XcmRouter>(destination, message)
+                .map_err(|_| Error::<T>::SendFailure)?;
+
+            Self::deposit_event(Event::TransactSent {
+                destination,
+                message_id,
+            });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        fn v3_location(location: VersionedMultiLocation) -> Result<MultiLocation, Error<T>> {
+            MultiLocation::try_from(location).map_err(|_| Error::<T>::BadVersion)
+        }
+    }
+}
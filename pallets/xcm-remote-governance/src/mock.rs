@@ -0,0 +1,156 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_xcm_remote_governance};
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, Everything},
+    weights::Weight,
+};
+use parity_scale_codec::Encode;
+use sp_core::H256;
+use sp_io::{hashing::blake2_256, TestExternalities};
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+use std::cell::RefCell;
+use xcm::v3::prelude::*;
+
+type BlockNumber = u64;
+pub(crate) type AccountId = u64;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) const MAX_CALL_LENGTH: u32 = 16;
+pub(crate) const MAX_ALLOWED_CALLS: u32 = 4;
+
+/// Destination to which the test router refuses to send messages.
+pub(crate) const UNROUTABLE_PARA_ID: u32 = 3000;
+
+construct_runtime!(
+    pub struct Test
+    where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system,
+        XcmRemoteGovernance: pallet_xcm_remote_governance,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::simple_max(Weight::from_parts(1_000_000, 0));
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = BlockWeights;
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Index = u64;
+    type RuntimeCall = RuntimeCall;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+thread_local! {
+    pub static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+}
+
+pub(crate) fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+    SENT_XCM.with(|q| (*q.borrow()).clone())
+}
+
+pub(crate) fn fake_message_hash<T>(message: &Xcm<T>) -> XcmHash {
+    message.using_encoded(blake2_256)
+}
+
+/// Sender which stores sent messages, except for the unroutable destination.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+    type Ticket = (MultiLocation, Xcm<()>);
+    fn validate(
+        dest: &mut Option<MultiLocation>,
+        msg: &mut Option<Xcm<()>>,
+    ) -> SendResult<(MultiLocation, Xcm<()>)> {
+        let (dest, msg) = (dest.take().unwrap(), msg.take().unwrap());
+        if dest == (Parent, Parachain(UNROUTABLE_PARA_ID)).into() {
+            Err(SendError::Unroutable)
+        } else {
+            Ok(((dest, msg), MultiAssets::new()))
+        }
+    }
+    fn deliver(pair: (MultiLocation, Xcm<()>)) -> Result<XcmHash, SendError> {
+        let hash = fake_message_hash(&pair.1);
+        SENT_XCM.with(|q| q.borrow_mut().push(pair));
+        Ok(hash)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+parameter_types! {
+    pub ReachableDest: Option<MultiLocation> = Some(Parent.into());
+}
+
+impl pallet_xcm_remote_governance::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type DispatchOrigin = frame_system::EnsureRoot<AccountId>;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type XcmRouter = TestSendXcm;
+    type MaxCallLength = ConstU32<MAX_CALL_LENGTH>;
+    type MaxAllowedCalls = ConstU32<MAX_ALLOWED_CALLS>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type ReachableDest = ReachableDest;
+    type WeightInfo = ();
+}
+
+pub struct ExternalityBuilder;
+
+impl ExternalityBuilder {
+    pub fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
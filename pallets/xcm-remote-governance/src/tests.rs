@@ -0,0 +1,180 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::{pallet::Error, pallet::Event, *};
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+fn para(id: u32) -> MultiLocation {
+    (Parent, Parachain(id)).into()
+}
+
+fn set_destination(destination: MultiLocation, allowed_calls: Vec<CallPrefix>) {
+    assert_ok!(XcmRemoteGovernance::set_destination(
+        RuntimeOrigin::root(),
+        Box::new(destination.into()),
+        OriginKind::SovereignAccount,
+        allowed_calls.try_into().unwrap(),
+    ));
+}
+
+#[test]
+fn only_manager_and_dispatch_origins() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_noop!(
+            XcmRemoteGovernance::set_destination(
+                RuntimeOrigin::signed(1),
+                Box::new(para(2000).into()),
+                OriginKind::SovereignAccount,
+                Default::default(),
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            XcmRemoteGovernance::remove_destination(
+                RuntimeOrigin::signed(1),
+                Box::new(para(2000).into())
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            XcmRemoteGovernance::send_transact(
+                RuntimeOrigin::signed(1),
+                Box::new(para(2000).into()),
+                vec![1, 2].try_into().unwrap(),
+                Weight::from_parts(1_000, 0),
+            ),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn set_and_remove_destination_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let destination = para(2000);
+        set_destination(destination, vec![[1, 2]]);
+        System::assert_last_event(mock::RuntimeEvent::XcmRemoteGovernance(
+            Event::DestinationSet {
+                destination,
+                origin_kind: OriginKind::SovereignAccount,
+            },
+        ));
+
+        // Allowed calls are replaced on update
+        assert_ok!(XcmRemoteGovernance::set_destination(
+            RuntimeOrigin::root(),
+            Box::new(destination.into()),
+            OriginKind::Xcm,
+            vec![[3, 4]].try_into().unwrap(),
+        ));
+        let config = XcmRemoteGovernance::destinations(destination).unwrap();
+        assert_eq!(config.origin_kind, OriginKind::Xcm);
+        assert_eq!(config.allowed_calls.into_inner(), vec![[3, 4]]);
+
+        assert_ok!(XcmRemoteGovernance::remove_destination(
+            RuntimeOrigin::root(),
+            Box::new(destination.into())
+        ));
+        System::assert_last_event(mock::RuntimeEvent::XcmRemoteGovernance(
+            Event::DestinationRemoved { destination },
+        ));
+        assert!(XcmRemoteGovernance::destinations(destination).is_none());
+
+        assert_noop!(
+            XcmRemoteGovernance::remove_destination(
+                RuntimeOrigin::root(),
+                Box::new(destination.into())
+            ),
+            Error::<Test>::UnknownDestination
+        );
+    })
+}
+
+#[test]
+fn send_transact_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let destination = para(2000);
+        set_destination(destination, vec![[1, 2], [5, 0]]);
+
+        let call = vec![5, 0, 42, 42];
+        let weight = Weight::from_parts(1_000_000, 1_024);
+        assert_ok!(XcmRemoteGovernance::send_transact(
+            RuntimeOrigin::root(),
+            Box::new(destination.into()),
+            call.clone().try_into().unwrap(),
+            weight,
+        ));
+
+        let message = Xcm(vec![
+            UnpaidExecution {
+                weight_limit: Unlimited,
+                check_origin: None,
+            },
+            Transact {
+                origin_kind: OriginKind::SovereignAccount,
+                require_weight_at_most: weight,
+                call: call.into(),
+            },
+        ]);
+        assert_eq!(sent_xcm(), vec![(destination, message.clone())]);
+        System::assert_last_event(mock::RuntimeEvent::XcmRemoteGovernance(
+            Event::TransactSent {
+                destination,
+                message_id: fake_message_hash(&message),
+            },
+        ));
+    })
+}
+
+#[test]
+fn send_transact_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        let send = |destination: MultiLocation, call: Vec<u8>| {
+            XcmRemoteGovernance::send_transact(
+                RuntimeOrigin::root(),
+                Box::new(destination.into()),
+                call.try_into().unwrap(),
+                Weight::from_parts(1_000, 0),
+            )
+        };
+
+        // Destination must be registered
+        assert_noop!(
+            send(para(2000), vec![1, 2]),
+            Error::<Test>::UnknownDestination
+        );
+
+        // Call must be allowed for the destination
+        set_destination(para(2000), vec![[1, 2]]);
+        set_destination(para(2001), vec![[3, 4]]);
+        assert_noop!(send(para(2000), vec![3, 4]), Error::<Test>::CallFiltered);
+        assert_noop!(send(para(2000), vec![1]), Error::<Test>::CallFiltered);
+        assert_noop!(send(para(2000), vec![]), Error::<Test>::CallFiltered);
+
+        // Router errors are reported
+        set_destination(para(UNROUTABLE_PARA_ID), vec![[1, 2]]);
+        assert_noop!(
+            send(para(UNROUTABLE_PARA_ID), vec![1, 2]),
+            Error::<Test>::SendFailure
+        );
+
+        assert!(sent_xcm().is_empty());
+    })
+}
@@ -0,0 +1,248 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for simulating XVM calls.
+//!
+//! Calls are executed on top of the state of a block without submitting a transaction,
+//! so the weight, return data and events of a cross-VM call can be estimated beforehand.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError as RpcCallError, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_xvm_runtime_api::{
+    Balance, CallError, DryRunInfo, VmId, Weight, XvmApi as XvmRuntimeApi,
+};
+
+/// VM of either side of a cross-VM call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Vm {
+    Evm,
+    Wasm,
+}
+
+impl From<Vm> for VmId {
+    fn from(vm: Vm) -> Self {
+        match vm {
+            Vm::Evm => VmId::Evm,
+            Vm::Wasm => VmId::Wasm,
+        }
+    }
+}
+
+/// Cross-VM call to simulate.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XvmCallRequest<AccountId> {
+    pub source: AccountId,
+    /// VM the call is made from.
+    pub source_vm: Vm,
+    /// VM of the target contract.
+    pub target_vm: Vm,
+    /// Target contract address, encoded as expected by XVM: `H160` for EVM,
+    /// SCALE encoded `MultiAddress` for WASM.
+    pub target: Bytes,
+    #[serde(default)]
+    pub input: Bytes,
+    #[serde(default)]
+    pub value: Balance,
+    /// Defaults to the max block weight.
+    #[serde(default)]
+    pub weight_limit: Option<Weight>,
+}
+
+/// Outcome of a simulated cross-VM call.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XvmDryRunResult {
+    pub success: bool,
+    pub used_weight: Weight,
+    /// Return data of the call, empty if it failed.
+    pub output: Bytes,
+    /// Reason of the failure, `null` on success.
+    pub error: Option<String>,
+    /// SCALE encoded `RuntimeEvent`s deposited during the call.
+    pub events: Vec<Bytes>,
+}
+
+impl From<DryRunInfo> for XvmDryRunResult {
+    fn from(info: DryRunInfo) -> Self {
+        let events = info.events.into_iter().map(Into::into).collect();
+        match info.result {
+            Ok(call_info) => Self {
+                success: true,
+                used_weight: call_info.used_weight,
+                output: call_info.output.into(),
+                error: None,
+                events,
+            },
+            Err(error) => Self {
+                success: false,
+                used_weight: error.used_weight,
+                output: Default::default(),
+                error: Some(match error.error {
+                    CallError::ExecutionFailed(reason) => {
+                        format!("ExecutionFailed: {}", String::from_utf8_lossy(&reason))
+                    }
+                    error => format!("{:?}", error),
+                }),
+                events,
+            },
+        }
+    }
+}
+
+#[rpc(client, server)]
+pub trait XvmApi<BlockHash, AccountId> {
+    /// Simulates a cross-VM call on top of the state of block `at`, without submitting
+    /// a transaction.
+    #[method(name = "xvm_dryRunCall")]
+    fn dry_run_call(
+        &self,
+        request: XvmCallRequest<AccountId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<XvmDryRunResult>;
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code returned when the runtime doesn't support XVM.
+const UNSUPPORTED_ERROR: i32 = 2;
+
+/// Provides RPC methods to simulate XVM calls.
+pub struct Xvm<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Xvm<C, Block> {
+    /// Creates a new instance of the `Xvm` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId> XvmApiServer<<Block as BlockT>::Hash, AccountId> for Xvm<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: XvmRuntimeApi<Block, AccountId>,
+    AccountId: Codec + Serialize + for<'de> Deserialize<'de> + Send + Sync + 'static,
+{
+    fn dry_run_call(
+        &self,
+        request: XvmCallRequest<AccountId>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<XvmDryRunResult> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let info = api
+            .dry_run_call(
+                at_hash,
+                request.source,
+                request.source_vm.into(),
+                request.target_vm.into(),
+                request.target.to_vec(),
+                request.input.to_vec(),
+                request.value,
+                request.weight_limit,
+            )
+            .map_err(|e| {
+                RpcCallError::Custom(ErrorObject::owned(
+                    RUNTIME_ERROR,
+                    "Unable to simulate XVM call.",
+                    Some(e.to_string()),
+                ))
+            })?
+            .ok_or_else(|| {
+                RpcCallError::Custom(ErrorObject::owned(
+                    UNSUPPORTED_ERROR,
+                    "XVM isn't supported by the runtime.",
+                    None::<()>,
+                ))
+            })?;
+
+        Ok(info.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pallet_xvm_runtime_api::{CallErrorWithWeight, CallInfo};
+
+    #[test]
+    fn dry_run_result_is_serialized_as_expected() {
+        let used_weight = Weight::from_parts(1_000, 64);
+        let info = DryRunInfo {
+            result: Ok(CallInfo {
+                output: vec![0xAA, 0xBB],
+                used_weight,
+            }),
+            events: vec![vec![1, 2, 3]],
+        };
+
+        assert_eq!(
+            serde_json::to_value(XvmDryRunResult::from(info)).unwrap(),
+            serde_json::json!({
+                "success": true,
+                "usedWeight": serde_json::to_value(used_weight).unwrap(),
+                "output": "0xaabb",
+                "error": null,
+                "events": ["0x010203"]
+            })
+        );
+    }
+
+    #[test]
+    fn failed_dry_run_reports_the_reason() {
+        let used_weight = Weight::from_parts(1_000, 64);
+        let failed = |error| DryRunInfo {
+            result: Err(CallErrorWithWeight { error, used_weight }),
+            events: vec![],
+        };
+
+        let result = XvmDryRunResult::from(failed(CallError::ExecutionFailed(
+            b"ContractTrapped".to_vec(),
+        )));
+        assert!(!result.success);
+        assert_eq!(result.used_weight, used_weight);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("ExecutionFailed: ContractTrapped")
+        );
+
+        let result = XvmDryRunResult::from(failed(CallError::InvalidTarget));
+        assert_eq!(result.error.as_deref(), Some("InvalidTarget"));
+    }
+}
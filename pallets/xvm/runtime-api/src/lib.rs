@@ -0,0 +1,61 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for simulating XVM calls.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+pub use frame_support::weights::Weight;
+
+pub use astar_primitives::{
+    xvm::{CallError, CallErrorWithWeight, CallInfo, CallResult, VmId},
+    Balance,
+};
+
+/// Outcome of a simulated XVM call.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DryRunInfo {
+    /// Result of the call, including the consumed weight.
+    pub result: CallResult,
+    /// SCALE encoded `RuntimeEvent`s deposited during the call.
+    pub events: Vec<Vec<u8>>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// API for simulating XVM calls.
+    pub trait XvmApi<AccountId> where AccountId: Codec {
+        /// Simulate the call of the `vm_id` contract `target` by `source`, from the `source_vm_id` VM.
+        ///
+        /// Nothing is committed to the state. `weight_limit` defaults to the max block weight.
+        /// Returns `None` if XVM isn't supported by the runtime.
+        fn dry_run_call(
+            source: AccountId,
+            source_vm_id: VmId,
+            vm_id: VmId,
+            target: Vec<u8>,
+            input: Vec<u8>,
+            value: Balance,
+            weight_limit: Option<Weight>,
+        ) -> Option<DryRunInfo>;
+    }
+}
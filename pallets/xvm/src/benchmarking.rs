@@ -70,6 +70,27 @@ mod benchmarks {
         }
     }
 
+    #[benchmark]
+    fn enqueue_call() {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: T::MaxAsyncCallWeight::get(),
+        };
+        let vm_id = VmId::Evm;
+        let source: T::AccountId = whitelisted_caller();
+        <T as pallet_contracts::Config>::Currency::make_free_balance_be(&source, Balance::MAX / 2);
+        let target = H160::repeat_byte(1).encode();
+        let input = vec![1; T::MaxAsyncCallInputLen::get() as usize];
+        let value = 1_000_000u128;
+        let callback = source.encode();
+
+        #[block]
+        {
+            Pallet::<T>::enqueue_call(context, vm_id, source, target, input, value, callback)
+                .unwrap();
+        }
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
@@ -28,28 +28,49 @@
 //! Together with other functionalities like Chain Extension and precompiles,
 //! the XVM pallet enables the runtime to support cross-VM calls.
 //!
+//! Calls can also be executed asynchronously. Such calls are put into a queue and executed
+//! in `on_idle` of the subsequent blocks, in the order they were queued, as soon as there's
+//! enough remaining block weight for their weight limit. The price of the reserved weight is
+//! held from the source when the call is queued, and the actually used weight is charged from
+//! it once the call is executed. The result is delivered with an event and to the
+//! `OnAsyncCallResult` handler, e.g. `ContractCallback` which calls the initiating contract back.
+//!
+//! Optionally, the result of synchronous calls can be mirrored as a `CallExecuted` event and
+//! a synthetic EVM log (see `MirrorCallResults`), both referencing the hash of the initiating
+//! extrinsic, so indexers following a single VM can track value flowing across the VM boundary.
+//!
 //! ## Interface
 //!
 //! ### Implementation
 //!
 //! - Implements `XvmCall` trait.
+//! - Implements `XvmAsyncCall` trait.
 //!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{ensure, traits::Currency, weights::Weight};
+use frame_support::{
+    ensure,
+    pallet_prelude::*,
+    traits::{Currency, OnUnbalanced, ReservableCurrency},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::*;
 use pallet_contracts::{CollectEvents, DebugInfo, Determinism};
 use pallet_evm::GasWeightMapping;
 use parity_scale_codec::Decode;
-use sp_core::{H160, U256};
-use sp_runtime::traits::StaticLookup;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{Convert, StaticLookup};
 use sp_std::{marker::PhantomData, prelude::*};
 
 use astar_primitives::{
     ethereum_checked::{
         AccountMapping, CheckedEthereumTransact, CheckedEthereumTx, EthereumTxInput,
     },
-    xvm::{CallError, CallErrorWithWeight, CallInfo, CallResult, Context, VmId, XvmCall},
+    xvm::{
+        AsyncCallId, CallError, CallErrorWithWeight, CallInfo, CallResult, Context,
+        OnAsyncCallResult, VmId, XvmAsyncCall, XvmCall,
+    },
     Balance,
 };
 
@@ -66,6 +87,39 @@ pub use pallet::*;
 
 pub type WeightInfoOf<T> = <T as Config>::WeightInfo;
 
+pub type NegativeImbalanceOf<T> = <<T as pallet_contracts::Config>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+/// Max length of the encoded target of an asynchronous call.
+pub const MAX_ASYNC_CALL_TARGET_LEN: u32 = 64;
+
+/// Asynchronous XVM call waiting in the queue.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxInputLen))]
+#[codec(mel_bound(AccountId: MaxEncodedLen))]
+pub struct AsyncCall<AccountId, MaxInputLen: Get<u32>> {
+    /// XVM context of the call.
+    pub context: Context,
+    /// The VM Id of the target contract.
+    pub vm_id: VmId,
+    /// Account which initiated the call.
+    pub source: AccountId,
+    /// Target contract address.
+    pub target: BoundedVec<u8, ConstU32<MAX_ASYNC_CALL_TARGET_LEN>>,
+    /// Call input data.
+    pub input: BoundedVec<u8, MaxInputLen>,
+    /// Value to transfer.
+    pub value: Balance,
+    /// Address of the initiating contract in the source VM, the result is delivered to.
+    pub callback: BoundedVec<u8, ConstU32<MAX_ASYNC_CALL_TARGET_LEN>>,
+    /// Price of the reserved weight, held from `source` until the call is executed.
+    pub deposit: Balance,
+}
+
+pub type AsyncCallOf<T> =
+    AsyncCall<<T as frame_system::Config>::AccountId, <T as Config>::MaxAsyncCallInputLen>;
+
 environmental::thread_local_impl!(static IN_XVM: environmental::RefCell<bool> = environmental::RefCell::new(false));
 
 #[frame_support::pallet]
@@ -76,7 +130,7 @@ pub mod pallet {
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + pallet_contracts::Config {
+    pub trait Config: frame_system::Config + pallet_contracts::Config + pallet_evm::Config {
         /// Mapping from `Account` to `H160`.
         type AccountMapping: AccountMapping<Self::AccountId>;
 
@@ -86,15 +140,102 @@ pub mod pallet {
         /// `CheckedEthereumTransact` implementation.
         type EthereumTransact: CheckedEthereumTransact;
 
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Handler of asynchronous call results.
+        type OnAsyncCallResult: OnAsyncCallResult<Self::AccountId>;
+
+        /// The destination of the fees paid for executing asynchronous calls.
+        type OnChargeAsyncCall: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Max number of asynchronous calls waiting in the queue.
+        #[pallet::constant]
+        type MaxQueuedCalls: Get<u32>;
+
+        /// Max input length of an asynchronous call.
+        #[pallet::constant]
+        type MaxAsyncCallInputLen: Get<u32>;
+
+        /// Max weight limit of an asynchronous call.
+        ///
+        /// Should be low enough to fit in the weight left for `on_idle`, otherwise
+        /// such call would block the queue.
+        #[pallet::constant]
+        type MaxAsyncCallWeight: Get<Weight>;
+
+        /// Address of the synthetic EVM logs mirroring the result of synchronous calls.
+        ///
+        /// `None` disables the mirroring, neither the `CallExecuted` event nor the EVM log
+        /// are emitted then.
+        #[pallet::constant]
+        type MirrorCallResults: Get<Option<H160>>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Asynchronous call was queued.
+        AsyncCallQueued {
+            id: AsyncCallId,
+            source: T::AccountId,
+            vm_id: VmId,
+        },
+        /// Asynchronous call was executed successfully.
+        AsyncCallSucceeded {
+            id: AsyncCallId,
+            source: T::AccountId,
+            output: Vec<u8>,
+        },
+        /// Asynchronous call failed.
+        AsyncCallFailed {
+            id: AsyncCallId,
+            source: T::AccountId,
+            error: CallError,
+        },
+        /// Synchronous call was executed, only emitted if `MirrorCallResults` is set.
+        CallExecuted {
+            source: T::AccountId,
+            vm_id: VmId,
+            target: Vec<u8>,
+            value: Balance,
+            success: bool,
+            /// Hash of the extrinsic which initiated the call, if any.
+            tx_hash: Option<H256>,
+        },
+    }
+
+    /// Asynchronous calls waiting for execution.
+    #[pallet::storage]
+    pub type AsyncCallQueue<T: Config> =
+        StorageMap<_, Twox64Concat, AsyncCallId, AsyncCallOf<T>, OptionQuery>;
+
+    /// Id of the oldest asynchronous call in the queue.
+    #[pallet::storage]
+    pub type QueueHead<T: Config> = StorageValue<_, AsyncCallId, ValueQuery>;
+
+    /// Id of the next queued asynchronous call.
+    #[pallet::storage]
+    pub type NextAsyncCallId<T: Config> = StorageValue<_, AsyncCallId, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
+    where
+        <T as pallet_contracts::Config>::Currency: Currency<T::AccountId, Balance = Balance>,
+    {
+        fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::process_async_calls(remaining_weight)
+        }
+    }
 }
 
 impl<T> XvmCall<T::AccountId> for Pallet<T>
 where
     T: Config,
-    T::Currency: Currency<T::AccountId, Balance = Balance>,
+    <T as pallet_contracts::Config>::Currency: Currency<T::AccountId, Balance = Balance>,
 {
     fn call(
         context: Context,
@@ -104,15 +245,382 @@ where
         input: Vec<u8>,
         value: Balance,
     ) -> CallResult {
-        Pallet::<T>::do_call(context, vm_id, source, target, input, value, false)
+        let result = Pallet::<T>::do_call(
+            context,
+            vm_id,
+            source.clone(),
+            target.clone(),
+            input,
+            value,
+            false,
+        );
+        if let Some(log_address) = T::MirrorCallResults::get() {
+            Pallet::<T>::mirror_call_result(
+                log_address,
+                source,
+                vm_id,
+                target,
+                value,
+                result.is_ok(),
+            );
+        }
+        result
+    }
+}
+
+impl<T> XvmAsyncCall<T::AccountId> for Pallet<T>
+where
+    T: Config,
+    <T as pallet_contracts::Config>::Currency: Currency<T::AccountId, Balance = Balance>,
+{
+    fn enqueue_call(
+        context: Context,
+        vm_id: VmId,
+        source: T::AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+        value: Balance,
+        callback: Vec<u8>,
+    ) -> Result<AsyncCallId, CallError> {
+        ensure!(context.source_vm_id != vm_id, CallError::SameVmCallDenied);
+        ensure!(
+            context.weight_limit.all_lte(T::MaxAsyncCallWeight::get()),
+            CallError::WeightLimitTooHigh
+        );
+
+        let target = BoundedVec::try_from(target).map_err(|_| CallError::InvalidTarget)?;
+        let input = BoundedVec::try_from(input).map_err(|_| CallError::InputTooLarge)?;
+        let callback = BoundedVec::try_from(callback).map_err(|_| CallError::InvalidTarget)?;
+
+        let id = NextAsyncCallId::<T>::get();
+        ensure!(
+            id.saturating_sub(QueueHead::<T>::get()) < T::MaxQueuedCalls::get().into(),
+            CallError::QueueFull
+        );
+
+        // Hold the price of the reserved weight, the unused part is released on execution.
+        let deposit = <T as pallet_contracts::Config>::WeightPrice::convert(
+            Pallet::<T>::async_call_weight(vm_id, context.weight_limit),
+        );
+        <T as pallet_contracts::Config>::Currency::reserve(&source, deposit)
+            .map_err(|_| CallError::InsufficientBalance)?;
+
+        AsyncCallQueue::<T>::insert(
+            id,
+            AsyncCall {
+                context,
+                vm_id,
+                source: source.clone(),
+                target,
+                input,
+                value,
+                callback,
+                deposit,
+            },
+        );
+        NextAsyncCallId::<T>::put(id.saturating_add(1));
+        Pallet::<T>::deposit_event(Event::AsyncCallQueued { id, source, vm_id });
+
+        Ok(id)
+    }
+
+    fn enqueue_call_weight() -> Weight {
+        WeightInfoOf::<T>::enqueue_call()
+    }
+}
+
+/// Sender of the EVM transactions delivering asynchronous call results.
+///
+/// Contracts can't be the sender of a transaction, so the callback is sent from the zero address.
+pub const ASYNC_CALL_RESULT_SENDER: H160 = H160([0u8; 20]);
+
+/// Delivers the result of an asynchronous call back to the initiating contract, by calling it
+/// in its own VM with `W` weight limit:
+///
+/// - EVM: `onXvmAsyncCallResult(uint64 id, bool success, bytes output)`, sent from
+///   `ASYNC_CALL_RESULT_SENDER`.
+/// - WASM: `on_xvm_async_call_result(id: u64, success: bool, output: Vec<u8>)` message, called by
+///   the contract itself.
+///
+/// On failure, `output` is the SCALE encoded `CallError`.
+pub struct ContractCallback<T, W>(PhantomData<(T, W)>);
+impl<T, W> OnAsyncCallResult<T::AccountId> for ContractCallback<T, W>
+where
+    T: Config,
+    <T as pallet_contracts::Config>::Currency: Currency<T::AccountId, Balance = Balance>,
+    W: Get<Weight>,
+{
+    fn on_async_call_result(
+        id: AsyncCallId,
+        context: &Context,
+        _source: &T::AccountId,
+        callback: &[u8],
+        result: &CallResult,
+    ) -> Weight {
+        let (success, output) = match result {
+            Ok(info) => (true, info.output.clone()),
+            Err(error) => (false, error.error.encode()),
+        };
+        let weight_limit = W::get();
+
+        let used_weight = match context.source_vm_id {
+            VmId::Evm => {
+                if callback.len() != H160::len_bytes() {
+                    return Weight::zero();
+                }
+                let target = H160::from_slice(callback);
+                let mut input =
+                    sp_io::hashing::keccak_256(b"onXvmAsyncCallResult(uint64,bool,bytes)")[..4]
+                        .to_vec();
+                input.extend(encode_callback_data(id, success, &output));
+                let input = match EthereumTxInput::try_from(input) {
+                    Ok(input) => input,
+                    Err(_) => return Weight::zero(),
+                };
+
+                let tx = CheckedEthereumTx {
+                    gas_limit: U256::from(<T as Config>::GasWeightMapping::weight_to_gas(
+                        weight_limit,
+                    )),
+                    target,
+                    value: U256::zero(),
+                    input,
+                    maybe_access_list: None,
+                };
+                let actual_weight =
+                    match T::EthereumTransact::xvm_transact(ASYNC_CALL_RESULT_SENDER, tx) {
+                        Ok((post_info, _)) => post_info.actual_weight,
+                        Err(e) => e.post_info.actual_weight,
+                    };
+                actual_weight.unwrap_or(weight_limit)
+            }
+            VmId::Wasm => {
+                let contract = match T::AccountId::decode(&mut &callback[..]) {
+                    Ok(contract) => contract,
+                    Err(_) => return Weight::zero(),
+                };
+                let mut input =
+                    sp_io::hashing::blake2_256(b"on_xvm_async_call_result")[..4].to_vec();
+                (id, success, output).encode_to(&mut input);
+
+                pallet_contracts::Pallet::<T>::bare_call(
+                    contract.clone(),
+                    contract,
+                    0,
+                    weight_limit,
+                    None,
+                    input,
+                    DebugInfo::Skip,
+                    CollectEvents::Skip,
+                    Determinism::Enforced,
+                )
+                .gas_consumed
+            }
+        };
+
+        used_weight.min(weight_limit)
+    }
+
+    fn max_weight() -> Weight {
+        W::get()
     }
 }
 
 impl<T> Pallet<T>
 where
     T: Config,
-    T::Currency: Currency<T::AccountId, Balance = Balance>,
+    <T as pallet_contracts::Config>::Currency: Currency<T::AccountId, Balance = Balance>,
 {
+    /// Execute a call as `XvmCall::call` does, returning its result along with the events
+    /// deposited during its execution.
+    ///
+    /// Meant to be used by runtime APIs, whose state changes are discarded: the events deposited
+    /// beforehand in the block are cleared.
+    pub fn dry_run_call(
+        context: Context,
+        vm_id: VmId,
+        source: T::AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+        value: Balance,
+    ) -> (CallResult, Vec<<T as frame_system::Config>::RuntimeEvent>) {
+        frame_system::Pallet::<T>::reset_events();
+        let result =
+            <Self as XvmCall<T::AccountId>>::call(context, vm_id, source, target, input, value);
+        let events = frame_system::Pallet::<T>::read_events_no_consensus()
+            .map(|record| record.event)
+            .collect();
+
+        (result, events)
+    }
+
+    /// Execute queued asynchronous calls as long as their weight limit fits in `remaining_weight`.
+    ///
+    /// Calls are executed in the order they were queued, returns the consumed weight.
+    fn process_async_calls(remaining_weight: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        // Reading the queue head & next call id, updating the queue head
+        let mut consumed_weight = db_weight.reads_writes(2, 1);
+        if consumed_weight.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let mut head = QueueHead::<T>::get();
+        let next_id = NextAsyncCallId::<T>::get();
+        let initial_head = head;
+
+        while head < next_id {
+            // Reading & removing the call
+            let queue_weight = db_weight.reads_writes(1, 1);
+            if consumed_weight
+                .saturating_add(queue_weight)
+                .any_gt(remaining_weight)
+            {
+                break;
+            }
+
+            let call = match AsyncCallQueue::<T>::get(head) {
+                Some(call) => call,
+                None => {
+                    consumed_weight.saturating_accrue(db_weight.reads(1));
+                    head = head.saturating_add(1);
+                    continue;
+                }
+            };
+
+            let required_weight = Self::async_call_weight(call.vm_id, call.context.weight_limit);
+            if consumed_weight
+                .saturating_add(required_weight)
+                .any_gt(remaining_weight)
+            {
+                // The call will be executed once there's enough weight left in a block
+                consumed_weight.saturating_accrue(db_weight.reads(1));
+                break;
+            }
+
+            AsyncCallQueue::<T>::remove(head);
+            let id = head;
+            head = head.saturating_add(1);
+
+            let result = Self::do_call(
+                call.context.clone(),
+                call.vm_id,
+                call.source.clone(),
+                call.target.into_inner(),
+                call.input.into_inner(),
+                call.value,
+                false,
+            );
+            let used_weight = match &result {
+                Ok(info) => info.used_weight,
+                Err(error) => error.used_weight,
+            };
+            let callback_weight = T::OnAsyncCallResult::on_async_call_result(
+                id,
+                &call.context,
+                &call.source,
+                &call.callback,
+                &result,
+            );
+            // Reading & removing the call, settling its fee
+            let call_weight = db_weight
+                .reads_writes(2, 2)
+                .saturating_add(used_weight)
+                .saturating_add(callback_weight);
+            consumed_weight.saturating_accrue(call_weight);
+            Self::charge_async_call(&call.source, call.deposit, call_weight);
+
+            match result {
+                Ok(info) => Self::deposit_event(Event::AsyncCallSucceeded {
+                    id,
+                    source: call.source,
+                    output: info.output,
+                }),
+                Err(error) => Self::deposit_event(Event::AsyncCallFailed {
+                    id,
+                    source: call.source,
+                    error: error.error,
+                }),
+            }
+        }
+
+        if head != initial_head {
+            QueueHead::<T>::put(head);
+        }
+
+        consumed_weight
+    }
+
+    /// Weight reserved for an asynchronous call to `vm_id`, including reading & removing it
+    /// from the queue, settling its fee and delivering its result.
+    fn async_call_weight(vm_id: VmId, weight_limit: Weight) -> Weight {
+        let overheads = match vm_id {
+            VmId::Evm => WeightInfoOf::<T>::evm_call_overheads(),
+            VmId::Wasm => WeightInfoOf::<T>::wasm_call_overheads(),
+        };
+        T::DbWeight::get()
+            .reads_writes(2, 2)
+            .saturating_add(overheads)
+            .saturating_add(weight_limit)
+            .saturating_add(T::OnAsyncCallResult::max_weight())
+    }
+
+    /// Charge the price of `used_weight` from the `deposit` held for an asynchronous call,
+    /// and release the rest.
+    fn charge_async_call(source: &T::AccountId, deposit: Balance, used_weight: Weight) {
+        let fee = <T as pallet_contracts::Config>::WeightPrice::convert(used_weight).min(deposit);
+        let (imbalance, _) = <T as pallet_contracts::Config>::Currency::slash_reserved(source, fee);
+        T::OnChargeAsyncCall::on_unbalanced(imbalance);
+        <T as pallet_contracts::Config>::Currency::unreserve(source, deposit.saturating_sub(fee));
+    }
+
+    /// Deposit the `CallExecuted` event, and its EVM counterpart:
+    ///
+    /// `event XvmCallExecuted(bytes32 indexed txHash, address indexed source, uint8 indexed vmId,
+    /// bytes target, uint256 value, bool success)`
+    fn mirror_call_result(
+        log_address: H160,
+        source: T::AccountId,
+        vm_id: VmId,
+        target: Vec<u8>,
+        value: Balance,
+        success: bool,
+    ) {
+        // Extrinsic data is only noted while applying extrinsics.
+        let tx_hash = frame_system::Pallet::<T>::extrinsic_index().map(|index| {
+            H256(sp_io::hashing::blake2_256(
+                &frame_system::Pallet::<T>::extrinsic_data(index),
+            ))
+        });
+
+        let mut vm_id_topic = H256::zero();
+        vm_id_topic.0[31] = vm_id as u8;
+        let log = pallet_evm::Log {
+            address: log_address,
+            topics: vec![
+                H256(sp_io::hashing::keccak_256(
+                    b"XvmCallExecuted(bytes32,address,uint8,bytes,uint256,bool)",
+                )),
+                tx_hash.unwrap_or_default(),
+                T::AccountMapping::into_h160(source.clone()).into(),
+                vm_id_topic,
+            ],
+            data: encode_log_data(&target, value, success),
+        };
+        frame_system::Pallet::<T>::deposit_event(<T as pallet_evm::Config>::RuntimeEvent::from(
+            pallet_evm::Event::<T>::Log { log },
+        ));
+
+        Self::deposit_event(Event::CallExecuted {
+            source,
+            vm_id,
+            target,
+            value,
+            success,
+            tx_hash,
+        });
+    }
+
     fn do_call(
         context: Context,
         vm_id: VmId,
@@ -206,7 +714,7 @@ where
         let value_u256 = U256::from(value);
         // With overheads, less weight is available.
         let weight_limit = context.weight_limit.saturating_sub(overheads);
-        let gas_limit = U256::from(T::GasWeightMapping::weight_to_gas(weight_limit));
+        let gas_limit = U256::from(<T as Config>::GasWeightMapping::weight_to_gas(weight_limit));
 
         let source = T::AccountMapping::into_h160(source);
         let tx = CheckedEthereumTx {
@@ -330,3 +838,33 @@ where
         Self::do_call(context, vm_id, source, target, input, value, true)
     }
 }
+
+/// ABI encoding of `(uint64 id, bool success, bytes output)`.
+fn encode_callback_data(id: AsyncCallId, success: bool, output: &[u8]) -> Vec<u8> {
+    let padded_len = (output.len() + 31) / 32 * 32;
+    let mut data = vec![0u8; 4 * 32 + padded_len];
+
+    U256::from(id).to_big_endian(&mut data[0..32]);
+    data[63] = success.into();
+    // Offset of `output`, right after the three head words.
+    U256::from(3 * 32).to_big_endian(&mut data[64..96]);
+    U256::from(output.len()).to_big_endian(&mut data[96..128]);
+    data[128..128 + output.len()].copy_from_slice(output);
+
+    data
+}
+
+/// ABI encoding of `(bytes target, uint256 value, bool success)`.
+fn encode_log_data(target: &[u8], value: Balance, success: bool) -> Vec<u8> {
+    let padded_len = (target.len() + 31) / 32 * 32;
+    let mut data = vec![0u8; 4 * 32 + padded_len];
+
+    // Offset of `target`, right after the three head words.
+    U256::from(3 * 32).to_big_endian(&mut data[0..32]);
+    U256::from(value).to_big_endian(&mut data[32..64]);
+    data[95] = success.into();
+    U256::from(target.len()).to_big_endian(&mut data[96..128]);
+    data[128..128 + target.len()].copy_from_slice(target);
+
+    data
+}
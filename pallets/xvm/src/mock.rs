@@ -21,7 +21,10 @@
 use super::*;
 use crate as pallet_xvm;
 
-use fp_evm::{CallInfo as EvmCallInfo, ExitReason, ExitSucceed, UsedGas};
+use astar_primitives::ethereum_checked::CheckedEthereumCreateTx;
+use fp_evm::{
+    CallInfo as EvmCallInfo, CreateInfo as EvmCreateInfo, ExitReason, ExitSucceed, UsedGas,
+};
 use frame_support::{
     construct_runtime,
     dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
@@ -30,10 +33,10 @@ use frame_support::{
     sp_io::TestExternalities,
     traits::{ConstBool, ConstU128, ConstU64, Nothing},
 };
-use sp_core::{H160, H256};
+use sp_core::{H160, H256, U256};
 use sp_runtime::{
     testing::Header,
-    traits::{AccountIdLookup, BlakeTwo256},
+    traits::{AccountIdLookup, BlakeTwo256, Convert},
     AccountId32,
 };
 use sp_std::cell::RefCell;
@@ -95,6 +98,14 @@ impl pallet_timestamp::Config for TestRuntime {
 
 impl pallet_insecure_randomness_collective_flip::Config for TestRuntime {}
 
+/// One unit of balance per unit of ref time.
+pub struct MockWeightPrice;
+impl Convert<Weight, Balance> for MockWeightPrice {
+    fn convert(weight: Weight) -> Balance {
+        weight.ref_time().into()
+    }
+}
+
 parameter_types! {
     pub const DepositPerItem: Balance = 1_000;
     pub const DepositPerByte: Balance = 1_000;
@@ -113,7 +124,7 @@ impl pallet_contracts::Config for TestRuntime {
     type DepositPerByte = DepositPerByte;
     type DefaultDepositLimit = DefaultDepositLimit;
     type CallStack = [pallet_contracts::Frame<Self>; 5];
-    type WeightPrice = ();
+    type WeightPrice = MockWeightPrice;
     type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
     type ChainExtension = ();
     type Schedule = Schedule;
@@ -124,6 +135,34 @@ impl pallet_contracts::Config for TestRuntime {
     type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
 }
 
+parameter_types! {
+    pub WeightPerGas: Weight = Weight::from_parts(1, 0);
+    pub BlockGasLimit: U256 = U256::MAX;
+}
+
+impl pallet_evm::Config for TestRuntime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type WeightPerGas = WeightPerGas;
+    type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+    type CallOrigin = pallet_evm::EnsureAddressRoot<AccountId>;
+    type WithdrawOrigin = pallet_evm::EnsureAddressNever<AccountId>;
+    type AddressMapping = pallet_evm::HashedAddressMapping<BlakeTwo256>;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = ();
+    type PrecompilesValue = ();
+    type ChainId = ConstU64<1024>;
+    type OnChargeTransaction = ();
+    type BlockGasLimit = BlockGasLimit;
+    type OnCreate = ();
+    type FindAuthor = ();
+    type Timestamp = Timestamp;
+    type WeightInfo = pallet_evm::weights::SubstrateWeight<TestRuntime>;
+    type GasLimitPovSizeRatio = ConstU64<4>;
+}
+
 pub struct HashedAccountMapping;
 impl astar_primitives::ethereum_checked::AccountMapping<AccountId> for HashedAccountMapping {
     fn into_h160(account_id: AccountId) -> H160 {
@@ -166,6 +205,28 @@ impl CheckedEthereumTransact for MockEthereumTransact {
             },
         ))
     }
+
+    fn xvm_create(
+        _source: H160,
+        _checked_tx: CheckedEthereumCreateTx,
+    ) -> Result<(PostDispatchInfo, EvmCreateInfo), DispatchErrorWithPostInfo> {
+        Ok((
+            PostDispatchInfo {
+                actual_weight: Default::default(),
+                pays_fee: Default::default(),
+            },
+            EvmCreateInfo {
+                exit_reason: ExitReason::Succeed(ExitSucceed::Returned),
+                value: Default::default(),
+                used_gas: UsedGas {
+                    standard: Default::default(),
+                    effective: Default::default(),
+                },
+                logs: Default::default(),
+                weight_info: None,
+            },
+        ))
+    }
 }
 
 pub struct MockGasWeightMapping;
@@ -178,10 +239,50 @@ impl GasWeightMapping for MockGasWeightMapping {
     }
 }
 
+thread_local! {
+    static ASYNC_CALL_RESULTS: RefCell<Vec<(AsyncCallId, AccountId, CallResult)>> = RefCell::new(vec![]);
+}
+
+pub struct MockOnAsyncCallResult;
+impl MockOnAsyncCallResult {
+    pub(crate) fn results() -> Vec<(AsyncCallId, AccountId, CallResult)> {
+        ASYNC_CALL_RESULTS.with(|v| v.borrow().clone())
+    }
+}
+impl OnAsyncCallResult<AccountId> for MockOnAsyncCallResult {
+    fn on_async_call_result(
+        id: AsyncCallId,
+        _context: &Context,
+        source: &AccountId,
+        _callback: &[u8],
+        result: &CallResult,
+    ) -> Weight {
+        ASYNC_CALL_RESULTS.with(|v| v.borrow_mut().push((id, source.clone(), result.clone())));
+        CallbackWeight::get()
+    }
+
+    fn max_weight() -> Weight {
+        CallbackWeight::get()
+    }
+}
+
+parameter_types! {
+    pub const CallbackWeight: Weight = Weight::from_parts(100_000, 0);
+    pub const MaxAsyncCallWeight: Weight = Weight::from_parts(2_000_000, 2_000_000);
+    pub static MirrorCallResults: Option<H160> = None;
+}
+
 impl pallet_xvm::Config for TestRuntime {
     type GasWeightMapping = MockGasWeightMapping;
     type AccountMapping = HashedAccountMapping;
     type EthereumTransact = MockEthereumTransact;
+    type RuntimeEvent = RuntimeEvent;
+    type OnAsyncCallResult = MockOnAsyncCallResult;
+    type OnChargeAsyncCall = ();
+    type MaxQueuedCalls = ConstU32<2>;
+    type MaxAsyncCallInputLen = ConstU32<1024>;
+    type MaxAsyncCallWeight = MaxAsyncCallWeight;
+    type MirrorCallResults = MirrorCallResults;
     type WeightInfo = weights::SubstrateWeight<TestRuntime>;
 }
 
@@ -203,11 +304,15 @@ construct_runtime!(
         Balances: pallet_balances,
         RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
         Contracts: pallet_contracts,
+        Evm: pallet_evm,
         Xvm: pallet_xvm,
     }
 );
 
 pub(crate) const ALICE: AccountId = AccountId32::new([0u8; 32]);
+pub(crate) const BOB: AccountId = AccountId32::new([1u8; 32]);
+
+pub(crate) const ALICE_BALANCE: Balance = 1_000_000_000;
 
 #[derive(Default)]
 pub struct ExtBuilder;
@@ -216,10 +321,17 @@ impl ExtBuilder {
     #[allow(dead_code)]
     pub fn build(self) -> TestExternalities {
         TRANSACTED.with(|v| *v.borrow_mut() = None);
+        ASYNC_CALL_RESULTS.with(|v| v.borrow_mut().clear());
+        MirrorCallResults::set(None);
 
-        let t = frame_system::GenesisConfig::default()
+        let mut t = frame_system::GenesisConfig::default()
             .build_storage::<TestRuntime>()
             .unwrap();
+        pallet_balances::GenesisConfig::<TestRuntime> {
+            balances: vec![(ALICE, ALICE_BALANCE)],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
 
         let mut ext = TestExternalities::from(t);
         ext.execute_with(|| {
@@ -21,7 +21,7 @@
 use super::*;
 use mock::*;
 
-use frame_support::{assert_noop, assert_ok, weights::Weight};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
 use parity_scale_codec::Encode;
 use sp_core::H160;
 use sp_runtime::MultiAddress;
@@ -200,3 +200,442 @@ fn wasm_call_fails_if_invalid_target() {
         );
     });
 }
+
+#[test]
+fn enqueue_call_fails_if_invalid() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let target = H160::repeat_byte(0xFF).encode();
+
+        assert_noop!(
+            Xvm::enqueue_call(
+                context.clone(),
+                VmId::Wasm,
+                ALICE,
+                target.clone(),
+                vec![1, 2, 3],
+                0,
+                vec![]
+            ),
+            CallError::SameVmCallDenied
+        );
+        assert_noop!(
+            Xvm::enqueue_call(
+                Context {
+                    source_vm_id: VmId::Wasm,
+                    weight_limit: Weight::from_parts(2_000_001, 1_000_000),
+                },
+                VmId::Evm,
+                ALICE,
+                target.clone(),
+                vec![1, 2, 3],
+                0,
+                vec![]
+            ),
+            CallError::WeightLimitTooHigh
+        );
+        assert_noop!(
+            Xvm::enqueue_call(
+                context.clone(),
+                VmId::Evm,
+                ALICE,
+                vec![1; 65],
+                vec![1, 2, 3],
+                0,
+                vec![]
+            ),
+            CallError::InvalidTarget
+        );
+        assert_noop!(
+            Xvm::enqueue_call(
+                context.clone(),
+                VmId::Evm,
+                ALICE,
+                target.clone(),
+                vec![1; 1025],
+                0,
+                vec![]
+            ),
+            CallError::InputTooLarge
+        );
+        assert_noop!(
+            Xvm::enqueue_call(
+                context.clone(),
+                VmId::Evm,
+                ALICE,
+                target.clone(),
+                vec![1, 2, 3],
+                0,
+                vec![1; 65]
+            ),
+            CallError::InvalidTarget
+        );
+        // The reserved weight can't be paid for
+        assert_noop!(
+            Xvm::enqueue_call(
+                context.clone(),
+                VmId::Evm,
+                BOB,
+                target.clone(),
+                vec![1, 2, 3],
+                0,
+                vec![]
+            ),
+            CallError::InsufficientBalance
+        );
+
+        // Fill the queue
+        for _ in 0..2 {
+            assert_ok!(Xvm::enqueue_call(
+                context.clone(),
+                VmId::Evm,
+                ALICE,
+                target.clone(),
+                vec![1, 2, 3],
+                0,
+                vec![]
+            ));
+        }
+        assert_noop!(
+            Xvm::enqueue_call(context, VmId::Evm, ALICE, target, vec![1, 2, 3], 0, vec![]),
+            CallError::QueueFull
+        );
+    });
+}
+
+#[test]
+fn async_call_is_executed_on_idle() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let target = H160::repeat_byte(0xFF);
+        let input = vec![1, 2, 3];
+        let value = 1_000_000u128;
+
+        assert_eq!(
+            Xvm::enqueue_call(
+                context.clone(),
+                VmId::Evm,
+                ALICE,
+                target.encode(),
+                input.clone(),
+                value,
+                vec![]
+            ),
+            Ok(0)
+        );
+        System::assert_last_event(RuntimeEvent::Xvm(Event::AsyncCallQueued {
+            id: 0,
+            source: ALICE,
+            vm_id: VmId::Evm,
+        }));
+
+        // The price of the reserved weight is held
+        let overheads = weights::SubstrateWeight::<TestRuntime>::evm_call_overheads();
+        let reserved_weight = overheads
+            .saturating_add(context.weight_limit)
+            .saturating_add(CallbackWeight::get());
+        assert_eq!(
+            Balances::reserved_balance(&ALICE),
+            MockWeightPrice::convert(reserved_weight)
+        );
+
+        // Not enough weight left for the call, it stays in the queue
+        let consumed_weight = Xvm::on_idle(1, Weight::from_parts(1_000_000, 1_000_000));
+        assert!(consumed_weight.all_lte(Weight::from_parts(1_000_000, 1_000_000)));
+        assert!(AsyncCallQueue::<TestRuntime>::contains_key(0));
+        assert!(MockOnAsyncCallResult::results().is_empty());
+
+        let consumed_weight = Xvm::on_idle(2, Weight::from_parts(2_000_000, 1_000_000));
+        assert_eq!(
+            consumed_weight,
+            overheads.saturating_add(CallbackWeight::get())
+        );
+        assert!(!AsyncCallQueue::<TestRuntime>::contains_key(0));
+        assert_eq!(QueueHead::<TestRuntime>::get(), 1);
+
+        // Only the used weight is charged, the rest is released
+        assert_eq!(Balances::reserved_balance(&ALICE), 0);
+        assert_eq!(
+            Balances::free_balance(&ALICE),
+            ALICE_BALANCE - MockWeightPrice::convert(consumed_weight)
+        );
+
+        System::assert_last_event(RuntimeEvent::Xvm(Event::AsyncCallSucceeded {
+            id: 0,
+            source: ALICE,
+            output: vec![],
+        }));
+        assert_eq!(
+            MockOnAsyncCallResult::results(),
+            vec![(
+                0,
+                ALICE,
+                Ok(CallInfo {
+                    output: vec![],
+                    used_weight: overheads,
+                })
+            )]
+        );
+        MockEthereumTransact::assert_transacted(
+            HashedAccountMapping::into_h160(ALICE),
+            CheckedEthereumTx {
+                gas_limit: U256::from(246000),
+                target,
+                value: U256::from(value),
+                input: EthereumTxInput::try_from(input).expect("input too large"),
+                maybe_access_list: None,
+            },
+        );
+    });
+}
+
+#[test]
+fn async_calls_are_executed_in_order() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+
+        // Invalid WASM target, fails on execution
+        assert_ok!(Xvm::enqueue_call(
+            context.clone(),
+            VmId::Wasm,
+            ALICE,
+            vec![1, 2, 3].encode(),
+            vec![],
+            0,
+            vec![]
+        ));
+        assert_ok!(Xvm::enqueue_call(
+            Context {
+                source_vm_id: VmId::Wasm,
+                ..context
+            },
+            VmId::Evm,
+            ALICE,
+            H160::repeat_byte(0xFF).encode(),
+            vec![],
+            0,
+            vec![]
+        ));
+
+        Xvm::on_idle(1, Weight::from_parts(10_000_000, 10_000_000));
+        assert_eq!(QueueHead::<TestRuntime>::get(), 2);
+
+        let results: Vec<_> = MockOnAsyncCallResult::results()
+            .into_iter()
+            .map(|(id, _, result)| (id, result.is_ok()))
+            .collect();
+        assert_eq!(results, vec![(0, false), (1, true)]);
+
+        System::assert_has_event(RuntimeEvent::Xvm(Event::AsyncCallFailed {
+            id: 0,
+            source: ALICE,
+            error: CallError::InvalidTarget,
+        }));
+        System::assert_last_event(RuntimeEvent::Xvm(Event::AsyncCallSucceeded {
+            id: 1,
+            source: ALICE,
+            output: vec![],
+        }));
+    });
+}
+
+#[test]
+fn contract_callback_delivers_result_to_evm_contract() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let callback = H160::repeat_byte(0xCA);
+        let result = Ok(CallInfo {
+            output: vec![1, 2, 3],
+            used_weight: Weight::zero(),
+        });
+        type Callback = ContractCallback<TestRuntime, CallbackWeight>;
+
+        // Nothing is delivered to an invalid address
+        assert_eq!(
+            Callback::on_async_call_result(7, &context, &ALICE, &[1, 2, 3], &result),
+            Weight::zero()
+        );
+        assert_eq!(
+            Callback::on_async_call_result(
+                7,
+                &Context {
+                    source_vm_id: VmId::Wasm,
+                    ..context.clone()
+                },
+                &ALICE,
+                &[1, 2, 3],
+                &result
+            ),
+            Weight::zero()
+        );
+
+        assert_eq!(
+            Callback::on_async_call_result(7, &context, &ALICE, callback.as_bytes(), &result),
+            CallbackWeight::get()
+        );
+        let mut input =
+            sp_io::hashing::keccak_256(b"onXvmAsyncCallResult(uint64,bool,bytes)")[..4].to_vec();
+        let mut data = vec![0u8; 160];
+        data[31] = 7;
+        data[63] = 1;
+        data[95] = 0x60;
+        data[127] = 3;
+        data[128..131].copy_from_slice(&[1, 2, 3]);
+        input.extend(data);
+        MockEthereumTransact::assert_transacted(
+            ASYNC_CALL_RESULT_SENDER,
+            CheckedEthereumTx {
+                gas_limit: U256::from(CallbackWeight::get().ref_time()),
+                target: callback,
+                value: U256::zero(),
+                input: EthereumTxInput::try_from(input).expect("input too large"),
+                maybe_access_list: None,
+            },
+        );
+    });
+}
+
+#[test]
+fn call_result_is_mirrored_if_enabled() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let target = H160::repeat_byte(0xFF).encode();
+        let value = 1_000_000u128;
+
+        // Disabled by default
+        assert_ok!(Xvm::call(
+            context.clone(),
+            VmId::Evm,
+            ALICE,
+            target.clone(),
+            vec![],
+            value
+        ));
+        assert!(System::events().is_empty());
+
+        let log_address = H160::repeat_byte(0x50);
+        MirrorCallResults::set(Some(log_address));
+        System::set_extrinsic_index(0);
+        System::note_extrinsic(vec![1, 2, 3]);
+        let tx_hash = H256(sp_io::hashing::blake2_256(&[1, 2, 3]));
+
+        assert_ok!(Xvm::call(
+            context.clone(),
+            VmId::Evm,
+            ALICE,
+            target.clone(),
+            vec![],
+            value
+        ));
+
+        let mut data = vec![0u8; 160];
+        data[31] = 0x60;
+        U256::from(value).to_big_endian(&mut data[32..64]);
+        data[95] = 1;
+        data[127] = 20;
+        data[128..148].copy_from_slice(&target);
+        let mut vm_id_topic = H256::zero();
+        vm_id_topic.0[31] = VmId::Evm as u8;
+        System::assert_has_event(RuntimeEvent::Evm(pallet_evm::Event::Log {
+            log: pallet_evm::Log {
+                address: log_address,
+                topics: vec![
+                    H256(sp_io::hashing::keccak_256(
+                        b"XvmCallExecuted(bytes32,address,uint8,bytes,uint256,bool)",
+                    )),
+                    tx_hash,
+                    HashedAccountMapping::into_h160(ALICE).into(),
+                    vm_id_topic,
+                ],
+                data,
+            },
+        }));
+        System::assert_last_event(RuntimeEvent::Xvm(Event::CallExecuted {
+            source: ALICE,
+            vm_id: VmId::Evm,
+            target: target.clone(),
+            value,
+            success: true,
+            tx_hash: Some(tx_hash),
+        }));
+
+        // Failed calls are mirrored as well
+        assert!(Xvm::call(context, VmId::Wasm, ALICE, target.clone(), vec![], value).is_err());
+        System::assert_last_event(RuntimeEvent::Xvm(Event::CallExecuted {
+            source: ALICE,
+            vm_id: VmId::Wasm,
+            target,
+            value,
+            success: false,
+            tx_hash: Some(tx_hash),
+        }));
+    });
+}
+
+#[test]
+fn dry_run_call_returns_only_its_own_events() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let target = H160::repeat_byte(0xFF).encode();
+        let value = 1_000_000u128;
+        MirrorCallResults::set(Some(H160::repeat_byte(0x50)));
+
+        // Events deposited before the dry run aren't returned
+        assert_ok!(Xvm::call(
+            context.clone(),
+            VmId::Evm,
+            ALICE,
+            target.clone(),
+            vec![],
+            value
+        ));
+        assert_eq!(System::events().len(), 2);
+
+        let (result, events) = Xvm::dry_run_call(
+            context.clone(),
+            VmId::Evm,
+            ALICE,
+            target.clone(),
+            vec![],
+            value,
+        );
+        assert_eq!(
+            result,
+            Xvm::call(context, VmId::Evm, ALICE, target.clone(), vec![], value)
+        );
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events[0],
+            RuntimeEvent::Evm(pallet_evm::Event::Log { .. })
+        ));
+        assert_eq!(
+            events[1],
+            RuntimeEvent::Xvm(Event::CallExecuted {
+                source: ALICE,
+                vm_id: VmId::Evm,
+                target,
+                value,
+                success: true,
+                tx_hash: None,
+            })
+        );
+    });
+}
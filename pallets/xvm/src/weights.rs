@@ -39,6 +39,9 @@
 // --output=./benchmark-results/xvm_weights.rs
 // --template=./scripts/templates/weight-template.hbs
 
+// TODO: weights marked as placeholders below were written or updated by hand for extrinsics
+// added after the benchmarks were last run, and must be regenerated by running the benchmarks.
+
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -50,6 +53,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn evm_call_overheads() -> Weight;
 	fn wasm_call_overheads() -> Weight;
+	fn enqueue_call() -> Weight;
 }
 
 /// Weights for pallet_xvm using the Substrate node and recommended hardware.
@@ -69,6 +73,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 309_000 picoseconds.
 		Weight::from_parts(347_000, 0)
 	}
+	// Storage: Xvm NextAsyncCallId (r:1 w:1)
+	// Storage: Xvm QueueHead (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Xvm AsyncCallQueue (r:0 w:1)
+	fn enqueue_call() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -87,4 +101,14 @@ impl WeightInfo for () {
 		// Minimum execution time: 309_000 picoseconds.
 		Weight::from_parts(347_000, 0)
 	}
+	// Storage: Xvm NextAsyncCallId (r:1 w:1)
+	// Storage: Xvm QueueHead (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Xvm AsyncCallQueue (r:0 w:1)
+	fn enqueue_call() -> Weight {
+		// TODO: placeholder weight, not generated by the benchmark CLI.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
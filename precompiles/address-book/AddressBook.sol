@@ -0,0 +1,50 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Address book interface.
+ */
+interface AddressBook {
+    /**
+     * @dev Get the EVM address registered under the given name.
+     * @param name Name of the address book entry, e.g. "treasury"
+     * @return found `true` if an EVM address is registered under the name
+     * @return evm_address Registered EVM address, zero address if not found
+     */
+    function evmAddressOf(string calldata name)
+        external
+        view
+        returns (bool found, address evm_address);
+
+    /**
+     * @dev Get the native account registered under the given name.
+     * @param name Name of the address book entry, e.g. "treasury"
+     * @return found `true` if a native account is registered under the name
+     * @return native_address Registered native account public key, zero if not found
+     */
+    function nativeAddressOf(string calldata name)
+        external
+        view
+        returns (bool found, bytes32 native_address);
+
+    /**
+     * @dev Derive the native accounts of the given EVM addresses, using the runtime address mapping.
+     * Up to 512 addresses can be mapped in a single call.
+     * @param evm_addresses EVM addresses to map
+     * @return native_addresses Native account public keys, in the same order
+     */
+    function toNativeAddresses(address[] calldata evm_addresses)
+        external
+        view
+        returns (bytes32[] memory native_addresses);
+
+    /**
+     * @dev Derive the EVM addresses of the given native accounts, using the runtime account mapping.
+     * Up to 512 accounts can be mapped in a single call.
+     * @param native_addresses Native account public keys to map
+     * @return evm_addresses EVM addresses, in the same order
+     */
+    function toEvmAddresses(bytes32[] calldata native_addresses)
+        external
+        view
+        returns (address[] memory evm_addresses);
+}
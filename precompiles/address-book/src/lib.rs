@@ -0,0 +1,162 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{PrecompileHandle, PrecompileOutput};
+use pallet_address_book::AddressRecord;
+use pallet_evm::Precompile;
+use parity_scale_codec::MaxEncodedLen;
+use sp_core::{ConstU32, H256};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use precompile_utils::{
+    bytes::BoundedString, data::BoundedVec, succeed, Address, AddressMapper, EvmDataWriter,
+    EvmResult, FunctionModifier, PrecompileHandleExt, ZeroAddressAsNone,
+};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    EvmAddressOf = "evmAddressOf(string)",
+    NativeAddressOf = "nativeAddressOf(string)",
+    ToNativeAddresses = "toNativeAddresses(address[])",
+    ToEvmAddresses = "toEvmAddresses(bytes32[])",
+}
+
+/// Max number of addresses which can be mapped in a single call.
+pub const ARRAY_LIMIT: u32 = 2u32.pow(9);
+/// Cost of mapping a single address, in line with the cost of hashing a few words in EVM.
+pub const MAPPING_GAS_COST: u64 = 60;
+
+type GetArrayLimit = ConstU32<ARRAY_LIMIT>;
+
+type NameOf<Runtime> = BoundedString<<Runtime as pallet_address_book::Config>::MaxNameLength>;
+
+/// A precompile exposing the address book to EVM contracts.
+///
+/// Besides the named entries, it converts EVM addresses and native accounts into each other
+/// using `M`, which should be the runtime's `AddressMapper`.
+pub struct AddressBookPrecompile<Runtime, M>(PhantomData<(Runtime, M)>);
+
+impl<Runtime, M> Precompile for AddressBookPrecompile<Runtime, M>
+where
+    Runtime: pallet_evm::Config + pallet_address_book::Config,
+    Runtime::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    M: AddressMapper<Runtime::AccountId>,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        log::trace!(target: "address-book-precompile", "In address book precompile");
+
+        let selector = handle.read_selector()?;
+
+        handle.check_function_modifier(FunctionModifier::View)?;
+
+        match selector {
+            Action::EvmAddressOf => Self::evm_address_of(handle),
+            Action::NativeAddressOf => Self::native_address_of(handle),
+            Action::ToNativeAddresses => Self::to_native_addresses(handle),
+            Action::ToEvmAddresses => Self::to_evm_addresses(handle),
+        }
+    }
+}
+
+impl<Runtime, M> AddressBookPrecompile<Runtime, M>
+where
+    Runtime: pallet_evm::Config + pallet_address_book::Config,
+    Runtime::AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+    M: AddressMapper<Runtime::AccountId>,
+{
+    fn evm_address_of(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let record = Self::read_record(handle)?;
+
+        let evm = record.and_then(|r| r.evm).map(Address);
+
+        Ok(succeed(
+            EvmDataWriter::new()
+                .write(evm.is_some())
+                .write(ZeroAddressAsNone(evm))
+                .build(),
+        ))
+    }
+
+    fn native_address_of(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let record = Self::read_record(handle)?;
+
+        let (found, account) = match record.and_then(|r| r.native) {
+            Some(native) => (true, H256::from(native.into())),
+            None => (false, H256::zero()),
+        };
+
+        Ok(succeed(
+            EvmDataWriter::new().write(found).write(account).build(),
+        ))
+    }
+
+    fn to_native_addresses(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+        let addresses = input.read::<BoundedVec<Address, GetArrayLimit>>()?;
+
+        handle.record_cost(MAPPING_GAS_COST.saturating_mul(addresses.len() as u64))?;
+
+        let accounts: Vec<H256> = addresses
+            .into_iter()
+            .map(|address| H256::from(M::into_account_id(address.0).into()))
+            .collect();
+
+        Ok(succeed(EvmDataWriter::new().write(accounts).build()))
+    }
+
+    fn to_evm_addresses(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+        let accounts = input.read::<BoundedVec<H256, GetArrayLimit>>()?;
+
+        handle.record_cost(MAPPING_GAS_COST.saturating_mul(accounts.len() as u64))?;
+
+        let addresses: Vec<Address> = accounts
+            .into_iter()
+            .map(|account| Address(M::into_h160(account.to_fixed_bytes().into())))
+            .collect();
+
+        Ok(succeed(EvmDataWriter::new().write(addresses).build()))
+    }
+
+    /// Read the name argument and fetch the matching record, charging for the storage read.
+    fn read_record(
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<Option<AddressRecord<Runtime::AccountId>>> {
+        // Storage item: Entries
+        // max encoded len: AddressRecord<AccountId>
+        handle.record_db_read::<Runtime>(AddressRecord::<Runtime::AccountId>::max_encoded_len())?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+        let name = input.read::<NameOf<Runtime>>()?;
+
+        Ok(pallet_address_book::Pallet::<Runtime>::lookup(
+            name.as_bytes(),
+        ))
+    }
+}
@@ -0,0 +1,247 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+
+use frame_support::traits::ConstU32;
+use precompile_utils::{testing::*, RuntimeHelper};
+
+fn precompiles() -> TestPrecompileSet<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn name(raw: &[u8]) -> BoundedString<ConstU32<MAX_NAME_LENGTH>> {
+    raw.into()
+}
+
+fn register(raw: &[u8], native: Option<TestAccount>, evm: Option<H160>) {
+    assert!(AddressBook::set_address(
+        RuntimeOrigin::root(),
+        raw.to_vec().try_into().unwrap(),
+        native,
+        evm
+    )
+    .is_ok());
+}
+
+#[test]
+fn evm_address_of_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let evm = H160::repeat_byte(0x11);
+        register(b"router", None, Some(evm));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::EvmAddressOf)
+                    .write(name(b"router"))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(true).write(Address(evm)).build());
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::EvmAddressOf)
+                    .write(name(b"treasury"))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(false)
+                    .write(Address(H160::zero()))
+                    .build(),
+            );
+    })
+}
+
+#[test]
+fn native_address_of_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        register(b"treasury", Some(TestAccount::Bob), None);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::NativeAddressOf)
+                    .write(name(b"treasury"))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(true)
+                    .write(H256::from([0xBB; 32]))
+                    .build(),
+            );
+
+        // Only native address is registered
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::EvmAddressOf)
+                    .write(name(b"treasury"))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(false)
+                    .write(Address(H160::zero()))
+                    .build(),
+            );
+    })
+}
+
+#[test]
+fn too_long_name_reverts() {
+    ExtBuilder::default().build().execute_with(|| {
+        let too_long = [b'a'; MAX_NAME_LENGTH as usize + 1];
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::EvmAddressOf)
+                    .write(name(&too_long))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"length, value too large");
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::NativeAddressOf)
+                    .write(name(&too_long))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"length, value too large");
+    })
+}
+
+#[test]
+fn to_native_addresses_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::ToNativeAddresses)
+                    .write(vec![
+                        Address(H160::repeat_byte(0xAA)),
+                        Address(H160::repeat_byte(0xCC)),
+                    ])
+                    .build(),
+            )
+            .expect_cost(2 * MAPPING_GAS_COST)
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(vec![H256::from([0xAA; 32]), H256::from([0xCC; 32])])
+                    .build(),
+            );
+    })
+}
+
+#[test]
+fn to_evm_addresses_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::ToEvmAddresses)
+                    .write(vec![H256::from([0xBB; 32]), H256::from([0xCC; 32])])
+                    .build(),
+            )
+            .expect_cost(2 * MAPPING_GAS_COST)
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(vec![
+                        Address(H160::repeat_byte(0xBB)),
+                        Address(H160::repeat_byte(0xCC)),
+                    ])
+                    .build(),
+            );
+    })
+}
+
+#[test]
+fn too_many_addresses_revert() {
+    ExtBuilder::default().build().execute_with(|| {
+        let addresses = vec![Address(H160::repeat_byte(0xAA)); ARRAY_LIMIT as usize + 1];
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::ToNativeAddresses)
+                    .write(addresses)
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| {
+                output == b"value too large : Array has more than max items allowed"
+            });
+    })
+}
+
+#[test]
+fn all_selectors_are_covered() {
+    assert_selector_coverage(
+        Action::SELECTORS,
+        &[
+            evm_address_of_works,
+            native_address_of_works,
+            too_long_name_reverts,
+            to_native_addresses_works,
+            to_evm_addresses_works,
+        ],
+    );
+}
+
+#[test]
+fn solidity_interface_matches_selectors() {
+    assert_solidity_interface("AddressBook.sol", Action::SELECTORS);
+}
+
+#[test]
+fn all_selectors_charge_base_cost() {
+    assert_selectors_charge_base_cost(
+        Action::SELECTORS,
+        RuntimeHelper::<Runtime>::db_read_gas_cost(),
+        &[
+            evm_address_of_works,
+            native_address_of_works,
+            too_long_name_reverts,
+            to_native_addresses_works,
+            to_evm_addresses_works,
+        ],
+    );
+}
@@ -0,0 +1,20 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Assets utility interface, mapping asset ids to their ERC20 precompile addresses.
+ *
+ * Address: 0x000000000000000000000000000000000000500C
+ */
+interface AssetsUtility {
+    /**
+     * @dev Returns the asset id behind the asset precompile at `token`.
+     * Reverts if `token` isn't an asset precompile address.
+     */
+    function assetIdOf(address token) external view returns (uint128);
+
+    /**
+     * @dev Returns the address of the asset precompile for `assetId`.
+     * The asset doesn't need to exist.
+     */
+    function tokenOf(uint128 assetId) external view returns (address);
+}
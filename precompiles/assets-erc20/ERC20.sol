@@ -83,6 +83,28 @@
     function transferFrom(address from, address to, uint256 value)
         external returns (bool);
 
+    /**
+     * @dev Transfer token for a specified address with a memo, e.g. a deposit tag.
+     * Emits both `Transfer` and `TransferWithMemo` events.
+     * Selector: 95777d59
+     * @param to The address to transfer to.
+     * @param value The amount to be transferred.
+     * @param memo The memo attached to the transfer.
+     */
+    function transferWithMemo(address to, uint256 value, bytes32 memo)
+        external returns (bool);
+
+    /**
+     * @dev Transfer tokens to several addresses at once, at most 128.
+     * Emits a `Transfer` event per recipient and a single `TransferBatch` event.
+     * Reverts all the transfers if any of them fails.
+     * Selector: 3b3e672f
+     * @param recipients The addresses to transfer to.
+     * @param values The amounts to be transferred, in the order of the recipients.
+     */
+    function transferBatch(address[] calldata recipients, uint256[] calldata values)
+        external returns (bool);
+
     /**
      * @dev Event emited when a transfer has been performed.
      * Selector: ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef
@@ -108,6 +130,30 @@
         address indexed spender,
         uint256 value
     );
+
+    /**
+     * @dev Event emited when a transfer with memo has been performed.
+     * Selector: 57bc7354aa85aed339e000bccffabbc529466af35f0772c8f8ee1145927de7f0
+     * @param from address The address sending the tokens
+     * @param to address The address receiving the tokens.
+     * @param value uint256 The amount of tokens transfered.
+     * @param memo bytes32 The memo attached to the transfer.
+     */
+    event TransferWithMemo(
+        address indexed from,
+        address indexed to,
+        uint256 value,
+        bytes32 memo
+    );
+
+    /**
+     * @dev Event emited when a batch of transfers has been performed.
+     * Selector: 809ad27013b5353579d03e250cb0fe2502ae414a7247c34b357f7d9b5a75daa3
+     * @param from address The address sending the tokens
+     * @param recipients uint256 The number of recipients.
+     * @param total uint256 The total amount of tokens transfered.
+     */
+    event TransferBatch(address indexed from, uint256 recipients, uint256 total);
 }
 
     /**
@@ -137,5 +183,56 @@
      * Selector: 9dc29fac
      */
     function burn(address who, uint256 amount) external returns (bool);
+
+    /**
+     * @dev Registers the caller to be notified when it receives this asset
+     * through `transfer` or `transferFrom`.
+     * Notification is done by calling `IXc20Receiver.onXc20Received` on the caller.
+     * Selector: ed5b0bd0
+     */
+    function registerTransferHook() external returns (bool);
+
+    /**
+     * @dev Removes the transfer notification registration of the caller.
+     * Selector: c30489da
+     */
+    function unregisterTransferHook() external returns (bool);
+
+    /**
+     * @dev Returns whether the contract is registered for transfer notifications.
+     * Selector: 16738ba6
+     */
+    function hasTransferHook(address who) external view returns (bool);
+
+    /**
+     * @dev Returns the total supply at the end of a past block.
+     * Reverts if the supply history of the asset isn't recorded for that block.
+     * Selector: 22dc0c1e
+     * @param blockNumber uint32 Number of the past block.
+     */
+    function totalSupplyAt(uint32 blockNumber) external view returns (uint256);
+
+    /**
+     * @dev Returns the number of accounts holding the asset.
+     * Selector: 6b4ed21b
+     */
+    function holdersCount() external view returns (uint32);
+}
+
+    /**
+     * @title Receiver of XC20 transfer notifications
+     * @dev Implemented by contracts registered through `registerTransferHook`.
+     */
+    interface IXc20Receiver {
+
+    /**
+     * @dev Called by the asset precompile after the contract received assets.
+     * The call is limited to 50_000 gas and reverting it reverts the transfer.
+     * Selector: b11ee846
+     * @param operator address Account which initiated the transfer.
+     * @param from address Owner of the transferred assets.
+     * @param value uint256 Amount of assets received.
+     */
+    function onXc20Received(address operator, address from, uint256 value) external;
 }
 
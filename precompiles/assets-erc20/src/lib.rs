@@ -36,28 +36,35 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use fp_evm::{IsPrecompileResult, PrecompileHandle, PrecompileOutput};
+use fp_evm::{
+    Context, ExitReason, IsPrecompileResult, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+};
 use frame_support::traits::fungibles::approvals::Inspect as ApprovalInspect;
 use frame_support::traits::fungibles::metadata::Inspect as MetadataInspect;
 use frame_support::traits::fungibles::Inspect;
-use frame_support::traits::OriginTrait;
+use frame_support::traits::{Currency, OriginTrait};
 use frame_support::{
     dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
     sp_runtime::traits::StaticLookup,
+    storage_alias, Blake2_128Concat,
 };
 use pallet_evm::{AddressMapping, PrecompileSet};
 use precompile_utils::{
-    keccak256, succeed, Address, Bytes, EvmData, EvmDataWriter, EvmResult, FunctionModifier,
-    LogExt, LogsBuilder, PrecompileHandleExt, RuntimeHelper,
+    data::BoundedVec, keccak256, log_costs, revert, saturating_from_u256, succeed, Address, Bytes,
+    EvmData, EvmDataWriter, EvmResult, LogExt, LogsBuilder, PrecompileHandleExt, RuntimeHelper,
 };
-use sp_runtime::traits::{Bounded, Zero};
+use sp_runtime::traits::Zero;
 
-use sp_core::{Get, MaxEncodedLen, H160, U256};
+use parity_scale_codec::{Decode, Encode};
+use sp_core::{ConstU32, Get, MaxEncodedLen, H160, H256, U256};
 use sp_std::{
     convert::{TryFrom, TryInto},
     marker::PhantomData,
 };
 
+mod utility;
+pub use utility::{AssetsUtilityPrecompile, UtilityAction};
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -69,6 +76,22 @@ pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address
 /// Solidity selector of the Approval log, which is the Keccak of the Log signature.
 pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
 
+/// Solidity selector of the TransferWithMemo log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER_WITH_MEMO: [u8; 32] =
+    keccak256!("TransferWithMemo(address,address,uint256,bytes32)");
+
+/// Solidity selector of the TransferBatch log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER_BATCH: [u8; 32] =
+    keccak256!("TransferBatch(address,uint256,uint256)");
+
+/// Max number of recipients of a `transferBatch` call.
+pub const TRANSFER_BATCH_LIMIT: u32 = 2u32.pow(7);
+
+type GetTransferBatchLimit = ConstU32<TRANSFER_BATCH_LIMIT>;
+
+/// Gas limit of the subcall notifying a registered contract about a received transfer.
+pub const TRANSFER_HOOK_GAS_LIMIT: u64 = 50_000;
+
 /// Alias for the Balance type for the provided Runtime and Instance.
 pub type BalanceOf<Runtime, Instance = ()> = <Runtime as pallet_assets::Config<Instance>>::Balance;
 
@@ -78,18 +101,115 @@ pub type AssetIdOf<Runtime, Instance = ()> = <Runtime as pallet_assets::Config<I
 #[precompile_utils::generate_function_selector]
 #[derive(Debug, PartialEq)]
 pub enum Action {
+    #[view]
     TotalSupply = "totalSupply()",
+    #[view]
     BalanceOf = "balanceOf(address)",
+    #[view]
     Allowance = "allowance(address,address)",
     Transfer = "transfer(address,uint256)",
     Approve = "approve(address,uint256)",
     TransferFrom = "transferFrom(address,address,uint256)",
+    #[view]
     Name = "name()",
+    #[view]
     Symbol = "symbol()",
+    #[view]
     Decimals = "decimals()",
+    #[view]
     MinimumBalance = "minimumBalance()",
     Mint = "mint(address,uint256)",
     Burn = "burn(address,uint256)",
+    RegisterTransferHook = "registerTransferHook()",
+    UnregisterTransferHook = "unregisterTransferHook()",
+    #[view]
+    HasTransferHook = "hasTransferHook(address)",
+    #[view]
+    TotalSupplyAt = "totalSupplyAt(uint32)",
+    #[view]
+    HoldersCount = "holdersCount()",
+    TransferWithMemo = "transferWithMemo(address,uint256,bytes32)",
+    TransferBatch = "transferBatch(address[],uint256[])",
+}
+
+/// Functions called on the contracts registered for transfer notifications.
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum TransferHookAction {
+    OnXc20Received = "onXc20Received(address,address,uint256)",
+}
+
+/// Contracts which are notified when they receive assets through `transfer` or `transferFrom`.
+///
+/// Keyed by the asset precompile address and the registered contract address.
+#[storage_alias]
+pub type TransferHooks =
+    StorageDoubleMap<Erc20AssetsPrecompile, Blake2_128Concat, H160, Blake2_128Concat, H160, ()>;
+
+/// Layout of `pallet_assets::AssetDetails`, which doesn't expose its fields.
+///
+/// Only used to read the number of accounts holding the asset and whether it is sufficient.
+#[derive(Encode, Decode)]
+struct AssetDetailsLayout<Balance, AccountId, DepositBalance> {
+    owner: AccountId,
+    issuer: AccountId,
+    admin: AccountId,
+    freezer: AccountId,
+    supply: Balance,
+    deposit: DepositBalance,
+    min_balance: Balance,
+    is_sufficient: bool,
+    accounts: u32,
+    sufficients: u32,
+    approvals: u32,
+    status: u8,
+}
+
+type DepositBalanceOf<Runtime, Instance> =
+    <<Runtime as pallet_assets::Config<Instance>>::Currency as Currency<
+        <Runtime as frame_system::Config>::AccountId,
+    >>::Balance;
+
+/// `pallet_assets::Asset` storage, read with [`AssetDetailsLayout`] values.
+#[storage_alias]
+type Asset<T: pallet_assets::Config<I>, I: 'static> = StorageMap<
+    pallet_assets::Pallet<T, I>,
+    Blake2_128Concat,
+    AssetIdOf<T, I>,
+    AssetDetailsLayout<
+        BalanceOf<T, I>,
+        <T as frame_system::Config>::AccountId,
+        DepositBalanceOf<T, I>,
+    >,
+>;
+
+/// Whether the asset can be held by accounts without any native balance.
+/// Returns `false` if the asset doesn't exist.
+pub fn is_sufficient<Runtime, Instance>(asset_id: AssetIdOf<Runtime, Instance>) -> bool
+where
+    Runtime: pallet_assets::Config<Instance>,
+    Instance: 'static,
+{
+    Asset::<Runtime, Instance>::get(asset_id).map_or(false, |details| details.is_sufficient)
+}
+
+/// Provides total supply of assets at past blocks.
+pub trait TotalSupplyHistory<AssetId, Balance> {
+    /// Total supply of the asset at the end of the given block, if known.
+    fn total_supply_at(asset_id: AssetId, block_number: u32) -> Option<Balance>;
+
+    /// Maximum encoded length of the history read by `total_supply_at`.
+    fn max_history_len() -> usize;
+}
+
+impl<AssetId, Balance> TotalSupplyHistory<AssetId, Balance> for () {
+    fn total_supply_at(_asset_id: AssetId, _block_number: u32) -> Option<Balance> {
+        None
+    }
+
+    fn max_history_len() -> usize {
+        0
+    }
 }
 
 /// This trait ensure we can convert EVM address to AssetIds
@@ -116,17 +236,20 @@ pub trait AddressToAssetId<AssetId> {
 
 /// This means that every address that starts with 0xFFFFFFFF will go through an additional db read,
 /// but the probability for this to happen is 2^-32 for random addresses
-pub struct Erc20AssetsPrecompileSet<Runtime, Instance: 'static = ()>(
-    PhantomData<(Runtime, Instance)>,
+///
+/// `History` provides the total supply history, `()` if it isn't available.
+pub struct Erc20AssetsPrecompileSet<Runtime, Instance: 'static = (), History = ()>(
+    PhantomData<(Runtime, Instance, History)>,
 );
 
-impl<Runtime, Instance> Erc20AssetsPrecompileSet<Runtime, Instance> {
+impl<Runtime, Instance, History> Erc20AssetsPrecompileSet<Runtime, Instance, History> {
     pub fn new() -> Self {
         Self(PhantomData)
     }
 }
 
-impl<Runtime, Instance> PrecompileSet for Erc20AssetsPrecompileSet<Runtime, Instance>
+impl<Runtime, Instance, History> PrecompileSet
+    for Erc20AssetsPrecompileSet<Runtime, Instance, History>
 where
     Instance: 'static,
     Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + frame_system::Config,
@@ -136,6 +259,7 @@ where
     BalanceOf<Runtime, Instance>: TryFrom<U256> + Into<U256> + EvmData,
     Runtime: AddressToAssetId<AssetIdOf<Runtime, Instance>>,
     <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
+    History: TotalSupplyHistory<AssetIdOf<Runtime, Instance>, BalanceOf<Runtime, Instance>>,
 {
     fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<EvmResult<PrecompileOutput>> {
         let address = handle.code_address();
@@ -152,14 +276,7 @@ where
                         Err(e) => return Some(Err(e)),
                     };
 
-                    if let Err(err) = handle.check_function_modifier(match selector {
-                        Action::Approve
-                        | Action::Transfer
-                        | Action::TransferFrom
-                        | Action::Mint
-                        | Action::Burn => FunctionModifier::NonPayable,
-                        _ => FunctionModifier::View,
-                    }) {
+                    if let Err(err) = handle.check_function_modifier(selector.modifier()) {
                         return Some(Err(err));
                     }
 
@@ -170,6 +287,8 @@ where
                         Action::Allowance => Self::allowance(asset_id, handle),
                         Action::Approve => Self::approve(asset_id, handle),
                         Action::Transfer => Self::transfer(asset_id, handle),
+                        Action::TransferWithMemo => Self::transfer_with_memo(asset_id, handle),
+                        Action::TransferBatch => Self::transfer_batch(asset_id, handle),
                         Action::TransferFrom => Self::transfer_from(asset_id, handle),
                         Action::Name => Self::name(asset_id, handle),
                         Action::Symbol => Self::symbol(asset_id, handle),
@@ -178,6 +297,13 @@ where
                         Action::MinimumBalance => Self::minimum_balance(asset_id, handle),
                         Action::Mint => Self::mint(asset_id, handle),
                         Action::Burn => Self::burn(asset_id, handle),
+                        // Transfer hooks
+                        Action::RegisterTransferHook => Self::register_transfer_hook(handle),
+                        Action::UnregisterTransferHook => Self::unregister_transfer_hook(handle),
+                        Action::HasTransferHook => Self::has_transfer_hook(handle),
+                        // Checkpoints
+                        Action::TotalSupplyAt => Self::total_supply_at(asset_id, handle),
+                        Action::HoldersCount => Self::holders_count(asset_id, handle),
                     }
                 };
                 return Some(result);
@@ -207,7 +333,7 @@ where
     }
 }
 
-impl<Runtime, Instance> Erc20AssetsPrecompileSet<Runtime, Instance>
+impl<Runtime, Instance, History> Erc20AssetsPrecompileSet<Runtime, Instance, History>
 where
     Instance: 'static,
     Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + frame_system::Config,
@@ -217,6 +343,7 @@ where
     BalanceOf<Runtime, Instance>: TryFrom<U256> + Into<U256> + EvmData,
     Runtime: AddressToAssetId<AssetIdOf<Runtime, Instance>>,
     <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
+    History: TotalSupplyHistory<AssetIdOf<Runtime, Instance>, BalanceOf<Runtime, Instance>>,
 {
     fn total_supply(
         asset_id: AssetIdOf<Runtime, Instance>,
@@ -234,6 +361,40 @@ where
         Ok(succeed(EvmDataWriter::new().write(amount).build()))
     }
 
+    fn total_supply_at(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<PrecompileOutput> {
+        handle.record_db_read::<Runtime>(History::max_history_len())?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+
+        let block_number: u32 = input.read()?;
+
+        let amount: U256 = match History::total_supply_at(asset_id, block_number) {
+            Some(amount) => amount.into(),
+            None => return Err(revert("total supply at the block is unknown")),
+        };
+
+        Ok(succeed(EvmDataWriter::new().write(amount).build()))
+    }
+
+    fn holders_count(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<PrecompileOutput> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: Asset:
+        // Blake2_128(16) + AssetId(16) + AssetDetails((4 * AccountId(32)) + (3 * Balance(16)) + 15)
+        handle.record_db_read::<Runtime>(223)?;
+
+        let holders =
+            Asset::<Runtime, Instance>::get(asset_id).map_or(0, |details| details.accounts);
+
+        Ok(succeed(EvmDataWriter::new().write(holders).build()))
+    }
+
     fn balance_of(
         asset_id: AssetIdOf<Runtime, Instance>,
         handle: &mut impl PrecompileHandle,
@@ -302,8 +463,7 @@ where
             let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
             let spender: Runtime::AccountId = Runtime::AddressMapping::into_account_id(spender);
             // Amount saturate if too high.
-            let amount: BalanceOf<Runtime, Instance> =
-                amount.try_into().unwrap_or_else(|_| Bounded::max_value());
+            let amount: BalanceOf<Runtime, Instance> = saturating_from_u256(amount);
 
             // Allowance read
             handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
@@ -360,6 +520,93 @@ where
         let to: H160 = input.read::<Address>()?.into();
         let amount = input.read::<BalanceOf<Runtime, Instance>>()?;
 
+        Self::do_transfer(asset_id, handle, to, amount)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    /// Transfer with a memo, e.g. a deposit tag, which is included in the `TransferWithMemo` log.
+    ///
+    /// Logs are also deposited by `pallet-evm` as `Log` events, so the memo can be read on
+    /// the Substrate side too.
+    fn transfer_with_memo(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<PrecompileOutput> {
+        handle.record_log_costs_manual(3, 32)?;
+        handle.record_log_costs_manual(3, 64)?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+
+        let to: H160 = input.read::<Address>()?.into();
+        let amount = input.read::<BalanceOf<Runtime, Instance>>()?;
+        let memo = input.read::<H256>()?;
+
+        Self::do_transfer(asset_id, handle, to, amount)?;
+
+        LogsBuilder::new(handle.context().address)
+            .log3(
+                SELECTOR_LOG_TRANSFER_WITH_MEMO,
+                handle.context().caller,
+                to,
+                EvmDataWriter::new().write(amount).write(memo).build(),
+            )
+            .record(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    /// Transfer to several recipients at once, e.g. for payrolls or airdrops.
+    ///
+    /// Each transfer records its own `Transfer` log and notifies the recipient's transfer hook,
+    /// and a single `TransferBatch` log sums them up. If any transfer fails, all are reverted.
+    fn transfer_batch(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(2)?;
+
+        let recipients = input.read::<BoundedVec<Address, GetTransferBatchLimit>>()?;
+        let amounts =
+            input.read::<BoundedVec<BalanceOf<Runtime, Instance>, GetTransferBatchLimit>>()?;
+        if recipients.len() != amounts.len() {
+            return Err(revert("recipients and amounts length mismatch"));
+        }
+
+        let count = recipients.len();
+        handle.record_cost(log_costs(3, 32)?.saturating_mul(count as u64))?;
+        handle.record_log_costs_manual(2, 64)?;
+
+        let mut total = U256::zero();
+        for (to, amount) in recipients.into_iter().zip(amounts) {
+            total = total.saturating_add(amount.into());
+            Self::do_transfer(asset_id.clone(), handle, to.into(), amount)?;
+        }
+
+        LogsBuilder::new(handle.context().address)
+            .log2(
+                SELECTOR_LOG_TRANSFER_BATCH,
+                handle.context().caller,
+                EvmDataWriter::new()
+                    .write(U256::from(count))
+                    .write(total)
+                    .build(),
+            )
+            .record(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    /// Transfer `amount` from the caller to `to`, record the `Transfer` log and notify
+    /// the recipient's transfer hook.
+    fn do_transfer(
+        asset_id: AssetIdOf<Runtime, Instance>,
+        handle: &mut impl PrecompileHandle,
+        to: H160,
+        amount: BalanceOf<Runtime, Instance>,
+    ) -> EvmResult {
         // Build call with origin.
         {
             let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
@@ -386,7 +633,8 @@ where
             )
             .record(handle)?;
 
-        Ok(succeed(EvmDataWriter::new().write(true).build()))
+        let caller = handle.context().caller;
+        Self::notify_transfer_hook(handle, caller, to, amount.into())
     }
 
     fn transfer_from(
@@ -444,6 +692,8 @@ where
             )
             .record(handle)?;
 
+        Self::notify_transfer_hook(handle, from, to, amount.into())?;
+
         Ok(succeed(EvmDataWriter::new().write(true).build()))
     }
 
@@ -584,4 +834,85 @@ where
 
         Ok(succeed(EvmDataWriter::new().write(true).build()))
     }
+
+    fn register_transfer_hook(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        // Storage item: TransferHooks
+        handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+        TransferHooks::insert(handle.context().address, handle.context().caller, ());
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    fn unregister_transfer_hook(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        // Storage item: TransferHooks
+        handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+        TransferHooks::remove(handle.context().address, handle.context().caller);
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    fn has_transfer_hook(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        // Storage item: TransferHooks:
+        // Twox128(16) * 2 + 2 * (Blake2_128(16) + H160(20))
+        handle.record_db_read::<Runtime>(104)?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+
+        let contract: H160 = input.read::<Address>()?.into();
+        let registered = TransferHooks::contains_key(handle.context().address, contract);
+
+        Ok(succeed(EvmDataWriter::new().write(registered).build()))
+    }
+
+    /// Notify the recipient about the received transfer, if it has registered a transfer hook.
+    ///
+    /// The subcall is bounded by `TRANSFER_HOOK_GAS_LIMIT` and a failing hook reverts the transfer.
+    fn notify_transfer_hook(
+        handle: &mut impl PrecompileHandle,
+        from: H160,
+        to: H160,
+        amount: U256,
+    ) -> EvmResult {
+        // Storage item: TransferHooks:
+        // Twox128(16) * 2 + 2 * (Blake2_128(16) + H160(20))
+        handle.record_db_read::<Runtime>(104)?;
+
+        let asset = handle.context().address;
+        if !TransferHooks::contains_key(asset, to) {
+            return Ok(());
+        }
+
+        let sub_context = Context {
+            caller: asset,
+            address: to,
+            apparent_value: U256::zero(),
+        };
+        let input = EvmDataWriter::new_with_selector(TransferHookAction::OnXc20Received)
+            .write(Address(handle.context().caller))
+            .write(Address(from))
+            .write(amount)
+            .build();
+
+        let (reason, output) = handle.call(
+            to,
+            None,
+            input,
+            Some(TRANSFER_HOOK_GAS_LIMIT),
+            false,
+            &sub_context,
+        );
+
+        match reason {
+            ExitReason::Succeed(_) => Ok(()),
+            ExitReason::Revert(exit_status) => Err(PrecompileFailure::Revert {
+                exit_status,
+                output,
+            }),
+            ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
+            ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
+        }
+    }
 }
@@ -44,6 +44,7 @@ use frame_support::{
 };
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 
+use fp_evm::{Precompile, PrecompileResult};
 use frame_system::EnsureRoot;
 use pallet_evm::{AddressMapping, EnsureAddressNever, EnsureAddressRoot};
 use scale_info::TypeInfo;
@@ -225,8 +226,62 @@ impl pallet_balances::Config for Runtime {
 }
 
 parameter_types! {
-    pub const PrecompilesValue: Erc20AssetsPrecompileSet<Runtime> =
-        Erc20AssetsPrecompileSet(PhantomData);
+    /// Supply history served to the precompile, as `(asset_id, block_number, supply)`.
+    pub static SupplySnapshots: Vec<(AssetId, u32, Balance)> = vec![];
+}
+
+pub struct MockSupplyHistory;
+
+impl TotalSupplyHistory<AssetId, Balance> for MockSupplyHistory {
+    fn total_supply_at(asset_id: AssetId, block_number: u32) -> Option<Balance> {
+        SupplySnapshots::get()
+            .into_iter()
+            .find(|(id, at, _)| *id == asset_id && *at == block_number)
+            .map(|(_, _, supply)| supply)
+    }
+
+    fn max_history_len() -> usize {
+        0
+    }
+}
+
+pub fn utility_precompile_address() -> H160 {
+    H160::from_low_u64_be(0x500C)
+}
+
+/// The asset precompiles together with the assets utility precompile.
+#[derive(Debug, Clone, Copy)]
+pub struct MockPrecompileSet<R>(PhantomData<R>);
+
+impl<R> PrecompileSet for MockPrecompileSet<R>
+where
+    Erc20AssetsPrecompileSet<R, (), MockSupplyHistory>: PrecompileSet,
+    AssetsUtilityPrecompile<R>: Precompile,
+{
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        match handle.code_address() {
+            a if a == utility_precompile_address() => {
+                Some(AssetsUtilityPrecompile::<R>::execute(handle))
+            }
+            _ => Erc20AssetsPrecompileSet::<R, (), MockSupplyHistory>::new().execute(handle),
+        }
+    }
+
+    fn is_precompile(&self, address: H160, gas: u64) -> IsPrecompileResult {
+        if address == utility_precompile_address() {
+            return IsPrecompileResult::Answer {
+                is_precompile: true,
+                extra_cost: 0,
+            };
+        }
+        Erc20AssetsPrecompileSet::<R, (), MockSupplyHistory>::new().is_precompile(address, gas)
+    }
+}
+
+pub type Precompiles = MockPrecompileSet<Runtime>;
+
+parameter_types! {
+    pub const PrecompilesValue: Precompiles = MockPrecompileSet(PhantomData);
     pub WeightPerGas: Weight = Weight::from_parts(1, 0);
 }
 
@@ -240,7 +295,7 @@ impl pallet_evm::Config for Runtime {
     type Currency = Balances;
     type RuntimeEvent = RuntimeEvent;
     type Runner = pallet_evm::runner::stack::Runner<Self>;
-    type PrecompilesType = Erc20AssetsPrecompileSet<Self>;
+    type PrecompilesType = Precompiles;
     type PrecompilesValue = PrecompilesValue;
     type Timestamp = Timestamp;
     type ChainId = ();
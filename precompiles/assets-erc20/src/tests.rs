@@ -39,10 +39,11 @@ use std::str::from_utf8;
 use crate::mock::*;
 use crate::*;
 
+use fp_evm::ExitError;
 use precompile_utils::{testing::*, EvmDataWriter, LogsBuilder};
 use sha3::{Digest, Keccak256};
 
-fn precompiles() -> Erc20AssetsPrecompileSet<Runtime> {
+fn precompiles() -> Precompiles {
     PrecompilesValue::get()
 }
 
@@ -98,6 +99,13 @@ fn selectors() {
     assert_eq!(Action::MinimumBalance as u32, 0xb9d1d49b);
     assert_eq!(Action::Mint as u32, 0x40c10f19);
     assert_eq!(Action::Burn as u32, 0x9dc29fac);
+    assert_eq!(Action::RegisterTransferHook as u32, 0xed5b0bd0);
+    assert_eq!(Action::UnregisterTransferHook as u32, 0xc30489da);
+    assert_eq!(Action::HasTransferHook as u32, 0x16738ba6);
+    assert_eq!(Action::TotalSupplyAt as u32, 0x22dc0c1e);
+    assert_eq!(Action::HoldersCount as u32, 0x6b4ed21b);
+    assert_eq!(Action::TransferWithMemo as u32, 0x95777d59);
+    assert_eq!(TransferHookAction::OnXc20Received as u32, 0xb11ee846);
 
     assert_eq!(
         crate::SELECTOR_LOG_TRANSFER,
@@ -108,6 +116,11 @@ fn selectors() {
         crate::SELECTOR_LOG_APPROVAL,
         &Keccak256::digest(b"Approval(address,address,uint256)")[..]
     );
+
+    assert_eq!(
+        crate::SELECTOR_LOG_TRANSFER_WITH_MEMO,
+        &Keccak256::digest(b"TransferWithMemo(address,address,uint256,bytes32)")[..]
+    );
 }
 
 #[test]
@@ -433,6 +446,61 @@ fn transfer() {
         });
 }
 
+#[test]
+fn transfer_with_memo() {
+    ExtBuilder::default()
+        .with_balances(vec![(Account::Alice, 1000)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(Assets::force_create(
+                RuntimeOrigin::root(),
+                0u128,
+                Account::Alice.into(),
+                true,
+                1
+            ));
+            assert_ok!(Assets::mint(
+                RuntimeOrigin::signed(Account::Alice),
+                0u128,
+                Account::Alice.into(),
+                1000
+            ));
+
+            let memo = H256::repeat_byte(0x42);
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::TransferWithMemo)
+                        .write(Address(Account::Bob.into()))
+                        .write(U256::from(400))
+                        .write(memo)
+                        .build(),
+                )
+                .expect_log(LogsBuilder::new(Account::AssetId(0u128).into()).log3(
+                    SELECTOR_LOG_TRANSFER,
+                    Account::Alice,
+                    Account::Bob,
+                    EvmDataWriter::new().write(U256::from(400)).build(),
+                ))
+                .expect_log(
+                    LogsBuilder::new(Account::AssetId(0u128).into()).log3(
+                        SELECTOR_LOG_TRANSFER_WITH_MEMO,
+                        Account::Alice,
+                        Account::Bob,
+                        EvmDataWriter::new()
+                            .write(U256::from(400))
+                            .write(memo)
+                            .build(),
+                    ),
+                )
+                .execute_returns(EvmDataWriter::new().write(true).build());
+
+            assert_eq!(Assets::balance(0u128, Account::Bob), 400);
+            assert_eq!(Assets::balance(0u128, Account::Alice), 600);
+        });
+}
+
 #[test]
 fn transfer_not_enough_founds() {
     ExtBuilder::default()
@@ -462,12 +530,128 @@ fn transfer_not_enough_founds() {
                         .write(U256::from(50))
                         .build(),
                 )
-                .execute_reverts(|output| {
-                    from_utf8(&output)
-                        .unwrap()
-                        .contains("Dispatched call failed with error: DispatchErrorWithPostInfo")
-                        && from_utf8(&output).unwrap().contains("BalanceLow")
-                });
+                .execute_reverts_with(DispatchFailed("BalanceLow"));
+        });
+}
+
+#[test]
+fn transfer_batch() {
+    ExtBuilder::default()
+        .with_balances(vec![(Account::Alice, 1000)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(Assets::force_create(
+                RuntimeOrigin::root(),
+                0u128,
+                Account::Alice.into(),
+                true,
+                1
+            ));
+            assert_ok!(Assets::mint(
+                RuntimeOrigin::signed(Account::Alice),
+                0u128,
+                Account::Alice.into(),
+                1000
+            ));
+
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::TransferBatch)
+                        .write(vec![
+                            Address(Account::Bob.into()),
+                            Address(Account::Charlie.into()),
+                        ])
+                        .write(vec![U256::from(400), U256::from(100)])
+                        .build(),
+                )
+                .expect_log(LogsBuilder::new(Account::AssetId(0u128).into()).log3(
+                    SELECTOR_LOG_TRANSFER,
+                    Account::Alice,
+                    Account::Bob,
+                    EvmDataWriter::new().write(U256::from(400)).build(),
+                ))
+                .expect_log(LogsBuilder::new(Account::AssetId(0u128).into()).log3(
+                    SELECTOR_LOG_TRANSFER,
+                    Account::Alice,
+                    Account::Charlie,
+                    EvmDataWriter::new().write(U256::from(100)).build(),
+                ))
+                .expect_log(
+                    LogsBuilder::new(Account::AssetId(0u128).into()).log2(
+                        SELECTOR_LOG_TRANSFER_BATCH,
+                        Account::Alice,
+                        EvmDataWriter::new()
+                            .write(U256::from(2))
+                            .write(U256::from(500))
+                            .build(),
+                    ),
+                )
+                .execute_returns(EvmDataWriter::new().write(true).build());
+
+            assert_eq!(Assets::balance(0u128, Account::Alice), 500);
+            assert_eq!(Assets::balance(0u128, Account::Bob), 400);
+            assert_eq!(Assets::balance(0u128, Account::Charlie), 100);
+        });
+}
+
+#[test]
+fn transfer_batch_reverts() {
+    ExtBuilder::default()
+        .with_balances(vec![(Account::Alice, 1000)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(Assets::force_create(
+                RuntimeOrigin::root(),
+                0u128,
+                Account::Alice.into(),
+                true,
+                1
+            ));
+            assert_ok!(Assets::mint(
+                RuntimeOrigin::signed(Account::Alice),
+                0u128,
+                Account::Alice.into(),
+                1000
+            ));
+
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::TransferBatch)
+                        .write(vec![Address(Account::Bob.into())])
+                        .write(vec![U256::from(400), U256::from(100)])
+                        .build(),
+                )
+                .execute_reverts(|output| output == b"recipients and amounts length mismatch");
+
+            let too_many = vec![Address(Account::Bob.into()); TRANSFER_BATCH_LIMIT as usize + 1];
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::TransferBatch)
+                        .write(too_many)
+                        .write(vec![U256::from(1); TRANSFER_BATCH_LIMIT as usize + 1])
+                        .build(),
+                )
+                .execute_reverts_with(RevertContains("more than max items"));
+
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::TransferBatch)
+                        .write(vec![
+                            Address(Account::Bob.into()),
+                            Address(Account::Charlie.into()),
+                        ])
+                        .write(vec![U256::from(400), U256::from(1000)])
+                        .build(),
+                )
+                .execute_reverts_with(DispatchFailed("BalanceLow"));
         });
 }
 
@@ -639,13 +823,7 @@ fn transfer_from_non_incremental_approval() {
                         .write(U256::from(500))
                         .build(),
                 )
-                .execute_reverts(|output| {
-                    output
-                        == b"Dispatched call failed with error: DispatchErrorWithPostInfo { \
-					post_info: PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes }, \
-					error: Module(ModuleError { index: 2, error: [10, 0, 0, 0], \
-					message: Some(\"Unapproved\") }) }"
-                });
+                .execute_reverts_with(DispatchFailed("Unapproved"));
         });
 }
 
@@ -690,13 +868,7 @@ fn transfer_from_above_allowance() {
                         .write(U256::from(400))
                         .build(),
                 )
-                .execute_reverts(|output| {
-                    output
-                        == b"Dispatched call failed with error: DispatchErrorWithPostInfo { \
-					post_info: PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes }, \
-					error: Module(ModuleError { index: 2, error: [10, 0, 0, 0], \
-					message: Some(\"Unapproved\") }) }"
-                });
+                .execute_reverts_with(DispatchFailed("Unapproved"));
         });
 }
 
@@ -981,3 +1153,295 @@ fn burn_non_admin_is_not_ok() {
             .execute_reverts(|output| from_utf8(&output).unwrap().contains("NoPermission"));
     });
 }
+
+#[test]
+fn register_transfer_hook_is_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Assets::force_create(
+            RuntimeOrigin::root(),
+            0u128,
+            Account::Alice.into(),
+            true,
+            1
+        ));
+
+        let check_registered = |registered: bool| {
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::HasTransferHook)
+                        .write(Address(Account::Bob.into()))
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(registered).build());
+        };
+        check_registered(false);
+
+        precompiles()
+            .prepare_test(
+                Account::Bob,
+                Account::AssetId(0u128),
+                EvmDataWriter::new_with_selector(Action::RegisterTransferHook).build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(true).build());
+        check_registered(true);
+
+        precompiles()
+            .prepare_test(
+                Account::Bob,
+                Account::AssetId(0u128),
+                EvmDataWriter::new_with_selector(Action::UnregisterTransferHook).build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(true).build());
+        check_registered(false);
+    });
+}
+
+#[test]
+fn transfer_notifies_registered_receiver() {
+    ExtBuilder::default()
+        .with_balances(vec![(Account::Alice, 1000)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(Assets::force_create(
+                RuntimeOrigin::root(),
+                0u128,
+                Account::Alice.into(),
+                true,
+                1
+            ));
+            assert_ok!(Assets::mint(
+                RuntimeOrigin::signed(Account::Alice),
+                0u128,
+                Account::Alice.into(),
+                1000
+            ));
+            TransferHooks::insert(
+                H160::from(Account::AssetId(0u128)),
+                H160::from(Account::Bob),
+                (),
+            );
+
+            let mut notified = false;
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::Transfer)
+                        .write(Address(Account::Bob.into()))
+                        .write(U256::from(400))
+                        .build(),
+                )
+                .with_subcall_handle(move |subcall| {
+                    assert!(!notified, "receiver is notified only once");
+                    notified = true;
+
+                    assert_eq!(subcall.address, Account::Bob.into());
+                    assert_eq!(subcall.context.caller, Account::AssetId(0u128).into());
+                    assert_eq!(subcall.target_gas, Some(TRANSFER_HOOK_GAS_LIMIT));
+                    assert!(subcall.transfer.is_none());
+                    assert!(!subcall.is_static);
+                    assert_eq!(
+                        subcall.input,
+                        EvmDataWriter::new_with_selector(TransferHookAction::OnXc20Received)
+                            .write(Address(Account::Alice.into()))
+                            .write(Address(Account::Alice.into()))
+                            .write(U256::from(400))
+                            .build()
+                    );
+
+                    SubcallOutput::succeed()
+                })
+                .expect_log(LogsBuilder::new(Account::AssetId(0u128).into()).log3(
+                    SELECTOR_LOG_TRANSFER,
+                    Account::Alice,
+                    Account::Bob,
+                    EvmDataWriter::new().write(U256::from(400)).build(),
+                ))
+                .execute_returns(EvmDataWriter::new().write(true).build());
+
+            assert_eq!(Assets::balance(0u128, &Account::Bob.into()), 400);
+        });
+}
+
+#[test]
+fn transfer_reverts_if_receiver_hook_fails() {
+    ExtBuilder::default()
+        .with_balances(vec![(Account::Alice, 1000)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(Assets::force_create(
+                RuntimeOrigin::root(),
+                0u128,
+                Account::Alice.into(),
+                true,
+                1
+            ));
+            assert_ok!(Assets::mint(
+                RuntimeOrigin::signed(Account::Alice),
+                0u128,
+                Account::Alice.into(),
+                1000
+            ));
+            TransferHooks::insert(
+                H160::from(Account::AssetId(0u128)),
+                H160::from(Account::Bob),
+                (),
+            );
+
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::Transfer)
+                        .write(Address(Account::Bob.into()))
+                        .write(U256::from(400))
+                        .build(),
+                )
+                .with_subcall_handle(|_| SubcallOutput::revert())
+                .execute_reverts(|output| output.is_empty());
+
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::Transfer)
+                        .write(Address(Account::Bob.into()))
+                        .write(U256::from(400))
+                        .build(),
+                )
+                .with_subcall_handle(|_| SubcallOutput::out_of_gas())
+                .execute_error(ExitError::OutOfGas);
+        });
+}
+
+#[test]
+fn total_supply_at_works() {
+    ExtBuilder::default()
+        .with_balances(vec![(Account::Alice, 1000)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(Assets::force_create(
+                RuntimeOrigin::root(),
+                0u128,
+                Account::Alice.into(),
+                true,
+                1
+            ));
+            assert_ok!(Assets::mint(
+                RuntimeOrigin::signed(Account::Alice),
+                0u128,
+                Account::Alice.into(),
+                1000
+            ));
+            SupplySnapshots::set(vec![(0u128, 5, 700)]);
+
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::TotalSupplyAt)
+                        .write(5u32)
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(U256::from(700)).build());
+
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::TotalSupplyAt)
+                        .write(6u32)
+                        .build(),
+                )
+                .execute_reverts(|output| output == b"total supply at the block is unknown");
+        });
+}
+
+#[test]
+fn holders_count_works() {
+    ExtBuilder::default()
+        .with_balances(vec![(Account::Alice, 1000)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(Assets::force_create(
+                RuntimeOrigin::root(),
+                0u128,
+                Account::Alice.into(),
+                true,
+                1
+            ));
+            assert_ok!(Assets::mint(
+                RuntimeOrigin::signed(Account::Alice),
+                0u128,
+                Account::Alice.into(),
+                1000
+            ));
+            assert_ok!(Assets::mint(
+                RuntimeOrigin::signed(Account::Alice),
+                0u128,
+                Account::Bob.into(),
+                1000
+            ));
+
+            precompiles()
+                .prepare_test(
+                    Account::Alice,
+                    Account::AssetId(0u128),
+                    EvmDataWriter::new_with_selector(Action::HoldersCount).build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(2u32).build());
+        });
+}
+
+#[test]
+fn asset_id_and_token_roundtrip() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Account::Alice,
+                utility_precompile_address(),
+                EvmDataWriter::new_with_selector(UtilityAction::TokenOf)
+                    .write(7u128)
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(Address(Account::AssetId(7u128).into()))
+                    .build(),
+            );
+
+        precompiles()
+            .prepare_test(
+                Account::Alice,
+                utility_precompile_address(),
+                EvmDataWriter::new_with_selector(UtilityAction::AssetIdOf)
+                    .write(Address(Account::AssetId(7u128).into()))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(7u128).build());
+    });
+}
+
+#[test]
+fn asset_id_of_non_asset_address_reverts() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Account::Alice,
+                utility_precompile_address(),
+                EvmDataWriter::new_with_selector(UtilityAction::AssetIdOf)
+                    .write(Address(Account::Bob.into()))
+                    .build(),
+            )
+            .execute_reverts(|output| output == b"not an asset address");
+    });
+}
@@ -0,0 +1,56 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! Registry of asset precompile addresses.
+//!
+//! Maps `pallet-assets` ids to the addresses of their ERC20 precompiles and back, so contracts
+//! don't need to re-implement the address derivation.
+//! Only the derivation is checked, the asset doesn't need to exist.
+
+use fp_evm::PrecompileHandle;
+use precompile_utils::{revert, Address, EvmResult};
+use sp_std::marker::PhantomData;
+
+use crate::{AddressToAssetId, AssetIdOf};
+
+/// A precompile mapping asset ids to their precompile addresses and back.
+pub struct AssetsUtilityPrecompile<R, I = ()>(PhantomData<(R, I)>);
+
+#[precompile_utils::precompile(UtilityAction, R)]
+impl<R, I> AssetsUtilityPrecompile<R, I>
+where
+    I: 'static,
+    R: pallet_evm::Config + pallet_assets::Config<I> + AddressToAssetId<AssetIdOf<R, I>>,
+    AssetIdOf<R, I>: TryFrom<u128> + Into<u128>,
+{
+    #[precompile::public("assetIdOf(address)")]
+    #[precompile::view]
+    fn asset_id_of(_handle: &mut impl PrecompileHandle, token: Address) -> EvmResult<u128> {
+        let asset_id =
+            R::address_to_asset_id(token.into()).ok_or_else(|| revert("not an asset address"))?;
+        Ok(asset_id.into())
+    }
+
+    #[precompile::public("tokenOf(uint128)")]
+    #[precompile::view]
+    fn token_of(_handle: &mut impl PrecompileHandle, asset_id: u128) -> EvmResult<Address> {
+        let asset_id: AssetIdOf<R, I> = asset_id
+            .try_into()
+            .map_err(|_| revert("asset id out of range"))?;
+        Ok(Address(R::asset_id_to_address(asset_id)))
+    }
+}
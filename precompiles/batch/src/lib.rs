@@ -44,7 +44,7 @@ use frame_support::{
 use pallet_evm::{Precompile, PrecompileOutput};
 use precompile_utils::{bytes::BoundedBytes, data::BoundedVec, *};
 use sp_core::{H160, U256};
-use sp_std::{iter::repeat, marker::PhantomData, vec, vec::Vec};
+use sp_std::{iter::repeat, marker::PhantomData, vec};
 
 #[cfg(test)]
 mod mock;
@@ -167,16 +167,13 @@ where
         call_data: BoundedVec<BoundedBytes<GetCallDataLimit>, GetArrayLimit>,
         gas_limit: BoundedVec<u64, GetArrayLimit>,
     ) -> EvmResult<PrecompileOutput> {
-        let addresses = Vec::from(to).into_iter().enumerate();
-        let values = Vec::from(value)
-            .into_iter()
-            .map(|x| Some(x))
-            .chain(repeat(None));
-        let calls_data = Vec::from(call_data)
+        let addresses = to.into_iter().enumerate();
+        let values = value.into_iter().map(|x| Some(x)).chain(repeat(None));
+        let calls_data = call_data
             .into_iter()
             .map(|x| Some(x.into()))
             .chain(repeat(None));
-        let gas_limits = Vec::from(gas_limit).into_iter().map(|x|
+        let gas_limits = gas_limit.into_iter().map(|x|
 			// x = 0 => forward all remaining gas
 			if x == 0 {
 				None
@@ -636,3 +636,37 @@ fn batch_some_until_failure_gas_limit() {
             .execute_returns(EvmDataWriter::new().write(true).build());
     })
 }
+
+#[test]
+fn batch_some_until_failure_stops_at_revert() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                EvmDataWriter::new_with_selector(Action::BatchSomeUntilFailure)
+                    .write(vec![
+                        Address(Bob.into()),
+                        Address(Charlie.into()),
+                        Address(David.into()),
+                    ])
+                    .write(vec![U256::from(1u8), U256::from(2u8), U256::from(3u8)])
+                    .write(vec![
+                        Bytes::from(&b"one"[..]),
+                        Bytes::from(&b"two"[..]),
+                        Bytes::from(&b"three"[..]),
+                    ])
+                    .write::<std::vec::Vec<U256>>(vec![])
+                    .build(),
+            )
+            .with_subcall_outputs(vec![
+                SubcallOutput::returns(b"ok".to_vec()).with_cost(13),
+                SubcallOutput::revert(),
+            ])
+            .expect_subcall(ExpectedSubcall::new(Bob, b"one".to_vec()).with_value(1u8))
+            .expect_subcall(ExpectedSubcall::new(Charlie, b"two".to_vec()).with_value(2u8))
+            .expect_log(log_subcall_succeeded(precompile_address(), 0))
+            .expect_log(log_subcall_failed(precompile_address(), 1))
+            .execute_returns_encoded(true);
+    })
+}
@@ -50,11 +50,25 @@ interface DappsStaking {
     function register(address) external;
 
     /// @notice Stake provided amount on the contract.
+    ///         Reverts with "stake cap exceeded" if the contract's stake cap would be exceeded.
     function bond_and_stake(address, uint128) external;
 
+    /// @notice Stake provided amount on the contract, tagged with a memo.
+    ///         Intended for liquid staking protocols to correlate stakes with their share accounting.
+    /// @param smart_contract: The smart contract address used for staking
+    /// @param value: The amount to stake
+    /// @param memo: The memo included in the emitted event
+    function bond_and_stake_with_memo(address smart_contract, uint128 value, bytes32 memo) external;
+
     /// @notice Start unbonding process and unstake balance from the contract.
     function unbond_and_unstake(address, uint128) external;
 
+    /// @notice Start unbonding process and unstake balance from the contract, tagged with a memo.
+    /// @param smart_contract: The smart contract address used for staking
+    /// @param value: The amount to unstake
+    /// @param memo: The memo included in the emitted event
+    function unbond_and_unstake_with_memo(address smart_contract, uint128 value, bytes32 memo) external;
+
     /// @notice Withdraw all funds that have completed the unbonding process.
     function withdraw_unbonded() external;
 
@@ -83,6 +97,7 @@ interface DappsStaking {
     function withdraw_from_unregistered(address smart_contract) external;
 
     /// @notice Transfer part or entire nomination from origin smart contract to target smart contract
+    ///         Reverts with "stake cap exceeded" if the target contract's stake cap would be exceeded.
     /// @param origin_smart_contract: The origin smart contract address
     /// @param amount: The amount to transfer from origin to target
     /// @param target_smart_contract: The target smart contract address
@@ -0,0 +1,57 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title dApps staking receipt token interface.
+ * Reflects the amount locked by each account in dApps staking as a non-transferable ERC20.
+ */
+interface DappsStakingReceipt {
+    /**
+     * @dev Returns the name of the token.
+     */
+    function name() external view returns (string memory);
+
+    /**
+     * @dev Returns the symbol of the token.
+     */
+    function symbol() external view returns (string memory);
+
+    /**
+     * @dev Returns the decimals places of the token.
+     */
+    function decimals() external view returns (uint8);
+
+    /**
+     * @dev Total amount locked in dApps staking.
+     */
+    function totalSupply() external view returns (uint256);
+
+    /**
+     * @dev Amount locked in dApps staking by `who`, including funds being unbonded.
+     */
+    function balanceOf(address who) external view returns (uint256);
+
+    /**
+     * @dev Always zero, the token can't be approved.
+     */
+    function allowance(address owner, address spender)
+        external
+        view
+        returns (uint256);
+
+    /**
+     * @dev Always reverts, the token can't be approved.
+     */
+    function approve(address spender, uint256 value) external returns (bool);
+
+    /**
+     * @dev Always reverts, the token can't be transferred.
+     */
+    function transfer(address to, uint256 value) external returns (bool);
+
+    /**
+     * @dev Always reverts, the token can't be transferred.
+     */
+    function transferFrom(address from, address to, uint256 value)
+        external
+        returns (bool);
+}
@@ -30,10 +30,10 @@ use frame_support::{
 use pallet_dapps_staking::RewardDestination;
 use pallet_evm::{AddressMapping, Precompile};
 use precompile_utils::{
-    error, revert, succeed, Address, Bytes, EvmData, EvmDataWriter, EvmResult, FunctionModifier,
-    PrecompileHandleExt, RuntimeHelper,
+    error, revert, succeed, Address, Bytes, EvmData, EvmDataWriter, EvmResult, PrecompileHandleExt,
+    RuntimeHelper,
 };
-use sp_core::H160;
+use sp_core::{H160, H256};
 use sp_runtime::traits::Zero;
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
@@ -43,6 +43,9 @@ type BalanceOf<Runtime> = <<Runtime as pallet_dapps_staking::Config>::Currency a
     <Runtime as frame_system::Config>::AccountId,
 >>::Balance;
 
+mod receipt;
+pub use receipt::{DappsStakingReceipt, ReceiptAction, ReceiptMetadata};
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -81,8 +84,10 @@ where
     }
 
     /// Fetch unbonding period
-    fn read_unbonding_period(_: &impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+    fn read_unbonding_period(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
         // constant, no DB read
+        handle.record_base_cost::<R>()?;
+
         let unbonding_period = R::UnbondingPeriod::get();
 
         Ok(succeed(
@@ -248,6 +253,40 @@ where
         Ok(succeed(EvmDataWriter::new().write(true).build()))
     }
 
+    /// Lock up and stake balance of the origin account, tagged with a 32-byte memo.
+    fn bond_and_stake_with_memo(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+
+        // parse contract's address
+        let contract_h160 = input.read::<Address>()?.0;
+        let contract_id = Self::decode_smart_contract(contract_h160)?;
+
+        // parse balance to be staked and the memo
+        let value: BalanceOf<R> = input.read()?;
+        let memo: H256 = input.read()?;
+
+        log::trace!(
+            target: "ds-precompile",
+            "bond_and_stake_with_memo {:?}, {:?}, {:?}",
+            contract_id,
+            value,
+            memo
+        );
+
+        // Build call with origin.
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_dapps_staking::Call::<R>::bond_and_stake_with_memo {
+            contract_id,
+            value,
+            memo: memo.into(),
+        };
+
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), call)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
     /// Start unbonding process and unstake balance from the contract.
     fn unbond_and_unstake(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
         let mut input = handle.read_input()?;
@@ -270,6 +309,42 @@ where
         Ok(succeed(EvmDataWriter::new().write(true).build()))
     }
 
+    /// Start unbonding process and unstake balance from the contract, tagged with a 32-byte memo.
+    fn unbond_and_unstake_with_memo(
+        handle: &mut impl PrecompileHandle,
+    ) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+
+        // parse contract's address
+        let contract_h160 = input.read::<Address>()?.0;
+        let contract_id = Self::decode_smart_contract(contract_h160)?;
+
+        // parse balance to be unstaked and the memo
+        let value: BalanceOf<R> = input.read()?;
+        let memo: H256 = input.read()?;
+
+        log::trace!(
+            target: "ds-precompile",
+            "unbond_and_unstake_with_memo {:?}, {:?}, {:?}",
+            contract_id,
+            value,
+            memo
+        );
+
+        // Build call with origin.
+        let origin = R::AddressMapping::into_account_id(handle.context().caller);
+        let call = pallet_dapps_staking::Call::<R>::unbond_and_unstake_with_memo {
+            contract_id,
+            value,
+            memo: memo.into(),
+        };
+
+        RuntimeHelper::<R>::try_dispatch(handle, Some(origin).into(), call)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
     /// Start unbonding process and unstake balance from the contract.
     fn withdraw_unbonded(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
         // Build call with origin.
@@ -450,12 +525,19 @@ where
 #[precompile_utils::generate_function_selector]
 #[derive(Debug, PartialEq)]
 pub enum Action {
+    #[view]
     ReadCurrentEra = "read_current_era()",
+    #[view]
     ReadUnbondingPeriod = "read_unbonding_period()",
+    #[view]
     ReadEraReward = "read_era_reward(uint32)",
+    #[view]
     ReadEraStaked = "read_era_staked(uint32)",
+    #[view]
     ReadStakedAmount = "read_staked_amount(bytes)",
+    #[view]
     ReadStakedAmountOnContract = "read_staked_amount_on_contract(address,bytes)",
+    #[view]
     ReadContractStake = "read_contract_stake(address)",
     Register = "register(address)",
     BondAndStake = "bond_and_stake(address,uint128)",
@@ -466,6 +548,8 @@ pub enum Action {
     SetRewardDestination = "set_reward_destination(uint8)",
     WithdrawFromUnregistered = "withdraw_from_unregistered(address)",
     NominationTransfer = "nomination_transfer(address,uint128,address)",
+    BondAndStakeWithMemo = "bond_and_stake_with_memo(address,uint128,bytes32)",
+    UnbondAndUnstakeWithMemo = "unbond_and_unstake_with_memo(address,uint128,bytes32)",
 }
 
 impl<R> Precompile for DappsStakingWrapper<R>
@@ -483,16 +567,7 @@ where
 
         let selector = handle.read_selector()?;
 
-        handle.check_function_modifier(match selector {
-            Action::ReadCurrentEra
-            | Action::ReadUnbondingPeriod
-            | Action::ReadEraReward
-            | Action::ReadEraStaked
-            | Action::ReadStakedAmount
-            | Action::ReadStakedAmountOnContract
-            | Action::ReadContractStake => FunctionModifier::View,
-            _ => FunctionModifier::NonPayable,
-        })?;
+        handle.check_function_modifier(selector.modifier())?;
 
         match selector {
             // read storage
@@ -513,6 +588,8 @@ where
             Action::SetRewardDestination => Self::set_reward_destination(handle),
             Action::WithdrawFromUnregistered => Self::withdraw_from_unregistered(handle),
             Action::NominationTransfer => Self::nomination_transfer(handle),
+            Action::BondAndStakeWithMemo => Self::bond_and_stake_with_memo(handle),
+            Action::UnbondAndUnstakeWithMemo => Self::unbond_and_unstake_with_memo(handle),
         }
     }
 }
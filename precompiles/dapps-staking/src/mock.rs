@@ -21,7 +21,7 @@ use super::*;
 use fp_evm::IsPrecompileResult;
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::{ConstU64, Currency, OnFinalize, OnInitialize},
+    traits::{ConstU128, ConstU64, Currency, OnFinalize, OnInitialize},
     weights::{RuntimeDbWeight, Weight},
     PalletId,
 };
@@ -36,7 +36,7 @@ use sp_io::TestExternalities;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, ConstU32, IdentityLookup},
-    AccountId32,
+    AccountId32, Perbill,
 };
 extern crate alloc;
 
@@ -205,6 +205,26 @@ pub fn precompile_address() -> H160 {
     H160::from_low_u64_be(0x5001)
 }
 
+pub fn receipt_precompile_address() -> H160 {
+    H160::from_low_u64_be(0x500B)
+}
+
+pub struct MockReceiptMetadata;
+
+impl ReceiptMetadata for MockReceiptMetadata {
+    fn name() -> &'static str {
+        "Staked Astar"
+    }
+
+    fn symbol() -> &'static str {
+        "stAST"
+    }
+
+    fn decimals() -> u8 {
+        18
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct DappPrecompile<R>(PhantomData<R>);
 
@@ -212,17 +232,23 @@ impl<R> PrecompileSet for DappPrecompile<R>
 where
     R: pallet_evm::Config,
     DappsStakingWrapper<R>: Precompile,
+    DappsStakingReceipt<R, MockReceiptMetadata>: Precompile,
 {
     fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
         match handle.code_address() {
             a if a == precompile_address() => Some(DappsStakingWrapper::<R>::execute(handle)),
+            a if a == receipt_precompile_address() => Some(DappsStakingReceipt::<
+                R,
+                MockReceiptMetadata,
+            >::execute(handle)),
             _ => None,
         }
     }
 
     fn is_precompile(&self, address: sp_core::H160, _gas: u64) -> IsPrecompileResult {
         IsPrecompileResult::Answer {
-            is_precompile: address == precompile_address(),
+            is_precompile: address == precompile_address()
+                || address == receipt_precompile_address(),
             extra_cost: 0,
         }
     }
@@ -290,6 +316,7 @@ parameter_types! {
     pub const MaxUnlockingChunks: u32 = MAX_UNLOCKING_CHUNKS;
     pub const UnbondingPeriod: EraIndex = UNBONDING_PERIOD;
     pub const MaxEraStakeValues: u32 = MAX_ERA_STAKE_VALUES;
+    pub const ClaimForTip: Perbill = Perbill::from_percent(0);
 }
 
 impl pallet_dapps_staking::Config for TestRuntime {
@@ -307,6 +334,13 @@ impl pallet_dapps_staking::Config for TestRuntime {
     type UnbondingPeriod = UnbondingPeriod;
     type MaxEraStakeValues = MaxEraStakeValues;
     type UnregisteredDappRewardRetention = ConstU32<2>;
+    type EraHistoryRetention = ConstU32<{ u32::MAX }>;
+    type FeelessClaimThreshold = ConstU128<{ Balance::MAX }>;
+    type FeelessClaimDelay = ConstU32<0>;
+    type ClaimForTip = ClaimForTip;
+    type InfractionReportOrigin = frame_system::EnsureRoot<AccountId32>;
+    type InfractionDelay = ConstU32<2>;
+    type MaxInfractionEras = ConstU32<30>;
 }
 
 pub struct ExternalityBuilder {
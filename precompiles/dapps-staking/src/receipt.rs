@@ -0,0 +1,129 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Receipt token of dApps staking.
+//!
+//! Exposes the amount locked by each account in dApps staking through the read-only part of
+//! the ERC20 interface, so contracts can rely on verified locked positions.
+//! The token can't be transferred nor approved, balances only change through dApps staking.
+
+use fp_evm::PrecompileHandle;
+use pallet_evm::AddressMapping;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::{revert, Address, Bytes, EvmResult, PrecompileHandleExt};
+use sp_core::U256;
+use sp_std::marker::PhantomData;
+
+/// Token metadata of the dApps staking receipt.
+pub trait ReceiptMetadata {
+    /// Returns the name of the token.
+    fn name() -> &'static str;
+
+    /// Returns the symbol of the token.
+    fn symbol() -> &'static str;
+
+    /// Returns the decimals places of the token.
+    fn decimals() -> u8;
+}
+
+/// A precompile reflecting dApps staking locked balances as a non-transferable ERC20.
+pub struct DappsStakingReceipt<R, Metadata>(PhantomData<(R, Metadata)>);
+
+#[precompile_utils::precompile(ReceiptAction, R)]
+impl<R, Metadata> DappsStakingReceipt<R, Metadata>
+where
+    R: pallet_evm::Config + pallet_dapps_staking::Config,
+    Metadata: ReceiptMetadata,
+{
+    #[precompile::public("name()")]
+    #[precompile::view]
+    fn name(_handle: &mut impl PrecompileHandle) -> EvmResult<Bytes> {
+        Ok(Metadata::name().into())
+    }
+
+    #[precompile::public("symbol()")]
+    #[precompile::view]
+    fn symbol(_handle: &mut impl PrecompileHandle) -> EvmResult<Bytes> {
+        Ok(Metadata::symbol().into())
+    }
+
+    #[precompile::public("decimals()")]
+    #[precompile::view]
+    fn decimals(_handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+        Ok(Metadata::decimals())
+    }
+
+    #[precompile::public("totalSupply()")]
+    #[precompile::view]
+    fn total_supply(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        // Storage items: CurrentEra & GeneralEraInfo
+        // Twox64(8) + EraIndex(4) + Twox64Concat(8 + 4) + EraInfo::max_encoded_len
+        handle.record_db_read::<R>(24 + pallet_dapps_staking::EraInfo::max_encoded_len())?;
+
+        Ok(pallet_dapps_staking::Pallet::<R>::tvl().into())
+    }
+
+    #[precompile::public("balanceOf(address)")]
+    #[precompile::view]
+    fn balance_of(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+        // Storage item: Ledger
+        // Blake2_128Concat(16 + 32) + Ledger::max_encoded_len
+        handle.record_db_read::<R>(48 + pallet_dapps_staking::AccountLedger::max_encoded_len())?;
+
+        let owner = R::AddressMapping::into_account_id(owner.into());
+        Ok(pallet_dapps_staking::Ledger::<R>::get(&owner).locked.into())
+    }
+
+    #[precompile::public("allowance(address,address)")]
+    #[precompile::view]
+    fn allowance(
+        _handle: &mut impl PrecompileHandle,
+        _owner: Address,
+        _spender: Address,
+    ) -> EvmResult<U256> {
+        Ok(U256::zero())
+    }
+
+    #[precompile::public("approve(address,uint256)")]
+    fn approve(
+        _handle: &mut impl PrecompileHandle,
+        _spender: Address,
+        _value: U256,
+    ) -> EvmResult<bool> {
+        Err(revert("non-transferable token"))
+    }
+
+    #[precompile::public("transfer(address,uint256)")]
+    fn transfer(
+        _handle: &mut impl PrecompileHandle,
+        _to: Address,
+        _value: U256,
+    ) -> EvmResult<bool> {
+        Err(revert("non-transferable token"))
+    }
+
+    #[precompile::public("transferFrom(address,address,uint256)")]
+    fn transfer_from(
+        _handle: &mut impl PrecompileHandle,
+        _from: Address,
+        _to: Address,
+        _value: U256,
+    ) -> EvmResult<bool> {
+        Err(revert("non-transferable token"))
+    }
+}
@@ -28,7 +28,7 @@ use fp_evm::ExitError;
 use frame_support::assert_ok;
 use pallet_dapps_staking::RewardDestination;
 use precompile_utils::testing::*;
-use sp_core::H160;
+use sp_core::{H160, H256, U256};
 use sp_runtime::{traits::Zero, AccountId32, Perbill};
 
 fn precompiles() -> DappPrecompile<TestRuntime> {
@@ -79,7 +79,7 @@ fn read_unbonding_period_is_ok() {
                 precompile_address(),
                 EvmDataWriter::new_with_selector(Action::ReadUnbondingPeriod).build(),
             )
-            .expect_cost(0)
+            .expect_cost(READ_WEIGHT)
             .expect_no_logs()
             .execute_returns(EvmDataWriter::new().write(UNBONDING_PERIOD).build());
     });
@@ -130,6 +130,32 @@ fn read_era_staked_is_ok() {
     });
 }
 
+#[test]
+fn solidity_interface_matches_selectors() {
+    assert_solidity_interface("DappsStaking.sol", Action::SELECTORS);
+}
+
+#[test]
+fn read_selectors_charge_base_cost() {
+    let read_selectors: Vec<(u32, &str)> = Action::SELECTORS
+        .iter()
+        .filter(|(_, signature)| signature.starts_with("read_"))
+        .copied()
+        .collect();
+
+    assert_selectors_charge_base_cost(
+        &read_selectors,
+        READ_WEIGHT,
+        &[
+            current_era_is_ok,
+            read_unbonding_period_is_ok,
+            read_era_reward_is_ok,
+            read_era_staked_is_ok,
+            bond_and_stake_is_ok,
+        ],
+    );
+}
+
 #[test]
 fn register_via_precompile_fails() {
     ExternalityBuilder::default()
@@ -393,6 +419,244 @@ fn nomination_transfer() {
         });
 }
 
+#[test]
+fn bond_and_stake_with_memo_is_ok() {
+    ExternalityBuilder::default()
+        .with_balances(vec![
+            (TestAccount::Alex.into(), 200 * AST),
+            (TestAccount::Bobo.into(), 200 * AST),
+        ])
+        .build()
+        .execute_with(|| {
+            initialize_first_block();
+
+            register_and_verify(TestAccount::Alex, TEST_CONTRACT);
+
+            let memo = H256::repeat_byte(0xAB);
+            let amount_staked_bobo = 100 * AST;
+            precompiles()
+                .prepare_test(
+                    TestAccount::Bobo,
+                    precompile_address(),
+                    EvmDataWriter::new_with_selector(Action::BondAndStakeWithMemo)
+                        .write(Address(TEST_CONTRACT))
+                        .write(amount_staked_bobo)
+                        .write(memo)
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(true).build());
+
+            let smart_contract =
+                decode_smart_contract_from_array(TEST_CONTRACT.clone().to_fixed_bytes()).unwrap();
+            System::assert_last_event(RuntimeEvent::DappsStaking(
+                pallet_dapps_staking::Event::BondAndStakeWithMemo(
+                    TestAccount::Bobo.into(),
+                    smart_contract,
+                    amount_staked_bobo,
+                    memo.into(),
+                ),
+            ));
+            contract_era_stake_verify(TEST_CONTRACT, amount_staked_bobo);
+
+            let amount_unstaked_bobo = 40 * AST;
+            precompiles()
+                .prepare_test(
+                    TestAccount::Bobo,
+                    precompile_address(),
+                    EvmDataWriter::new_with_selector(Action::UnbondAndUnstakeWithMemo)
+                        .write(Address(TEST_CONTRACT))
+                        .write(amount_unstaked_bobo)
+                        .write(memo)
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(true).build());
+
+            System::assert_last_event(RuntimeEvent::DappsStaking(
+                pallet_dapps_staking::Event::UnbondAndUnstakeWithMemo(
+                    TestAccount::Bobo.into(),
+                    smart_contract,
+                    amount_unstaked_bobo,
+                    memo.into(),
+                ),
+            ));
+            contract_era_stake_verify(TEST_CONTRACT, amount_staked_bobo - amount_unstaked_bobo);
+        });
+}
+
+#[test]
+fn bond_and_stake_over_stake_cap_reverts() {
+    ExternalityBuilder::default()
+        .with_balances(vec![
+            (TestAccount::Alex.into(), 200 * AST),
+            (TestAccount::Bobo.into(), 200 * AST),
+        ])
+        .build()
+        .execute_with(|| {
+            initialize_first_block();
+
+            register_and_verify(TestAccount::Alex, TEST_CONTRACT);
+            let smart_contract =
+                decode_smart_contract_from_array(TEST_CONTRACT.clone().to_fixed_bytes()).unwrap();
+            assert_ok!(DappsStaking::set_contract_stake_cap(
+                RuntimeOrigin::root(),
+                smart_contract,
+                Some(100 * AST)
+            ));
+
+            bond_stake_and_verify(TestAccount::Bobo, TEST_CONTRACT, 60 * AST);
+
+            precompiles()
+                .prepare_test(
+                    TestAccount::Bobo,
+                    precompile_address(),
+                    EvmDataWriter::new_with_selector(Action::BondAndStake)
+                        .write(Address(TEST_CONTRACT))
+                        .write(50 * AST)
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_reverts(|output| {
+                    output == b"Dispatched call failed with error: StakeCapExceeded"
+                });
+
+            contract_era_stake_verify(TEST_CONTRACT, 60 * AST);
+        });
+}
+
+#[test]
+fn receipt_reflects_locked_balances() {
+    ExternalityBuilder::default()
+        .with_balances(vec![
+            (TestAccount::Alex.into(), 200 * AST),
+            (TestAccount::Bobo.into(), 200 * AST),
+            (TestAccount::Dino.into(), 100 * AST),
+        ])
+        .build()
+        .execute_with(|| {
+            initialize_first_block();
+
+            register_and_verify(TestAccount::Alex, TEST_CONTRACT);
+            bond_stake_and_verify(TestAccount::Bobo, TEST_CONTRACT, 100 * AST);
+            bond_stake_and_verify(TestAccount::Dino, TEST_CONTRACT, 50 * AST);
+
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alex,
+                    receipt_precompile_address(),
+                    EvmDataWriter::new_with_selector(ReceiptAction::BalanceOf)
+                        .write(Address(TestAccount::Bobo.into()))
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(U256::from(100 * AST)).build());
+
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alex,
+                    receipt_precompile_address(),
+                    EvmDataWriter::new_with_selector(ReceiptAction::BalanceOf)
+                        .write(Address(TestAccount::Alex.into()))
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(U256::zero()).build());
+
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alex,
+                    receipt_precompile_address(),
+                    EvmDataWriter::new_with_selector(ReceiptAction::TotalSupply).build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(U256::from(150 * AST)).build());
+
+            precompiles()
+                .prepare_test(
+                    TestAccount::Alex,
+                    receipt_precompile_address(),
+                    EvmDataWriter::new_with_selector(ReceiptAction::Symbol).build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(Bytes::from("stAST")).build());
+        });
+}
+
+#[test]
+fn receipt_is_not_transferable() {
+    ExternalityBuilder::default()
+        .with_balances(vec![(TestAccount::Bobo.into(), 200 * AST)])
+        .build()
+        .execute_with(|| {
+            initialize_first_block();
+
+            precompiles()
+                .prepare_test(
+                    TestAccount::Bobo,
+                    receipt_precompile_address(),
+                    EvmDataWriter::new_with_selector(ReceiptAction::Transfer)
+                        .write(Address(TestAccount::Dino.into()))
+                        .write(U256::one())
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_reverts(|output| output == b"non-transferable token");
+
+            precompiles()
+                .prepare_test(
+                    TestAccount::Bobo,
+                    receipt_precompile_address(),
+                    EvmDataWriter::new_with_selector(ReceiptAction::Approve)
+                        .write(Address(TestAccount::Dino.into()))
+                        .write(U256::one())
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_reverts(|output| output == b"non-transferable token");
+
+            precompiles()
+                .prepare_test(
+                    TestAccount::Bobo,
+                    receipt_precompile_address(),
+                    EvmDataWriter::new_with_selector(ReceiptAction::Allowance)
+                        .write(Address(TestAccount::Bobo.into()))
+                        .write(Address(TestAccount::Dino.into()))
+                        .build(),
+                )
+                .expect_no_logs()
+                .execute_returns(EvmDataWriter::new().write(U256::zero()).build());
+        });
+}
+
+#[test]
+fn receipt_solidity_interface_matches_selectors() {
+    assert_solidity_interface("DappsStakingReceipt.sol", ReceiptAction::SELECTORS);
+}
+
+#[test]
+fn receipt_selectors_charge_base_cost() {
+    // Charged by the code generated with `#[precompile]`, before any input is decoded.
+    let executed_selectors: Vec<(u32, &str)> = ReceiptAction::SELECTORS
+        .iter()
+        .filter(|(_, signature)| {
+            !signature.starts_with("name")
+                && !signature.starts_with("decimals")
+                && !signature.starts_with("transferFrom")
+        })
+        .copied()
+        .collect();
+
+    assert_selectors_charge_base_cost(
+        &executed_selectors,
+        READ_WEIGHT,
+        &[
+            receipt_reflects_locked_balances,
+            receipt_is_not_transferable,
+        ],
+    );
+}
+
 // ****************************************************************************************************
 // Helper functions
 // ****************************************************************************************************
@@ -0,0 +1,72 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Dispatch precompile wrapper emitting a log for every dispatched call.
+//!
+//! The dispatch precompile takes a SCALE encoded runtime call as its input, which is opaque to
+//! EVM-only explorers. On a successful dispatch this wrapper emits
+//! `Dispatched(uint8 indexed palletIndex, uint8 indexed callIndex, bytes32 callHash)`, where the
+//! indices are the first two bytes of the encoded call and `callHash` is its blake2-256 hash.
+//!
+//! A failed dispatch reverts, discarding any logs, so the presence of the log is the outcome.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{Precompile, PrecompileHandle, PrecompileResult};
+use precompile_utils::{keccak256, EvmDataWriter, LogExt, LogsBuilder, PrecompileHandleExt};
+use sp_core::{hashing::blake2_256, H256};
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the Dispatched log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_DISPATCHED: [u8; 32] = keccak256!("Dispatched(uint8,uint8,bytes32)");
+
+/// Wraps the `Dispatch` precompile, logging the dispatched call.
+pub struct DispatchWithLogs<Dispatch>(PhantomData<Dispatch>);
+
+impl<Dispatch> Precompile for DispatchWithLogs<Dispatch>
+where
+    Dispatch: Precompile,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let (pallet_index, call_index) = match handle.input() {
+            [pallet_index, call_index, ..] => (*pallet_index, *call_index),
+            // Not a call, let the dispatch precompile handle it.
+            _ => return Dispatch::execute(handle),
+        };
+        let call_hash = H256(blake2_256(handle.input()));
+
+        let output = Dispatch::execute(handle)?;
+
+        handle.record_log_costs_manual(3, 32)?;
+        LogsBuilder::new(handle.context().address)
+            .log3(
+                SELECTOR_LOG_DISPATCHED,
+                H256::from_low_u64_be(pallet_index.into()),
+                H256::from_low_u64_be(call_index.into()),
+                EvmDataWriter::new().write(call_hash).build(),
+            )
+            .record(handle)?;
+
+        Ok(output)
+    }
+}
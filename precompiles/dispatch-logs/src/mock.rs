@@ -0,0 +1,72 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Testing utilities.
+
+use super::*;
+
+use fp_evm::{
+    ExitError, ExitSucceed, IsPrecompileResult, PrecompileFailure, PrecompileOutput, PrecompileSet,
+};
+use sp_core::H160;
+
+pub const PRECOMPILE_ADDRESS: H160 = H160::repeat_byte(0x7B);
+
+/// Pallet index of calls `MockDispatch` refuses to dispatch.
+pub const FAILING_PALLET_INDEX: u8 = 0xFF;
+
+/// Stands in for the dispatch precompile, succeeding unless the call belongs to
+/// `FAILING_PALLET_INDEX`.
+pub struct MockDispatch;
+
+impl Precompile for MockDispatch {
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        match handle.input().first() {
+            Some(&pallet_index) if pallet_index != FAILING_PALLET_INDEX => Ok(PrecompileOutput {
+                exit_status: ExitSucceed::Returned,
+                output: Default::default(),
+            }),
+            _ => Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("dispatch execution failed".into()),
+            }),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TestPrecompileSet;
+
+impl PrecompileSet for TestPrecompileSet {
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        match handle.code_address() {
+            a if a == PRECOMPILE_ADDRESS => Some(DispatchWithLogs::<MockDispatch>::execute(handle)),
+            _ => None,
+        }
+    }
+
+    fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
+        IsPrecompileResult::Answer {
+            is_precompile: address == PRECOMPILE_ADDRESS,
+            extra_cost: 0,
+        }
+    }
+}
+
+pub fn precompiles() -> TestPrecompileSet {
+    TestPrecompileSet
+}
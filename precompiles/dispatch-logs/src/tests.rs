@@ -0,0 +1,54 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+
+use fp_evm::ExitError;
+use precompile_utils::testing::*;
+
+#[test]
+fn dispatch_is_logged() {
+    let call = vec![4u8, 0, 1, 2, 3];
+
+    precompiles()
+        .prepare_test(Alice, PRECOMPILE_ADDRESS, call.clone())
+        .expect_log(LogsBuilder::new(PRECOMPILE_ADDRESS).log3(
+            SELECTOR_LOG_DISPATCHED,
+            H256::from_low_u64_be(4),
+            H256::from_low_u64_be(0),
+            EvmDataWriter::new().write(H256(blake2_256(&call))).build(),
+        ))
+        .execute_returns(Vec::new());
+}
+
+#[test]
+fn failed_dispatch_is_not_logged() {
+    precompiles()
+        .prepare_test(Alice, PRECOMPILE_ADDRESS, vec![FAILING_PALLET_INDEX, 0])
+        .expect_no_logs()
+        .execute_error(ExitError::Other("dispatch execution failed".into()));
+}
+
+#[test]
+fn input_without_call_indices_is_not_logged() {
+    precompiles()
+        .prepare_test(Alice, PRECOMPILE_ADDRESS, vec![4u8])
+        .expect_no_logs()
+        .execute_returns(Vec::new());
+}
@@ -0,0 +1,21 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Governance executor interface, dispatching runtime calls delegated by governance.
+ *
+ * Address: 0x000000000000000000000000000000000000500D
+ */
+interface GovernanceExecutor {
+    /**
+     * @dev Dispatches a SCALE encoded runtime call with the root origin, on behalf of the caller.
+     * Reverts if the caller has no active delegation, if the call isn't allowed for executors
+     * or if the call fails.
+     * @param call SCALE encoded runtime call
+     */
+    function execute(bytes calldata call) external;
+
+    /**
+     * @dev Returns `true` if `executor` has a delegation which hasn't expired.
+     */
+    function isExecutor(address executor) external view returns (bool);
+}
@@ -0,0 +1,100 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+//! Governance executor precompile.
+//!
+//! Lets EVM contracts which governance delegated to, e.g. a DAO, dispatch the runtime calls
+//! allowed by `pallet-governance-executor`. The caller of the precompile is the executor.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::GetDispatchInfo;
+use parity_scale_codec::{DecodeLimit, MaxEncodedLen};
+use precompile_utils::{
+    bytes::BoundedBytes, revert, Address, EvmResult, MeteredHandle, PrecompileHandleExt,
+};
+use sp_core::{ConstU32, H160};
+use sp_std::{boxed::Box, marker::PhantomData};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Max length of an encoded call.
+pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+/// Max nesting depth when decoding a call.
+pub const CALL_DECODE_DEPTH_LIMIT: u32 = 8;
+
+type GetCallDataLimit = ConstU32<CALL_DATA_LIMIT>;
+
+type ExecutorCallOf<Runtime> = <Runtime as pallet_governance_executor::Config>::RuntimeCall;
+
+/// A precompile dispatching calls delegated to the caller by governance.
+pub struct GovernanceExecutorPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile(Action, Runtime)]
+impl<Runtime> GovernanceExecutorPrecompile<Runtime>
+where
+    Runtime: pallet_evm::Config + pallet_governance_executor::Config,
+{
+    #[precompile::public("execute(bytes)")]
+    fn execute_call(
+        handle: &mut impl PrecompileHandle,
+        call: BoundedBytes<GetCallDataLimit>,
+    ) -> EvmResult {
+        Self::record_executor_read(handle)?;
+
+        let call = ExecutorCallOf::<Runtime>::decode_all_with_depth_limit(
+            CALL_DECODE_DEPTH_LIMIT,
+            &mut call.as_bytes(),
+        )
+        .map_err(|_| revert("failed to decode call"))?;
+        let weight = call.get_dispatch_info().weight;
+        let executor = handle.context().caller;
+
+        MeteredHandle::<_, Runtime>::new(handle).try_call_runtime(weight, || {
+            pallet_governance_executor::Pallet::<Runtime>::execute(executor, Box::new(call))
+        })?;
+
+        Ok(())
+    }
+
+    #[precompile::public("isExecutor(address)")]
+    #[precompile::view]
+    fn is_executor(handle: &mut impl PrecompileHandle, executor: Address) -> EvmResult<bool> {
+        Self::record_executor_read(handle)?;
+
+        Ok(pallet_governance_executor::Pallet::<Runtime>::is_executor(
+            &executor.into(),
+        ))
+    }
+}
+
+impl<Runtime> GovernanceExecutorPrecompile<Runtime>
+where
+    Runtime: pallet_evm::Config + pallet_governance_executor::Config,
+{
+    fn record_executor_read(handle: &mut impl PrecompileHandle) -> EvmResult {
+        // Storage item: Executors
+        // max encoded len: Blake2_128(16) + H160(20) + BlockNumber
+        handle.record_db_read::<Runtime>(
+            16 + H160::max_encoded_len() + Runtime::BlockNumber::max_encoded_len(),
+        )
+    }
+}
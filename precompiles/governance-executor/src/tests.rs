@@ -0,0 +1,143 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+use crate::mock::*;
+use crate::*;
+
+use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+use parity_scale_codec::Encode;
+use precompile_utils::{testing::*, Bytes, EvmDataWriter};
+
+fn precompiles() -> TestPrecompileSet<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn delegate(executor: impl Into<H160>) {
+    assert_ok!(GovernanceExecutor::delegate(
+        RuntimeOrigin::root(),
+        executor.into(),
+        10
+    ));
+}
+
+fn execute_input(call: RuntimeCall) -> Vec<u8> {
+    EvmDataWriter::new_with_selector(Action::ExecuteCall)
+        .write(Bytes(call.encode()))
+        .build()
+}
+
+fn set_storage(value: &[u8]) -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::set_storage {
+        items: vec![(b"key".to_vec(), value.to_vec())],
+    })
+}
+
+#[test]
+fn is_executor_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        delegate(Alice);
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                precompile_address(),
+                EvmDataWriter::new_with_selector(Action::IsExecutor)
+                    .write(Address(Alice.into()))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns_encoded(true);
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                EvmDataWriter::new_with_selector(Action::IsExecutor)
+                    .write(Address(Bob.into()))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns_encoded(false);
+    })
+}
+
+#[test]
+fn executor_dispatches_allowed_call() {
+    ExtBuilder::default().build().execute_with(|| {
+        delegate(Alice);
+
+        // Storage reads are free in the mock, only the weight of the call is charged.
+        let call = set_storage(b"value");
+        let weight = call.get_dispatch_info().weight;
+
+        precompiles()
+            .prepare_test(Alice, precompile_address(), execute_input(call))
+            .expect_cost(weight.ref_time())
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().build());
+
+        assert_eq!(sp_io::storage::get(b"key").as_deref(), Some(&b"value"[..]));
+    })
+}
+
+#[test]
+fn execute_reverts_for_other_callers() {
+    ExtBuilder::default().build().execute_with(|| {
+        delegate(Alice);
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                precompile_address(),
+                execute_input(set_storage(b"value")),
+            )
+            .execute_reverts_with(DispatchFailed("NotDelegated"));
+
+        System::set_block_number(10);
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                execute_input(set_storage(b"value")),
+            )
+            .execute_reverts_with(DispatchFailed("DelegationExpired"));
+
+        assert_eq!(sp_io::storage::get(b"key"), None);
+    })
+}
+
+#[test]
+fn execute_reverts_for_filtered_or_malformed_calls() {
+    ExtBuilder::default().build().execute_with(|| {
+        delegate(Alice);
+
+        let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![1] });
+        precompiles()
+            .prepare_test(Alice, precompile_address(), execute_input(remark))
+            .execute_reverts_with(DispatchFailed("CallFiltered"));
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                EvmDataWriter::new_with_selector(Action::ExecuteCall)
+                    .write(Bytes(vec![0xff, 0xff]))
+                    .build(),
+            )
+            .execute_reverts_with("failed to decode call");
+    })
+}
@@ -0,0 +1,34 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Merkle distributor interface.
+ */
+interface MerkleDistributor {
+    /**
+     * @dev Claim the caller's entry of the distribution.
+     * Leaf is computed as `keccak256(abi.encodePacked(msg.sender, amount))`.
+     * Claimed amount is paid out to the native account mapped from the caller.
+     * @param distribution_id Id of the distribution
+     * @param amount Amount of the entry
+     * @param proof Merkle proof of the entry, using sorted pair hashing
+     * @return Whether the claim succeeded
+     */
+    function claim(
+        uint32 distribution_id,
+        uint256 amount,
+        bytes32[] calldata proof
+    ) external returns (bool);
+
+    /**
+     * @dev Check whether the entry of the distribution has already been claimed.
+     * @param distribution_id Id of the distribution
+     * @param claimant EVM address of the entry
+     * @param amount Amount of the entry
+     * @return Whether the entry has already been claimed
+     */
+    function isClaimed(
+        uint32 distribution_id,
+        address claimant,
+        uint256 amount
+    ) external view returns (bool);
+}
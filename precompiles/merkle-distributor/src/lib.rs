@@ -0,0 +1,132 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{PrecompileHandle, PrecompileOutput};
+use frame_support::{
+    dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+    BoundedVec,
+};
+use pallet_evm::{AddressMapping, Precompile};
+use pallet_merkle_distributor::{BalanceOf, Claimant, DistributionId};
+use sp_core::H256;
+use sp_std::{marker::PhantomData, prelude::*};
+
+use precompile_utils::{
+    revert, succeed, Address, EvmData, EvmDataWriter, EvmResult, PrecompileHandleExt, RuntimeHelper,
+};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    Claim = "claim(uint32,uint256,bytes32[])",
+    #[view]
+    IsClaimed = "isClaimed(uint32,address,uint256)",
+}
+
+/// A precompile for claiming Merkle distribution entries of the EVM caller.
+pub struct MerkleDistributorPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for MerkleDistributorPrecompile<Runtime>
+where
+    Runtime: pallet_evm::Config + pallet_merkle_distributor::Config,
+    BalanceOf<Runtime>: EvmData,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    Runtime::RuntimeCall: From<pallet_merkle_distributor::Call<Runtime>>,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        log::trace!(target: "merkle-distributor-precompile", "In merkle distributor precompile");
+
+        let selector = handle.read_selector()?;
+
+        handle.check_function_modifier(selector.modifier())?;
+
+        match selector {
+            // Dispatchables
+            Action::Claim => Self::claim(handle),
+            // Storage getters
+            Action::IsClaimed => Self::is_claimed(handle),
+        }
+    }
+}
+
+impl<Runtime> MerkleDistributorPrecompile<Runtime>
+where
+    Runtime: pallet_evm::Config + pallet_merkle_distributor::Config,
+    BalanceOf<Runtime>: EvmData,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    Runtime::RuntimeCall: From<pallet_merkle_distributor::Call<Runtime>>,
+{
+    /// Claim the entry of the caller, paid out to the native account mapped from the caller.
+    fn claim(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+
+        let id = input.read::<DistributionId>()?;
+        let amount = input.read::<BalanceOf<Runtime>>()?;
+        let proof: BoundedVec<H256, Runtime::MaxProofLength> = input
+            .read::<Vec<H256>>()?
+            .try_into()
+            .map_err(|_| revert("proof too long"))?;
+
+        let caller = handle.context().caller;
+        log::trace!(target: "merkle-distributor-precompile", "claim {:?}, {:?}, {:?}", id, caller, amount);
+
+        let origin = Runtime::AddressMapping::into_account_id(caller);
+        let call = pallet_merkle_distributor::Call::<Runtime>::claim {
+            id,
+            claimant: Claimant::Evm(caller),
+            amount,
+            proof,
+        };
+
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    /// Check whether the entry of the given EVM claimant has already been claimed.
+    fn is_claimed(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        // Storage item: ClaimedLeaves
+        // Twox64(8) + DistributionId(4) + H256(32)
+        handle.record_db_read::<Runtime>(44)?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+
+        let id = input.read::<DistributionId>()?;
+        let claimant = input.read::<Address>()?.0;
+        let amount = input.read::<BalanceOf<Runtime>>()?;
+
+        let is_claimed = pallet_merkle_distributor::Pallet::<Runtime>::is_claimed(
+            id,
+            &Claimant::Evm(claimant),
+            amount,
+        );
+
+        Ok(succeed(EvmDataWriter::new().write(is_claimed).build()))
+    }
+}
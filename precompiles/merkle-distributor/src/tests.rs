@@ -0,0 +1,142 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+
+use frame_support::assert_ok;
+use pallet_merkle_distributor::Pallet as Distributor;
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<Runtime> {
+    PrecompilesValue::get()
+}
+
+/// Creates a two leaf distribution with entries for `Bobo` and `Alex`, and returns proof for `Bobo`.
+fn create_distribution() -> Vec<H256> {
+    let bobo_leaf =
+        Distributor::<Runtime>::leaf_hash(&Claimant::Evm(TestAccount::Bobo.into()), 100);
+    let alex_leaf =
+        Distributor::<Runtime>::leaf_hash(&Claimant::Evm(TestAccount::Alex.into()), 200);
+    let merkle_root = Distributor::<Runtime>::compute_root(bobo_leaf, &[alex_leaf]);
+
+    assert_ok!(Distributor::<Runtime>::create_distribution(
+        RuntimeOrigin::root(),
+        TestAccount::Alex.into(),
+        merkle_root,
+        300
+    ));
+
+    vec![alex_leaf]
+}
+
+#[test]
+fn claim_is_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        let proof = create_distribution();
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Bobo,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Claim)
+                    .write(0u32)
+                    .write(100u128)
+                    .write(proof)
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(true).build());
+
+        assert_eq!(
+            Balances::free_balance(AccountId::from(TestAccount::Bobo)),
+            100
+        );
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alex,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::IsClaimed)
+                    .write(0u32)
+                    .write(Address(TestAccount::Bobo.into()))
+                    .write(100u128)
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(true).build());
+    })
+}
+
+#[test]
+fn claim_with_invalid_proof_reverts() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = create_distribution();
+
+        // Caller isn't part of the distribution
+        precompiles()
+            .prepare_test(
+                TestAccount::Empty,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Claim)
+                    .write(0u32)
+                    .write(100u128)
+                    .write(vec![H256::zero()])
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| {
+                output
+                    .windows(b"InvalidProof".len())
+                    .any(|w| w == b"InvalidProof")
+            });
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Bobo,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Claim)
+                    .write(0u32)
+                    .write(100u128)
+                    .write(vec![H256::zero(); MAX_PROOF_LENGTH as usize + 1])
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"proof too long");
+    })
+}
+
+#[test]
+fn is_claimed_is_false_for_unclaimed_entry() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = create_distribution();
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alex,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::IsClaimed)
+                    .write(0u32)
+                    .write(Address(TestAccount::Alex.into()))
+                    .write(200u128)
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(false).build());
+    })
+}
@@ -0,0 +1,20 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Native transfer interface, moving native currency from EVM to native accounts.
+ *
+ * Address: 0x000000000000000000000000000000000000500A
+ */
+interface NativeTransfer {
+    /**
+     * @dev Emitted when `amount` is moved from the caller (`from`) to the native account `to`.
+     */
+    event TransferToWasm(address indexed from, bytes32 indexed to, uint256 amount);
+
+    /**
+     * @dev Moves `amount` of native currency from the caller to the native account `dest`,
+     * e.g. the account used with WASM contracts.
+     * Reverts if `dest` doesn't exist and `amount` is below the existential deposit.
+     */
+    function transferToWasm(bytes32 dest, uint256 amount) external;
+}
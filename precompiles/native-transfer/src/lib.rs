@@ -0,0 +1,133 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Native currency transfers from EVM to native accounts.
+//!
+//! An EVM address spends the balance of the native account it maps to, so moving funds
+//! to a native account, e.g. the one used with WASM contracts, is a plain transfer between
+//! the two accounts. The native `Transfer` event is emitted by the balances pallet, together
+//! with the `TransferToWasm` log of the precompile.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{PrecompileHandle, PrecompileOutput};
+use frame_support::traits::{Currency, ExistenceRequirement};
+use pallet_evm::{AddressMapping, Precompile};
+use precompile_utils::{
+    keccak256, revert, succeed, EvmDataWriter, EvmResult, FunctionModifier, LogExt, LogsBuilder,
+    PrecompileHandleExt, RuntimeHelper,
+};
+use sp_core::{H256, U256};
+use sp_runtime::traits::Zero;
+use sp_std::{convert::TryFrom, marker::PhantomData};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the TransferToWasm log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER_TO_WASM: [u8; 32] =
+    keccak256!("TransferToWasm(address,bytes32,uint256)");
+
+type BalanceOf<Runtime> = <<Runtime as pallet_evm::Config>::Currency as Currency<
+    <Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    TransferToWasm = "transferToWasm(bytes32,uint256)",
+}
+
+/// A precompile moving native currency from the caller to a native account.
+pub struct NativeTransferPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for NativeTransferPrecompile<Runtime>
+where
+    Runtime: pallet_evm::Config,
+    Runtime::AccountId: From<[u8; 32]>,
+    BalanceOf<Runtime>: TryFrom<U256>,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        log::trace!(target: "native-transfer-precompile", "In native transfer precompile");
+
+        let selector = handle.read_selector()?;
+
+        handle.check_function_modifier(FunctionModifier::NonPayable)?;
+
+        match selector {
+            Action::TransferToWasm => Self::transfer_to_wasm(handle),
+        }
+    }
+}
+
+impl<Runtime> NativeTransferPrecompile<Runtime>
+where
+    Runtime: pallet_evm::Config,
+    Runtime::AccountId: From<[u8; 32]>,
+    BalanceOf<Runtime>: TryFrom<U256>,
+{
+    fn transfer_to_wasm(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_log_costs_manual(3, 32)?;
+        // Storage item: System::Account (destination)
+        // Blake2_128(16) + AccountId(32) + AccountInfo(80)
+        handle.record_db_read::<Runtime>(128)?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(2)?;
+
+        let dest: H256 = input.read()?;
+        let amount: U256 = input.read()?;
+
+        let value = RuntimeHelper::<Runtime>::checked_balance(amount, "amount")?;
+
+        let caller = handle.context().caller;
+        let source = Runtime::AddressMapping::into_account_id(caller);
+        let dest_account: Runtime::AccountId = dest.to_fixed_bytes().into();
+
+        // A new destination account must be created with at least the existential deposit.
+        if Runtime::Currency::total_balance(&dest_account).is_zero()
+            && value < Runtime::Currency::minimum_balance()
+        {
+            return Err(revert("amount is below existential deposit"));
+        }
+
+        // Storage items: System::Account (caller & destination)
+        handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost().saturating_mul(2))?;
+        // Same as plain EVM value transfers, the caller account may be reaped.
+        Runtime::Currency::transfer(
+            &source,
+            &dest_account,
+            value,
+            ExistenceRequirement::AllowDeath,
+        )
+        .map_err(|_| revert("failed to transfer native currency"))?;
+
+        LogsBuilder::new(handle.context().address)
+            .log3(
+                SELECTOR_LOG_TRANSFER_TO_WASM,
+                caller,
+                dest,
+                EvmDataWriter::new().write(amount).build(),
+            )
+            .record(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().build()))
+    }
+}
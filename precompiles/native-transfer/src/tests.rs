@@ -0,0 +1,126 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+
+use precompile_utils::testing::*;
+use sp_runtime::AccountId32;
+
+fn precompiles() -> TestPrecompileSet<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn transfer_to_wasm_input(dest: H256, amount: U256) -> Vec<u8> {
+    EvmDataWriter::new_with_selector(Action::TransferToWasm)
+        .write(dest)
+        .write(amount)
+        .build()
+}
+
+#[test]
+fn transfer_to_wasm_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let dest = H256::repeat_byte(0x09);
+        let amount = 1_000;
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alex,
+                PRECOMPILE_ADDRESS,
+                transfer_to_wasm_input(dest, amount.into()),
+            )
+            .expect_log(LogsBuilder::new(PRECOMPILE_ADDRESS).log3(
+                SELECTOR_LOG_TRANSFER_TO_WASM,
+                H160::from(TestAccount::Alex),
+                dest,
+                EvmDataWriter::new().write(U256::from(amount)).build(),
+            ))
+            .execute_returns(EvmDataWriter::new().build());
+
+        let dest_account = AccountId32::from(dest.to_fixed_bytes());
+        assert_eq!(Balances::free_balance(&dest_account), amount);
+        assert_eq!(
+            Balances::free_balance(AccountId32::from(TestAccount::Alex)),
+            INITIAL_BALANCE - amount
+        );
+        System::assert_last_event(RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+            from: TestAccount::Alex.into(),
+            to: dest_account,
+            amount,
+        }));
+    });
+}
+
+#[test]
+fn transfer_to_wasm_respects_existential_deposit() {
+    ExtBuilder::default().build().execute_with(|| {
+        // New account can't be created below the existential deposit
+        precompiles()
+            .prepare_test(
+                TestAccount::Alex,
+                PRECOMPILE_ADDRESS,
+                transfer_to_wasm_input(H256::repeat_byte(0x09), (EXISTENTIAL_DEPOSIT - 1).into()),
+            )
+            .execute_reverts(|output| output == b"amount is below existential deposit");
+
+        // Existing account can receive any amount
+        let bobo = H256::from(<[u8; 32]>::from(AccountId32::from(TestAccount::Bobo)));
+        precompiles()
+            .prepare_test(
+                TestAccount::Alex,
+                PRECOMPILE_ADDRESS,
+                transfer_to_wasm_input(bobo, U256::one()),
+            )
+            .execute_returns(EvmDataWriter::new().build());
+        assert_eq!(
+            Balances::free_balance(AccountId32::from(TestAccount::Bobo)),
+            INITIAL_BALANCE + 1
+        );
+    });
+}
+
+#[test]
+fn transfer_to_wasm_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Alex,
+                PRECOMPILE_ADDRESS,
+                transfer_to_wasm_input(H256::repeat_byte(0x09), (INITIAL_BALANCE + 1).into()),
+            )
+            .execute_reverts(|output| output == b"failed to transfer native currency");
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alex,
+                PRECOMPILE_ADDRESS,
+                transfer_to_wasm_input(H256::repeat_byte(0x09), U256::MAX),
+            )
+            .execute_reverts(|output| output == b"amount is too large");
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alex,
+                PRECOMPILE_ADDRESS,
+                transfer_to_wasm_input(H256::repeat_byte(0x09), U256::from(1_000)),
+            )
+            .with_value(1_000)
+            .execute_reverts(|output| output == b"function is not payable");
+    });
+}
@@ -13,4 +13,17 @@ interface SR25519 {
         bytes calldata signature,
         bytes calldata message
     ) external view returns (bool);
-}
\ No newline at end of file
+
+    /**
+     * @dev Verify SR25519 (Schnorrkel) VRF proof.
+     * The VRF transcript is created with `signing_context(b"substrate").bytes(input)`,
+     * and `proof` is the 32 bytes VRF pre-output followed by the 64 bytes proof.
+     * @return A boolean confirming the proof validity and the VRF output derived
+     * with `make_bytes(b"substrate-vrf-output")`, zero if the proof is invalid.
+     */
+    function vrfVerify(
+        bytes32 public_key,
+        bytes calldata input,
+        bytes calldata proof
+    ) external view returns (bool, bytes32);
+}
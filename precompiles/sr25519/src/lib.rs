@@ -20,6 +20,11 @@
 
 use fp_evm::{PrecompileHandle, PrecompileOutput};
 use pallet_evm::Precompile;
+use schnorrkel::{
+    signing_context,
+    vrf::{VRFOutput, VRFProof, VRF_OUTPUT_LENGTH, VRF_PROOF_LENGTH},
+    PublicKey,
+};
 use sp_core::{crypto::UncheckedFrom, sr25519, H256};
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
@@ -37,8 +42,18 @@ mod tests;
 #[derive(Debug, PartialEq)]
 pub enum Action {
     Verify = "verify(bytes32,bytes,bytes)",
+    VrfVerify = "vrfVerify(bytes32,bytes,bytes)",
 }
 
+/// Signing context of the VRF transcript, same as the one used for sr25519 signatures.
+pub const VRF_SIGNING_CONTEXT: &[u8] = b"substrate";
+/// Context used to derive the 32 bytes VRF output from the verified in-out pair.
+pub const VRF_OUTPUT_CONTEXT: &[u8] = b"substrate-vrf-output";
+/// Length of the VRF proof accepted by the precompile, pre-output followed by the proof.
+pub const VRF_SIGNATURE_LENGTH: usize = VRF_OUTPUT_LENGTH + VRF_PROOF_LENGTH;
+/// Gas charged for VRF verification, which is executed in the runtime without a host function.
+pub const VRF_VERIFY_GAS_COST: u64 = 150_000;
+
 /// A precompile to wrap substrate sr25519 functions.
 pub struct Sr25519Precompile<Runtime>(PhantomData<Runtime>);
 
@@ -53,6 +68,7 @@ impl<Runtime: pallet_evm::Config> Precompile for Sr25519Precompile<Runtime> {
         match selector {
             // Dispatchables
             Action::Verify => Self::verify(handle),
+            Action::VrfVerify => Self::vrf_verify(handle),
         }
     }
 }
@@ -93,4 +109,65 @@ impl<Runtime: pallet_evm::Config> Sr25519Precompile<Runtime> {
 
         Ok(succeed(EvmDataWriter::new().write(is_confirmed).build()))
     }
+    fn vrf_verify(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_cost(VRF_VERIFY_GAS_COST)?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+
+        // Parse arguments
+        let public_bytes = input.read::<H256>()?;
+        let vrf_input: Vec<u8> = input.read::<Bytes>()?.into();
+        let signature_bytes: Vec<u8> = input.read::<Bytes>()?.into();
+
+        // Return `(false, 0)` if any of the arguments is malformed or the proof is invalid
+        let not_verified = || {
+            Ok(succeed(
+                EvmDataWriter::new()
+                    .write(false)
+                    .write(H256::zero())
+                    .build(),
+            ))
+        };
+
+        if signature_bytes.len() != VRF_SIGNATURE_LENGTH {
+            return not_verified();
+        }
+        let (output_bytes, proof_bytes) = signature_bytes.split_at(VRF_OUTPUT_LENGTH);
+
+        let (public, output, proof) = match (
+            PublicKey::from_bytes(public_bytes.as_bytes()),
+            VRFOutput::from_bytes(output_bytes),
+            VRFProof::from_bytes(proof_bytes),
+        ) {
+            (Ok(public), Ok(output), Ok(proof)) => (public, output, proof),
+            _ => return not_verified(),
+        };
+
+        log::trace!(
+            target: "sr25519-precompile",
+            "Verify VRF proof {:?} for public {:?} and input {:?}",
+            signature_bytes, public_bytes, vrf_input,
+        );
+
+        let transcript = signing_context(VRF_SIGNING_CONTEXT).bytes(&vrf_input[..]);
+        let inout = match public.vrf_verify(transcript, &output, &proof) {
+            Ok((inout, _)) => inout,
+            Err(_) => return not_verified(),
+        };
+        let vrf_output: [u8; 32] = inout.make_bytes(VRF_OUTPUT_CONTEXT);
+
+        log::trace!(
+            target: "sr25519-precompile",
+            "Verified VRF proof {:?} with output {:?}",
+            signature_bytes, vrf_output,
+        );
+
+        Ok(succeed(
+            EvmDataWriter::new()
+                .write(true)
+                .write(H256::from(vrf_output))
+                .build(),
+        ))
+    }
 }
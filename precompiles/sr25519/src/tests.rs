@@ -106,3 +106,86 @@ fn substrate_test_vector_works() {
             .execute_returns(EvmDataWriter::new().write(true).build());
     });
 }
+
+const VRF_SEED: &[u8; 32] = b"12345678901234567890123456789012";
+
+fn vrf_sign(input: &[u8]) -> (Vec<u8>, [u8; 32]) {
+    use schnorrkel::{signing_context, ExpansionMode, MiniSecretKey};
+
+    // Same derivation as `sr25519::Pair::from_seed`
+    let keypair = MiniSecretKey::from_bytes(&VRF_SEED[..])
+        .unwrap()
+        .expand_to_keypair(ExpansionMode::Ed25519);
+    let (inout, proof, _) = keypair.vrf_sign(signing_context(VRF_SIGNING_CONTEXT).bytes(input));
+
+    let mut signature = inout.to_output().to_bytes().to_vec();
+    signature.extend_from_slice(&proof.to_bytes());
+    (signature, inout.make_bytes(VRF_OUTPUT_CONTEXT))
+}
+
+#[test]
+fn vrf_verify_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(VRF_SEED);
+        let input = b"round #1";
+        let (signature, output) = vrf_sign(input);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::VrfVerify)
+                    .write(H256::from(pair.public()))
+                    .write(Bytes::from(&input[..]))
+                    .write(Bytes::from(&signature[..]))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(true)
+                    .write(H256::from(output))
+                    .build(),
+            );
+    });
+}
+
+#[test]
+fn vrf_verify_bad_proof_returns_false() {
+    ExtBuilder::default().build().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(VRF_SEED);
+        let (signature, _) = vrf_sign(b"round #1");
+        let not_verified = EvmDataWriter::new()
+            .write(false)
+            .write(H256::zero())
+            .build();
+
+        // Proof for a different input
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::VrfVerify)
+                    .write(H256::from(pair.public()))
+                    .write(Bytes::from(&b"round #2"[..]))
+                    .write(Bytes::from(&signature[..]))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(not_verified.clone());
+
+        // Wrong proof length
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::VrfVerify)
+                    .write(H256::from(pair.public()))
+                    .write(Bytes::from(&b"round #1"[..]))
+                    .write(Bytes::from(&signature[1..]))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(not_verified);
+    });
+}
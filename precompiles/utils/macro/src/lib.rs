@@ -23,11 +23,16 @@
 #![crate_type = "proc-macro"]
 extern crate proc_macro;
 
+mod precompile;
+
 use proc_macro::TokenStream;
 use proc_macro2::Literal;
 use quote::{quote, quote_spanned};
 use sha3::{Digest, Keccak256};
-use syn::{parse_macro_input, spanned::Spanned, Expr, ExprLit, Ident, ItemEnum, Lit, LitStr};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Attribute, Data, DataEnum, DeriveInput, Expr,
+    ExprLit, Fields, Generics, Ident, Index, ItemEnum, Lit, LitStr,
+};
 
 struct Bytes(Vec<u8>);
 
@@ -63,6 +68,94 @@ pub fn keccak256(input: TokenStream) -> TokenStream {
     quote!(#eval_ts).into()
 }
 
+/// Check a Solidity function signature is in its canonical form, e.g. `transfer(address,uint256)`,
+/// since any other form (spaces, argument names) would produce a different selector.
+fn check_signature(signature: &str) -> Result<(), String> {
+    let name_end = signature
+        .find('(')
+        .ok_or_else(|| format!("Missing arguments list in signature \"{}\"", signature))?;
+    let name = &signature[..name_end];
+
+    let valid_name = name
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid_name {
+        return Err(format!(
+            "Invalid function name in signature \"{}\"",
+            signature
+        ));
+    }
+
+    let arguments = &signature[name_end..];
+    let valid_arguments = arguments.ends_with(')')
+        && arguments
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "(),[]".contains(c));
+    if !valid_arguments {
+        return Err(format!(
+            "Signature \"{}\" is not canonical, expected e.g. \"transfer(address,uint256)\"",
+            signature
+        ));
+    }
+
+    Ok(())
+}
+
+/// Number of top-level arguments of a canonical Solidity signature.
+fn count_arguments(signature: &str) -> usize {
+    let arguments = match signature.find('(') {
+        Some(start) => &signature[start + 1..signature.len() - 1],
+        None => return 0,
+    };
+    if arguments.is_empty() {
+        return 0;
+    }
+
+    let mut depth = 0usize;
+    let mut count = 1;
+    for c in arguments.chars() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => count += 1,
+            _ => {}
+        }
+    }
+    count
+}
+
+/// First 4 bytes of the Keccak256 hash of a signature.
+fn signature_selector(signature: &str) -> u32 {
+    let digest = Keccak256::digest(signature.as_bytes());
+    u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]])
+}
+
+/// Compute the 4 bytes selector of a Solidity function signature at compile time, as a `u32`.
+/// The signature must be in its canonical form, otherwise compilation fails.
+///
+/// Usage:
+///
+/// ```ignore
+/// const SELECTOR_TRANSFER: u32 = selector!("transfer(address,uint256)");
+/// ```
+#[proc_macro]
+pub fn selector(input: TokenStream) -> TokenStream {
+    let lit_str = parse_macro_input!(input as LitStr);
+    let signature = lit_str.value();
+
+    if let Err(error) = check_signature(&signature) {
+        return quote_spanned! {
+            lit_str.span() => compile_error!(#error)
+        }
+        .into();
+    }
+
+    let selector = Literal::u32_suffixed(signature_selector(&signature));
+    quote!(#selector).into()
+}
+
 /// This macro allows to associate to each variant of an enumeration a discriminant (of type u32
 /// whose value corresponds to the first 4 bytes of the Hash Keccak256 of the character string
 ///indicated by the user of this macro.
@@ -77,6 +170,10 @@ pub fn keccak256(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// Variants can be marked `#[view]` or `#[payable]`, the other ones being non-payable, in which
+/// case a `modifier` method returning the `FunctionModifier` of a selector is also generated,
+/// to be passed to `check_function_modifier` before dispatching.
+///
 /// Extanded to:
 ///
 /// ```rust
@@ -85,6 +182,13 @@ pub fn keccak256(input: TokenStream) -> TokenStream {
 ///     Toto = 119097542u32,
 ///     Tata = 1414311903u32,
 /// }
+///
+/// impl Action {
+///     pub const SELECTORS: &'static [(u32, &'static str)] = &[
+///         (119097542u32, "toto()"),
+///         (1414311903u32, "tata()"),
+///     ];
+/// }
 /// ```
 ///
 #[proc_macro_attribute]
@@ -101,15 +205,32 @@ pub fn generate_function_selector(_: TokenStream, input: TokenStream) -> TokenSt
     } = item;
 
     let mut ident_expressions: Vec<Ident> = vec![];
+    let mut variant_attrs: Vec<Vec<Attribute>> = vec![];
+    let mut modifiers: Vec<Option<Ident>> = vec![];
     let mut variant_expressions: Vec<Expr> = vec![];
-    for variant in variants {
+    let mut signatures: Vec<LitStr> = vec![];
+    for mut variant in variants {
+        match take_modifier(&mut variant.attrs) {
+            Ok(modifier) => modifiers.push(modifier),
+            Err(error) => return error.to_compile_error().into(),
+        }
+        variant_attrs.push(std::mem::take(&mut variant.attrs));
+
         match variant.discriminant {
             Some((_, Expr::Lit(ExprLit { lit, .. }))) => {
                 if let Lit::Str(lit_str) = lit {
+                    if let Err(error) = check_signature(&lit_str.value()) {
+                        return quote_spanned! {
+                            lit_str.span() => compile_error!(#error);
+                        }
+                        .into();
+                    }
+
                     let digest = Keccak256::digest(lit_str.value().as_bytes());
                     let selector = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
 
                     ident_expressions.push(variant.ident);
+                    signatures.push(lit_str);
                     variant_expressions.push(Expr::Lit(ExprLit {
                         lit: Lit::Verbatim(Literal::u32_suffixed(selector)),
                         attrs: Default::default(),
@@ -136,15 +257,465 @@ pub fn generate_function_selector(_: TokenStream, input: TokenStream) -> TokenSt
         }
     }
 
+    // Only generated when modifiers are declared, as it requires `precompile_utils` in scope.
+    let modifier_fn = if modifiers.iter().any(Option::is_some) {
+        let modifiers = modifiers.iter().map(|modifier| match modifier {
+            Some(modifier) => quote!(::precompile_utils::FunctionModifier::#modifier),
+            None => quote!(::precompile_utils::FunctionModifier::NonPayable),
+        });
+        quote! {
+            /// Modifier of the function of the selector, to be checked before dispatching to it.
+            pub fn modifier(&self) -> ::precompile_utils::FunctionModifier {
+                match self {
+                    #( Self::#ident_expressions => #modifiers, )*
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
     (quote! {
         #(#attrs)*
         #[derive(num_enum::TryFromPrimitive, num_enum::IntoPrimitive)]
         #[repr(u32)]
         #vis #enum_token #ident {
             #(
+                #(#variant_attrs)*
                 #ident_expressions = #variant_expressions,
             )*
         }
+
+        impl #ident {
+            /// Selectors of all the variants, paired with their function signatures.
+            pub const SELECTORS: &'static [(u32, &'static str)] = &[
+                #(
+                    (#variant_expressions, #signatures),
+                )*
+            ];
+
+            #modifier_fn
+        }
+    })
+    .into()
+}
+
+/// Extract and remove the `#[view]` or `#[payable]` attribute of a selector variant.
+fn take_modifier(attrs: &mut Vec<Attribute>) -> syn::Result<Option<Ident>> {
+    let mut modifier: Option<Ident> = None;
+    let mut kept = vec![];
+
+    for attr in attrs.drain(..) {
+        let name = if attr.path.is_ident("view") {
+            "View"
+        } else if attr.path.is_ident("payable") {
+            "Payable"
+        } else {
+            kept.push(attr);
+            continue;
+        };
+
+        if modifier.is_some() {
+            return Err(syn::Error::new(attr.span(), "Duplicate modifier attribute"));
+        }
+        modifier = Some(Ident::new(name, attr.span()));
+    }
+    *attrs = kept;
+
+    Ok(modifier)
+}
+
+/// Generates the `Precompile` implementation of a precompile from its inherent methods.
+///
+/// Every method marked with `#[precompile::public("signature")]` becomes a variant of the
+/// selector enum named by the first attribute argument, and is dispatched to with its arguments
+/// decoded from the input. Methods are `NonPayable` unless marked `#[precompile::view]` or
+/// `#[precompile::payable]`. The returned value is encoded with `encode_return_value`.
+///
+/// The second attribute argument is the runtime, whose base cost (`record_base_cost`) is charged
+/// before the selector and arguments are decoded. Methods only record their additional costs.
+///
+/// Usage:
+///
+/// ```ignore
+/// #[precompile_utils::precompile(Action, R)]
+/// impl<R: pallet_evm::Config> MyPrecompile<R> {
+///     #[precompile::public("balanceOf(address)")]
+///     #[precompile::view]
+///     fn balance_of(handle: &mut impl PrecompileHandle, who: Address) -> EvmResult<U256> {
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// The example generates `enum Action { BalanceOf = "balanceOf(address)" }` as if declared with
+/// `#[generate_function_selector]`.
+///
+/// Variants are named after the methods, so Solidity overloads are implemented by one method
+/// each, every signature getting its own selector:
+///
+/// ```ignore
+/// #[precompile::public("transfer(address,uint256)")]
+/// fn transfer(handle: &mut impl PrecompileHandle, to: Address, value: U256) -> EvmResult<bool> {
+///     Self::transfer_with_memo(handle, to, value, H256::zero())
+/// }
+///
+/// #[precompile::public("transfer(address,uint256,bytes32)")]
+/// fn transfer_with_memo(
+///     handle: &mut impl PrecompileHandle,
+///     to: Address,
+///     value: U256,
+///     memo: H256,
+/// ) -> EvmResult<bool> {
+///     // ...
+/// }
+/// ```
+///
+/// Both signatures are listed in `Action::SELECTORS`, and thus in the generated Solidity
+/// interface.
+#[proc_macro_attribute]
+pub fn precompile(attr: TokenStream, input: TokenStream) -> TokenStream {
+    precompile::main(attr, input)
+}
+
+/// Derives `EvmData` for a struct, encoding it as a Solidity struct, i.e. a tuple of its fields
+/// in declaration order.
+///
+/// Encoding is delegated to the tuple implementation, so static and dynamic fields can be mixed
+/// and the struct has a static size only if all of its fields have one.
+/// Like tuples, structs can have up to 18 fields.
+///
+/// Structs marked with `#[evm_data(explicit_tuple)]` stand for multiple named values instead,
+/// such as the return values of a function: returned by a precompile or written with
+/// `EvmDataWriter::write_arguments`, their fields are encoded as separate values, not as
+/// a single tuple prefixed by its offset.
+///
+/// Fieldless enums are encoded as a Solidity enum, i.e. a `uint8` holding the index of the
+/// variant in declaration order. Explicit discriminants are ignored, and reading a value
+/// without a matching variant reverts.
+///
+/// Usage:
+///
+/// ```ignore
+/// #[derive(EvmData)]
+/// struct Transfer {
+///     to: Address,
+///     amount: U256,
+///     data: Bytes,
+/// }
+///
+/// #[derive(EvmData)]
+/// #[evm_data(explicit_tuple)]
+/// struct Balances {
+///     free: U256,
+///     holders: Vec<Address>,
+/// }
+///
+/// #[derive(EvmData)]
+/// enum RewardDestination {
+///     FreeBalance,
+///     StakeBalance,
+/// }
+/// ```
+#[proc_macro_derive(EvmData, attributes(evm_data))]
+pub fn derive_evm_data(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident,
+        mut generics,
+        data,
+        attrs,
+        ..
+    } = parse_macro_input!(input as DeriveInput);
+
+    let explicit_tuple = match attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("evm_data"))
+        .map(|attr| attr.parse_args::<Ident>())
+    {
+        Some(Ok(arg)) if arg == "explicit_tuple" => true,
+        Some(Ok(arg)) => return quote_spanned! {
+            arg.span() => compile_error!("Unknown evm_data argument, expected `explicit_tuple`");
+        }
+        .into(),
+        Some(Err(error)) => return error.to_compile_error().into(),
+        None => false,
+    };
+
+    let fields = match data {
+        Data::Struct(data) => data.fields,
+        Data::Enum(_) if explicit_tuple => {
+            return quote_spanned! {
+                ident.span() => compile_error!("Only structs can be explicit tuples");
+            }
+            .into()
+        }
+        Data::Enum(data) => return derive_evm_data_enum(ident, generics, data),
+        Data::Union(_) => {
+            return quote_spanned! {
+                ident.span() => compile_error!("EvmData can't be derived for unions");
+            }
+            .into()
+        }
+    };
+
+    let types: Vec<_> = fields.iter().map(|field| field.ty.clone()).collect();
+    let indexes: Vec<_> = (0..types.len()).map(Index::from).collect();
+
+    // Fields are read into a tuple, and written from one, in declaration order.
+    let (read_fields, write_fields, field_refs): (_, _, Vec<_>) = match &fields {
+        Fields::Named(named) => {
+            let names: Vec<_> = named.named.iter().map(|f| f.ident.clone()).collect();
+            (
+                quote!({ #(#names: items.#indexes),* }),
+                quote!(#(value.#names,)*),
+                names.iter().map(|name| quote!(&value.#name)).collect(),
+            )
+        }
+        Fields::Unnamed(_) => (
+            quote!(( #(items.#indexes),* )),
+            quote!(#(value.#indexes,)*),
+            indexes.iter().map(|index| quote!(&value.#index)).collect(),
+        ),
+        Fields::Unit => {
+            return quote_spanned! {
+                ident.span() => compile_error!("EvmData can't be derived for unit structs");
+            }
+            .into()
+        }
+    };
+
+    let tuple = quote!((#(#types,)*));
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#tuple: ::precompile_utils::data::EvmData));
+    // Size hints are computed field by field, as a tuple can't be built from references.
+    for ty in &types {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#ty: ::precompile_utils::data::EvmData));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // Explicit tuples stand for multiple values, which are read & written without the offset
+    // of the tuple when used as arguments.
+    let explicit_tuple_fns = if explicit_tuple {
+        quote! {
+            fn read_arguments(
+                reader: &mut ::precompile_utils::data::EvmDataReader,
+            ) -> ::precompile_utils::EvmResult<Self> {
+                let items = reader.read_arguments::<#tuple>()?;
+                Ok(Self #read_fields)
+            }
+
+            fn write_arguments(writer: &mut ::precompile_utils::data::EvmDataWriter, value: Self) {
+                <#tuple as ::precompile_utils::data::EvmData>::write_arguments(writer, (#write_fields));
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    (quote! {
+        impl #impl_generics ::precompile_utils::data::EvmData for #ident #ty_generics #where_clause {
+            #explicit_tuple_fns
+
+            fn read(
+                reader: &mut ::precompile_utils::data::EvmDataReader,
+            ) -> ::precompile_utils::EvmResult<Self> {
+                let items = reader.read::<#tuple>()?;
+                Ok(Self #read_fields)
+            }
+
+            fn write(writer: &mut ::precompile_utils::data::EvmDataWriter, value: Self) {
+                <#tuple as ::precompile_utils::data::EvmData>::write(writer, (#write_fields));
+            }
+
+            fn has_static_size() -> bool {
+                <#tuple as ::precompile_utils::data::EvmData>::has_static_size()
+            }
+
+            fn minimum_encoded_size() -> usize {
+                <#tuple as ::precompile_utils::data::EvmData>::minimum_encoded_size()
+            }
+
+            fn encoded_size_hint(value: &Self) -> usize {
+                let size = 0 #( + <#types as ::precompile_utils::data::EvmData>::encoded_size_hint(#field_refs) )*;
+                if <Self as ::precompile_utils::data::EvmData>::has_static_size() {
+                    size
+                } else {
+                    32 + size
+                }
+            }
+
+            fn encode_in_place(value: Self) -> ::precompile_utils::__private::Vec<u8> {
+                <#tuple as ::precompile_utils::data::EvmData>::encode_in_place((#write_fields))
+            }
+
+            fn encode_topic(value: Self) -> ::precompile_utils::__private::H256 {
+                <#tuple as ::precompile_utils::data::EvmData>::encode_topic((#write_fields))
+            }
+        }
+    })
+    .into()
+}
+
+fn derive_evm_data_enum(ident: Ident, generics: Generics, data: DataEnum) -> TokenStream {
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return quote_spanned! {
+            variant.span() => compile_error!("EvmData can only be derived for fieldless enums");
+        }
+        .into();
+    }
+
+    if data.variants.is_empty() || data.variants.len() > 256 {
+        return quote_spanned! {
+            ident.span() => compile_error!("EvmData enums must have between 1 and 256 variants");
+        }
+        .into();
+    }
+
+    let variants: Vec<_> = data.variants.iter().map(|variant| &variant.ident).collect();
+    let indexes: Vec<_> = (0..variants.len())
+        .map(|i| Literal::u8_suffixed(i as u8))
+        .collect();
+    let out_of_range = LitStr::new(&format!("{} out of range", ident), ident.span());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    (quote! {
+        impl #impl_generics ::precompile_utils::data::EvmData for #ident #ty_generics #where_clause {
+            fn read(
+                reader: &mut ::precompile_utils::data::EvmDataReader,
+            ) -> ::precompile_utils::EvmResult<Self> {
+                let word = reader.read::<::precompile_utils::__private::H256>()?;
+                if word.as_bytes()[..31].iter().any(|byte| *byte != 0) {
+                    return Err(::precompile_utils::revert(#out_of_range));
+                }
+
+                match word.as_bytes()[31] {
+                    #( #indexes => Ok(Self::#variants), )*
+                    _ => Err(::precompile_utils::revert(#out_of_range)),
+                }
+            }
+
+            fn write(writer: &mut ::precompile_utils::data::EvmDataWriter, value: Self) {
+                let index: u8 = match value {
+                    #( Self::#variants => #indexes, )*
+                };
+                <u8 as ::precompile_utils::data::EvmData>::write(writer, index);
+            }
+
+            fn has_static_size() -> bool {
+                true
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives `EvmError` for a struct, making it a Solidity custom error whose arguments are
+/// the fields of the struct in declaration order.
+///
+/// The canonical signature of the error is provided with the `evm_error` attribute, and must
+/// have as many arguments as the struct has fields.
+///
+/// Usage:
+///
+/// ```ignore
+/// #[derive(EvmError)]
+/// #[evm_error("InsufficientBalance(uint256,uint256)")]
+/// struct InsufficientBalance {
+///     available: U256,
+///     required: U256,
+/// }
+/// ```
+#[proc_macro_derive(EvmError, attributes(evm_error))]
+pub fn derive_evm_error(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident,
+        generics,
+        data,
+        attrs,
+        ..
+    } = parse_macro_input!(input as DeriveInput);
+
+    let fields = match data {
+        Data::Struct(data) => data.fields,
+        _ => {
+            return quote_spanned! {
+                ident.span() => compile_error!("EvmError can only be derived for structs");
+            }
+            .into()
+        }
+    };
+
+    let signature =
+        match attrs
+            .iter()
+            .find(|attr| attr.path.is_ident("evm_error"))
+            .map(|attr| attr.parse_args::<LitStr>())
+        {
+            Some(Ok(signature)) => signature,
+            Some(Err(error)) => return error.to_compile_error().into(),
+            None => return quote_spanned! {
+                ident.span() => compile_error!("Missing #[evm_error(\"Signature(..)\")] attribute");
+            }
+            .into(),
+        };
+
+    if let Err(error) = check_signature(&signature.value()) {
+        return quote_spanned! {
+            signature.span() => compile_error!(#error);
+        }
+        .into();
+    }
+
+    if count_arguments(&signature.value()) != fields.len() {
+        let error = format!(
+            "Signature has {} arguments but the struct has {} fields",
+            count_arguments(&signature.value()),
+            fields.len()
+        );
+        return quote_spanned! {
+            signature.span() => compile_error!(#error);
+        }
+        .into();
+    }
+
+    let arguments: Vec<_> = match &fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|f| {
+                let name = &f.ident;
+                quote!(self.#name)
+            })
+            .collect(),
+        _ => (0..fields.len())
+            .map(|i| {
+                let index = Index::from(i);
+                quote!(self.#index)
+            })
+            .collect(),
+    };
+
+    let selector = Literal::u32_suffixed(signature_selector(&signature.value()));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    (quote! {
+        impl #impl_generics ::precompile_utils::EvmError for #ident #ty_generics #where_clause {
+            const SELECTOR: u32 = #selector;
+            const SIGNATURE: &'static str = #signature;
+
+            fn encode_arguments(self) -> ::precompile_utils::__private::Vec<u8> {
+                ::precompile_utils::data::encode_arguments((#(#arguments,)*))
+            }
+        }
     })
     .into()
 }
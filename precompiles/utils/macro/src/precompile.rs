@@ -0,0 +1,309 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    spanned::Spanned,
+    Attribute, FnArg, Ident, ImplItem, ItemImpl, LitStr, Pat, Token, Type,
+};
+
+use crate::{check_signature, count_arguments, signature_selector};
+
+/// A method exposed to Solidity.
+struct PublicMethod {
+    ident: Ident,
+    variant: Ident,
+    signature: LitStr,
+    modifier: Ident,
+    arguments: Vec<Type>,
+    argument_names: Vec<String>,
+}
+
+/// Arguments of the attribute: the selector enum to generate and the runtime the base cost is
+/// charged for, e.g. `#[precompile(Action, Runtime)]`.
+struct PrecompileArgs {
+    enum_ident: Ident,
+    runtime: Type,
+}
+
+impl Parse for PrecompileArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let enum_ident = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let runtime = input.parse()?;
+        Ok(Self {
+            enum_ident,
+            runtime,
+        })
+    }
+}
+
+pub fn main(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as PrecompileArgs);
+    let mut item = parse_macro_input!(input as ItemImpl);
+
+    match expand(args, &mut item) {
+        Ok(expanded) => expanded.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(args: PrecompileArgs, item: &mut ItemImpl) -> syn::Result<TokenStream2> {
+    let PrecompileArgs {
+        enum_ident,
+        runtime,
+    } = args;
+    let mut methods: Vec<PublicMethod> = vec![];
+
+    for impl_item in item.items.iter_mut() {
+        let method = match impl_item {
+            ImplItem::Method(method) => method,
+            _ => continue,
+        };
+
+        let (signature, modifier) = match parse_attributes(&mut method.attrs)? {
+            Some(parsed) => parsed,
+            None => continue,
+        };
+
+        check_signature(&signature.value())
+            .map_err(|error| syn::Error::new(signature.span(), error))?;
+
+        let mut inputs = method.sig.inputs.iter();
+        match inputs.next() {
+            Some(FnArg::Typed(_)) => {}
+            _ => {
+                return Err(syn::Error::new(
+                    method.sig.span(),
+                    "Public methods must take the precompile handle as first argument",
+                ))
+            }
+        }
+
+        let (arguments, argument_names): (Vec<_>, Vec<_>) = inputs
+            .enumerate()
+            .map(|(i, input)| match input {
+                FnArg::Typed(pat_type) => {
+                    // Named in revert reasons, patterns other than plain idents get a placeholder.
+                    let name = match &*pat_type.pat {
+                        Pat::Ident(pat) => pat.ident.to_string(),
+                        _ => format!("arg{}", i),
+                    };
+                    Ok(((*pat_type.ty).clone(), name))
+                }
+                FnArg::Receiver(receiver) => Err(syn::Error::new(
+                    receiver.span(),
+                    "Public methods can't take `self`",
+                )),
+            })
+            .collect::<syn::Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        let expected = count_arguments(&signature.value());
+        if expected != arguments.len() {
+            return Err(syn::Error::new(
+                signature.span(),
+                format!(
+                    "Signature has {} arguments but the method takes {}",
+                    expected,
+                    arguments.len()
+                ),
+            ));
+        }
+
+        if methods
+            .iter()
+            .any(|m| m.signature.value() == signature.value())
+        {
+            return Err(syn::Error::new(signature.span(), "Duplicate signature"));
+        }
+
+        // Overloads only differ by their arguments, so they can't be told apart by a clash of
+        // selectors or variant names at runtime.
+        let selector = signature_selector(&signature.value());
+        if let Some(other) = methods
+            .iter()
+            .find(|m| signature_selector(&m.signature.value()) == selector)
+        {
+            return Err(syn::Error::new(
+                signature.span(),
+                format!(
+                    "Selector 0x{:08x} collides with the one of \"{}\"",
+                    selector,
+                    other.signature.value()
+                ),
+            ));
+        }
+
+        let variant = Ident::new(
+            &to_upper_camel_case(&method.sig.ident.to_string()),
+            method.sig.ident.span(),
+        );
+        if let Some(other) = methods.iter().find(|m| m.variant == variant) {
+            return Err(syn::Error::new(
+                method.sig.ident.span(),
+                format!(
+                    "Variant `{}` is already generated by method `{}`, rename one of them",
+                    variant, other.ident
+                ),
+            ));
+        }
+
+        methods.push(PublicMethod {
+            ident: method.sig.ident.clone(),
+            variant,
+            signature,
+            modifier,
+            arguments,
+            argument_names,
+        });
+    }
+
+    if methods.is_empty() {
+        return Err(syn::Error::new(
+            item.span(),
+            "No method marked with #[precompile::public(..)]",
+        ));
+    }
+
+    let variants: Vec<_> = methods.iter().map(|m| &m.variant).collect();
+    let signatures: Vec<_> = methods.iter().map(|m| &m.signature).collect();
+    let arms = methods.iter().map(|method| {
+        let PublicMethod {
+            ident,
+            variant,
+            modifier,
+            arguments,
+            argument_names,
+            ..
+        } = method;
+        let names: Vec<_> = (0..arguments.len())
+            .map(|i| format_ident!("arg{}", i))
+            .collect();
+        let count = arguments.len();
+
+        quote! {
+            #enum_ident::#variant => {
+                ::precompile_utils::PrecompileHandleExt::check_function_modifier(
+                    &*handle,
+                    ::precompile_utils::FunctionModifier::#modifier,
+                )?;
+
+                let (#(#names,)*) = {
+                    let mut input = ::precompile_utils::PrecompileHandleExt::read_input(&*handle)?;
+                    input.expect_arguments(#count)?;
+                    (#(input.read_named::<#arguments>(#argument_names)?,)*)
+                };
+
+                let output = Self::#ident(handle, #(#names),*)?;
+                Ok(::precompile_utils::succeed(
+                    ::precompile_utils::data::encode_return_value(output),
+                ))
+            }
+        }
+    });
+
+    let self_ty = &item.self_ty;
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+
+    Ok(quote! {
+        #[::precompile_utils::generate_function_selector]
+        #[derive(Debug, PartialEq)]
+        pub enum #enum_ident {
+            #(
+                #variants = #signatures,
+            )*
+        }
+
+        #item
+
+        impl #impl_generics ::precompile_utils::__private::Precompile for #self_ty #where_clause {
+            fn execute(
+                handle: &mut impl ::precompile_utils::__private::PrecompileHandle,
+            ) -> ::precompile_utils::EvmResult<::precompile_utils::__private::PrecompileOutput> {
+                // Charged before the input is decoded, so no selector decodes it for free.
+                ::precompile_utils::PrecompileHandleExt::record_base_cost::<#runtime>(&mut *handle)?;
+
+                let selector =
+                    ::precompile_utils::PrecompileHandleExt::read_selector::<#enum_ident>(&*handle)?;
+
+                match selector {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Extract and remove the `#[precompile::..]` attributes of a method.
+/// Returns its signature and function modifier if it is public.
+fn parse_attributes(attrs: &mut Vec<Attribute>) -> syn::Result<Option<(LitStr, Ident)>> {
+    let mut signature = None;
+    let mut modifier = None;
+    let mut kept = vec![];
+
+    for attr in attrs.drain(..) {
+        let segments: Vec<_> = attr.path.segments.iter().map(|s| &s.ident).collect();
+        if segments.len() != 2 || segments[0] != "precompile" {
+            kept.push(attr);
+            continue;
+        }
+
+        let span = attr.span();
+        match segments[1].to_string().as_str() {
+            "public" if signature.is_none() => signature = Some(attr.parse_args::<LitStr>()?),
+            "view" if modifier.is_none() => modifier = Some(Ident::new("View", span)),
+            "payable" if modifier.is_none() => modifier = Some(Ident::new("Payable", span)),
+            "public" | "view" | "payable" => {
+                return Err(syn::Error::new(span, "Duplicate precompile attribute"))
+            }
+            _ => return Err(syn::Error::new(span, "Unknown precompile attribute")),
+        }
+    }
+    *attrs = kept;
+
+    match (signature, modifier) {
+        (Some(signature), modifier) => Ok(Some((
+            signature,
+            modifier.unwrap_or_else(|| Ident::new("NonPayable", Span::call_site())),
+        ))),
+        (None, Some(modifier)) => Err(syn::Error::new(
+            modifier.span(),
+            "Modifier without #[precompile::public(..)]",
+        )),
+        (None, None) => Ok(None),
+    }
+}
+
+fn to_upper_camel_case(ident: &str) -> String {
+    ident
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -44,6 +44,18 @@ fn test_keccak256() {
     );
 }
 
+#[test]
+fn test_selector() {
+    const TOTO: u32 = precompile_utils_macro::selector!("toto()");
+
+    assert_eq!(&TOTO.to_be_bytes()[..], &Keccak256::digest(b"toto()")[0..4]);
+    assert_eq!(TOTO, Action::Toto as u32);
+    assert_eq!(
+        precompile_utils_macro::selector!("transfer(address,uint256)"),
+        0xa9059cbb
+    );
+}
+
 #[test]
 fn test_generate_function_selector() {
     assert_eq!(
@@ -56,3 +68,14 @@ fn test_generate_function_selector() {
     );
     assert_ne!(Action::Toto as u32, Action::Tata as u32);
 }
+
+#[test]
+fn test_generate_function_selector_lists_selectors() {
+    assert_eq!(
+        Action::SELECTORS,
+        &[
+            (Action::Toto as u32, "toto()"),
+            (Action::Tata as u32, "tata()"),
+        ]
+    );
+}
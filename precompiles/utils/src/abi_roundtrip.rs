@@ -0,0 +1,276 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Randomized round-trip tests of the encoding of nested data, checked against a reference
+//! encoder following the Solidity ABI specification.
+//!
+//! Values are generated from fixed seeds so failures are reproducible. Encoding is also checked
+//! behind a selector, since offsets are then baked into a buffer which doesn't start at 0.
+
+use super::*;
+use core::fmt::Debug;
+
+const ITERATIONS: usize = 256;
+
+/// Xorshift generator, enough to explore the shapes of the data.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.next_u64() as u8).collect()
+    }
+}
+
+/// ABI value, as seen by the reference encoder.
+enum Token {
+    Word([u8; 32]),
+    Bytes(Vec<u8>),
+    Array(Vec<Token>),
+    Tuple(Vec<Token>),
+}
+
+fn word(value: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    U256::from(value).to_big_endian(&mut word);
+    word
+}
+
+impl Token {
+    fn is_dynamic(&self) -> bool {
+        match self {
+            Token::Word(_) => false,
+            Token::Bytes(_) | Token::Array(_) => true,
+            Token::Tuple(items) => items.iter().any(Token::is_dynamic),
+        }
+    }
+
+    /// Size of the head of the token in its parent.
+    fn head_len(&self) -> usize {
+        match self {
+            Token::Tuple(items) if !self.is_dynamic() => items.iter().map(Token::head_len).sum(),
+            _ => 32,
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        match self {
+            Token::Word(word) => word.to_vec(),
+            Token::Bytes(bytes) => {
+                let mut output = word(bytes.len()).to_vec();
+                output.extend(bytes);
+                output.resize(32 + (bytes.len() + 31) / 32 * 32, 0);
+                output
+            }
+            Token::Array(items) => {
+                let mut output = word(items.len()).to_vec();
+                output.extend(encode_sequence(items));
+                output
+            }
+            Token::Tuple(items) => encode_sequence(items),
+        }
+    }
+}
+
+/// Encode `tokens` as the members of a tuple: static heads followed by the dynamic tails,
+/// whose offsets are relative to the start of the sequence.
+fn encode_sequence(tokens: &[Token]) -> Vec<u8> {
+    let heads_len: usize = tokens.iter().map(Token::head_len).sum();
+    let mut heads = vec![];
+    let mut tails = vec![];
+    for token in tokens {
+        if token.is_dynamic() {
+            heads.extend(word(heads_len + tails.len()));
+            tails.extend(token.encode());
+        } else {
+            heads.extend(token.encode());
+        }
+    }
+    heads.extend(tails);
+    heads
+}
+
+trait Arbitrary: EvmData + Clone + PartialEq + Debug {
+    fn arbitrary(rng: &mut Rng) -> Self;
+    fn token(&self) -> Token;
+}
+
+impl Arbitrary for U256 {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        if rng.below(2) == 0 {
+            U256::from(rng.below(1000))
+        } else {
+            U256([
+                rng.next_u64(),
+                rng.next_u64(),
+                rng.next_u64(),
+                rng.next_u64(),
+            ])
+        }
+    }
+
+    fn token(&self) -> Token {
+        let mut word = [0u8; 32];
+        self.to_big_endian(&mut word);
+        Token::Word(word)
+    }
+}
+
+impl Arbitrary for bool {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        rng.below(2) == 0
+    }
+
+    fn token(&self) -> Token {
+        Token::Word(word(*self as usize))
+    }
+}
+
+impl Arbitrary for Address {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        Address(H160::from_slice(&rng.bytes(20)))
+    }
+
+    fn token(&self) -> Token {
+        let mut word = [0u8; 32];
+        word[12..].copy_from_slice(self.0.as_bytes());
+        Token::Word(word)
+    }
+}
+
+impl Arbitrary for Bytes {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        // Up to 3 words, to cover lengths on and around word boundaries.
+        let len = rng.below(97) as usize;
+        Bytes(rng.bytes(len))
+    }
+
+    fn token(&self) -> Token {
+        Token::Bytes(self.0.clone())
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Vec<T> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        let len = rng.below(4) as usize;
+        (0..len).map(|_| T::arbitrary(rng)).collect()
+    }
+
+    fn token(&self) -> Token {
+        Token::Array(self.iter().map(T::token).collect())
+    }
+}
+
+impl<T: Arbitrary, const N: usize> Arbitrary for [T; N] {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        core::array::from_fn(|_| T::arbitrary(rng))
+    }
+
+    fn token(&self) -> Token {
+        Token::Tuple(self.iter().map(T::token).collect())
+    }
+}
+
+macro_rules! impl_arbitrary_for_tuple {
+    ($($name:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($name: Arbitrary),+> Arbitrary for ($($name,)+) {
+            fn arbitrary(rng: &mut Rng) -> Self {
+                ($($name::arbitrary(rng),)+)
+            }
+
+            fn token(&self) -> Token {
+                let ($($name,)+) = self;
+                Token::Tuple(vec![$($name.token()),+])
+            }
+        }
+    };
+}
+
+impl_arbitrary_for_tuple!(A, B);
+impl_arbitrary_for_tuple!(A, B, C);
+
+/// Check that random values of `T` are encoded as the reference encoder does, and decoded back.
+fn check_roundtrip<T: Arbitrary>(seed: u64) {
+    let mut rng = Rng(seed);
+    let selector = 0x12345678u32;
+
+    for _ in 0..ITERATIONS {
+        let value = T::arbitrary(&mut rng);
+        let expected = encode_sequence(&[value.token()]);
+
+        let encoded = EvmDataWriter::new().write(value.clone()).build();
+        assert_eq!(encoded, expected, "encoding of {:?}", value);
+        let decoded = EvmDataReader::new(&encoded)
+            .read::<T>()
+            .unwrap_or_else(|_| panic!("decoding of {:?}", value));
+        assert_eq!(decoded, value);
+
+        let encoded = EvmDataWriter::new_with_selector(selector)
+            .write(value.clone())
+            .build();
+        assert_eq!(encoded[..4], selector.to_be_bytes());
+        assert_eq!(
+            encoded[4..],
+            expected,
+            "encoding of {:?} with selector",
+            value
+        );
+        let decoded = EvmDataReader::new_skip_selector(&encoded)
+            .and_then(|mut reader| reader.read::<T>())
+            .unwrap_or_else(|_| panic!("decoding of {:?} with selector", value));
+        assert_eq!(decoded, value);
+    }
+}
+
+#[test]
+fn roundtrip_arrays_of_dynamic_tuples() {
+    check_roundtrip::<Vec<(Address, Vec<Bytes>, U256)>>(1);
+    check_roundtrip::<Vec<Vec<(Bytes, Vec<Address>)>>>(2);
+    check_roundtrip::<Vec<[(U256, Bytes); 2]>>(3);
+}
+
+#[test]
+fn roundtrip_dynamic_tuples_of_arrays() {
+    check_roundtrip::<(Vec<Vec<U256>>, Bytes, (Address, Vec<(Bytes, U256)>))>(4);
+    check_roundtrip::<(U256, Vec<(Vec<Bytes>, (bool, Bytes))>, [Bytes; 2])>(5);
+}
+
+#[test]
+fn roundtrip_fixed_arrays() {
+    check_roundtrip::<[(Address, Vec<U256>); 2]>(6);
+    check_roundtrip::<[[Bytes; 2]; 2]>(7);
+}
+
+#[test]
+fn roundtrip_static_tuples() {
+    check_roundtrip::<((U256, Address), [U256; 3], Vec<bool>)>(8);
+    check_roundtrip::<Vec<((bool, U256), [Address; 2])>>(9);
+}
@@ -0,0 +1,148 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Conversion between EVM addresses and native accounts.
+//!
+//! Precompiles, chain extensions and pallets dealing with both kinds of accounts should resolve
+//! them through the same [`AddressMapper`], configured by the runtime, so an account is never
+//! mapped differently depending on where it comes from.
+//!
+//! Every mapper implements `pallet_evm::AddressMapping` as well, so it can be used as the
+//! `AddressMapping` of `pallet_evm` directly.
+
+use core::marker::PhantomData;
+use parity_scale_codec::Encode;
+use sp_core::{Hasher, H160, H256};
+
+/// Conversion between EVM addresses and native accounts.
+pub trait AddressMapper<AccountId> {
+    /// Native account controlled by the EVM address `address`.
+    fn into_account_id(address: H160) -> AccountId;
+
+    /// EVM address representing the native account `account_id` in the EVM.
+    fn into_h160(account_id: AccountId) -> H160;
+}
+
+/// Maps EVM addresses to the native accounts starting with them, padded with zeros, and native
+/// accounts to their first 20 bytes.
+///
+/// Both directions are consistent with `pallet_evm::EnsureAddressTruncated`.
+pub struct TruncatedAddressMapper;
+
+impl<AccountId> AddressMapper<AccountId> for TruncatedAddressMapper
+where
+    AccountId: From<[u8; 32]> + Into<[u8; 32]>,
+{
+    fn into_account_id(address: H160) -> AccountId {
+        let mut data = [0u8; 32];
+        data[0..20].copy_from_slice(address.as_bytes());
+        data.into()
+    }
+
+    fn into_h160(account_id: AccountId) -> H160 {
+        H160::from_slice(&account_id.into()[0..20])
+    }
+}
+
+/// Maps EVM addresses to native accounts the same way `pallet_evm::HashedAddressMapping` does,
+/// i.e. `H("evm:" ++ address)`, and native accounts to the first 20 bytes of
+/// `H("evm:" ++ SCALE(account_id))`.
+///
+/// Hashing is one way, so the EVM address of an account doesn't map back to it.
+pub struct HashedAddressMapper<H>(PhantomData<H>);
+
+impl<AccountId, H> AddressMapper<AccountId> for HashedAddressMapper<H>
+where
+    AccountId: From<[u8; 32]> + Encode,
+    H: Hasher<Out = H256>,
+{
+    fn into_account_id(address: H160) -> AccountId {
+        let mut data = [0u8; 24];
+        data[0..4].copy_from_slice(b"evm:");
+        data[4..24].copy_from_slice(address.as_bytes());
+        H::hash(&data).to_fixed_bytes().into()
+    }
+
+    fn into_h160(account_id: AccountId) -> H160 {
+        let data = (b"evm:", account_id);
+        H160::from_slice(&H::hash(&data.encode())[0..20])
+    }
+}
+
+/// Explicit links between native accounts and EVM addresses, e.g. claimed by their owner.
+pub trait UnifiedAccounts<AccountId> {
+    /// Native account linked to the EVM address `address`, if any.
+    fn native_account(address: &H160) -> Option<AccountId>;
+
+    /// EVM address linked to the native account `account_id`, if any.
+    fn evm_address(account_id: &AccountId) -> Option<H160>;
+}
+
+impl<AccountId> UnifiedAccounts<AccountId> for () {
+    fn native_account(_: &H160) -> Option<AccountId> {
+        None
+    }
+
+    fn evm_address(_: &AccountId) -> Option<H160> {
+        None
+    }
+}
+
+/// Resolves accounts linked in `U` to each other, and falls back to `M` for the others.
+pub struct UnifiedAddressMapper<U, M>(PhantomData<(U, M)>);
+
+impl<AccountId, U, M> AddressMapper<AccountId> for UnifiedAddressMapper<U, M>
+where
+    U: UnifiedAccounts<AccountId>,
+    M: AddressMapper<AccountId>,
+{
+    fn into_account_id(address: H160) -> AccountId {
+        U::native_account(&address).unwrap_or_else(|| M::into_account_id(address))
+    }
+
+    fn into_h160(account_id: AccountId) -> H160 {
+        U::evm_address(&account_id).unwrap_or_else(|| M::into_h160(account_id))
+    }
+}
+
+impl<AccountId> pallet_evm::AddressMapping<AccountId> for TruncatedAddressMapper
+where
+    Self: AddressMapper<AccountId>,
+{
+    fn into_account_id(address: H160) -> AccountId {
+        <Self as AddressMapper<AccountId>>::into_account_id(address)
+    }
+}
+
+impl<AccountId, H> pallet_evm::AddressMapping<AccountId> for HashedAddressMapper<H>
+where
+    Self: AddressMapper<AccountId>,
+{
+    fn into_account_id(address: H160) -> AccountId {
+        <Self as AddressMapper<AccountId>>::into_account_id(address)
+    }
+}
+
+impl<AccountId, U, M> pallet_evm::AddressMapping<AccountId> for UnifiedAddressMapper<U, M>
+where
+    Self: AddressMapper<AccountId>,
+{
+    fn into_account_id(address: H160) -> AccountId {
+        <Self as AddressMapper<AccountId>>::into_account_id(address)
+    }
+}
@@ -21,6 +21,7 @@
 // along with Utils.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::data::{bytes_size_hint, hash_topic, pad_to_word};
 use alloc::borrow::ToOwned;
 pub use alloc::string::String;
 use sp_core::{ConstU32, Get};
@@ -143,6 +144,19 @@ impl<K: Kind, S: Get<u32>> EvmData for BoundedBytesString<K, S> {
     fn has_static_size() -> bool {
         false
     }
+
+    fn encoded_size_hint(value: &Self) -> usize {
+        bytes_size_hint(value.data.len())
+    }
+
+    fn encode_in_place(value: Self) -> Vec<u8> {
+        pad_to_word(value.into())
+    }
+
+    fn encode_topic(value: Self) -> H256 {
+        let value: Vec<u8> = value.into();
+        hash_topic(&value)
+    }
 }
 
 // BytesString <=> Vec/&[u8]
@@ -22,8 +22,9 @@
 
 use crate::{revert, EvmResult};
 
-use alloc::borrow::ToOwned;
-use core::{any::type_name, marker::PhantomData, ops::Range};
+use alloc::{borrow::ToOwned, string::String};
+use core::{any::type_name, cell::Cell, marker::PhantomData, ops::Range};
+use fp_evm::{ExitError, PrecompileFailure};
 use impl_trait_for_tuples::impl_for_tuples;
 use sp_core::{Get, H160, H256, U256};
 use sp_std::{convert::TryInto, vec, vec::Vec};
@@ -31,7 +32,7 @@ use sp_std::{convert::TryInto, vec, vec::Vec};
 /// The `address` type of Solidity.
 /// H160 could represent 2 types of data (bytes20 and address) that are not encoded the same way.
 /// To avoid issues writing H160 is thus not supported.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Address(pub H160);
 
 impl From<H160> for Address {
@@ -49,7 +50,7 @@ impl From<Address> for H160 {
 /// The `bytes`/`string` type of Solidity.
 /// It is different from `Vec<u8>` which will be serialized with padding for each `u8` element
 /// of the array, while `Bytes` is tightly packed.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Bytes(pub Vec<u8>);
 
 impl Bytes {
@@ -83,18 +84,214 @@ impl From<Bytes> for Vec<u8> {
     }
 }
 
+/// The `string` type of Solidity.
+/// Encoded exactly like `Bytes`, but the content is checked to be valid UTF8 when read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolidityString(pub String);
+
+impl SolidityString {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for SolidityString {
+    fn from(a: &str) -> Self {
+        Self(a.to_owned())
+    }
+}
+
+impl From<String> for SolidityString {
+    fn from(a: String) -> Self {
+        Self(a)
+    }
+}
+
+impl From<SolidityString> for String {
+    fn from(s: SolidityString) -> String {
+        s.0
+    }
+}
+
+/// Gas charged for each 32 bytes word processed by a metered reader,
+/// equal to the per word cost of `CALLDATACOPY`.
+pub const INPUT_WORD_GAS_COST: u64 = 3;
+
+/// Default max number of nested pointers an `EvmDataReader` follows.
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 16;
+
+/// What the limit of a `ReadMeter` is derived from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ReadLimit {
+    Gas,
+    Size,
+}
+
+/// Keeps track of the input processed by a metered `EvmDataReader` and all the readers
+/// derived from it (pointed data, array items).
+/// Data reachable through several pointers is accounted for each time it is read.
+#[derive(Debug)]
+pub struct ReadMeter {
+    processed: Cell<usize>,
+    limit: usize,
+    kind: ReadLimit,
+}
+
+impl ReadMeter {
+    /// Create a meter allowing to process as much input as `gas` can pay for.
+    pub fn new(gas: u64) -> Self {
+        let limit = (gas / INPUT_WORD_GAS_COST).saturating_mul(32);
+
+        Self {
+            processed: Cell::new(0),
+            limit: limit.try_into().unwrap_or(usize::MAX),
+            kind: ReadLimit::Gas,
+        }
+    }
+
+    /// Create a meter allowing to process at most `max_size` bytes of input.
+    /// Parsing reverts once the budget is exceeded.
+    pub fn with_size_limit(max_size: usize) -> Self {
+        Self {
+            processed: Cell::new(0),
+            limit: max_size,
+            kind: ReadLimit::Size,
+        }
+    }
+
+    /// Amount of bytes processed so far.
+    pub fn processed(&self) -> usize {
+        self.processed.get()
+    }
+
+    /// Gas cost of the input processed so far.
+    pub fn gas_cost(&self) -> u64 {
+        let words = (self.processed() as u64).saturating_add(31) / 32;
+        words.saturating_mul(INPUT_WORD_GAS_COST)
+    }
+
+    fn record(&self, len: usize) -> EvmResult {
+        let processed = self.processed().saturating_add(len);
+        if processed > self.limit {
+            return Err(match self.kind {
+                ReadLimit::Gas => PrecompileFailure::Error {
+                    exit_status: ExitError::OutOfGas,
+                },
+                ReadLimit::Size => revert("decoded data exceeds size limit"),
+            });
+        }
+
+        self.processed.set(processed);
+        Ok(())
+    }
+}
+
+/// Prefix the reason of an `Error(string)` revert with `prefix`.
+/// Other failures, including custom errors, are returned as is.
+fn prefix_revert_reason(failure: PrecompileFailure, prefix: &str) -> PrecompileFailure {
+    let reason = match &failure {
+        PrecompileFailure::Revert { output, .. }
+            if output.get(0..4) == Some(&crate::ERROR_STRING_SELECTOR.to_be_bytes()[..]) =>
+        {
+            match EvmDataReader::new(&output[4..]).read::<Bytes>() {
+                Ok(reason) => reason,
+                Err(_) => return failure,
+            }
+        }
+        _ => return failure,
+    };
+
+    let mut message = prefix.as_bytes().to_vec();
+    message.extend_from_slice(reason.as_bytes());
+    revert(message)
+}
+
+/// Keeps track of the furthest input byte read by a strict `EvmDataReader` and all the readers
+/// derived from it, so `EvmDataReader::finish` can tell trailing data apart from pointed data.
+#[derive(Debug, Default)]
+pub struct ReadExtent {
+    end: Cell<usize>,
+}
+
+impl ReadExtent {
+    /// Position right after the furthest byte read so far.
+    pub fn end(&self) -> usize {
+        self.end.get()
+    }
+
+    fn record(&self, end: usize) {
+        if end > self.end() {
+            self.end.set(end);
+        }
+    }
+}
+
 /// Wrapper around an EVM input slice, helping to parse it.
 /// Provide functions to parse common types.
 #[derive(Clone, Copy, Debug)]
 pub struct EvmDataReader<'a> {
     input: &'a [u8],
     cursor: usize,
+    meter: Option<&'a ReadMeter>,
+    depth: usize,
+    max_depth: usize,
+    extent: Option<&'a ReadExtent>,
+    origin: &'a [u8],
 }
 
 impl<'a> EvmDataReader<'a> {
     /// Create a new input parser.
     pub fn new(input: &'a [u8]) -> Self {
-        Self { input, cursor: 0 }
+        Self {
+            input,
+            cursor: 0,
+            meter: None,
+            depth: 0,
+            max_depth: DEFAULT_MAX_NESTING_DEPTH,
+            extent: None,
+            origin: input,
+        }
+    }
+
+    /// Record all the data processed by this parser, and the parsers derived from it, in `meter`.
+    /// Parsing fails once the meter limit is reached, see `ReadMeter`.
+    pub fn metered(mut self, meter: &'a ReadMeter) -> Self {
+        self.meter = Some(meter);
+        self
+    }
+
+    /// Record in `extent` how far this parser, and the parsers derived from it, read the input.
+    /// Required for `finish` to detect trailing data after dynamic arguments.
+    pub fn strict(mut self, extent: &'a ReadExtent) -> Self {
+        extent.record(self.cursor);
+        self.extent = Some(extent);
+        self.origin = self.input;
+        self
+    }
+
+    /// Ensure no data other than zero padding is left after the arguments read so far.
+    /// Without `strict` only the data after the cursor is checked, which is only correct if all
+    /// arguments have a static size.
+    pub fn finish(&self) -> EvmResult {
+        let end = match self.extent {
+            Some(extent) => extent.end().saturating_sub(self.origin_shift()),
+            None => 0,
+        }
+        .max(self.cursor);
+
+        let trailing = self.input.get(end..).unwrap_or_default();
+        if trailing.iter().any(|byte| *byte != 0) {
+            return Err(revert("unexpected trailing input"));
+        }
+
+        Ok(())
+    }
+
+    /// Set the max number of nested pointers followed by this parser,
+    /// `DEFAULT_MAX_NESTING_DEPTH` by default.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
     }
 
     /// Create a new input parser from a selector-initial input.
@@ -143,6 +340,62 @@ impl<'a> EvmDataReader<'a> {
         T::read(self)
     }
 
+    /// Read a list of arguments from the input, e.g. the multiple return values of a function
+    /// as a tuple or a struct deriving `EvmData` with `#[evm_data(explicit_tuple)]`.
+    pub fn read_arguments<T: EvmData>(&mut self) -> EvmResult<T> {
+        T::read_arguments(self)
+    }
+
+    /// Read data from the input without moving the cursor.
+    /// Peeked data doesn't count as read for `finish`, only actually reading it does.
+    pub fn peek<T: EvmData>(&self) -> EvmResult<T> {
+        let mut reader = Self {
+            extent: None,
+            ..*self
+        };
+        T::read(&mut reader)
+    }
+
+    /// Parse the 4 bytes at the cursor as a selector, without moving the cursor.
+    /// Useful for inputs embedding another call, e.g. to dispatch on overloaded selectors.
+    pub fn peek_selector<T>(&self) -> EvmResult<T>
+    where
+        T: num_enum::TryFromPrimitive<Primitive = u32>,
+    {
+        Self::read_selector(self.input.get(self.cursor..).unwrap_or_default())
+    }
+
+    /// Read data from the input, adding the name and position of the argument to the revert
+    /// reason on failure, e.g. "failed to decode argument 1 `staker`: tried to parse H160 out of
+    /// bounds".
+    ///
+    /// The position is the index of the 32 bytes word the argument starts at, which is its index
+    /// among the arguments as long as the previous ones are encoded in a single word.
+    pub fn read_named<T: EvmData>(&mut self, name: &str) -> EvmResult<T> {
+        let position = self.cursor / 32;
+        T::read(self).map_err(|failure| {
+            prefix_revert_reason(
+                failure,
+                &alloc::format!("failed to decode argument {} `{}`: ", position, name),
+            )
+        })
+    }
+
+    /// Read data from the input if there is any left, otherwise return `None`.
+    /// Useful for optional trailing arguments kept for backwards compatibility.
+    pub fn read_optional<T: EvmData>(&mut self) -> EvmResult<Option<T>> {
+        if self.remaining_len() == 0 {
+            Ok(None)
+        } else {
+            self.read().map(Some)
+        }
+    }
+
+    /// Amount of bytes left to read after the cursor.
+    pub fn remaining_len(&self) -> usize {
+        self.input.len().saturating_sub(self.cursor)
+    }
+
     /// Read raw bytes from the input.
     /// Doesn't handle any alignment checks, prefer using `read` instead of possible.
     /// Returns an error if trying to parse out of bounds.
@@ -169,9 +422,15 @@ impl<'a> EvmDataReader<'a> {
             return Err(revert("pointer points out of bounds"));
         }
 
+        if self.depth >= self.max_depth {
+            return Err(revert("exceeded max nesting depth"));
+        }
+
         Ok(Self {
             input: &self.input[offset..],
             cursor: 0,
+            depth: self.depth + 1,
+            ..*self
         })
     }
 
@@ -197,10 +456,26 @@ impl<'a> EvmDataReader<'a> {
             .checked_add(len)
             .ok_or_else(|| revert("data reading cursor overflow"))?;
 
+        if let Some(meter) = self.meter {
+            meter.record(len)?;
+        }
+
+        if let Some(extent) = self.extent {
+            extent.record(
+                self.origin_shift()
+                    .saturating_add(end.min(self.input.len())),
+            );
+        }
+
         self.cursor = end;
 
         Ok(start..end)
     }
+
+    /// Position of this parser input in the input of the strict parser it is derived from.
+    fn origin_shift(&self) -> usize {
+        (self.input.as_ptr() as usize).saturating_sub(self.origin.as_ptr() as usize)
+    }
 }
 
 /// Help build an EVM input/output data.
@@ -250,21 +525,37 @@ impl EvmDataWriter {
 
     /// Return the built data.
     pub fn build(mut self) -> Vec<u8> {
-        Self::bake_offsets(&mut self.data, self.offset_data);
+        if self.selector.is_some() {
+            let mut output = vec![];
+            self.write_into(&mut output);
+            return output;
+        }
+
+        Self::bake_offsets(&mut self.data, 0, self.offset_data);
+        self.data
+    }
+
+    /// Append the built data to `output` in a single pass, without allocating an
+    /// intermediate buffer. `output` can be reused across calls to avoid reallocations.
+    pub fn write_into(self, output: &mut Vec<u8>) {
+        let offseted_len: usize = self.offset_data.iter().map(|datum| datum.data.len()).sum();
+        output.reserve(4 + self.data.len() + offseted_len);
 
         if let Some(selector) = self.selector {
-            let mut output = selector.to_be_bytes().to_vec();
-            output.append(&mut self.data);
-            output
-        } else {
-            self.data
+            output.extend_from_slice(&selector.to_be_bytes());
         }
+        let start = output.len();
+        output.extend_from_slice(&self.data);
+        Self::bake_offsets(output, start, self.offset_data);
     }
 
-    /// Add offseted data at the end of this writer's data, updating the offsets.
-    fn bake_offsets(output: &mut Vec<u8>, offsets: Vec<OffsetDatum>) {
+    /// Add offseted data at the end of `output`, updating the offsets of the data
+    /// written from `start`.
+    fn bake_offsets(output: &mut Vec<u8>, start: usize, offsets: Vec<OffsetDatum>) {
+        output.reserve(offsets.iter().map(|datum| datum.data.len()).sum());
+
         for mut offset_datum in offsets {
-            let offset_position = offset_datum.offset_position;
+            let offset_position = start + offset_datum.offset_position;
             let offset_position_end = offset_position + 32;
 
             // The offset is the distance between the start of the data and the
@@ -272,7 +563,7 @@ impl EvmDataWriter {
             // Offsets in inner data are relative to the start of their respective "container".
             // However in arrays the "container" is actually the item itself instead of the whole
             // array, which is corrected by `offset_shift`.
-            let free_space_offset = output.len() - offset_datum.offset_shift;
+            let free_space_offset = output.len() - start - offset_datum.offset_shift;
 
             // Override dummy offset to the offset it will be in the final output.
             U256::from(free_space_offset)
@@ -296,6 +587,24 @@ impl EvmDataWriter {
         self
     }
 
+    /// Write the value as a list of arguments, e.g. the multiple return values of a function
+    /// given as a tuple or a struct deriving `EvmData` with `#[evm_data(explicit_tuple)]`.
+    ///
+    /// Unlike `write`, dynamic tuples aren't prefixed by their offset, which would shift the
+    /// whole output by 32 bytes.
+    pub fn write_arguments<T: EvmData>(mut self, value: T) -> Self {
+        T::write_arguments(&mut self, value);
+        self
+    }
+
+    /// Set the selector prepended to the data when calling `build`, replacing any previous one.
+    ///
+    /// Like for `new_with_selector`, offsets aren't affected by the selector.
+    pub fn write_selector(mut self, selector: impl Into<u32>) -> Self {
+        self.selector = Some(selector.into());
+        self
+    }
+
     /// Writes a pointer to given data.
     /// The data will be appended when calling `build`.
     /// Initially write a dummy value as offset in this writer's data, which will be replaced by
@@ -325,37 +634,131 @@ pub trait EvmData: Sized {
     fn read(reader: &mut EvmDataReader) -> EvmResult<Self>;
     fn write(writer: &mut EvmDataWriter, value: Self);
     fn has_static_size() -> bool;
-    fn is_explicit_tuple() -> bool {
-        false
+    /// Read the value from a list of arguments, such as function arguments or return values.
+    ///
+    /// Items of explicit tuples are read one after the other, without the offset prefixing
+    /// a dynamic tuple read as a single value. Other values are read as with `read`.
+    fn read_arguments(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        Self::read(reader)
+    }
+    /// Write the value as a list of arguments, such as function arguments or return values.
+    ///
+    /// Items of explicit tuples are written one after the other, without the offset prefixing
+    /// a dynamic tuple written as a single value. Other values are written as with `write`.
+    fn write_arguments(writer: &mut EvmDataWriter, value: Self) {
+        Self::write(writer, value)
+    }
+    /// Minimum number of bytes taken by the value in its parent, i.e. its static part or
+    /// the offset of its dynamic part.
+    fn minimum_encoded_size() -> usize {
+        32
+    }
+    /// Estimated number of bytes taken by the whole encoding of the value, including its
+    /// dynamic part. It is only used to reserve output capacity up front and doesn't need
+    /// to be exact.
+    fn encoded_size_hint(_value: &Self) -> usize {
+        Self::minimum_encoded_size()
+    }
+    /// Encoding of the value inside an indexed event parameter, before it is hashed.
+    ///
+    /// Same as the ABI encoding for static types. Dynamic types are encoded in place,
+    /// without offsets nor lengths, with `bytes` and `string` padded to 32 bytes.
+    fn encode_in_place(value: Self) -> Vec<u8> {
+        encode(value)
     }
+    /// Topic of the value when used as an indexed event parameter.
+    ///
+    /// Value types are stored as is, while `bytes`, `string`, arrays and tuples are
+    /// stored as the Keccak256 of their in place encoding.
+    fn encode_topic(value: Self) -> H256 {
+        let encoded = Self::encode_in_place(value);
+        if encoded.len() == 32 {
+            H256::from_slice(&encoded)
+        } else {
+            hash_topic(&encoded)
+        }
+    }
+}
+
+/// Keccak256 of the in place encoding of an indexed event parameter.
+pub(crate) fn hash_topic(encoded: &[u8]) -> H256 {
+    H256(sp_io::hashing::keccak_256(encoded))
+}
+
+/// Size of the encoding of `bytes` or `string` of `length` bytes: offset, length and
+/// padded data.
+pub(crate) fn bytes_size_hint(length: usize) -> usize {
+    64 + (length + 31) / 32 * 32
+}
+
+/// Pad `bytes` with zeros up to the next multiple of 32 bytes.
+pub(crate) fn pad_to_word(mut bytes: Vec<u8>) -> Vec<u8> {
+    bytes.resize((bytes.len() + 31) / 32 * 32, 0);
+    bytes
+}
+
+/// Ensure `array_size` items of type `T` can fit in the remaining `input`, so absurd array
+/// lengths revert immediately instead of driving long loops of failing reads.
+fn ensure_array_fits<T: EvmData>(array_size: usize, input: &[u8]) -> EvmResult {
+    // Zero-sized items are counted as one byte so their number is bounded as well.
+    let min_size = array_size.saturating_mul(T::minimum_encoded_size().max(1));
+    if min_size > input.len() {
+        return Err(revert("array length exceeds remaining input"));
+    }
+
+    Ok(())
 }
 /// Encode the value into its Solidity ABI format.
 /// If `T` is a tuple it is encoded as a Solidity tuple with dynamic-size offset.
 fn encode<T: EvmData>(value: T) -> Vec<u8> {
-    EvmDataWriter::new().write(value).build()
+    let mut writer = EvmDataWriter::new();
+    writer.data.reserve(T::encoded_size_hint(&value));
+    writer.write(value).build()
 }
 
 /// Encode the value into its Solidity ABI format.
 /// If `T` is a tuple every element is encoded without a prefixed offset.
 /// It matches the encoding of Solidity function arguments and return value, or event data.
 pub fn encode_arguments<T: EvmData>(value: T) -> Vec<u8> {
-    let output = encode(value);
-    if T::is_explicit_tuple() && !T::has_static_size() {
-        output[32..].to_vec()
-    } else {
-        output
-    }
+    let mut writer = EvmDataWriter::new();
+    writer.data.reserve(T::encoded_size_hint(&value));
+    writer.write_arguments(value).build()
 }
 
 pub use self::encode_arguments as encode_return_value;
 pub use self::encode_arguments as encode_event_data;
 
+/// The unit type, matching functions with no argument or return data.
+impl EvmData for () {
+    fn read(_reader: &mut EvmDataReader) -> EvmResult<Self> {
+        Ok(())
+    }
+
+    fn write(_writer: &mut EvmDataWriter, _value: Self) {}
+
+    fn has_static_size() -> bool {
+        true
+    }
+
+    fn minimum_encoded_size() -> usize {
+        0
+    }
+}
+
 #[impl_for_tuples(1, 18)]
 impl EvmData for Tuple {
     fn has_static_size() -> bool {
         for_tuples!(#( Tuple::has_static_size() )&*)
     }
 
+    fn minimum_encoded_size() -> usize {
+        if Self::has_static_size() {
+            for_tuples!(#( Tuple::minimum_encoded_size() )+*)
+        } else {
+            32
+        }
+    }
+
     fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
         if !Self::has_static_size() {
             let reader = &mut reader.read_pointer()?;
@@ -365,6 +768,19 @@ impl EvmData for Tuple {
         }
     }
 
+    fn read_arguments(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        Ok(for_tuples!( ( #( reader.read::<Tuple>()? ),* ) ))
+    }
+
+    fn encoded_size_hint(value: &Self) -> usize {
+        let size = for_tuples!(#( Tuple::encoded_size_hint(&value.Tuple) )+*);
+        if Self::has_static_size() {
+            size
+        } else {
+            32 + size
+        }
+    }
+
     fn write(writer: &mut EvmDataWriter, value: Self) {
         if !Self::has_static_size() {
             let mut inner_writer = EvmDataWriter::new();
@@ -374,6 +790,20 @@ impl EvmData for Tuple {
             for_tuples!( #( Tuple::write(writer, value.Tuple); )* );
         }
     }
+
+    fn write_arguments(writer: &mut EvmDataWriter, value: Self) {
+        for_tuples!( #( Tuple::write(writer, value.Tuple); )* );
+    }
+
+    fn encode_in_place(value: Self) -> Vec<u8> {
+        let mut output = vec![];
+        for_tuples!( #( output.extend(Tuple::encode_in_place(value.Tuple)); )* );
+        output
+    }
+
+    fn encode_topic(value: Self) -> H256 {
+        hash_topic(&Self::encode_in_place(value))
+    }
 }
 
 impl EvmData for H256 {
@@ -397,6 +827,98 @@ impl EvmData for H256 {
     }
 }
 
+/// Solidity `bytesN`, with `N` between 1 and 32.
+/// Unlike `H256` or integers, the bytes are left aligned and padded with zeros on the right.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FixedBytes<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> From<[u8; N]> for FixedBytes<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        FixedBytes(bytes)
+    }
+}
+
+impl<const N: usize> From<FixedBytes<N>> for [u8; N] {
+    fn from(bytes: FixedBytes<N>) -> Self {
+        bytes.0
+    }
+}
+
+impl From<H256> for FixedBytes<32> {
+    fn from(hash: H256) -> Self {
+        FixedBytes(hash.to_fixed_bytes())
+    }
+}
+
+impl From<FixedBytes<32>> for H256 {
+    fn from(bytes: FixedBytes<32>) -> Self {
+        H256(bytes.0)
+    }
+}
+
+impl<const N: usize> EvmData for FixedBytes<N> {
+    fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        let range = reader.move_cursor(32)?;
+
+        let data = reader
+            .input
+            .get(range)
+            .ok_or_else(|| revert(alloc::format!("tried to parse bytes{} out of bounds", N)))?;
+
+        if N == 0 || N > 32 || data[N..].iter().any(|byte| *byte != 0) {
+            return Err(revert(alloc::format!("invalid bytes{}", N)));
+        }
+
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&data[..N]);
+        Ok(FixedBytes(bytes))
+    }
+
+    fn write(writer: &mut EvmDataWriter, value: Self) {
+        let mut buffer = [0u8; 32];
+        buffer[..N].copy_from_slice(&value.0);
+        writer.data.extend_from_slice(&buffer);
+    }
+
+    fn has_static_size() -> bool {
+        true
+    }
+}
+
+/// The external `function` type of Solidity: the address of a contract followed by the
+/// selector of one of its functions, encoded as a `bytes24`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FunctionSelectorArg {
+    pub address: H160,
+    pub selector: u32,
+}
+
+impl EvmData for FunctionSelectorArg {
+    fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        let bytes: FixedBytes<24> = reader
+            .read()
+            .map_err(|_| revert("invalid function type argument"))?;
+
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&bytes.0[20..]);
+        Ok(Self {
+            address: H160::from_slice(&bytes.0[..20]),
+            selector: u32::from_be_bytes(selector),
+        })
+    }
+
+    fn write(writer: &mut EvmDataWriter, value: Self) {
+        let mut bytes = [0u8; 24];
+        bytes[..20].copy_from_slice(value.address.as_bytes());
+        bytes[20..].copy_from_slice(&value.selector.to_be_bytes());
+        FixedBytes::write(writer, FixedBytes(bytes));
+    }
+
+    fn has_static_size() -> bool {
+        true
+    }
+}
+
 impl EvmData for Address {
     fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
         let range = reader.move_cursor(32)?;
@@ -418,6 +940,114 @@ impl EvmData for Address {
     }
 }
 
+/// An `address` where the zero address stands for `None`, e.g. "no beneficiary".
+/// Reads the zero address as `None` and writes `None` as the zero address.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ZeroAddressAsNone<A = Address>(pub Option<A>);
+
+impl<A> From<Option<A>> for ZeroAddressAsNone<A> {
+    fn from(a: Option<A>) -> Self {
+        ZeroAddressAsNone(a)
+    }
+}
+
+impl<A> From<ZeroAddressAsNone<A>> for Option<A> {
+    fn from(a: ZeroAddressAsNone<A>) -> Option<A> {
+        a.0
+    }
+}
+
+impl<A: From<H160> + Into<H160>> EvmData for ZeroAddressAsNone<A> {
+    fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        let address: H160 = reader.read::<Address>()?.into();
+
+        if address.is_zero() {
+            Ok(ZeroAddressAsNone(None))
+        } else {
+            Ok(ZeroAddressAsNone(Some(address.into())))
+        }
+    }
+
+    fn write(writer: &mut EvmDataWriter, value: Self) {
+        let address = value.0.map(Into::into).unwrap_or_default();
+        Address::write(writer, Address(address));
+    }
+
+    fn has_static_size() -> bool {
+        true
+    }
+}
+
+/// An optional value encoded as a `(bool, T)` tuple, where the flag tells if the value is set.
+/// `None` is written with the default value of `T`, which is ignored when reading.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolidityOption<T>(pub Option<T>);
+
+impl<T> From<Option<T>> for SolidityOption<T> {
+    fn from(a: Option<T>) -> Self {
+        SolidityOption(a)
+    }
+}
+
+impl<T> From<SolidityOption<T>> for Option<T> {
+    fn from(a: SolidityOption<T>) -> Option<T> {
+        a.0
+    }
+}
+
+impl<T: EvmData + Default> EvmData for SolidityOption<T> {
+    fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        let (is_some, value) = reader.read::<(bool, T)>()?;
+
+        if is_some {
+            Ok(SolidityOption(Some(value)))
+        } else {
+            Ok(SolidityOption(None))
+        }
+    }
+
+    fn write(writer: &mut EvmDataWriter, value: Self) {
+        let value = match value.0 {
+            Some(value) => (true, value),
+            None => (false, T::default()),
+        };
+        <(bool, T)>::write(writer, value);
+    }
+
+    fn has_static_size() -> bool {
+        T::has_static_size()
+    }
+
+    fn minimum_encoded_size() -> usize {
+        <(bool, T)>::minimum_encoded_size()
+    }
+
+    fn encoded_size_hint(value: &Self) -> usize {
+        let size = 32
+            + value
+                .0
+                .as_ref()
+                .map_or_else(T::minimum_encoded_size, T::encoded_size_hint);
+        if Self::has_static_size() {
+            size
+        } else {
+            32 + size
+        }
+    }
+
+    fn encode_in_place(value: Self) -> Vec<u8> {
+        let value = match value.0 {
+            Some(value) => (true, value),
+            None => (false, T::default()),
+        };
+        <(bool, T)>::encode_in_place(value)
+    }
+
+    fn encode_topic(value: Self) -> H256 {
+        hash_topic(&Self::encode_in_place(value))
+    }
+}
+
 impl EvmData for U256 {
     fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
         let range = reader.move_cursor(32)?;
@@ -476,6 +1106,111 @@ macro_rules! impl_evmdata_for_uints {
 
 impl_evmdata_for_uints!(u16, u32, u64, u128,);
 
+/// `true` if the 32 bytes hold a two's complement value which fits into `size` bytes,
+/// i.e. all leading bytes are the sign extension of the lowest `size` bytes.
+fn is_sign_extended(data: &[u8], size: usize) -> bool {
+    let padding = if data[32 - size] & 0x80 == 0 {
+        0x00
+    } else {
+        0xff
+    };
+    data[..32 - size].iter().all(|byte| *byte == padding)
+}
+
+macro_rules! impl_evmdata_for_ints {
+	($($int:ty, )*) => {
+		$(
+			impl EvmData for $int {
+				fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+					let range = reader.move_cursor(32)?;
+
+					let data = reader
+						.input
+						.get(range)
+						.ok_or_else(|| revert(alloc::format!(
+							"tried to parse {} out of bounds", core::any::type_name::<Self>()
+						)))?;
+
+					if !is_sign_extended(data, core::mem::size_of::<Self>()) {
+						return Err(revert(alloc::format!(
+							"value out of range for {}", core::any::type_name::<Self>()
+						)));
+					}
+
+					let mut buffer = [0u8; core::mem::size_of::<Self>()];
+					buffer.copy_from_slice(&data[32 - core::mem::size_of::<Self>()..]);
+					Ok(Self::from_be_bytes(buffer))
+				}
+
+				fn write(writer: &mut EvmDataWriter, value: Self) {
+					// Negative values are sign extended.
+					let mut buffer = if value < 0 { [0xffu8; 32] } else { [0u8; 32] };
+					buffer[32 - core::mem::size_of::<Self>()..].copy_from_slice(&value.to_be_bytes());
+					writer.data.extend_from_slice(&buffer);
+				}
+
+				fn has_static_size() -> bool {
+					true
+				}
+			}
+		)*
+	};
+}
+
+impl_evmdata_for_ints!(i8, i16, i32, i64, i128,);
+
+/// Solidity `int256`, kept in its two's complement representation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct I256(pub U256);
+
+impl I256 {
+    /// `true` if the value is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.0.bit(255)
+    }
+}
+
+impl From<i128> for I256 {
+    fn from(value: i128) -> Self {
+        let mut buffer = if value < 0 { [0xffu8; 32] } else { [0u8; 32] };
+        buffer[16..].copy_from_slice(&value.to_be_bytes());
+        I256(U256::from_big_endian(&buffer))
+    }
+}
+
+impl TryFrom<I256> for i128 {
+    type Error = &'static str;
+
+    fn try_from(value: I256) -> Result<Self, Self::Error> {
+        let mut buffer = [0u8; 32];
+        value.0.to_big_endian(&mut buffer);
+
+        if !is_sign_extended(&buffer, 16) {
+            return Err("value out of range for i128");
+        }
+
+        let mut low = [0u8; 16];
+        low.copy_from_slice(&buffer[16..]);
+        Ok(i128::from_be_bytes(low))
+    }
+}
+
+impl EvmData for I256 {
+    fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        let value = U256::read(reader).map_err(|_| revert("tried to parse I256 out of bounds"))?;
+
+        Ok(I256(value))
+    }
+
+    fn write(writer: &mut EvmDataWriter, value: Self) {
+        U256::write(writer, value.0);
+    }
+
+    fn has_static_size() -> bool {
+        true
+    }
+}
+
 // The implementation for u8 is specific, for performance reasons.
 impl EvmData for u8 {
     fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
@@ -522,6 +1257,47 @@ impl EvmData for bool {
     }
 }
 
+/// Sum of the size hints of array `items`.
+fn array_size_hint<T: EvmData>(items: &[T]) -> usize {
+    items.iter().map(T::encoded_size_hint).sum()
+}
+
+/// Write `items` as a dynamic-size Solidity array.
+fn write_array<T: EvmData>(writer: &mut EvmDataWriter, items: Vec<T>) {
+    let mut inner_writer = EvmDataWriter::new();
+    // Reserve for the length and all items, including their dynamic parts, so that baking
+    // the offsets doesn't reallocate.
+    inner_writer.data.reserve(32 + array_size_hint(&items));
+    inner_writer = inner_writer.write(U256::from(items.len()));
+
+    // Items of static size don't contain offsets, they are written in place.
+    if T::has_static_size() {
+        for inner in items {
+            T::write(&mut inner_writer, inner);
+        }
+        writer.write_pointer(inner_writer.build());
+        return;
+    }
+
+    for inner in items {
+        // Any offset in items are relative to the start of the item instead of the
+        // start of the array. However if there is offseted data it must but appended after
+        // all items (offsets) are written. We thus need to rely on `compute_offsets` to do
+        // that, and must store a "shift" to correct the offsets.
+        let shift = inner_writer.data.len();
+        let item_writer = EvmDataWriter::new().write(inner);
+
+        inner_writer = inner_writer.write_raw_bytes(&item_writer.data);
+        for mut offset_datum in item_writer.offset_data {
+            offset_datum.offset_shift += 32;
+            offset_datum.offset_position += shift;
+            inner_writer.offset_data.push(offset_datum);
+        }
+    }
+
+    writer.write_pointer(inner_writer.build());
+}
+
 impl<T: EvmData> EvmData for Vec<T> {
     fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
         let mut inner_reader = reader.read_pointer()?;
@@ -540,7 +1316,9 @@ impl<T: EvmData> EvmData for Vec<T> {
                 .get(32..)
                 .ok_or_else(|| revert("try to read array items out of bound"))?,
             cursor: 0,
+            ..inner_reader
         };
+        ensure_array_fits::<T>(array_size, item_reader.input)?;
 
         for _ in 0..array_size {
             array.push(item_reader.read()?);
@@ -550,29 +1328,81 @@ impl<T: EvmData> EvmData for Vec<T> {
     }
 
     fn write(writer: &mut EvmDataWriter, value: Self) {
-        let mut inner_writer = EvmDataWriter::new().write(U256::from(value.len()));
-
-        for inner in value {
-            // Any offset in items are relative to the start of the item instead of the
-            // start of the array. However if there is offseted data it must but appended after
-            // all items (offsets) are written. We thus need to rely on `compute_offsets` to do
-            // that, and must store a "shift" to correct the offsets.
-            let shift = inner_writer.data.len();
-            let item_writer = EvmDataWriter::new().write(inner);
-
-            inner_writer = inner_writer.write_raw_bytes(&item_writer.data);
-            for mut offset_datum in item_writer.offset_data {
-                offset_datum.offset_shift += 32;
-                offset_datum.offset_position += shift;
-                inner_writer.offset_data.push(offset_datum);
+        write_array(writer, value);
+    }
+
+    fn has_static_size() -> bool {
+        false
+    }
+
+    fn encoded_size_hint(value: &Self) -> usize {
+        64 + array_size_hint(value)
+    }
+
+    fn encode_in_place(value: Self) -> Vec<u8> {
+        value.into_iter().flat_map(T::encode_in_place).collect()
+    }
+
+    fn encode_topic(value: Self) -> H256 {
+        hash_topic(&Self::encode_in_place(value))
+    }
+}
+
+/// Solidity fixed-size array `T[N]`.
+/// Same as a tuple, it is encoded inline if `T` has a static size and behind an offset otherwise.
+impl<T: EvmData, const N: usize> EvmData for [T; N] {
+    fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        let mut items = Vec::with_capacity(N);
+
+        if !Self::has_static_size() {
+            let reader = &mut reader.read_pointer()?;
+            for _ in 0..N {
+                items.push(reader.read::<T>()?);
+            }
+        } else {
+            for _ in 0..N {
+                items.push(reader.read::<T>()?);
             }
         }
 
-        writer.write_pointer(inner_writer.build());
+        items
+            .try_into()
+            .map_err(|_| revert("fixed-size array length mismatch"))
+    }
+
+    fn write(writer: &mut EvmDataWriter, value: Self) {
+        if !Self::has_static_size() {
+            let mut inner_writer = EvmDataWriter::new();
+            for item in value {
+                T::write(&mut inner_writer, item);
+            }
+            writer.write_pointer(inner_writer.build());
+        } else {
+            for item in value {
+                T::write(writer, item);
+            }
+        }
     }
 
     fn has_static_size() -> bool {
-        false
+        T::has_static_size()
+    }
+
+    fn encoded_size_hint(value: &Self) -> usize {
+        let size = array_size_hint(value);
+        if Self::has_static_size() {
+            size
+        } else {
+            32 + size
+        }
+    }
+
+    fn encode_in_place(value: Self) -> Vec<u8> {
+        value.into_iter().flat_map(T::encode_in_place).collect()
+    }
+
+    fn encode_topic(value: Self) -> H256 {
+        hash_topic(&Self::encode_in_place(value))
     }
 }
 
@@ -626,6 +1456,48 @@ impl EvmData for Bytes {
     fn has_static_size() -> bool {
         false
     }
+
+    fn encoded_size_hint(value: &Self) -> usize {
+        bytes_size_hint(value.0.len())
+    }
+
+    fn encode_in_place(value: Self) -> Vec<u8> {
+        pad_to_word(value.0)
+    }
+
+    fn encode_topic(value: Self) -> H256 {
+        hash_topic(&value.0)
+    }
+}
+
+impl EvmData for SolidityString {
+    fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+        let bytes: Bytes = reader.read()?;
+
+        String::from_utf8(bytes.0)
+            .map(Self)
+            .map_err(|_| revert("string is not valid UTF8"))
+    }
+
+    fn write(writer: &mut EvmDataWriter, value: Self) {
+        Bytes::write(writer, Bytes(value.0.into_bytes()))
+    }
+
+    fn has_static_size() -> bool {
+        false
+    }
+
+    fn encoded_size_hint(value: &Self) -> usize {
+        bytes_size_hint(value.0.len())
+    }
+
+    fn encode_in_place(value: Self) -> Vec<u8> {
+        Bytes::encode_in_place(Bytes(value.0.into_bytes()))
+    }
+
+    fn encode_topic(value: Self) -> H256 {
+        Bytes::encode_topic(Bytes(value.0.into_bytes()))
+    }
 }
 
 /// Wrapper around a Vec that provides a max length bound on read.
@@ -657,7 +1529,9 @@ impl<T: EvmData, S: Get<u32>> EvmData for BoundedVec<T, S> {
                 .get(32..)
                 .ok_or_else(|| revert("read out of bounds: array content"))?,
             cursor: 0,
+            ..inner_reader
         };
+        ensure_array_fits::<T>(array_size, item_reader.input)?;
 
         for _ in 0..array_size {
             array.push(item_reader.read()?);
@@ -670,31 +1544,80 @@ impl<T: EvmData, S: Get<u32>> EvmData for BoundedVec<T, S> {
     }
 
     fn write(writer: &mut EvmDataWriter, value: Self) {
-        let value: Vec<_> = value.into();
-        let mut inner_writer = EvmDataWriter::new().write(U256::from(value.len()));
-
-        for inner in value {
-            // Any offset in items are relative to the start of the item instead of the
-            // start of the array. However if there is offseted data it must but appended after
-            // all items (offsets) are written. We thus need to rely on `compute_offsets` to do
-            // that, and must store a "shift" to correct the offsets.
-            let shift = inner_writer.data.len();
-            let item_writer = EvmDataWriter::new().write(inner);
-
-            inner_writer = inner_writer.write_raw_bytes(&item_writer.data);
-            for mut offset_datum in item_writer.offset_data {
-                offset_datum.offset_shift += 32;
-                offset_datum.offset_position += shift;
-                inner_writer.offset_data.push(offset_datum);
-            }
-        }
-
-        writer.write_pointer(inner_writer.build());
+        write_array(writer, value.into());
     }
 
     fn has_static_size() -> bool {
         false
     }
+
+    fn encoded_size_hint(value: &Self) -> usize {
+        64 + array_size_hint(&value.inner)
+    }
+
+    fn encode_in_place(value: Self) -> Vec<u8> {
+        value
+            .inner
+            .into_iter()
+            .flat_map(T::encode_in_place)
+            .collect()
+    }
+
+    fn encode_topic(value: Self) -> H256 {
+        hash_topic(&Self::encode_in_place(value))
+    }
+}
+
+impl<T, S> BoundedVec<T, S> {
+    /// Number of items.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Item at `index`, if any.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.inner.get(index)
+    }
+
+    /// Iterator over the items.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.inner.iter()
+    }
+}
+
+impl<T, S: Get<u32>> BoundedVec<T, S> {
+    /// Append an item if the max length isn't reached yet, otherwise return it back.
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        if self.inner.len() >= S::get() as usize {
+            return Err(item);
+        }
+
+        self.inner.push(item);
+        Ok(())
+    }
+}
+
+impl<T, S> IntoIterator for BoundedVec<T, S> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T, S> IntoIterator for &'a BoundedVec<T, S> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
 }
 
 impl<T, S> From<Vec<T>> for BoundedVec<T, S> {
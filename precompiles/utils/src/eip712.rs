@@ -0,0 +1,192 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Hashing of EIP-712 typed structured data.
+//!
+//! Precompiles verifying EIP-712 signatures build the domain separator of their signing domain
+//! with `Eip712Domain` and the hash of the signed struct with `StructHasher`, then recover the
+//! signer from `hash_typed_data`.
+//!
+//! Usage:
+//!
+//! ```ignore
+//! const PERMIT_TYPEHASH: [u8; 32] =
+//!     keccak256!("Permit(address owner,address spender,uint256 value,uint256 nonce)");
+//!
+//! let struct_hash = StructHasher::new(PERMIT_TYPEHASH)
+//!     .field(&Address(owner))
+//!     .field(&Address(spender))
+//!     .field(&value)
+//!     .field(&nonce)
+//!     .hash();
+//! let digest = hash_typed_data(domain.separator(), struct_hash);
+//! ```
+
+use crate::{
+    data::{Address, Bytes, SolidityString},
+    keccak256,
+};
+use sp_core::{H160, H256, U256};
+use sp_std::vec::Vec;
+
+/// Type hash of the EIP-712 domain with all of `name`, `version`, `chainId` and
+/// `verifyingContract`.
+pub const EIP712_DOMAIN_TYPEHASH: [u8; 32] = keccak256!(
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+fn keccak(data: &[u8]) -> H256 {
+    H256(sp_io::hashing::keccak_256(data))
+}
+
+/// A value of a member of an EIP-712 struct.
+///
+/// Atomic values are encoded as a single ABI word, `bytes` and `string` as the hash of their
+/// content and arrays as the hash of the concatenated encoding of their items.
+/// Members which are themselves structs are encoded as their struct hash, an `H256`.
+pub trait Eip712Encode {
+    /// Encoding of the value, always 32 bytes.
+    fn encode_eip712(&self) -> H256;
+}
+
+macro_rules! impl_eip712_encode_for_uints {
+    ($($uint:ty),*) => {
+        $(
+            impl Eip712Encode for $uint {
+                fn encode_eip712(&self) -> H256 {
+                    let mut word = [0u8; 32];
+                    word[32 - core::mem::size_of::<$uint>()..].copy_from_slice(&self.to_be_bytes());
+                    H256(word)
+                }
+            }
+        )*
+    };
+}
+
+impl_eip712_encode_for_uints!(u8, u16, u32, u64, u128);
+
+impl Eip712Encode for U256 {
+    fn encode_eip712(&self) -> H256 {
+        let mut word = [0u8; 32];
+        self.to_big_endian(&mut word);
+        H256(word)
+    }
+}
+
+impl Eip712Encode for bool {
+    fn encode_eip712(&self) -> H256 {
+        (*self as u8).encode_eip712()
+    }
+}
+
+impl Eip712Encode for H256 {
+    fn encode_eip712(&self) -> H256 {
+        *self
+    }
+}
+
+impl Eip712Encode for Address {
+    fn encode_eip712(&self) -> H256 {
+        self.0.into()
+    }
+}
+
+impl Eip712Encode for Bytes {
+    fn encode_eip712(&self) -> H256 {
+        keccak(self.as_bytes())
+    }
+}
+
+impl Eip712Encode for SolidityString {
+    fn encode_eip712(&self) -> H256 {
+        keccak(self.as_str().as_bytes())
+    }
+}
+
+impl<T: Eip712Encode> Eip712Encode for Vec<T> {
+    fn encode_eip712(&self) -> H256 {
+        let mut data = Vec::with_capacity(self.len() * 32);
+        for item in self {
+            data.extend_from_slice(item.encode_eip712().as_bytes());
+        }
+        keccak(&data)
+    }
+}
+
+/// Builds the hash of an EIP-712 struct from its type hash and its members,
+/// which must be provided in the order of the type definition.
+pub struct StructHasher {
+    data: Vec<u8>,
+}
+
+impl StructHasher {
+    /// Start hashing a struct whose type hash is `type_hash`, the Keccak256 of its
+    /// encoded type, e.g. `keccak256!("Mail(address from,address to,string contents)")`.
+    pub fn new(type_hash: [u8; 32]) -> Self {
+        Self {
+            data: type_hash.to_vec(),
+        }
+    }
+
+    /// Add the next member of the struct.
+    pub fn field<T: Eip712Encode>(mut self, value: &T) -> Self {
+        self.data
+            .extend_from_slice(value.encode_eip712().as_bytes());
+        self
+    }
+
+    /// The struct hash, `keccak256(typeHash ‖ encodeData(s))`.
+    pub fn hash(self) -> H256 {
+        keccak(&self.data)
+    }
+}
+
+/// The EIP-712 signing domain of a precompile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Eip712Domain<'a> {
+    /// Name of the signing domain, e.g. the name of the precompile.
+    pub name: &'a [u8],
+    /// Version of the signing domain, to be bumped when the signed structs change.
+    pub version: &'a [u8],
+    /// EVM chain id of the runtime, to prevent replays on other chains.
+    pub chain_id: u64,
+    /// Address of the precompile verifying the signatures.
+    pub verifying_contract: H160,
+}
+
+impl<'a> Eip712Domain<'a> {
+    /// The domain separator, i.e. the struct hash of the domain.
+    pub fn separator(&self) -> H256 {
+        StructHasher::new(EIP712_DOMAIN_TYPEHASH)
+            .field(&keccak(self.name))
+            .field(&keccak(self.version))
+            .field(&self.chain_id)
+            .field(&Address(self.verifying_contract))
+            .hash()
+    }
+}
+
+/// The digest signed for the struct hashed into `struct_hash` in the domain with
+/// `domain_separator`, `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`.
+pub fn hash_typed_data(domain_separator: H256, struct_hash: H256) -> H256 {
+    let mut data = Vec::with_capacity(66);
+    data.extend_from_slice(b"\x19\x01");
+    data.extend_from_slice(domain_separator.as_bytes());
+    data.extend_from_slice(struct_hash.as_bytes());
+    keccak(&data)
+}
@@ -23,6 +23,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
+// Allows `#[derive(EvmData)]` to be used within this crate.
+extern crate self as precompile_utils;
 
 use crate::alloc::borrow::ToOwned;
 pub use alloc::string::String;
@@ -33,18 +35,42 @@ use fp_evm::{
 use frame_support::{
     dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
     pallet_prelude::Weight,
-    traits::Get,
+    traits::{Currency, Get},
 };
 use pallet_evm::{GasWeightMapping, Log};
 use sp_core::{H160, H256, U256};
-use sp_std::{marker::PhantomData, vec, vec::Vec};
+use sp_runtime::{traits::Bounded, DispatchError, ModuleError};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, vec, vec::Vec};
 
+pub mod address_mapping;
 pub mod bytes;
 pub mod data;
+pub mod eip712;
+pub mod metered;
 
-pub use data::{Address, Bytes, EvmData, EvmDataReader, EvmDataWriter};
-pub use precompile_utils_macro::{generate_function_selector, keccak256};
+pub use address_mapping::{
+    AddressMapper, HashedAddressMapper, TruncatedAddressMapper, UnifiedAccounts,
+    UnifiedAddressMapper,
+};
+pub use data::{
+    Address, Bytes, EvmData, EvmDataReader, EvmDataWriter, FixedBytes, FunctionSelectorArg,
+    ReadExtent, ReadMeter, SolidityOption, SolidityString, ZeroAddressAsNone, I256,
+};
+pub use metered::MeteredHandle;
+pub use precompile_utils_macro::{
+    generate_function_selector, keccak256, precompile, selector, EvmData, EvmError,
+};
 
+/// Items used by code generated with the macros of this crate.
+#[doc(hidden)]
+pub mod __private {
+    pub use fp_evm::{Precompile, PrecompileHandle, PrecompileOutput};
+    pub use sp_core::H256;
+    pub use sp_std::vec::Vec;
+}
+
+#[cfg(test)]
+mod abi_roundtrip;
 #[cfg(feature = "testing")]
 pub mod testing;
 #[cfg(test)]
@@ -163,6 +189,68 @@ impl LogExt for Log {
     }
 }
 
+/// Builder of a Solidity event log.
+///
+/// The first topic is the Keccak256 of the event signature, indexed parameters are added
+/// as topics (see `EvmData::encode_topic`) and non-indexed ones are ABI encoded as data.
+///
+/// ```ignore
+/// LogBuilder::new(handle.context().address, "Transfer(address,address,uint256)")
+///     .indexed(Address(from))
+///     .indexed(Address(to))
+///     .data(amount)
+///     .record(handle)?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct LogBuilder {
+    address: H160,
+    topics: Vec<H256>,
+    data: Vec<u8>,
+}
+
+impl LogBuilder {
+    /// Create a log of the event with the given signature, emitted by `address`.
+    pub fn new(address: impl Into<H160>, signature: &str) -> Self {
+        Self {
+            address: address.into(),
+            topics: vec![H256(sp_io::hashing::keccak_256(signature.as_bytes()))],
+            data: vec![],
+        }
+    }
+
+    /// Add the next indexed parameter. Events have at most 3 indexed parameters.
+    #[must_use]
+    pub fn indexed<T: EvmData>(mut self, value: T) -> Self {
+        debug_assert!(self.topics.len() < 4, "too many indexed parameters");
+        self.topics.push(T::encode_topic(value));
+        self
+    }
+
+    /// Set the non-indexed parameters, usually as a tuple.
+    #[must_use]
+    pub fn data<T: EvmData>(mut self, value: T) -> Self {
+        self.data = data::encode_event_data(value);
+        self
+    }
+
+    /// Build the log without recording it.
+    #[must_use]
+    pub fn build(self) -> Log {
+        Log {
+            address: self.address,
+            topics: self.topics,
+            data: self.data,
+        }
+    }
+
+    /// Charge the cost of the log and record it.
+    pub fn record(self, handle: &mut impl PrecompileHandle) -> EvmResult {
+        let log = self.build();
+        handle.record_cost(log.compute_cost()?)?;
+        log.record(handle)
+    }
+}
+
 /// Helper functions requiring a Runtime.
 /// This runtime must of course implement `pallet_evm::Config`.
 #[derive(Clone, Copy, Debug)]
@@ -197,7 +285,7 @@ where
     ) -> Result<u64, ExitError> {
         // Refund weights and compute used weight them record used gas
         let used_weight = if let Some(actual_weight) = maybe_actual_weight {
-            let refund_weight = weight - actual_weight;
+            let refund_weight = weight.saturating_sub(actual_weight);
             handle.refund_external_cost(None, Some(refund_weight.proof_size()));
             actual_weight
         } else {
@@ -208,9 +296,13 @@ where
         Ok(used_gas)
     }
 
-    /// Try to dispatch a Substrate call.
-    /// Return an error if there are not enough gas, or if the call fails.
-    /// If successful returns the used gas using the Runtime GasWeightMapping.
+    /// Try to dispatch a Substrate call with the given origin.
+    ///
+    /// Reverts if there is not enough gas, if the precompile is called statically, or if
+    /// the call fails, with the reason given by `dispatch_error_reason`. The weight of the
+    /// call is charged as gas, minus what the call reports it didn't use.
+    ///
+    /// Dispatching doesn't enter a new EVM frame, so the call depth is left to the EVM.
     pub fn try_dispatch<Call>(
         handle: &mut impl PrecompileHandleExt,
         origin: <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin,
@@ -219,26 +311,12 @@ where
     where
         Runtime::RuntimeCall: From<Call>,
     {
-        let call = Runtime::RuntimeCall::from(call);
-        let dispatch_info = call.get_dispatch_info();
-
-        // Make sure there is enough gas.
-        Self::record_weight_v2_cost(handle, dispatch_info.weight)?;
-
-        // Dispatch call.
         // It may be possible to not record gas cost if the call returns Pays::No.
         // However while Substrate handle checking weight while not making the sender pay for it,
         // the EVM doesn't. It seems this safer to always record the costs to avoid unmetered
         // computations.
-        let post_dispatch_info = call
-            .dispatch(origin)
-            .map_err(|e| revert(alloc::format!("Dispatched call failed with error: {:?}", e)))?;
-
-        Self::refund_weight_v2_cost(
-            handle,
-            dispatch_info.weight,
-            post_dispatch_info.actual_weight,
-        )?;
+        MeteredHandle::<_, Runtime>::new(handle)
+            .dispatch(origin, Runtime::RuntimeCall::from(call))?;
 
         Ok(())
     }
@@ -261,10 +339,120 @@ where
             <Runtime as frame_system::Config>::DbWeight::get().reads(1),
         )
     }
+
+    /// Convert `value` into a native balance, reverting with "`name` is too large" if it
+    /// doesn't fit.
+    pub fn checked_balance(value: U256, name: &str) -> EvmResult<NativeBalanceOf<Runtime>>
+    where
+        NativeBalanceOf<Runtime>: TryFrom<U256>,
+    {
+        checked_from_u256(value, name)
+    }
+
+    /// Convert `value` into a native balance, saturating at the maximum balance.
+    pub fn saturating_balance(value: U256) -> NativeBalanceOf<Runtime>
+    where
+        NativeBalanceOf<Runtime>: TryFrom<U256>,
+    {
+        saturating_from_u256(value)
+    }
+
+    /// Convert `value` into a block number, reverting with "`name` is too large" if it
+    /// doesn't fit.
+    pub fn checked_block_number(value: U256, name: &str) -> EvmResult<Runtime::BlockNumber>
+    where
+        Runtime::BlockNumber: TryFrom<U256>,
+    {
+        checked_from_u256(value, name)
+    }
+
+    /// Convert `value` into a block number, saturating at the maximum block number.
+    pub fn saturating_block_number(value: U256) -> Runtime::BlockNumber
+    where
+        Runtime::BlockNumber: TryFrom<U256>,
+    {
+        saturating_from_u256(value)
+    }
+}
+
+/// Memoizes storage reads within a single precompile call, so an item read again, e.g. a config
+/// consulted for each item of a list, is only charged and read once.
+///
+/// A cache must not outlive the call it is created in, and must be cleared after anything
+/// that can write to the cached items, such as dispatching a call.
+pub struct StorageReadCache<K, V> {
+    entries: BTreeMap<K, V>,
+}
+
+impl<K: Ord, V: Clone> StorageReadCache<K, V> {
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Return the cached value of `key`, or read it with `read`, which is expected to charge
+    /// for the read, and cache it. Failed reads aren't cached.
+    pub fn get_or_try_read(
+        &mut self,
+        key: K,
+        read: impl FnOnce(&K) -> EvmResult<V>,
+    ) -> EvmResult<V> {
+        if let Some(value) = self.entries.get(&key) {
+            return Ok(value.clone());
+        }
+
+        let value = read(&key)?;
+        self.entries.insert(key, value.clone());
+        Ok(value)
+    }
+
+    /// Return the cached value of `key`, or record the cost of one DB read of `data_length`
+    /// bytes, read it with `read` and cache it.
+    pub fn read<Runtime: pallet_evm::Config>(
+        &mut self,
+        handle: &mut impl PrecompileHandle,
+        key: K,
+        data_length: usize,
+        read: impl FnOnce(&K) -> V,
+    ) -> EvmResult<V> {
+        self.get_or_try_read(key, |key| {
+            handle.record_db_read::<Runtime>(data_length)?;
+            Ok(read(key))
+        })
+    }
+
+    /// Forget all the cached values.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<K: Ord, V: Clone> Default for StorageReadCache<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Balance of the native currency of the runtime, as used by `pallet-evm`.
+pub type NativeBalanceOf<Runtime> = <<Runtime as pallet_evm::Config>::Currency as Currency<
+    <Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// Convert `value`, e.g. read from the calldata, into a runtime type such as a balance,
+/// reverting with "`name` is too large" if it doesn't fit.
+pub fn checked_from_u256<T: TryFrom<U256>>(value: U256, name: &str) -> EvmResult<T> {
+    T::try_from(value).map_err(|_| revert(alloc::format!("{} is too large", name)))
+}
+
+/// Convert `value`, e.g. read from the calldata, into a runtime type such as a balance,
+/// saturating at the maximum value of the type.
+pub fn saturating_from_u256<T: TryFrom<U256> + Bounded>(value: U256) -> T {
+    T::try_from(value).unwrap_or_else(|_| T::max_value())
 }
 
 /// Represents modifiers a Solidity function can be annotated with.
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum FunctionModifier {
     /// Function that doesn't modify the state.
     View,
@@ -307,6 +495,21 @@ pub trait PrecompileHandleExt: PrecompileHandle {
         &mut self,
         data_length: usize,
     ) -> Result<(), ExitError>;
+
+    /// Record the base cost of a selector, equal to the gas cost of one DB read.
+    ///
+    /// Every selector handler is expected to charge at least this much before decoding its
+    /// arguments or touching the state, either through this method or through `record_db_read`.
+    /// Use `testing::assert_selectors_charge_base_cost` to enforce it in tests.
+    #[must_use]
+    fn record_base_cost<Runtime: pallet_evm::Config>(&mut self) -> EvmResult;
+
+    /// Record the cost of the input processed by readers metered with `meter`.
+    ///
+    /// Readers metered with `ReadMeter::new(handle.remaining_gas())` stop before processing
+    /// more input than the remaining gas can pay for.
+    #[must_use]
+    fn record_read_cost(&mut self, meter: &ReadMeter) -> EvmResult;
 }
 
 pub fn log_costs(topics: usize, data_len: usize) -> EvmResult<u64> {
@@ -452,16 +655,93 @@ impl<T: PrecompileHandle> PrecompileHandleExt for T {
         self.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
         self.record_external_cost(None, Some(data_length as u64))
     }
+
+    #[must_use]
+    fn record_base_cost<Runtime: pallet_evm::Config>(&mut self) -> EvmResult {
+        self.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+        Ok(())
+    }
+
+    #[must_use]
+    fn record_read_cost(&mut self, meter: &ReadMeter) -> EvmResult {
+        self.record_cost(meter.gas_cost())?;
+
+        Ok(())
+    }
 }
 
+/// Selector of `Error(string)`, the error Solidity emits for `revert("reason")`.
+pub const ERROR_STRING_SELECTOR: u32 = 0x08c379a0;
+
+/// Revert with a human readable reason, encoded as a Solidity `Error(string)`.
 #[must_use]
-pub fn revert(output: impl AsRef<[u8]>) -> PrecompileFailure {
+pub fn revert(message: impl AsRef<[u8]>) -> PrecompileFailure {
+    revert_as_error_string(message)
+}
+
+/// Revert with `message` wrapped in the `Error(string)` ABI encoding, which
+/// wallets and libraries decode as the revert reason.
+#[must_use]
+pub fn revert_as_error_string(message: impl AsRef<[u8]>) -> PrecompileFailure {
+    revert_as_raw(
+        EvmDataWriter::new_with_selector(ERROR_STRING_SELECTOR)
+            .write(Bytes(message.as_ref().to_vec()))
+            .build(),
+    )
+}
+
+/// Revert with `output` as is, e.g. to forward the revert data of a subcall.
+#[must_use]
+pub fn revert_as_raw(output: impl AsRef<[u8]>) -> PrecompileFailure {
     PrecompileFailure::Revert {
         exit_status: ExitRevert::Reverted,
         output: output.as_ref().to_owned(),
     }
 }
 
+/// A Solidity custom error, e.g. `error InsufficientBalance(uint256 available)`.
+///
+/// Usually derived with `#[derive(EvmError)]`, so callers can `catch` it by type.
+pub trait EvmError: Sized {
+    /// Selector of the error, the first 4 bytes of the Keccak256 of its signature.
+    const SELECTOR: u32;
+    /// Canonical signature of the error, e.g. `InsufficientBalance(uint256)`.
+    const SIGNATURE: &'static str;
+
+    /// ABI encoded arguments of the error.
+    fn encode_arguments(self) -> Vec<u8>;
+}
+
+/// Revert with a Solidity custom error: its selector followed by its ABI encoded arguments.
+#[must_use]
+pub fn revert_with_error<E: EvmError>(error: E) -> PrecompileFailure {
+    let mut output = Vec::from(E::SELECTOR.to_be_bytes());
+    output.extend(error.encode_arguments());
+    revert_as_raw(output)
+}
+
+/// Revert because a dispatched Substrate call failed, with a readable reason.
+#[must_use]
+pub fn revert_dispatch_error(error: DispatchError) -> PrecompileFailure {
+    revert(alloc::format!(
+        "Dispatched call failed with error: {}",
+        dispatch_error_reason(&error)
+    ))
+}
+
+/// Readable reason of a dispatch error: the name of the pallet error if known,
+/// e.g. `BalanceLow`, or the debug output of the error otherwise.
+pub fn dispatch_error_reason(error: &DispatchError) -> String {
+    match error {
+        DispatchError::Module(ModuleError {
+            message: Some(message),
+            ..
+        }) => (*message).into(),
+        error => alloc::format!("{:?}", error),
+    }
+}
+
 #[must_use]
 pub fn succeed(output: impl AsRef<[u8]>) -> PrecompileOutput {
     PrecompileOutput {
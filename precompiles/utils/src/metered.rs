@@ -0,0 +1,216 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile handle charging the storage accesses of runtime calls.
+//!
+//! Precompiles calling into pallets tend to charge hardcoded estimates for the storage the pallets
+//! touch. [`MeteredHandle`] charges runtime weights instead: DB reads and writes are priced with
+//! the runtime `DbWeight`, runtime calls with their weight, and each weight is converted to gas
+//! with the runtime `GasWeightMapping` while its proof size is recorded as an external cost,
+//! counting towards the proof size limit of the transaction.
+
+use fp_evm::{Context, ExitError, ExitReason, PrecompileHandle, Transfer};
+use frame_support::{
+    dispatch::{DispatchResultWithPostInfo, Dispatchable, GetDispatchInfo, PostDispatchInfo},
+    pallet_prelude::Weight,
+    traits::Get,
+};
+use pallet_evm::GasWeightMapping;
+use sp_core::{H160, H256};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use crate::{revert, revert_dispatch_error, EvmResult};
+
+/// Wraps a precompile handle, charging storage accesses and runtime calls from their weights.
+///
+/// The wrapper is itself a `PrecompileHandle`, so it can be passed on to code expecting one.
+pub struct MeteredHandle<'a, H, Runtime> {
+    handle: &'a mut H,
+    recorded: Weight,
+    _runtime: PhantomData<Runtime>,
+}
+
+impl<'a, H, Runtime> MeteredHandle<'a, H, Runtime>
+where
+    H: PrecompileHandle,
+    Runtime: pallet_evm::Config,
+{
+    pub fn new(handle: &'a mut H) -> Self {
+        Self {
+            handle,
+            recorded: Weight::zero(),
+            _runtime: PhantomData,
+        }
+    }
+
+    /// Weight charged through this handle so far.
+    pub fn recorded_weight(&self) -> Weight {
+        self.recorded
+    }
+
+    /// Charge `weight`, its ref time as gas and its proof size as an external cost.
+    pub fn record_weight(&mut self, weight: Weight) -> EvmResult {
+        self.handle
+            .record_cost(Runtime::GasWeightMapping::weight_to_gas(weight))?;
+        self.handle
+            .record_external_cost(None, Some(weight.proof_size()))?;
+        self.recorded.saturating_accrue(weight);
+
+        Ok(())
+    }
+
+    /// Charge `reads` DB reads of keys & values at most `data_length` bytes long.
+    pub fn record_db_reads(&mut self, reads: u64, data_length: usize) -> EvmResult {
+        let proof_size = (data_length as u64).saturating_mul(reads);
+        self.record_weight(
+            <Runtime as frame_system::Config>::DbWeight::get()
+                .reads(reads)
+                .saturating_add(Weight::from_parts(0, proof_size)),
+        )
+    }
+
+    /// Charge `writes` DB writes.
+    pub fn record_db_writes(&mut self, writes: u64) -> EvmResult {
+        self.record_weight(<Runtime as frame_system::Config>::DbWeight::get().writes(writes))
+    }
+
+    /// Call into the runtime, e.g. a pallet function, weighing at most `weight`.
+    ///
+    /// The remaining gas is checked against `weight` and its proof size is recorded before `call`
+    /// runs. `call` returns its actual weight if known, which is charged instead of `weight`.
+    pub fn call_runtime<T>(
+        &mut self,
+        weight: Weight,
+        call: impl FnOnce() -> (T, Option<Weight>),
+    ) -> EvmResult<T> {
+        if Runtime::GasWeightMapping::weight_to_gas(weight) > self.handle.remaining_gas() {
+            return Err(ExitError::OutOfGas.into());
+        }
+        self.handle
+            .record_external_cost(None, Some(weight.proof_size()))?;
+
+        let (output, actual_weight) = call();
+
+        let used_weight = actual_weight.unwrap_or(weight).min(weight);
+        self.handle.refund_external_cost(
+            None,
+            Some(weight.proof_size().saturating_sub(used_weight.proof_size())),
+        );
+        self.handle
+            .record_cost(Runtime::GasWeightMapping::weight_to_gas(used_weight))?;
+        self.recorded.saturating_accrue(used_weight);
+
+        Ok(output)
+    }
+
+    /// Call into the runtime like `call_runtime`, for calls returning dispatch results.
+    /// Reverts in a static context, as the call may modify the state, and if `call` fails,
+    /// after charging the weight it used.
+    pub fn try_call_runtime(
+        &mut self,
+        weight: Weight,
+        call: impl FnOnce() -> DispatchResultWithPostInfo,
+    ) -> EvmResult<PostDispatchInfo> {
+        if self.handle.is_static() {
+            return Err(revert("can't dispatch a call in static context"));
+        }
+
+        self.call_runtime(weight, || {
+            let result = call();
+            let actual_weight = match &result {
+                Ok(post_info) => post_info.actual_weight,
+                Err(error) => error.post_info.actual_weight,
+            };
+            (result, actual_weight)
+        })?
+        .map_err(|e| revert_dispatch_error(e.error))
+    }
+
+    /// Dispatch a runtime call, charging its weight.
+    /// Reverts if the call fails, after charging the weight it used.
+    pub fn dispatch<Call>(
+        &mut self,
+        origin: <Call as Dispatchable>::RuntimeOrigin,
+        call: Call,
+    ) -> EvmResult<PostDispatchInfo>
+    where
+        Call: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    {
+        let weight = call.get_dispatch_info().weight;
+        self.try_call_runtime(weight, || call.dispatch(origin))
+    }
+}
+
+impl<'a, H: PrecompileHandle, Runtime> PrecompileHandle for MeteredHandle<'a, H, Runtime> {
+    fn call(
+        &mut self,
+        address: H160,
+        transfer: Option<Transfer>,
+        input: Vec<u8>,
+        target_gas: Option<u64>,
+        is_static: bool,
+        context: &Context,
+    ) -> (ExitReason, Vec<u8>) {
+        self.handle
+            .call(address, transfer, input, target_gas, is_static, context)
+    }
+
+    fn record_cost(&mut self, cost: u64) -> Result<(), ExitError> {
+        self.handle.record_cost(cost)
+    }
+
+    fn record_external_cost(
+        &mut self,
+        ref_time: Option<u64>,
+        proof_size: Option<u64>,
+    ) -> Result<(), ExitError> {
+        self.handle.record_external_cost(ref_time, proof_size)
+    }
+
+    fn refund_external_cost(&mut self, ref_time: Option<u64>, proof_size: Option<u64>) {
+        self.handle.refund_external_cost(ref_time, proof_size)
+    }
+
+    fn remaining_gas(&self) -> u64 {
+        self.handle.remaining_gas()
+    }
+
+    fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) -> Result<(), ExitError> {
+        self.handle.log(address, topics, data)
+    }
+
+    fn code_address(&self) -> H160 {
+        self.handle.code_address()
+    }
+
+    fn input(&self) -> &[u8] {
+        self.handle.input()
+    }
+
+    fn context(&self) -> &Context {
+        self.handle.context()
+    }
+
+    fn is_static(&self) -> bool {
+        self.handle.is_static()
+    }
+
+    fn gas_limit(&self) -> Option<u64> {
+        self.handle.gas_limit()
+    }
+}
@@ -0,0 +1,101 @@
+// This file is part of Astar.
+
+// Copyright 2019-2022 PureStake Inc.
+// Copyright (C) 2022-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of Utils package, originally developed by Purestake Inc.
+// Utils package used in Astar Network in terms of GPLv3.
+//
+// Utils is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Utils is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Utils.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Checks that selectors charge their base cost before doing anything else.
+//!
+//! Precompiles declared with `#[precompile]` have the base cost charged by the generated
+//! dispatch, before the input is decoded. Precompiles dispatching by hand on a
+//! `#[generate_function_selector]` enum aren't covered by the macro and must call
+//! `record_base_cost` themselves, which `assert_selectors_charge_base_cost` checks in tests.
+
+use std::{cell::RefCell, collections::BTreeMap};
+
+/// Lowest cost recorded first by each executed selector.
+/// `None` if at least one execution of the selector didn't record any cost.
+pub type SelectorBaseCosts = BTreeMap<u32, Option<u64>>;
+
+thread_local! {
+    static SELECTOR_BASE_COSTS: RefCell<Option<SelectorBaseCosts>> = RefCell::new(None);
+}
+
+/// Records the first cost charged by an execution, if base costs are being tracked.
+pub(crate) fn record_selector_base_cost(input: &[u8], first_cost: Option<u64>) {
+    if input.len() < 4 {
+        return;
+    }
+    let selector = u32::from_be_bytes([input[0], input[1], input[2], input[3]]);
+
+    SELECTOR_BASE_COSTS.with(|costs| {
+        if let Some(costs) = costs.borrow_mut().as_mut() {
+            costs
+                .entry(selector)
+                .and_modify(|cost| *cost = (*cost).min(first_cost))
+                .or_insert(first_cost);
+        }
+    });
+}
+
+/// Runs the provided tests and returns the lowest first recorded cost of each executed selector.
+pub fn track_selector_base_costs(tests: &[fn()]) -> SelectorBaseCosts {
+    SELECTOR_BASE_COSTS.with(|costs| *costs.borrow_mut() = Some(SelectorBaseCosts::new()));
+
+    for test in tests {
+        test();
+    }
+
+    SELECTOR_BASE_COSTS
+        .with(|costs| costs.borrow_mut().take())
+        .unwrap_or_default()
+}
+
+/// Runs the provided tests and panics unless every selector was executed and, in every
+/// execution, recorded a cost of at least `base_cost` before any other cost.
+///
+/// This catches handlers which decode arguments or read the state before charging anything.
+/// `base_cost` is usually `RuntimeHelper::<Runtime>::db_read_gas_cost()`, which is what
+/// `PrecompileHandleExt::record_base_cost` charges.
+pub fn assert_selectors_charge_base_cost(
+    selectors: &[(u32, &str)],
+    base_cost: u64,
+    tests: &[fn()],
+) {
+    let costs = track_selector_base_costs(tests);
+
+    let offending: Vec<String> = selectors
+        .iter()
+        .filter_map(|(selector, signature)| match costs.get(selector) {
+            None => Some(format!("{}: not executed", signature)),
+            Some(None) => Some(format!("{}: no cost recorded", signature)),
+            Some(Some(cost)) if *cost < base_cost => Some(format!(
+                "{}: first recorded cost {} is below base cost {}",
+                signature, cost, base_cost
+            )),
+            Some(_) => None,
+        })
+        .collect();
+
+    assert!(
+        offending.is_empty(),
+        "Selectors not charging base cost:\n{}",
+        offending.join("\n")
+    );
+}
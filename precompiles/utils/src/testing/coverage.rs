@@ -0,0 +1,99 @@
+// This file is part of Astar.
+
+// Copyright 2019-2022 PureStake Inc.
+// Copyright (C) 2022-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of Utils package, originally developed by Purestake Inc.
+// Utils package used in Astar Network in terms of GPLv3.
+//
+// Utils is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Utils is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Utils.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::{cell::RefCell, collections::BTreeMap};
+
+/// Outcomes observed for a single selector while coverage is being tracked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SelectorOutcomes {
+    /// Selector was executed successfully at least once.
+    pub success: bool,
+    /// Selector reverted (or errored) at least once.
+    pub revert: bool,
+}
+
+/// Outcomes observed per selector.
+pub type SelectorCoverage = BTreeMap<u32, SelectorOutcomes>;
+
+thread_local! {
+    static SELECTOR_COVERAGE: RefCell<Option<SelectorCoverage>> = RefCell::new(None);
+}
+
+/// Records the outcome of an execution, if coverage is being tracked.
+pub(crate) fn record_selector_outcome(input: &[u8], success: bool) {
+    if input.len() < 4 {
+        return;
+    }
+    let selector = u32::from_be_bytes([input[0], input[1], input[2], input[3]]);
+
+    SELECTOR_COVERAGE.with(|coverage| {
+        if let Some(coverage) = coverage.borrow_mut().as_mut() {
+            let outcomes = coverage.entry(selector).or_default();
+            if success {
+                outcomes.success = true;
+            } else {
+                outcomes.revert = true;
+            }
+        }
+    });
+}
+
+/// Runs the provided tests and returns the outcomes observed for each executed selector.
+pub fn track_selector_coverage(tests: &[fn()]) -> SelectorCoverage {
+    SELECTOR_COVERAGE.with(|coverage| *coverage.borrow_mut() = Some(SelectorCoverage::new()));
+
+    for test in tests {
+        test();
+    }
+
+    SELECTOR_COVERAGE
+        .with(|coverage| coverage.borrow_mut().take())
+        .unwrap_or_default()
+}
+
+/// Runs the provided tests and panics unless every selector was covered by at least
+/// one successful and one reverting execution.
+///
+/// Selectors are usually taken from the `SELECTORS` constant generated by
+/// `#[generate_function_selector]`, so newly added functions are required to be covered.
+pub fn assert_selector_coverage(selectors: &[(u32, &str)], tests: &[fn()]) {
+    let coverage = track_selector_coverage(tests);
+
+    let missing: Vec<String> = selectors
+        .iter()
+        .filter_map(|(selector, signature)| {
+            let outcomes = coverage.get(selector).copied().unwrap_or_default();
+            match (outcomes.success, outcomes.revert) {
+                (true, true) => None,
+                (false, true) => Some(format!("{}: success path", signature)),
+                (true, false) => Some(format!("{}: revert path", signature)),
+                (false, false) => Some(format!("{}: success & revert paths", signature)),
+            }
+        })
+        .collect();
+
+    assert!(
+        missing.is_empty(),
+        "Selectors not covered by tests:\n{}",
+        missing.join("\n")
+    );
+}
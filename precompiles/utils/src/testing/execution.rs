@@ -42,7 +42,14 @@
 // along with Utils.  If not, see <http://www.gnu.org/licenses/>.
 
 use {
-    crate::testing::{decode_revert_message, MockHandle, PrettyLog, SubcallHandle, SubcallTrait},
+    crate::{
+        testing::{
+            decode_revert_message, record_selector_base_cost, record_selector_outcome,
+            scripted_subcalls, ExpectedSubcall, MockHandle, PrettyLog, SubcallHandle,
+            SubcallOutput, SubcallTrait,
+        },
+        EvmData, EvmDataWriter,
+    },
     assert_matches::assert_matches,
     fp_evm::{
         Context, ExitError, ExitSucceed, Log, PrecompileFailure, PrecompileOutput,
@@ -62,6 +69,7 @@ pub struct PrecompilesTester<'p, P> {
 
     expected_cost: Option<u64>,
     expected_logs: Option<Vec<PrettyLog>>,
+    expected_subcalls: Option<Vec<ExpectedSubcall>>,
 }
 
 impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
@@ -92,6 +100,7 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
 
             expected_cost: None,
             expected_logs: None,
+            expected_subcalls: None,
         }
     }
 
@@ -105,6 +114,12 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
         self
     }
 
+    /// Answer the subcalls made by the precompile with `outputs`, in order.
+    /// Any additional subcall makes the test panic.
+    pub fn with_subcall_outputs(self, outputs: impl IntoIterator<Item = SubcallOutput>) -> Self {
+        self.with_subcall_handle(scripted_subcalls(outputs))
+    }
+
     pub fn with_target_gas(mut self, target_gas: Option<u64>) -> Self {
         self.target_gas = target_gas;
         self
@@ -134,6 +149,21 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
         self
     }
 
+    pub fn expect_no_subcalls(mut self) -> Self {
+        self.expected_subcalls = Some(vec![]);
+        self
+    }
+
+    /// Expect the precompile to make `subcall`, after the previously expected ones.
+    pub fn expect_subcall(mut self, subcall: ExpectedSubcall) -> Self {
+        self.expected_subcalls = Some({
+            let mut subcalls = self.expected_subcalls.unwrap_or_else(Vec::new);
+            subcalls.push(subcall);
+            subcalls
+        });
+        self
+    }
+
     fn assert_optionals(&self) {
         if let Some(cost) = &self.expected_cost {
             assert_eq!(&self.handle.gas_used, cost);
@@ -142,6 +172,27 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
         if let Some(logs) = &self.expected_logs {
             similar_asserts::assert_eq!(&self.handle.logs, logs);
         }
+
+        if let Some(expected_subcalls) = &self.expected_subcalls {
+            let subcalls: Vec<ExpectedSubcall> = self
+                .handle
+                .subcalls
+                .iter()
+                .enumerate()
+                .map(|(i, subcall)| {
+                    let mut subcall = ExpectedSubcall::from(subcall);
+                    // Forwarded gas is only compared when it is expected.
+                    if expected_subcalls
+                        .get(i)
+                        .map_or(false, |expected| expected.target_gas.is_none())
+                    {
+                        subcall.target_gas = None;
+                    }
+                    subcall
+                })
+                .collect();
+            similar_asserts::assert_eq!(&subcalls, expected_subcalls);
+        }
     }
 
     fn execute(&mut self) -> Option<PrecompileResult> {
@@ -155,6 +206,7 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
         let res = self.precompiles.execute(handle);
 
         self.subcall_handle = handle.subcall_handle.take();
+        record_selector_base_cost(&handle.input, handle.first_cost);
 
         res
     }
@@ -211,6 +263,7 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
         }
 
         self.assert_optionals();
+        record_selector_outcome(&self.handle.input, true);
     }
 
     /// Execute the precompile set and check it returns provided Solidity encoded output.
@@ -218,16 +271,48 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
         self.execute_returns_raw(output)
     }
 
+    /// Execute the precompile set and check it returns `output` once ABI encoded.
+    pub fn execute_returns_encoded(self, output: impl EvmData) {
+        self.execute_returns_raw(EvmDataWriter::new().write(output).build())
+    }
+
     /// Execute the precompile set and check if it reverts.
-    /// Take a closure allowing to perform custom matching on the output.
+    /// Take a closure allowing to perform custom matching on the output,
+    /// which is the reason of `Error(string)` reverts and the raw data otherwise.
     pub fn execute_reverts(mut self, check: impl Fn(&[u8]) -> bool) {
         let res = self.execute();
         assert_matches!(
             res,
             Some(Err(PrecompileFailure::Revert { output, ..}))
-                if check(&output)
+                if check(decode_revert_message(&output))
         );
         self.assert_optionals();
+        record_selector_outcome(&self.handle.input, false);
+    }
+
+    /// Execute the precompile set and check it reverts with a reason accepted by `matcher`,
+    /// printing the actual reason otherwise.
+    pub fn execute_reverts_with(mut self, matcher: impl RevertMatcher) {
+        let res = self.execute();
+        match res {
+            Some(Err(PrecompileFailure::Revert { output, .. })) => {
+                let reason = decode_revert_message(&output);
+                if !matcher.matches(reason) {
+                    eprintln!(
+                        "Revert reason (bytes): {:?}",
+                        sp_core::hexdisplay::HexDisplay::from(&reason)
+                    );
+                    panic!(
+                        "Revert reason {:?} doesn't match {:?}",
+                        String::from_utf8_lossy(reason),
+                        matcher
+                    );
+                }
+            }
+            other => panic!("Expected a revert, got: {:?}", other),
+        }
+        self.assert_optionals();
+        record_selector_outcome(&self.handle.input, false);
     }
 
     /// Execute the precompile set and check it returns provided output.
@@ -238,6 +323,43 @@ impl<'p, P: PrecompileSet> PrecompilesTester<'p, P> {
             Some(Err(PrecompileFailure::Error { exit_status: error }))
         );
         self.assert_optionals();
+        record_selector_outcome(&self.handle.input, false);
+    }
+}
+
+/// Expectation on the reason of a revert, see `PrecompilesTester::execute_reverts_with`.
+///
+/// A string matches the exact reason.
+pub trait RevertMatcher: core::fmt::Debug {
+    fn matches(&self, reason: &[u8]) -> bool;
+}
+
+impl RevertMatcher for &str {
+    fn matches(&self, reason: &[u8]) -> bool {
+        self.as_bytes() == reason
+    }
+}
+
+/// Matches reasons containing the given text.
+#[derive(Debug)]
+pub struct RevertContains<'a>(pub &'a str);
+
+impl RevertMatcher for RevertContains<'_> {
+    fn matches(&self, reason: &[u8]) -> bool {
+        core::str::from_utf8(reason).map_or(false, |reason| reason.contains(self.0))
+    }
+}
+
+/// Matches reverts of a runtime call which failed with the given error,
+/// e.g. `DispatchFailed("BalanceLow")`.
+#[derive(Debug)]
+pub struct DispatchFailed<'a>(pub &'a str);
+
+impl RevertMatcher for DispatchFailed<'_> {
+    fn matches(&self, reason: &[u8]) -> bool {
+        reason
+            .strip_prefix(b"Dispatched call failed with error: ".as_slice())
+            .map_or(false, |error| error == self.0.as_bytes())
     }
 }
 
@@ -24,7 +24,7 @@ use {
     crate::testing::PrettyLog,
     evm::{ExitRevert, ExitSucceed},
     fp_evm::{Context, ExitError, ExitReason, Log, PrecompileHandle, Transfer},
-    sp_core::{H160, H256},
+    sp_core::{H160, H256, U256},
     sp_std::boxed::Box,
 };
 
@@ -38,6 +38,58 @@ pub struct Subcall {
     pub context: Context,
 }
 
+/// Subcall expected to be made by a precompile, see `PrecompilesTester::expect_subcall`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedSubcall {
+    pub address: H160,
+    pub input: Vec<u8>,
+    /// Value sent along the subcall.
+    pub value: U256,
+    /// Gas forwarded to the subcall, not checked if `None`.
+    pub target_gas: Option<u64>,
+    pub is_static: bool,
+}
+
+impl ExpectedSubcall {
+    /// Non-static subcall of `address` with `input`, without value.
+    pub fn new(address: impl Into<H160>, input: impl Into<Vec<u8>>) -> Self {
+        Self {
+            address: address.into(),
+            input: input.into(),
+            value: U256::zero(),
+            target_gas: None,
+            is_static: false,
+        }
+    }
+
+    pub fn with_value(mut self, value: impl Into<U256>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn with_target_gas(mut self, target_gas: Option<u64>) -> Self {
+        self.target_gas = target_gas;
+        self
+    }
+
+    pub fn with_static(mut self, is_static: bool) -> Self {
+        self.is_static = is_static;
+        self
+    }
+}
+
+impl From<&Subcall> for ExpectedSubcall {
+    fn from(subcall: &Subcall) -> Self {
+        Self {
+            address: subcall.address,
+            input: subcall.input.clone(),
+            value: subcall.context.apparent_value,
+            target_gas: subcall.target_gas,
+            is_static: subcall.is_static,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SubcallOutput {
     pub reason: ExitReason,
@@ -73,6 +125,24 @@ impl SubcallOutput {
             logs: Vec::new(),
         }
     }
+
+    /// Successful subcall returning `output`.
+    pub fn returns(output: impl Into<Vec<u8>>) -> Self {
+        Self {
+            output: output.into(),
+            ..Self::succeed()
+        }
+    }
+
+    pub fn with_cost(mut self, cost: u64) -> Self {
+        self.cost = cost;
+        self
+    }
+
+    pub fn with_log(mut self, log: Log) -> Self {
+        self.logs.push(log);
+        self
+    }
 }
 
 pub trait SubcallTrait: FnMut(Subcall) -> SubcallOutput + 'static {}
@@ -81,12 +151,29 @@ impl<T: FnMut(Subcall) -> SubcallOutput + 'static> SubcallTrait for T {}
 
 pub type SubcallHandle = Box<dyn SubcallTrait>;
 
+/// Subcall handle answering the subcalls with `outputs`, in order.
+/// Panics if more subcalls are made than there are outputs.
+pub fn scripted_subcalls(outputs: impl IntoIterator<Item = SubcallOutput>) -> impl SubcallTrait {
+    let mut outputs: Vec<SubcallOutput> = outputs.into_iter().collect();
+    outputs.reverse();
+
+    move |subcall: Subcall| {
+        outputs
+            .pop()
+            .unwrap_or_else(|| panic!("unexpected subcall: {:?}", subcall))
+    }
+}
+
 /// Mock handle to write tests for precompiles.
 pub struct MockHandle {
     pub gas_limit: u64,
     pub gas_used: u64,
+    /// Cost recorded first during the execution, if any.
+    pub first_cost: Option<u64>,
     pub logs: Vec<PrettyLog>,
     pub subcall_handle: Option<SubcallHandle>,
+    /// Subcalls made during the execution, in order.
+    pub subcalls: Vec<Subcall>,
     pub code_address: H160,
     pub input: Vec<u8>,
     pub context: Context,
@@ -98,8 +185,10 @@ impl MockHandle {
         Self {
             gas_limit: u64::MAX,
             gas_used: 0,
+            first_cost: None,
             logs: vec![],
             subcall_handle: None,
+            subcalls: vec![],
             code_address,
             input: Vec::new(),
             context,
@@ -130,6 +219,16 @@ impl PrecompileHandle for MockHandle {
             return (ExitReason::Error(ExitError::OutOfGas), vec![]);
         }
 
+        let subcall = Subcall {
+            address,
+            transfer,
+            input,
+            target_gas,
+            is_static,
+            context: context.clone(),
+        };
+        self.subcalls.push(subcall.clone());
+
         match &mut self.subcall_handle {
             Some(handle) => {
                 let SubcallOutput {
@@ -137,14 +236,7 @@ impl PrecompileHandle for MockHandle {
                     output,
                     cost,
                     logs,
-                } = handle(Subcall {
-                    address,
-                    transfer,
-                    input,
-                    target_gas,
-                    is_static,
-                    context: context.clone(),
-                });
+                } = handle(subcall);
 
                 if self.record_cost(cost).is_err() {
                     return (ExitReason::Error(ExitError::OutOfGas), vec![]);
@@ -162,6 +254,7 @@ impl PrecompileHandle for MockHandle {
     }
 
     fn record_cost(&mut self, cost: u64) -> Result<(), ExitError> {
+        self.first_cost.get_or_insert(cost);
         self.gas_used += cost;
 
         if self.gas_used > self.gas_limit {
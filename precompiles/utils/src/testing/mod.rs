@@ -21,23 +21,31 @@
 // along with Utils.  If not, see <http://www.gnu.org/licenses/>.
 
 pub mod account;
+pub mod base_cost;
+pub mod coverage;
 pub mod execution;
 pub mod handle;
+pub mod solidity;
 
-pub use {account::*, execution::*, handle::*};
+pub use {account::*, base_cost::*, coverage::*, execution::*, handle::*, solidity::*};
 
 use fp_evm::Log;
 
+/// Extract the reason of an `Error(string)` revert.
+/// Any other revert data is returned as is.
 pub fn decode_revert_message(encoded: &[u8]) -> &[u8] {
-    let encoded_len = encoded.len();
-    // selector 4 + offset 32 + string length 32
-    if encoded_len > 68 {
-        let message_len = encoded[36..68].iter().sum::<u8>();
-        if encoded_len >= 68 + message_len as usize {
-            return &encoded[68..68 + message_len as usize];
-        }
+    let selector = crate::ERROR_STRING_SELECTOR.to_be_bytes();
+    if !encoded.starts_with(&selector) {
+        return encoded;
+    }
+
+    let mut reader =
+        crate::EvmDataReader::new_skip_selector(encoded).expect("selector is 4 bytes long; qed");
+    match reader.read::<crate::Bytes>() {
+        // selector 4 + offset 32 + string length 32
+        Ok(message) if encoded.len() >= 68 + message.0.len() => &encoded[68..68 + message.0.len()],
+        _ => b"decode_revert_message: error",
     }
-    b"decode_revert_message: error"
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -0,0 +1,234 @@
+// This file is part of Astar.
+
+// Copyright 2019-2022 PureStake Inc.
+// Copyright (C) 2022-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// This file is part of Utils package, originally developed by Purestake Inc.
+// Utils package used in Astar Network in terms of GPLv3.
+//
+// Utils is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Utils is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Utils.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Checker keeping the Solidity interfaces shipped with precompiles in sync with their
+//! Rust implementation.
+//!
+//! Only function names and argument types are compared. Return values and mutability aren't
+//! part of the selectors, so they are neither checked nor generated.
+
+use std::{collections::BTreeSet, path::Path};
+
+/// Returns the canonical signatures of the functions declared in the given Solidity source,
+/// e.g. `transfer(address,uint256)`.
+///
+/// Enums declared in the source are encoded as `uint8`, function types as `function`,
+/// structs aren't supported.
+pub fn solidity_signatures(source: &str) -> Vec<String> {
+    let source = strip_comments(source);
+    let enums = declared_names(&source, "enum");
+
+    declared_names(&source, "function")
+        .into_iter()
+        .map(|(name, rest)| {
+            let arguments = split_arguments(matching_parenthesis(rest))
+                .into_iter()
+                .map(|argument| {
+                    let ty = argument.split_whitespace().next().unwrap_or_default();
+                    canonical_type(ty, &enums)
+                })
+                .collect::<Vec<_>>();
+            format!("{}({})", name, arguments.join(","))
+        })
+        .collect()
+}
+
+/// Stub declarations of the functions of `selectors`, printed when the check fails so the
+/// missing ones can be copied into the shipped interface.
+///
+/// This isn't a complete interface: return values, mutability and argument names can't be
+/// derived from the selectors and must be completed by hand, as well as structs declarations
+/// for tuple arguments.
+pub fn solidity_function_stubs(name: &str, selectors: &[(u32, &str)]) -> String {
+    let mut interface = format!("pragma solidity ^0.8.0;\n\ninterface {} {{\n", name);
+
+    for (selector, signature) in selectors {
+        let start = signature
+            .find('(')
+            .expect("signatures have an argument list");
+        let function = &signature[..start];
+        let arguments = split_arguments(&signature[start + 1..signature.len() - 1])
+            .into_iter()
+            .enumerate()
+            .map(|(i, ty)| {
+                let dynamic = ty == "bytes" || ty == "string" || ty.ends_with(']');
+                let location = if dynamic { " calldata" } else { "" };
+                format!("{}{} arg{}", ty, location, i)
+            })
+            .collect::<Vec<_>>();
+
+        interface.push_str(&format!(
+            "    /// @dev selector: 0x{:08x}\n    function {}({}) external;\n",
+            selector,
+            function,
+            arguments.join(", ")
+        ));
+    }
+
+    interface.push_str("}\n");
+    interface
+}
+
+/// Panics unless the Solidity file at `path` declares exactly the functions of `selectors`.
+///
+/// Selectors are usually taken from the `SELECTORS` constant generated by
+/// `#[generate_function_selector]`, so the shipped interface can't drift from the implementation.
+pub fn assert_solidity_interface(path: impl AsRef<Path>, selectors: &[(u32, &str)]) {
+    let path = path.as_ref();
+    let source = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Unable to read {}: {}", path.display(), e));
+
+    let declared: BTreeSet<String> = solidity_signatures(&source).into_iter().collect();
+    let implemented: BTreeSet<String> = selectors
+        .iter()
+        .map(|(_, signature)| signature.to_string())
+        .collect();
+
+    let missing: Vec<_> = implemented.difference(&declared).cloned().collect();
+    let unknown: Vec<_> = declared.difference(&implemented).cloned().collect();
+
+    assert!(
+        missing.is_empty() && unknown.is_empty(),
+        "{} doesn't match the precompile.\nNot declared: {:?}\nNot implemented: {:?}\n\
+        Function stubs, to be completed with return values and mutability:\n{}",
+        path.display(),
+        missing,
+        unknown,
+        solidity_function_stubs("Precompile", selectors)
+    );
+}
+
+/// Removes `//` and `/* */` comments.
+fn strip_comments(source: &str) -> String {
+    let mut stripped = String::with_capacity(source.len());
+    let mut rest = source;
+
+    loop {
+        let line = rest.find("//");
+        let block = rest.find("/*");
+        let (start, end_marker) = match (line, block) {
+            (Some(l), Some(b)) if b < l => (b, "*/"),
+            (Some(l), _) => (l, "\n"),
+            (None, Some(b)) => (b, "*/"),
+            (None, None) => break,
+        };
+
+        stripped.push_str(&rest[..start]);
+        rest = &rest[start + 2..];
+        rest = match rest.find(end_marker) {
+            Some(end) => &rest[end + end_marker.len()..],
+            None => "",
+        };
+        stripped.push(' ');
+    }
+
+    stripped.push_str(rest);
+    stripped
+}
+
+/// Names following the `keyword` keyword, together with the source that follows them.
+fn declared_names<'a>(source: &'a str, keyword: &str) -> Vec<(&'a str, &'a str)> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    let mut names = vec![];
+
+    for (index, _) in source.match_indices(keyword) {
+        let preceded = source[..index].chars().next_back().map_or(false, is_ident);
+        let after = &source[index + keyword.len()..];
+        if preceded || !after.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let after = after.trim_start();
+        let end = after.find(|c: char| !is_ident(c)).unwrap_or(after.len());
+        if end > 0 {
+            names.push((&after[..end], &after[end..]));
+        }
+    }
+
+    names
+}
+
+/// Content of the first parenthesis of `source`.
+fn matching_parenthesis(source: &str) -> &str {
+    let start = match source.find('(') {
+        Some(start) => start + 1,
+        None => return "",
+    };
+
+    let mut depth = 1;
+    for (index, c) in source[start..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return &source[start..start + index];
+                }
+            }
+            _ => {}
+        }
+    }
+    &source[start..]
+}
+
+/// Splits arguments on top-level commas.
+fn split_arguments(arguments: &str) -> Vec<&str> {
+    let mut split = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (index, c) in arguments.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                split.push(arguments[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = arguments[start..].trim();
+    if !last.is_empty() || !split.is_empty() {
+        split.push(last);
+    }
+    split
+}
+
+/// Canonical ABI name of a Solidity type.
+fn canonical_type(ty: &str, enums: &[(&str, &str)]) -> String {
+    // External function types, e.g. `function(uint256) external`, are encoded as `function`.
+    if ty.starts_with("function") {
+        return "function".into();
+    }
+
+    let (base, arrays) = ty.split_at(ty.find('[').unwrap_or(ty.len()));
+    let base = match base {
+        "uint" => "uint256",
+        "int" => "int256",
+        "byte" => "bytes1",
+        base if enums.iter().any(|(name, _)| *name == base) => "uint8",
+        base => base,
+    };
+    format!("{}{}", base, arrays)
+}
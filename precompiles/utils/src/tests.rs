@@ -21,8 +21,9 @@
 // along with Utils.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::*;
+use data::BoundedVec;
 use hex_literal::hex;
-use sp_core::{H256, U256};
+use sp_core::{ConstU32, H256, U256};
 
 fn u256_repeat_byte(byte: u8) -> U256 {
     let value = H256::repeat_byte(byte);
@@ -132,6 +133,80 @@ fn read_u256() {
     assert_eq!(value, parsed);
 }
 
+#[test]
+fn peek_does_not_move_cursor() {
+    let writer_output = EvmDataWriter::new()
+        .write(U256::from(42))
+        .write(true)
+        .build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert_eq!(reader.remaining_len(), 64);
+
+    let peeked: U256 = reader.peek().expect("to correctly peek U256");
+    assert_eq!(peeked, U256::from(42));
+    assert_eq!(reader.remaining_len(), 64);
+
+    let parsed: U256 = reader.read().expect("to correctly parse U256");
+    assert_eq!(parsed, peeked);
+    assert_eq!(reader.remaining_len(), 32);
+
+    assert_eq!(reader.peek::<bool>(), Ok(true));
+}
+
+#[test]
+fn peek_selector_does_not_move_cursor() {
+    let input = EvmDataWriter::new_with_selector(Action::TransferMultiAsset)
+        .write(U256::from(42))
+        .build();
+
+    let reader = EvmDataReader::new(&input);
+    assert_eq!(
+        reader.peek_selector::<Action>(),
+        Ok(Action::TransferMultiAsset)
+    );
+    assert_eq!(reader.remaining_len(), 36);
+
+    let reader = EvmDataReader::new(&input[4..]);
+    assert_eq!(
+        reader.peek_selector::<Action>(),
+        Err(revert("unknown selector"))
+    );
+}
+
+#[test]
+fn peeked_data_is_not_considered_read() {
+    let input = EvmDataWriter::new().write(Bytes(b"astar".to_vec())).build();
+
+    let extent = ReadExtent::default();
+    let mut reader = EvmDataReader::new(&input).strict(&extent);
+    reader.peek::<Bytes>().expect("to correctly peek bytes");
+    assert!(reader.finish().is_err());
+
+    reader.read::<Bytes>().expect("to correctly parse bytes");
+    assert!(reader.finish().is_ok());
+}
+
+#[test]
+fn read_optional_trailing_argument() {
+    let writer_output = EvmDataWriter::new().write(U256::from(42)).build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert_eq!(
+        reader.read_optional::<U256>().expect("argument is present"),
+        Some(U256::from(42))
+    );
+    assert_eq!(reader.remaining_len(), 0);
+    assert_eq!(
+        reader.read_optional::<U256>().expect("argument is absent"),
+        None
+    );
+
+    // Partial argument is still an error
+    let mut reader = EvmDataReader::new(&writer_output[..16]);
+    assert!(reader.read_optional::<U256>().is_err());
+}
+
 #[test]
 fn read_selector() {
     use sha3::{Digest, Keccak256};
@@ -151,6 +226,125 @@ fn read_selector() {
     assert_eq!(parsed_selector, FakeAction::Action1)
 }
 
+#[test]
+fn selector_modifiers_are_declared_on_variants() {
+    #[precompile_utils_macro::generate_function_selector]
+    #[derive(Debug, PartialEq)]
+    enum FakeAction {
+        #[view]
+        BalanceOf = "balanceOf(address)",
+        #[payable]
+        Deposit = "deposit()",
+        Withdraw = "withdraw(uint256)",
+    }
+
+    assert_eq!(FakeAction::BalanceOf.modifier(), FunctionModifier::View);
+    assert_eq!(FakeAction::Deposit.modifier(), FunctionModifier::Payable);
+    assert_eq!(
+        FakeAction::Withdraw.modifier(),
+        FunctionModifier::NonPayable
+    );
+
+    let context = Context {
+        address: H160::repeat_byte(0x11),
+        caller: H160::repeat_byte(0x22),
+        apparent_value: U256::one(),
+    };
+    assert!(check_function_modifier(&context, false, FakeAction::Deposit.modifier()).is_ok());
+    assert!(check_function_modifier(&context, false, FakeAction::Withdraw.modifier()).is_err());
+    assert!(check_function_modifier(&context, true, FakeAction::Deposit.modifier()).is_err());
+}
+
+#[test]
+fn write_negative_ints() {
+    let writer_output = EvmDataWriter::new().write(-1i8).write(-42i64).build();
+
+    let mut expected_output = [0xffu8; 64];
+    (U256::MAX - 41).to_big_endian(&mut expected_output[32..]);
+
+    assert_eq!(writer_output, expected_output);
+}
+
+#[test]
+fn read_write_ints_round_trip() {
+    let writer_output = EvmDataWriter::new()
+        .write(i8::MIN)
+        .write(i16::MAX)
+        .write(-42i32)
+        .write(i64::MIN)
+        .write(i128::MAX)
+        .write(0i128)
+        .build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert_eq!(reader.read::<i8>().expect("to correctly parse i8"), i8::MIN);
+    assert_eq!(
+        reader.read::<i16>().expect("to correctly parse i16"),
+        i16::MAX
+    );
+    assert_eq!(reader.read::<i32>().expect("to correctly parse i32"), -42);
+    assert_eq!(
+        reader.read::<i64>().expect("to correctly parse i64"),
+        i64::MIN
+    );
+    assert_eq!(
+        reader.read::<i128>().expect("to correctly parse i128"),
+        i128::MAX
+    );
+    assert_eq!(reader.read::<i128>().expect("to correctly parse i128"), 0);
+}
+
+#[test]
+fn read_int_out_of_range() {
+    // 128 doesn't fit into `int8`, and `-129` isn't sign extended as `int8`.
+    let writer_output = EvmDataWriter::new().write(128i16).write(-129i16).build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert!(reader.read::<i8>().is_err());
+    assert!(reader.read::<i8>().is_err());
+
+    // Unsigned value with the highest bit set isn't a valid positive `int64`.
+    let writer_output = EvmDataWriter::new().write(u64::MAX).build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert!(reader.read::<i64>().is_err());
+}
+
+#[test]
+fn write_i256() {
+    let value = I256::from(-1i128);
+    assert!(value.is_negative());
+
+    let writer_output = EvmDataWriter::new().write(value).build();
+
+    assert_eq!(writer_output, [0xffu8; 32]);
+}
+
+#[test]
+fn read_i256() {
+    let value = I256(U256::MAX - 41);
+    let writer_output = EvmDataWriter::new().write(value).build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    let parsed: I256 = reader.read().expect("to correctly parse I256");
+
+    assert_eq!(value, parsed);
+    assert_eq!(i128::try_from(parsed), Ok(-42));
+}
+
+#[test]
+fn i256_i128_conversions() {
+    for value in [i128::MIN, -1, 0, 1, i128::MAX] {
+        let converted = I256::from(value);
+        assert_eq!(converted.is_negative(), value < 0);
+        assert_eq!(i128::try_from(converted), Ok(value));
+    }
+
+    // `int256` values outside of `int128` range
+    assert!(i128::try_from(I256(U256::one() << 127)).is_err());
+    assert!(i128::try_from(I256(U256::MAX - (U256::one() << 127))).is_err());
+}
+
 #[test]
 #[should_panic(expected = "to correctly parse U256")]
 fn read_u256_too_short() {
@@ -210,6 +404,69 @@ fn read_h256_too_short() {
     let _: H256 = reader.read().expect("to correctly parse H256");
 }
 
+#[test]
+fn write_fixed_bytes() {
+    let writer_output = EvmDataWriter::new()
+        .write(FixedBytes(hex!("01020304")))
+        .build();
+
+    let mut expected_output = [0u8; 32];
+    expected_output[..4].copy_from_slice(&hex!("01020304"));
+
+    assert_eq!(writer_output, expected_output);
+}
+
+#[test]
+fn read_fixed_bytes() {
+    let value = FixedBytes::<20>([0x42; 20]);
+    let writer_output = EvmDataWriter::new().write(value).build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    let parsed: FixedBytes<20> = reader.read().expect("to correctly parse bytes20");
+    assert_eq!(value, parsed);
+
+    // `bytes32` is the same as `H256`
+    let mut reader = EvmDataReader::new(&writer_output);
+    let parsed: H256 = FixedBytes::<32>::read(&mut reader)
+        .expect("to correctly parse bytes32")
+        .into();
+    assert_eq!(&parsed.as_bytes()[..20], &[0x42; 20]);
+}
+
+#[test]
+fn read_fixed_bytes_with_invalid_padding() {
+    // `uint256` 1 isn't a valid `bytes4`, since only the 4 leftmost bytes can be set.
+    let writer_output = EvmDataWriter::new().write(U256::one()).build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert!(reader.read::<FixedBytes<4>>().is_err());
+}
+
+#[test]
+fn read_write_function_type() {
+    let value = FunctionSelectorArg {
+        address: H160::repeat_byte(0x11),
+        selector: 0x01020304,
+    };
+
+    let writer_output = EvmDataWriter::new().write(value).build();
+    assert_eq!(
+        writer_output,
+        hex!("111111111111111111111111111111111111111101020304 0000000000000000").to_vec()
+    );
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert_eq!(reader.read::<FunctionSelectorArg>(), Ok(value));
+
+    // An `address` has its padding on the wrong side.
+    let writer_output = EvmDataWriter::new().write(Address(value.address)).build();
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert_eq!(
+        reader.read::<FunctionSelectorArg>(),
+        Err(revert("invalid function type argument"))
+    );
+}
+
 #[test]
 fn write_address() {
     let value = H160::repeat_byte(0xAA);
@@ -231,6 +488,74 @@ fn read_address() {
     assert_eq!(value, parsed.0);
 }
 
+#[test]
+fn write_zero_address_as_none() {
+    let value = H160::repeat_byte(0xAA);
+
+    let output = EvmDataWriter::new()
+        .write(ZeroAddressAsNone(Some(Address(value))))
+        .write(ZeroAddressAsNone::<Address>(None))
+        .build();
+
+    assert_eq!(output.len(), 64);
+    assert_eq!(&output[12..32], value.as_bytes());
+    assert_eq!(&output[32..64], &[0u8; 32]);
+}
+
+#[test]
+fn read_zero_address_as_none() {
+    let value = H160::repeat_byte(0xAA);
+    let writer_output = EvmDataWriter::new()
+        .write(Address(value))
+        .write(Address(H160::zero()))
+        .build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    let some: ZeroAddressAsNone = reader.read().expect("to correctly parse address");
+    let none: ZeroAddressAsNone = reader.read().expect("to correctly parse zero address");
+
+    assert_eq!(some, ZeroAddressAsNone(Some(Address(value))));
+    assert_eq!(none, ZeroAddressAsNone(None));
+}
+
+#[test]
+fn solidity_option_is_bool_value_tuple() {
+    let value = H160::repeat_byte(0xAA);
+
+    let output = EvmDataWriter::new()
+        .write(SolidityOption(Some(Address(value))))
+        .write(SolidityOption::<Address>(None))
+        .build();
+    let expected = EvmDataWriter::new()
+        .write((true, Address(value)))
+        .write((false, Address(H160::zero())))
+        .build();
+    assert_eq!(output, expected);
+
+    let mut reader = EvmDataReader::new(&output);
+    let some: SolidityOption<Address> = reader.read().expect("to correctly parse option");
+    let none: SolidityOption<Address> = reader.read().expect("to correctly parse option");
+    assert_eq!(some, SolidityOption(Some(Address(value))));
+    assert_eq!(none, SolidityOption(None));
+}
+
+#[test]
+fn solidity_option_of_dynamic_type() {
+    let output = EvmDataWriter::new()
+        .write(SolidityOption(Some(Bytes(b"astar".to_vec()))))
+        .build();
+    assert_eq!(
+        output,
+        EvmDataWriter::new()
+            .write((true, Bytes(b"astar".to_vec())))
+            .build()
+    );
+
+    let mut reader = EvmDataReader::new(&output);
+    let parsed: SolidityOption<Bytes> = reader.read().expect("to correctly parse option");
+    assert_eq!(parsed, SolidityOption(Some(Bytes(b"astar".to_vec()))));
+}
+
 #[test]
 fn write_h256_array() {
     let array = vec![
@@ -313,6 +638,59 @@ fn read_u256_array() {
     assert_eq!(array, parsed);
 }
 
+#[test]
+fn write_u256_fixed_array() {
+    let array = [u256_repeat_byte(0x11), u256_repeat_byte(0x22)];
+    let writer_output = EvmDataWriter::new().write(array).build();
+    assert_eq!(writer_output.len(), 0x40);
+
+    // Static items are encoded inline, without offset nor size.
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert_eq!(reader.read::<U256>().expect("read 1st"), array[0]);
+    assert_eq!(reader.read::<U256>().expect("read 2nd"), array[1]);
+}
+
+#[test]
+fn read_address_fixed_array() {
+    let array = [
+        Address(H160::repeat_byte(0x11)),
+        Address(H160::repeat_byte(0x22)),
+        Address(H160::repeat_byte(0x33)),
+    ];
+    let writer_output = EvmDataWriter::new().write(array).write(42u32).build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    let parsed: [Address; 3] = reader.read().expect("to correctly parse [Address; 3]");
+    assert_eq!(array, parsed);
+    assert_eq!(reader.read::<u32>().expect("to correctly parse u32"), 42);
+
+    // Missing items
+    let mut reader = EvmDataReader::new(&writer_output[..0x40]);
+    assert!(reader.read::<[Address; 3]>().is_err());
+}
+
+#[test]
+fn fixed_array_is_encoded_as_tuple() {
+    let static_array = [1u32, 2, 3];
+    assert_eq!(
+        EvmDataWriter::new().write(static_array).build(),
+        EvmDataWriter::new().write((1u32, 2u32, 3u32)).build()
+    );
+
+    let dynamic_array = [vec![1u32, 2], vec![3]];
+    let writer_output = EvmDataWriter::new().write(dynamic_array.clone()).build();
+    assert_eq!(
+        writer_output,
+        EvmDataWriter::new()
+            .write((vec![1u32, 2], vec![3u32]))
+            .build()
+    );
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    let parsed: [Vec<u32>; 2] = reader.read().expect("to correctly parse [Vec<u32>; 2]");
+    assert_eq!(dynamic_array, parsed);
+}
+
 #[test]
 fn write_address_array() {
     let array = vec![
@@ -370,11 +748,27 @@ fn read_address_array_size_too_big() {
 
     match reader.read::<Vec<Address>>() {
         Ok(_) => panic!("should not parse correctly"),
-        Err(PrecompileFailure::Revert { output: err, .. }) => {
-            assert_eq!(err, b"tried to parse H160 out of bounds")
-        }
-        Err(_) => panic!("unexpected error"),
+        Err(err) => assert_eq!(err, revert("array length exceeds remaining input")),
+    }
+}
+
+#[test]
+fn read_array_with_absurd_length_reverts_early() {
+    let mut writer_output = EvmDataWriter::new()
+        .write(vec![U256::one(), U256::from(2u32)])
+        .build();
+
+    U256::from(u32::MAX).to_big_endian(&mut writer_output[0x20..0x40]);
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    match reader.read::<BoundedVec<U256, ConstU32<{ u32::MAX }>>>() {
+        Ok(_) => panic!("should not parse correctly"),
+        Err(err) => assert_eq!(err, revert("array length exceeds remaining input")),
     }
+
+    // Zero-sized items are bounded by the remaining input as well.
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert!(reader.read::<Vec<()>>().is_err());
 }
 
 #[test]
@@ -494,6 +888,23 @@ fn read_multiple_arrays() {
     assert_eq!(array2, parsed);
 }
 
+#[test]
+fn bounded_vec_accessors() {
+    let mut bounded: BoundedVec<u32, ConstU32<3>> = vec![1, 2].into();
+    assert_eq!(bounded.len(), 2);
+    assert!(!bounded.is_empty());
+    assert_eq!(bounded.get(1), Some(&2));
+    assert_eq!(bounded.get(2), None);
+
+    assert_eq!(bounded.try_push(3), Ok(()));
+    assert_eq!(bounded.try_push(4), Err(4));
+    assert_eq!(bounded.len(), 3);
+
+    assert_eq!(bounded.iter().sum::<u32>(), 6);
+    assert_eq!((&bounded).into_iter().max(), Some(&3));
+    assert_eq!(bounded.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
 #[test]
 fn read_bytes() {
     let data = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod\
@@ -567,65 +978,221 @@ fn write_string() {
 }
 
 #[test]
-fn write_vec_bytes() {
-    let data = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod\
-	tempor incididunt ut labore et dolore magna aliqua.";
-
+fn metered_reader_records_processed_input() {
     let writer_output = EvmDataWriter::new()
-        .write(vec![Bytes::from(&data[..]), Bytes::from(&data[..])])
+        .write(U256::one())
+        .write(vec![Address(H160::repeat_byte(0x11)); 3])
         .build();
 
-    writer_output
-        .chunks_exact(32)
-        .map(|chunk| H256::from_slice(chunk))
-        .for_each(|hash| println!("{:?}", hash));
-
-    // We pad data to a multiple of 32 bytes.
-    let mut padded = data.to_vec();
-    assert!(data.len() < 0x80);
-    padded.resize(0x80, 0);
+    let meter = ReadMeter::new(u64::MAX);
+    let mut reader = EvmDataReader::new(&writer_output).metered(&meter);
+    let _: U256 = reader.read().expect("to correctly parse U256");
+    let _: Vec<Address> = reader.read().expect("to correctly parse Vec<Address>");
 
-    let mut reader = EvmDataReader::new(&writer_output);
+    // integer, array offset, array length and 3 items
+    assert_eq!(meter.processed(), 6 * 32);
+    assert_eq!(meter.gas_cost(), 6 * data::INPUT_WORD_GAS_COST);
+}
 
-    // Offset of vec
-    assert_eq!(reader.read::<U256>().expect("read offset"), 32.into());
+#[test]
+fn metered_reader_counts_aliased_data() {
+    let array = EvmDataWriter::new().write(vec![U256::one(); 4]).build();
 
-    // Length of vec
-    assert_eq!(reader.read::<U256>().expect("read offset"), 2.into());
+    // Two offsets pointing to the same array.
+    let mut input = EvmDataWriter::new()
+        .write(U256::from(64))
+        .write(U256::from(64))
+        .build();
+    input.extend_from_slice(&array[32..]);
 
-    // Relative offset of first bytgmes object
-    assert_eq!(reader.read::<U256>().expect("read offset"), 0x40.into());
-    // Relative offset of second bytes object
-    assert_eq!(reader.read::<U256>().expect("read offset"), 0xe0.into());
+    let meter = ReadMeter::new(u64::MAX);
+    let mut reader = EvmDataReader::new(&input).metered(&meter);
+    let _: Vec<U256> = reader.read().expect("to correctly parse first array");
+    let _: Vec<U256> = reader.read().expect("to correctly parse second array");
 
-    // Length of first bytes object
-    assert_eq!(reader.read::<U256>().expect("read size"), data.len().into());
+    // twice the offset, length and 4 items
+    assert_eq!(meter.processed(), 2 * 6 * 32);
+}
 
-    // First byte objects data
-    let mut read = |e| reader.read::<H256>().expect(e); // shorthand
-    assert_eq!(read("read part 1"), H256::from_slice(&padded[0x00..0x20]));
-    assert_eq!(read("read part 2"), H256::from_slice(&padded[0x20..0x40]));
-    assert_eq!(read("read part 3"), H256::from_slice(&padded[0x40..0x60]));
-    assert_eq!(read("read part 4"), H256::from_slice(&padded[0x60..0x80]));
+#[test]
+fn metered_reader_stops_at_limit() {
+    let writer_output = EvmDataWriter::new().write(vec![U256::one(); 16]).build();
 
-    // Length of second bytes object
-    assert_eq!(reader.read::<U256>().expect("read size"), data.len().into());
+    // Enough gas for 4 words only.
+    let meter = ReadMeter::new(4 * data::INPUT_WORD_GAS_COST);
+    let mut reader = EvmDataReader::new(&writer_output).metered(&meter);
 
-    // Second byte objects data
-    let mut read = |e| reader.read::<H256>().expect(e); // shorthand
-    assert_eq!(read("read part 1"), H256::from_slice(&padded[0x00..0x20]));
-    assert_eq!(read("read part 2"), H256::from_slice(&padded[0x20..0x40]));
-    assert_eq!(read("read part 3"), H256::from_slice(&padded[0x40..0x60]));
-    assert_eq!(read("read part 4"), H256::from_slice(&padded[0x60..0x80]));
+    assert_eq!(
+        reader.read::<Vec<U256>>(),
+        Err(PrecompileFailure::Error {
+            exit_status: ExitError::OutOfGas
+        })
+    );
+    assert_eq!(meter.processed(), 4 * 32);
 }
 
 #[test]
-fn read_vec_of_bytes() {
-    let data = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod\
-	tempor incididunt ut labore et dolore magna aliqua.";
-
+fn reader_enforces_max_nesting_depth() {
     let writer_output = EvmDataWriter::new()
-        .write(vec![Bytes::from(&data[..]), Bytes::from(&data[..])])
+        .write(vec![vec![vec![U256::one()]]])
+        .build();
+
+    // 3 nested arrays are 3 levels of pointers
+    let mut reader = EvmDataReader::new(&writer_output).with_max_depth(3);
+    let parsed: Vec<Vec<Vec<U256>>> = reader.read().expect("to correctly parse nested arrays");
+    assert_eq!(parsed, vec![vec![vec![U256::one()]]]);
+
+    let mut reader = EvmDataReader::new(&writer_output).with_max_depth(2);
+    assert_eq!(
+        reader.read::<Vec<Vec<Vec<U256>>>>(),
+        Err(revert("exceeded max nesting depth"))
+    );
+}
+
+#[test]
+fn size_limited_reader_reverts() {
+    let writer_output = EvmDataWriter::new().write(vec![U256::one(); 16]).build();
+
+    let meter = ReadMeter::with_size_limit(4 * 32);
+    let mut reader = EvmDataReader::new(&writer_output).metered(&meter);
+
+    assert_eq!(
+        reader.read::<Vec<U256>>(),
+        Err(revert("decoded data exceeds size limit"))
+    );
+}
+
+#[test]
+fn read_write_solidity_string() {
+    let data = "Lorem ipsum dolor sit amet ✓";
+
+    let writer_output = EvmDataWriter::new()
+        .write(SolidityString::from(data))
+        .build();
+
+    // Same layout as `Bytes`.
+    assert_eq!(
+        writer_output,
+        EvmDataWriter::new().write(Bytes::from(data)).build()
+    );
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    let parsed: SolidityString = reader.read().expect("to correctly parse string");
+    assert_eq!(data, parsed.as_str());
+}
+
+#[test]
+fn read_solidity_string_with_invalid_utf8() {
+    let writer_output = EvmDataWriter::new()
+        .write(Bytes(vec![0x61, 0xff, 0x62]))
+        .build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    match reader.read::<SolidityString>() {
+        Err(err) => assert_eq!(err, revert("string is not valid UTF8")),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn write_vec_bytes() {
+    let data = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod\
+	tempor incididunt ut labore et dolore magna aliqua.";
+
+    let writer_output = EvmDataWriter::new()
+        .write(vec![Bytes::from(&data[..]), Bytes::from(&data[..])])
+        .build();
+
+    writer_output
+        .chunks_exact(32)
+        .map(|chunk| H256::from_slice(chunk))
+        .for_each(|hash| println!("{:?}", hash));
+
+    // We pad data to a multiple of 32 bytes.
+    let mut padded = data.to_vec();
+    assert!(data.len() < 0x80);
+    padded.resize(0x80, 0);
+
+    let mut reader = EvmDataReader::new(&writer_output);
+
+    // Offset of vec
+    assert_eq!(reader.read::<U256>().expect("read offset"), 32.into());
+
+    // Length of vec
+    assert_eq!(reader.read::<U256>().expect("read offset"), 2.into());
+
+    // Relative offset of first bytgmes object
+    assert_eq!(reader.read::<U256>().expect("read offset"), 0x40.into());
+    // Relative offset of second bytes object
+    assert_eq!(reader.read::<U256>().expect("read offset"), 0xe0.into());
+
+    // Length of first bytes object
+    assert_eq!(reader.read::<U256>().expect("read size"), data.len().into());
+
+    // First byte objects data
+    let mut read = |e| reader.read::<H256>().expect(e); // shorthand
+    assert_eq!(read("read part 1"), H256::from_slice(&padded[0x00..0x20]));
+    assert_eq!(read("read part 2"), H256::from_slice(&padded[0x20..0x40]));
+    assert_eq!(read("read part 3"), H256::from_slice(&padded[0x40..0x60]));
+    assert_eq!(read("read part 4"), H256::from_slice(&padded[0x60..0x80]));
+
+    // Length of second bytes object
+    assert_eq!(reader.read::<U256>().expect("read size"), data.len().into());
+
+    // Second byte objects data
+    let mut read = |e| reader.read::<H256>().expect(e); // shorthand
+    assert_eq!(read("read part 1"), H256::from_slice(&padded[0x00..0x20]));
+    assert_eq!(read("read part 2"), H256::from_slice(&padded[0x20..0x40]));
+    assert_eq!(read("read part 3"), H256::from_slice(&padded[0x40..0x60]));
+    assert_eq!(read("read part 4"), H256::from_slice(&padded[0x60..0x80]));
+}
+
+#[test]
+fn write_into_appends_built_data() {
+    let addresses = vec![
+        Address(H160::repeat_byte(0x11)),
+        Address(H160::repeat_byte(0x22)),
+    ];
+    let writer = || {
+        EvmDataWriter::new_with_selector(0x12345678u32)
+            .write(Bytes::from(&b"Lorem ipsum"[..]))
+            .write(addresses.clone())
+    };
+
+    let mut output = b"prefix".to_vec();
+    writer().write_into(&mut output);
+    assert_eq!(output, [&b"prefix"[..], &writer().build()].concat());
+
+    // The buffer can be reused once cleared.
+    output.clear();
+    writer().write_into(&mut output);
+    assert_eq!(output, writer().build());
+}
+
+#[test]
+fn write_static_array_matches_dynamic_encoding() {
+    let values = vec![U256::from(1u8), U256::from(2u8), U256::from(3u8)];
+    let output = EvmDataWriter::new().write(values.clone()).build();
+
+    let mut reader = EvmDataReader::new(&output);
+    assert_eq!(reader.read::<U256>().expect("read offset"), 32.into());
+    assert_eq!(reader.read::<U256>().expect("read length"), 3.into());
+    for value in &values {
+        assert_eq!(&reader.read::<U256>().expect("read item"), value);
+    }
+
+    let mut reader = EvmDataReader::new(&output);
+    let parsed: Vec<U256> = reader.read().expect("to correctly parse Vec<U256>");
+    assert_eq!(parsed, values);
+}
+
+#[test]
+fn read_vec_of_bytes() {
+    let data = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod\
+	tempor incididunt ut labore et dolore magna aliqua.";
+
+    let writer_output = EvmDataWriter::new()
+        .write(vec![Bytes::from(&data[..]), Bytes::from(&data[..])])
         .build();
 
     writer_output
@@ -684,6 +1251,159 @@ pub enum Action {
     TransferMultiAsset = "transfer_multiasset((uint8,bytes[]),uint256,(uint8,bytes[]),uint64)",
 }
 
+#[derive(Clone, Debug, PartialEq, EvmData)]
+struct StaticStruct {
+    to: Address,
+    amount: U256,
+}
+
+#[derive(Clone, Debug, PartialEq, EvmData)]
+struct MixedStruct {
+    id: u32,
+    data: Bytes,
+    inner: StaticStruct,
+    values: Vec<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, EvmData)]
+struct TupleStruct(H256, Bytes);
+
+#[derive(Clone, Copy, Debug, PartialEq, EvmData)]
+enum Tier {
+    Gold = 10,
+    Silver,
+    Bronze,
+}
+
+#[test]
+fn derived_static_struct() {
+    let value = StaticStruct {
+        to: Address(H160::repeat_byte(0x11)),
+        amount: 42.into(),
+    };
+    assert!(StaticStruct::has_static_size());
+
+    let writer_output = EvmDataWriter::new().write(value.clone()).build();
+    assert_eq!(
+        writer_output,
+        EvmDataWriter::new().write((value.to, value.amount)).build()
+    );
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    let parsed: StaticStruct = reader.read().expect("to correctly parse StaticStruct");
+    assert_eq!(value, parsed);
+}
+
+#[test]
+fn derived_mixed_struct() {
+    let value = MixedStruct {
+        id: 7,
+        data: Bytes::from(&b"some data"[..]),
+        inner: StaticStruct {
+            to: Address(H160::repeat_byte(0x22)),
+            amount: U256::MAX,
+        },
+        values: vec![1, 2, 3],
+    };
+    assert!(!MixedStruct::has_static_size());
+
+    // Written with an offset, then as a tuple of its fields.
+    let writer_output = EvmDataWriter::new().write(value.clone()).write(5u8).build();
+    assert_eq!(
+        writer_output,
+        EvmDataWriter::new()
+            .write((
+                value.id,
+                value.data.clone(),
+                (value.inner.to, value.inner.amount),
+                value.values.clone(),
+            ))
+            .write(5u8)
+            .build()
+    );
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    let parsed: MixedStruct = reader.read().expect("to correctly parse MixedStruct");
+    assert_eq!(value, parsed);
+    assert_eq!(reader.read::<u8>().expect("to correctly parse u8"), 5);
+}
+
+#[test]
+fn derived_tuple_struct() {
+    let value = TupleStruct(H256::repeat_byte(0x33), Bytes::from(&b"bytes"[..]));
+    assert!(!TupleStruct::has_static_size());
+
+    let writer_output = EvmDataWriter::new()
+        .write(vec![value.clone(), value.clone()])
+        .build();
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    let parsed: Vec<TupleStruct> = reader.read().expect("to correctly parse Vec<TupleStruct>");
+    assert_eq!(vec![value.clone(), value], parsed);
+}
+
+#[test]
+fn derived_enum_is_uint8() {
+    assert!(Tier::has_static_size());
+
+    let writer_output = EvmDataWriter::new()
+        .write(Tier::Gold)
+        .write(Tier::Bronze)
+        .build();
+    // Variants are numbered in declaration order, like Solidity enums.
+    assert_eq!(
+        writer_output,
+        EvmDataWriter::new().write(0u8).write(2u8).build()
+    );
+
+    let mut reader = EvmDataReader::new(&writer_output);
+    assert_eq!(reader.read::<Tier>(), Ok(Tier::Gold));
+    assert_eq!(reader.read::<Tier>(), Ok(Tier::Bronze));
+
+    let input = EvmDataWriter::new().write(3u8).build();
+    assert_eq!(
+        EvmDataReader::new(&input).read::<Tier>(),
+        Err(revert("Tier out of range"))
+    );
+    // Values which don't fit into an uint8 aren't truncated.
+    let input = EvmDataWriter::new().write(U256::from(256u32)).build();
+    assert_eq!(
+        EvmDataReader::new(&input).read::<Tier>(),
+        Err(revert("Tier out of range"))
+    );
+}
+
+#[test]
+fn encoded_size_hint_matches_encoding() {
+    fn assert_exact_hint<T: EvmData + Clone>(value: T) {
+        let encoded = EvmDataWriter::new().write(value.clone()).build();
+        assert_eq!(T::encoded_size_hint(&value), encoded.len());
+    }
+
+    assert_exact_hint(U256::one());
+    assert_exact_hint(SolidityString::from(
+        "forty characters long text for the test",
+    ));
+    assert_exact_hint(vec![
+        Bytes::from(&b"short"[..]),
+        Bytes::from(&[0x42u8; 70][..]),
+    ]);
+    assert_exact_hint((Address(H160::repeat_byte(0x11)), vec![1u64, 2, 3]));
+    assert_exact_hint(TupleStruct(
+        H256::repeat_byte(0x33),
+        Bytes::from(&b"bytes"[..]),
+    ));
+    assert_exact_hint(MixedStruct {
+        id: 7,
+        data: Bytes::from(&b"some data"[..]),
+        inner: StaticStruct {
+            to: Address(H160::repeat_byte(0x22)),
+            amount: U256::MAX,
+        },
+        values: vec![1, 2, 3],
+    });
+}
+
 #[test]
 fn read_complex_solidity_function() {
     // Function call data generated by web3.
@@ -744,3 +1464,563 @@ fn read_complex_solidity_function() {
     // weight
     assert_eq!(reader.read::<U256>().unwrap(), 100u32.into());
 }
+
+#[test]
+fn unit_encodes_to_empty_output() {
+    assert!(data::encode_return_value(()).is_empty());
+    assert!(EvmDataWriter::new().write(()).build().is_empty());
+
+    let mut reader = EvmDataReader::new(&[]);
+    assert!(reader.read::<()>().is_ok());
+}
+
+#[test]
+fn return_tuple_is_encoded_as_arguments() {
+    let value = (true, Bytes(b"hello".to_vec()));
+
+    let expected = EvmDataWriter::new()
+        .write(true)
+        .write(Bytes(b"hello".to_vec()))
+        .build();
+    assert_eq!(data::encode_return_value(value.clone()), expected);
+
+    // Written as a single value, a dynamic tuple is prefixed by its offset.
+    let wrapped = EvmDataWriter::new().write(value).build();
+    assert_eq!(&wrapped[32..], &expected[..]);
+}
+
+#[derive(Clone, Debug, PartialEq, EvmData)]
+#[evm_data(explicit_tuple)]
+struct MultiReturn {
+    amount: U256,
+    holders: Vec<Address>,
+    data: Bytes,
+}
+
+#[derive(Clone, Debug, PartialEq, EvmData)]
+struct SingleReturn {
+    amount: U256,
+    data: Bytes,
+}
+
+/// Output of solc for `return (42, [0x11..11, 0x22..22], hex"deadbeef")` from a function
+/// returning `(uint256, address[], bytes)`.
+fn solc_multi_return() -> Vec<u8> {
+    hex!(
+        "
+        000000000000000000000000000000000000000000000000000000000000002a
+        0000000000000000000000000000000000000000000000000000000000000060
+        00000000000000000000000000000000000000000000000000000000000000e0
+        0000000000000000000000000000000000000000000000000000000000000002
+        0000000000000000000000001111111111111111111111111111111111111111
+        0000000000000000000000002222222222222222222222222222222222222222
+        0000000000000000000000000000000000000000000000000000000000000004
+        deadbeef00000000000000000000000000000000000000000000000000000000
+        "
+    )
+    .to_vec()
+}
+
+fn multi_return() -> MultiReturn {
+    MultiReturn {
+        amount: 42u32.into(),
+        holders: vec![
+            Address(H160::repeat_byte(0x11)),
+            Address(H160::repeat_byte(0x22)),
+        ],
+        data: Bytes(hex!("deadbeef").to_vec()),
+    }
+}
+
+#[test]
+fn multiple_return_values_match_solc() {
+    let value = multi_return();
+    let tuple = (value.amount, value.holders.clone(), value.data.clone());
+
+    assert_eq!(
+        EvmDataWriter::new().write_arguments(tuple.clone()).build(),
+        solc_multi_return()
+    );
+    assert_eq!(data::encode_return_value(tuple), solc_multi_return());
+    assert_eq!(
+        EvmDataWriter::new().write_arguments(value.clone()).build(),
+        solc_multi_return()
+    );
+    assert_eq!(data::encode_return_value(value), solc_multi_return());
+}
+
+#[test]
+fn multiple_return_values_can_be_read() {
+    let output = solc_multi_return();
+
+    let mut reader = EvmDataReader::new(&output);
+    assert_eq!(
+        reader.read_arguments::<MultiReturn>().unwrap(),
+        multi_return()
+    );
+
+    let mut reader = EvmDataReader::new(&output);
+    let (amount, holders, data) = reader
+        .read_arguments::<(U256, Vec<Address>, Bytes)>()
+        .unwrap();
+    assert_eq!(amount, 42u32.into());
+    assert_eq!(holders, multi_return().holders);
+    assert_eq!(data, multi_return().data);
+}
+
+#[test]
+fn write_selector_does_not_shift_offsets() {
+    let output = EvmDataWriter::new()
+        .write_selector(0x12345678u32)
+        .write_arguments(multi_return())
+        .build();
+
+    assert_eq!(&output[..4], &hex!("12345678"));
+    assert_eq!(&output[4..], &solc_multi_return()[..]);
+
+    let mut reader = EvmDataReader::new_skip_selector(&output).unwrap();
+    assert_eq!(
+        reader.read_arguments::<MultiReturn>().unwrap(),
+        multi_return()
+    );
+}
+
+#[test]
+fn struct_without_explicit_tuple_is_a_single_value() {
+    let value = SingleReturn {
+        amount: 42u32.into(),
+        data: Bytes(hex!("deadbeef").to_vec()),
+    };
+
+    let output = data::encode_return_value(value.clone());
+    assert_eq!(&output[..32], H256::from_low_u64_be(0x20).as_bytes());
+    assert_eq!(
+        &output[32..],
+        &EvmDataWriter::new()
+            .write_arguments((value.amount, value.data))
+            .build()[..]
+    );
+}
+
+struct OverloadedPrecompile<R>(PhantomData<R>);
+
+#[precompile_utils::precompile(OverloadedAction, R)]
+impl<R: pallet_evm::Config> OverloadedPrecompile<R> {
+    #[precompile::public("transfer(address,uint256)")]
+    fn transfer(handle: &mut impl PrecompileHandle, to: Address, value: U256) -> EvmResult<bool> {
+        Self::transfer_with_memo(handle, to, value, H256::zero())
+    }
+
+    #[precompile::public("transfer(address,uint256,bytes32)")]
+    fn transfer_with_memo(
+        _handle: &mut impl PrecompileHandle,
+        _to: Address,
+        _value: U256,
+        _memo: H256,
+    ) -> EvmResult<bool> {
+        Ok(true)
+    }
+}
+
+#[test]
+fn overloads_get_distinct_selectors() {
+    assert_eq!(
+        OverloadedAction::SELECTORS,
+        &[
+            (0xa9059cbb, "transfer(address,uint256)"),
+            (
+                selector!("transfer(address,uint256,bytes32)"),
+                "transfer(address,uint256,bytes32)"
+            ),
+        ]
+    );
+    assert_ne!(
+        OverloadedAction::Transfer as u32,
+        OverloadedAction::TransferWithMemo as u32
+    );
+
+    let input = EvmDataWriter::new_with_selector(OverloadedAction::TransferWithMemo)
+        .write(Address(H160::repeat_byte(0x11)))
+        .write(U256::one())
+        .write(H256::repeat_byte(0x22))
+        .build();
+    assert_eq!(
+        EvmDataReader::read_selector::<OverloadedAction>(&input).unwrap(),
+        OverloadedAction::TransferWithMemo
+    );
+}
+
+#[test]
+fn read_named_adds_argument_to_revert_reason() {
+    let input = EvmDataWriter::new()
+        .write(Address(H160::repeat_byte(0x11)))
+        .write(Bytes(b"data".to_vec()))
+        .build();
+    let mut reader = EvmDataReader::new(&input[..input.len() - 32]);
+
+    assert_eq!(
+        reader.read_named::<Address>("to"),
+        Ok(Address(H160::repeat_byte(0x11)))
+    );
+    assert_eq!(
+        reader.read_named::<Bytes>("data"),
+        Err(revert(
+            "failed to decode argument 1 `data`: tried to parse bytes/string out of bounds"
+        ))
+    );
+}
+
+#[test]
+fn u256_conversions_check_or_saturate() {
+    assert_eq!(
+        checked_from_u256::<u32>(U256::from(42u32), "amount"),
+        Ok(42)
+    );
+    assert_eq!(
+        checked_from_u256::<u32>(U256::from(u32::MAX) + 1, "amount"),
+        Err(revert("amount is too large"))
+    );
+
+    assert_eq!(saturating_from_u256::<u128>(U256::from(42u32)), 42);
+    assert_eq!(saturating_from_u256::<u128>(U256::MAX), u128::MAX);
+}
+
+#[test]
+fn storage_read_cache_reads_each_key_once() {
+    let mut cache = StorageReadCache::<u32, u64>::new();
+    let mut reads = 0;
+
+    for key in [1, 2, 1, 1, 2] {
+        let value = cache
+            .get_or_try_read(key, |key| {
+                reads += 1;
+                Ok(u64::from(*key) * 10)
+            })
+            .expect("read doesn't fail");
+        assert_eq!(value, u64::from(key) * 10);
+    }
+    assert_eq!(reads, 2);
+
+    // Failed reads aren't cached.
+    assert!(cache
+        .get_or_try_read(3, |_| Err(revert("out of gas")))
+        .is_err());
+    assert_eq!(cache.get_or_try_read(3, |_| Ok(30)), Ok(30));
+
+    cache.clear();
+    assert_eq!(cache.get_or_try_read(1, |_| Ok(11)), Ok(11));
+}
+
+#[test]
+fn finish_rejects_trailing_data() {
+    let input = EvmDataWriter::new()
+        .write(Address(H160::repeat_byte(0x11)))
+        .write(U256::from(42u32))
+        .build();
+
+    let mut reader = EvmDataReader::new(&input);
+    reader
+        .read::<Address>()
+        .expect("to correctly parse address");
+    reader.read::<U256>().expect("to correctly parse U256");
+    assert!(reader.finish().is_ok());
+
+    let mut garbage_input = input.clone();
+    garbage_input.extend_from_slice(&[0u8, 0xff]);
+    let mut reader = EvmDataReader::new(&garbage_input);
+    reader
+        .read::<Address>()
+        .expect("to correctly parse address");
+    reader.read::<U256>().expect("to correctly parse U256");
+    match reader.finish() {
+        Ok(_) => panic!("should not accept trailing data"),
+        Err(err) => assert_eq!(err, revert("unexpected trailing input")),
+    }
+}
+
+#[test]
+fn strict_finish_accepts_pointed_data_and_padding() {
+    let input = EvmDataWriter::new()
+        .write(Bytes(b"astar".to_vec()))
+        .write(U256::from(42u32))
+        .build();
+
+    let extent = ReadExtent::default();
+    let mut reader = EvmDataReader::new(&input).strict(&extent);
+    reader.read::<Bytes>().expect("to correctly parse bytes");
+    reader.read::<U256>().expect("to correctly parse U256");
+    assert!(reader.finish().is_ok());
+
+    let mut garbage_input = input.clone();
+    garbage_input.extend_from_slice(&[0x42u8; 32]);
+    let extent = ReadExtent::default();
+    let mut reader = EvmDataReader::new(&garbage_input).strict(&extent);
+    reader.read::<Bytes>().expect("to correctly parse bytes");
+    reader.read::<U256>().expect("to correctly parse U256");
+    assert!(reader.finish().is_err());
+}
+
+#[test]
+fn custom_error_is_selector_and_arguments() {
+    #[derive(EvmError)]
+    #[evm_error("InsufficientBalance(uint256,bytes)")]
+    struct InsufficientBalance {
+        available: U256,
+        reason: Bytes,
+    }
+
+    #[derive(EvmError)]
+    #[evm_error("Unauthorized()")]
+    struct Unauthorized;
+
+    assert_eq!(
+        InsufficientBalance::SELECTOR,
+        selector!("InsufficientBalance(uint256,bytes)")
+    );
+
+    let error = InsufficientBalance {
+        available: U256::from(42u32),
+        reason: Bytes(b"locked".to_vec()),
+    };
+    let mut expected = selector!("InsufficientBalance(uint256,bytes)")
+        .to_be_bytes()
+        .to_vec();
+    expected.extend(
+        EvmDataWriter::new()
+            .write(U256::from(42u32))
+            .write(Bytes(b"locked".to_vec()))
+            .build(),
+    );
+    assert_eq!(
+        revert_with_error(error),
+        PrecompileFailure::Revert {
+            exit_status: ExitRevert::Reverted,
+            output: expected,
+        }
+    );
+
+    assert_eq!(
+        revert_with_error(Unauthorized),
+        revert_as_raw(selector!("Unauthorized()").to_be_bytes())
+    );
+}
+
+#[test]
+fn revert_reason_is_error_string() {
+    assert_eq!(ERROR_STRING_SELECTOR, selector!("Error(string)"));
+
+    let expected = hex!(
+        "
+        08c379a0
+        0000000000000000000000000000000000000000000000000000000000000020
+        000000000000000000000000000000000000000000000000000000000000000d
+        756e6b6e6f776e2076616c756500000000000000000000000000000000000000
+        "
+    );
+    assert_eq!(revert("unknown value"), revert_as_raw(expected));
+}
+
+#[test]
+fn log_builder_encodes_indexed_parameters() {
+    let address = H160::repeat_byte(0x05);
+    let from = H160::repeat_byte(0x11);
+    let log = LogBuilder::new(address, "Named(address,string,uint256[],uint256,bool)")
+        .indexed(Address(from))
+        .indexed(SolidityString::from("hello"))
+        .indexed(vec![U256::from(1u32), U256::from(2u32)])
+        .data((U256::from(42u32), true))
+        .build();
+
+    let mut array = [0u8; 64];
+    array[31] = 1;
+    array[63] = 2;
+    assert_eq!(
+        log,
+        Log {
+            address,
+            topics: vec![
+                H256(keccak256!("Named(address,string,uint256[],uint256,bool)")),
+                from.into(),
+                H256(sp_io::hashing::keccak_256(b"hello")),
+                H256(sp_io::hashing::keccak_256(&array)),
+            ],
+            data: EvmDataWriter::new()
+                .write(U256::from(42u32))
+                .write(true)
+                .build(),
+        }
+    );
+}
+
+#[test]
+fn nested_dynamic_topics_are_padded() {
+    // Strings are padded inside arrays and tuples, and tuples are always hashed.
+    let mut expected = b"hello".to_vec();
+    expected.resize(32, 0);
+    assert_eq!(
+        <(SolidityString,)>::encode_topic((SolidityString::from("hello"),)),
+        H256(sp_io::hashing::keccak_256(&expected))
+    );
+    assert_eq!(
+        <(U256,)>::encode_topic((U256::from(1u32),)),
+        H256(sp_io::hashing::keccak_256(&H256::from_low_u64_be(1).0))
+    );
+    assert_eq!(
+        U256::encode_topic(U256::from(1u32)),
+        H256::from_low_u64_be(1)
+    );
+}
+
+#[test]
+fn eip712_mail_example_hashes() {
+    use eip712::{hash_typed_data, Eip712Domain, StructHasher};
+
+    // Example of the EIP-712 specification.
+    const PERSON_TYPEHASH: [u8; 32] = keccak256!("Person(string name,address wallet)");
+    const MAIL_TYPEHASH: [u8; 32] =
+        keccak256!("Mail(Person from,Person to,string contents)Person(string name,address wallet)");
+
+    let domain = Eip712Domain {
+        name: b"Ether Mail",
+        version: b"1",
+        chain_id: 1,
+        verifying_contract: H160(hex!("CcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC")),
+    };
+    assert_eq!(
+        domain.separator(),
+        H256(hex!(
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        ))
+    );
+
+    let person = |name: &str, wallet: [u8; 20]| {
+        StructHasher::new(PERSON_TYPEHASH)
+            .field(&SolidityString::from(name))
+            .field(&Address(H160(wallet)))
+            .hash()
+    };
+    let mail = StructHasher::new(MAIL_TYPEHASH)
+        .field(&person(
+            "Cow",
+            hex!("CD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"),
+        ))
+        .field(&person(
+            "Bob",
+            hex!("bBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"),
+        ))
+        .field(&SolidityString::from("Hello, Bob!"))
+        .hash();
+    assert_eq!(
+        mail,
+        H256(hex!(
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        ))
+    );
+
+    assert_eq!(
+        hash_typed_data(domain.separator(), mail),
+        H256(hex!(
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        ))
+    );
+}
+
+#[test]
+fn dispatch_error_reason_is_readable() {
+    use sp_runtime::{DispatchError, ModuleError};
+
+    assert_eq!(
+        dispatch_error_reason(&DispatchError::Module(ModuleError {
+            index: 2,
+            error: [10, 0, 0, 0],
+            message: Some("Unapproved"),
+        })),
+        "Unapproved"
+    );
+    assert_eq!(
+        dispatch_error_reason(&DispatchError::Module(ModuleError {
+            index: 2,
+            error: [10, 0, 0, 0],
+            message: None,
+        })),
+        "Module(ModuleError { index: 2, error: [10, 0, 0, 0], message: None })"
+    );
+    assert_eq!(
+        dispatch_error_reason(&DispatchError::BadOrigin),
+        "BadOrigin"
+    );
+}
+
+#[test]
+fn hashed_address_mapper_matches_pallet_evm() {
+    use pallet_evm::AddressMapping;
+    use parity_scale_codec::Encode;
+    use sp_runtime::{traits::BlakeTwo256, AccountId32};
+
+    type Mapper = HashedAddressMapper<BlakeTwo256>;
+    let address = H160::repeat_byte(0xAA);
+
+    let account: AccountId32 = <Mapper as AddressMapper<_>>::into_account_id(address);
+    assert_eq!(
+        account,
+        pallet_evm::HashedAddressMapping::<BlakeTwo256>::into_account_id(address)
+    );
+    assert_eq!(
+        <Mapper as AddressMapping<AccountId32>>::into_account_id(address),
+        account
+    );
+
+    let data = (b"evm:", account.clone());
+    assert_eq!(
+        Mapper::into_h160(account),
+        H160::from_slice(&sp_io::hashing::blake2_256(&data.encode())[0..20])
+    );
+}
+
+#[test]
+fn truncated_address_mapper_roundtrips_addresses() {
+    use sp_runtime::AccountId32;
+
+    let address = H160::repeat_byte(0xAA);
+    let account: AccountId32 = TruncatedAddressMapper::into_account_id(address);
+
+    let mut expected = [0u8; 32];
+    expected[0..20].copy_from_slice(&[0xAA; 20]);
+    assert_eq!(account, AccountId32::new(expected));
+    assert_eq!(TruncatedAddressMapper::into_h160(account), address);
+    assert_eq!(
+        TruncatedAddressMapper::into_h160(AccountId32::new([0xBB; 32])),
+        H160::repeat_byte(0xBB)
+    );
+}
+
+#[test]
+fn unified_address_mapper_prefers_linked_accounts() {
+    use sp_runtime::AccountId32;
+
+    struct Linked;
+    impl UnifiedAccounts<AccountId32> for Linked {
+        fn native_account(address: &H160) -> Option<AccountId32> {
+            (*address == H160::repeat_byte(0x01)).then(|| AccountId32::new([0x02; 32]))
+        }
+
+        fn evm_address(account_id: &AccountId32) -> Option<H160> {
+            (*account_id == AccountId32::new([0x02; 32])).then(|| H160::repeat_byte(0x01))
+        }
+    }
+    type Mapper = UnifiedAddressMapper<Linked, TruncatedAddressMapper>;
+
+    // Linked accounts map to each other
+    let linked: AccountId32 = Mapper::into_account_id(H160::repeat_byte(0x01));
+    assert_eq!(linked, AccountId32::new([0x02; 32]));
+    assert_eq!(Mapper::into_h160(linked), H160::repeat_byte(0x01));
+
+    // Others are mapped by the fallback
+    let address = H160::repeat_byte(0x03);
+    let account: AccountId32 = Mapper::into_account_id(address);
+    assert_eq!(
+        account,
+        <TruncatedAddressMapper as AddressMapper<AccountId32>>::into_account_id(address)
+    );
+    assert_eq!(Mapper::into_h160(account), address);
+}
@@ -0,0 +1,93 @@
+pragma solidity ^0.8.0;
+
+/**
+ * @title Canonical wrapped native currency interface, compatible with WETH9.
+ *
+ * Address: 0x0000000000000000000000000000000000005009
+ */
+interface WrappedNative {
+    /**
+     * @dev Emitted when `value` tokens are moved from one account (`from`) to another (`to`).
+     */
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    /**
+     * @dev Emitted when the allowance of a `spender` for an `owner` is set by a call to {approve}.
+     */
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+
+    /**
+     * @dev Emitted when native currency is wrapped.
+     */
+    event Deposit(address indexed dst, uint256 wad);
+
+    /**
+     * @dev Emitted when wrapped currency is released back as native currency.
+     */
+    event Withdrawal(address indexed src, uint256 wad);
+
+    /**
+     * @dev Returns the name of the token.
+     */
+    function name() external view returns (string memory);
+
+    /**
+     * @dev Returns the symbol of the token.
+     */
+    function symbol() external view returns (string memory);
+
+    /**
+     * @dev Returns the decimals places of the token.
+     */
+    function decimals() external view returns (uint8);
+
+    /**
+     * @dev Returns the amount of native currency held by the precompile.
+     */
+    function totalSupply() external view returns (uint256);
+
+    /**
+     * @dev Returns the amount of tokens owned by `who`.
+     */
+    function balanceOf(address who) external view returns (uint256);
+
+    /**
+     * @dev Returns the remaining number of tokens that `spender` will be
+     * allowed to spend on behalf of `owner` through {transferFrom}.
+     * An allowance of `type(uint256).max` is never decreased.
+     */
+    function allowance(address owner, address spender)
+        external
+        view
+        returns (uint256);
+
+    /**
+     * @dev Sets `value` as the allowance of `spender` over the caller's tokens.
+     */
+    function approve(address spender, uint256 value) external returns (bool);
+
+    /**
+     * @dev Moves `value` tokens from the caller's account to `to`.
+     */
+    function transfer(address to, uint256 value) external returns (bool);
+
+    /**
+     * @dev Moves `value` tokens from `from` to `to` using the allowance mechanism.
+     */
+    function transferFrom(
+        address from,
+        address to,
+        uint256 value
+    ) external returns (bool);
+
+    /**
+     * @dev Wraps the native currency sent along with the call.
+     * Plain value transfers to the precompile are treated the same way.
+     */
+    function deposit() external payable;
+
+    /**
+     * @dev Unwraps `wad` tokens and sends them back to the caller as native currency.
+     */
+    function withdraw(uint256 wad) external;
+}
@@ -0,0 +1,437 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Canonical wrapped native currency precompile.
+//!
+//! Implements the WETH9 interface (`deposit`, `withdraw` and ERC20) on top of the native currency.
+//! Deposited funds are held by the account mapped from the precompile address, which has neither
+//! code nor key, so they can only ever leave it through `withdraw`.
+//!
+//! The vault account must hold the existential deposit on top of the deposited funds, otherwise
+//! a withdrawal leaving less than it would reap the funds backing the other holders. It is
+//! endowed at genesis, or on an existing chain by the [`FundVault`] migration.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{PrecompileHandle, PrecompileOutput};
+use frame_support::{
+    storage_alias,
+    traits::{Currency, ExistenceRequirement, Get, OnRuntimeUpgrade},
+    weights::Weight,
+    Blake2_128Concat,
+};
+use pallet_evm::{AddressMapping, Precompile};
+use precompile_utils::{
+    keccak256, revert, succeed, Address, Bytes, EvmDataWriter, EvmResult, FunctionModifier, LogExt,
+    LogsBuilder, PrecompileHandleExt, RuntimeHelper,
+};
+use sp_core::{H160, U256};
+use sp_std::{convert::TryFrom, marker::PhantomData};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the Approval log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+/// Solidity selector of the Deposit log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_DEPOSIT: [u8; 32] = keccak256!("Deposit(address,uint256)");
+
+/// Solidity selector of the Withdrawal log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_WITHDRAWAL: [u8; 32] = keccak256!("Withdrawal(address,uint256)");
+
+type BalanceOf<Runtime> = <<Runtime as pallet_evm::Config>::Currency as Currency<
+    <Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+#[precompile_utils::generate_function_selector]
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    #[view]
+    Name = "name()",
+    #[view]
+    Symbol = "symbol()",
+    #[view]
+    Decimals = "decimals()",
+    #[view]
+    TotalSupply = "totalSupply()",
+    #[view]
+    BalanceOf = "balanceOf(address)",
+    #[view]
+    Allowance = "allowance(address,address)",
+    Approve = "approve(address,uint256)",
+    Transfer = "transfer(address,uint256)",
+    TransferFrom = "transferFrom(address,address,uint256)",
+    #[payable]
+    Deposit = "deposit()",
+    Withdraw = "withdraw(uint256)",
+}
+
+/// Wrapped balances, keyed by the precompile address and the holder.
+#[storage_alias]
+pub type WrappedBalances =
+    StorageDoubleMap<WrappedNativePrecompile, Blake2_128Concat, H160, Blake2_128Concat, H160, U256>;
+
+/// Allowances, keyed by the precompile address and the `(owner, spender)` pair.
+#[storage_alias]
+pub type Allowances = StorageDoubleMap<
+    WrappedNativePrecompile,
+    Blake2_128Concat,
+    H160,
+    Blake2_128Concat,
+    (H160, H160),
+    U256,
+>;
+
+/// Token metadata of the wrapped native currency.
+pub trait WrappedNativeMetadata {
+    /// Returns the name of the token.
+    fn name() -> &'static str;
+
+    /// Returns the symbol of the token.
+    fn symbol() -> &'static str;
+
+    /// Returns the decimals places of the token.
+    fn decimals() -> u8;
+}
+
+/// A precompile providing the canonical wrapped native currency.
+pub struct WrappedNativePrecompile<Runtime, Metadata>(PhantomData<(Runtime, Metadata)>);
+
+impl<Runtime, Metadata> Precompile for WrappedNativePrecompile<Runtime, Metadata>
+where
+    Runtime: pallet_evm::Config,
+    Metadata: WrappedNativeMetadata,
+    BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        log::trace!(target: "wrapped-native-precompile", "In wrapped native precompile");
+
+        // Plain value transfers are treated as deposits, like the WETH9 fallback function.
+        if handle.input().is_empty() {
+            handle.check_function_modifier(FunctionModifier::Payable)?;
+            return Self::deposit(handle);
+        }
+
+        let selector = handle.read_selector()?;
+
+        handle.check_function_modifier(selector.modifier())?;
+
+        match selector {
+            Action::Name => Self::name(handle),
+            Action::Symbol => Self::symbol(handle),
+            Action::Decimals => Self::decimals(handle),
+            Action::TotalSupply => Self::total_supply(handle),
+            Action::BalanceOf => Self::balance_of(handle),
+            Action::Allowance => Self::allowance(handle),
+            Action::Approve => Self::approve(handle),
+            Action::Transfer => Self::transfer(handle),
+            Action::TransferFrom => Self::transfer_from(handle),
+            Action::Deposit => Self::deposit(handle),
+            Action::Withdraw => Self::withdraw(handle),
+        }
+    }
+}
+
+impl<Runtime, Metadata> WrappedNativePrecompile<Runtime, Metadata>
+where
+    Runtime: pallet_evm::Config,
+    Metadata: WrappedNativeMetadata,
+    BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+{
+    fn name(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_base_cost::<Runtime>()?;
+
+        Ok(succeed(
+            EvmDataWriter::new()
+                .write::<Bytes>(Metadata::name().into())
+                .build(),
+        ))
+    }
+
+    fn symbol(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_base_cost::<Runtime>()?;
+
+        Ok(succeed(
+            EvmDataWriter::new()
+                .write::<Bytes>(Metadata::symbol().into())
+                .build(),
+        ))
+    }
+
+    fn decimals(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_base_cost::<Runtime>()?;
+
+        Ok(succeed(
+            EvmDataWriter::new().write(Metadata::decimals()).build(),
+        ))
+    }
+
+    fn total_supply(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        // Storage item: System::Account
+        // Blake2_128(16) + AccountId(32) + AccountInfo(80)
+        handle.record_db_read::<Runtime>(128)?;
+
+        // Same as WETH9, the supply is the balance held by the precompile,
+        // except for the existential deposit keeping the vault alive.
+        let vault = Runtime::AddressMapping::into_account_id(handle.context().address);
+        let supply: U256 = Runtime::Currency::free_balance(&vault)
+            .saturating_sub(Runtime::Currency::minimum_balance())
+            .into();
+
+        Ok(succeed(EvmDataWriter::new().write(supply).build()))
+    }
+
+    fn balance_of(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+
+        let owner: H160 = input.read::<Address>()?.into();
+        let amount = Self::read_balance(handle, owner)?;
+
+        Ok(succeed(EvmDataWriter::new().write(amount).build()))
+    }
+
+    fn allowance(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(2)?;
+
+        let owner: H160 = input.read::<Address>()?.into();
+        let spender: H160 = input.read::<Address>()?.into();
+        let amount = Self::read_allowance(handle, owner, spender)?;
+
+        Ok(succeed(EvmDataWriter::new().write(amount).build()))
+    }
+
+    fn approve(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_log_costs_manual(3, 32)?;
+        // Storage item: Allowances
+        handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(2)?;
+
+        let spender: H160 = input.read::<Address>()?.into();
+        let amount: U256 = input.read()?;
+
+        let (precompile, caller) = (handle.context().address, handle.context().caller);
+        Allowances::insert(precompile, (caller, spender), amount);
+
+        LogsBuilder::new(precompile)
+            .log3(
+                SELECTOR_LOG_APPROVAL,
+                caller,
+                spender,
+                EvmDataWriter::new().write(amount).build(),
+            )
+            .record(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    fn transfer(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(2)?;
+
+        let to: H160 = input.read::<Address>()?.into();
+        let amount: U256 = input.read()?;
+
+        let caller = handle.context().caller;
+        Self::do_transfer(handle, caller, to, amount)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    fn transfer_from(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        let mut input = handle.read_input()?;
+        input.expect_arguments(3)?;
+
+        let from: H160 = input.read::<Address>()?.into();
+        let to: H160 = input.read::<Address>()?.into();
+        let amount: U256 = input.read()?;
+
+        // Same as WETH9, the owner doesn't need an allowance and an infinite one is never spent.
+        let caller = handle.context().caller;
+        if caller != from {
+            let allowance = Self::read_allowance(handle, from, caller)?;
+            if allowance != U256::MAX {
+                let remaining = match allowance.checked_sub(amount) {
+                    Some(remaining) => remaining,
+                    None => return Err(revert("trying to spend more than allowed")),
+                };
+
+                // Storage item: Allowances
+                handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+                Allowances::insert(handle.context().address, (from, caller), remaining);
+            }
+        }
+
+        Self::do_transfer(handle, from, to, amount)?;
+
+        Ok(succeed(EvmDataWriter::new().write(true).build()))
+    }
+
+    fn deposit(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_log_costs_manual(2, 32)?;
+
+        // The deposited value has already been moved to the precompile account by the EVM.
+        let amount = handle.context().apparent_value;
+        let (precompile, caller) = (handle.context().address, handle.context().caller);
+
+        let balance = Self::read_balance(handle, caller)?;
+        // Storage item: WrappedBalances
+        handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+        WrappedBalances::insert(precompile, caller, balance.saturating_add(amount));
+
+        LogsBuilder::new(precompile)
+            .log2(
+                SELECTOR_LOG_DEPOSIT,
+                caller,
+                EvmDataWriter::new().write(amount).build(),
+            )
+            .record(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().build()))
+    }
+
+    fn withdraw(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+        handle.record_log_costs_manual(2, 32)?;
+
+        let mut input = handle.read_input()?;
+        input.expect_arguments(1)?;
+
+        let amount: U256 = input.read()?;
+        let (precompile, caller) = (handle.context().address, handle.context().caller);
+
+        let balance = Self::read_balance(handle, caller)?;
+        let remaining = match balance.checked_sub(amount) {
+            Some(remaining) => remaining,
+            None => return Err(revert("trying to withdraw more than owned")),
+        };
+        let value = RuntimeHelper::<Runtime>::checked_balance(amount, "amount")?;
+
+        // Storage items: WrappedBalances, System::Account (vault & caller)
+        handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost().saturating_mul(3))?;
+        WrappedBalances::insert(precompile, caller, remaining);
+        Runtime::Currency::transfer(
+            &Runtime::AddressMapping::into_account_id(precompile),
+            &Runtime::AddressMapping::into_account_id(caller),
+            value,
+            ExistenceRequirement::KeepAlive,
+        )
+        .map_err(|_| revert("failed to release native currency"))?;
+
+        LogsBuilder::new(precompile)
+            .log2(
+                SELECTOR_LOG_WITHDRAWAL,
+                caller,
+                EvmDataWriter::new().write(amount).build(),
+            )
+            .record(handle)?;
+
+        Ok(succeed(EvmDataWriter::new().build()))
+    }
+
+    /// Move wrapped balance between two holders and emit the `Transfer` log.
+    fn do_transfer(
+        handle: &mut impl PrecompileHandle,
+        from: H160,
+        to: H160,
+        amount: U256,
+    ) -> EvmResult {
+        handle.record_log_costs_manual(3, 32)?;
+
+        let precompile = handle.context().address;
+
+        let from_balance = Self::read_balance(handle, from)?;
+        let from_remaining = match from_balance.checked_sub(amount) {
+            Some(remaining) => remaining,
+            None => return Err(revert("trying to transfer more than owned")),
+        };
+        // Storage item: WrappedBalances
+        handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+        WrappedBalances::insert(precompile, from, from_remaining);
+
+        // Read after the write so that self transfers are a no-op.
+        let to_balance = Self::read_balance(handle, to)?;
+        // Storage item: WrappedBalances
+        handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+        WrappedBalances::insert(precompile, to, to_balance.saturating_add(amount));
+
+        LogsBuilder::new(precompile)
+            .log3(
+                SELECTOR_LOG_TRANSFER,
+                from,
+                to,
+                EvmDataWriter::new().write(amount).build(),
+            )
+            .record(handle)?;
+
+        Ok(())
+    }
+
+    fn read_balance(handle: &mut impl PrecompileHandle, owner: H160) -> EvmResult<U256> {
+        // Storage item: WrappedBalances:
+        // Blake2_128(16) * 2 + H160(20) * 2 + U256(32)
+        handle.record_db_read::<Runtime>(104)?;
+
+        Ok(WrappedBalances::get(handle.context().address, owner).unwrap_or_default())
+    }
+
+    fn read_allowance(
+        handle: &mut impl PrecompileHandle,
+        owner: H160,
+        spender: H160,
+    ) -> EvmResult<U256> {
+        // Storage item: Allowances:
+        // Blake2_128(16) * 2 + H160(20) * 3 + U256(32)
+        handle.record_db_read::<Runtime>(124)?;
+
+        Ok(Allowances::get(handle.context().address, (owner, spender)).unwrap_or_default())
+    }
+}
+
+/// Endows the vault of the precompile at `VaultAddress` with the existential deposit, so that
+/// withdrawals can never reap it.
+///
+/// The existential deposit is minted, as the vault must not hold funds backing wrapped balances.
+pub struct FundVault<Runtime, VaultAddress>(PhantomData<(Runtime, VaultAddress)>);
+
+impl<Runtime, VaultAddress> OnRuntimeUpgrade for FundVault<Runtime, VaultAddress>
+where
+    Runtime: pallet_evm::Config,
+    VaultAddress: Get<H160>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        let vault = Runtime::AddressMapping::into_account_id(VaultAddress::get());
+        let free_balance = Runtime::Currency::free_balance(&vault);
+        let minimum_balance = Runtime::Currency::minimum_balance();
+
+        if free_balance < minimum_balance {
+            let _ = Runtime::Currency::deposit_creating(&vault, minimum_balance - free_balance);
+            <Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 2)
+        } else {
+            <Runtime as frame_system::Config>::DbWeight::get().reads(1)
+        }
+    }
+}
@@ -0,0 +1,446 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+
+use precompile_utils::testing::*;
+
+fn precompiles() -> TestPrecompileSet<Runtime> {
+    PrecompilesValue::get()
+}
+
+/// Deposit `amount` on behalf of `who`, emulating the value transfer performed by the EVM.
+fn deposit(who: TestAccount, amount: Balance) {
+    assert!(Balances::transfer_allow_death(
+        RuntimeOrigin::signed(who.clone()),
+        TestAccount::Precompile,
+        amount
+    )
+    .is_ok());
+
+    precompiles()
+        .prepare_test(
+            who.clone(),
+            PRECOMPILE_ADDRESS,
+            EvmDataWriter::new_with_selector(Action::Deposit).build(),
+        )
+        .with_value(amount)
+        .expect_log(LogsBuilder::new(PRECOMPILE_ADDRESS).log2(
+            SELECTOR_LOG_DEPOSIT,
+            H160::from(who),
+            EvmDataWriter::new().write(U256::from(amount)).build(),
+        ))
+        .execute_returns(EvmDataWriter::new().build());
+}
+
+fn balance_of(who: TestAccount) -> U256 {
+    WrappedBalances::get(PRECOMPILE_ADDRESS, H160::from(who)).unwrap_or_default()
+}
+
+#[test]
+fn metadata_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Name).build(),
+            )
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write::<Bytes>("Wrapped Native".into())
+                    .build(),
+            );
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Symbol).build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write::<Bytes>("WNAT".into()).build());
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Decimals).build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(18u8).build());
+    });
+}
+
+#[test]
+fn deposit_and_withdraw_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        deposit(TestAccount::Alice, 1_000);
+        assert_eq!(balance_of(TestAccount::Alice), U256::from(1_000));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Bob,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::TotalSupply).build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(U256::from(1_000)).build());
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Bob,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::BalanceOf)
+                    .write(Address(TestAccount::Alice.into()))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(U256::from(1_000)).build());
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Withdraw)
+                    .write(U256::from(400))
+                    .build(),
+            )
+            .expect_log(LogsBuilder::new(PRECOMPILE_ADDRESS).log2(
+                SELECTOR_LOG_WITHDRAWAL,
+                H160::from(TestAccount::Alice),
+                EvmDataWriter::new().write(U256::from(400)).build(),
+            ))
+            .execute_returns(EvmDataWriter::new().build());
+
+        assert_eq!(balance_of(TestAccount::Alice), U256::from(600));
+        assert_eq!(
+            Balances::free_balance(TestAccount::Precompile),
+            600 + ExistentialDeposit::get()
+        );
+        assert_eq!(
+            Balances::free_balance(TestAccount::Alice),
+            INITIAL_BALANCE - 600
+        );
+    });
+}
+
+#[test]
+fn withdrawing_everything_keeps_the_vault_alive() {
+    ExtBuilder::default().build().execute_with(|| {
+        deposit(TestAccount::Alice, 300);
+        deposit(TestAccount::Bob, 200);
+
+        for (who, amount) in [(TestAccount::Alice, 300), (TestAccount::Bob, 200)] {
+            precompiles()
+                .prepare_test(
+                    who.clone(),
+                    PRECOMPILE_ADDRESS,
+                    EvmDataWriter::new_with_selector(Action::Withdraw)
+                        .write(U256::from(amount))
+                        .build(),
+                )
+                .expect_log(LogsBuilder::new(PRECOMPILE_ADDRESS).log2(
+                    SELECTOR_LOG_WITHDRAWAL,
+                    H160::from(who),
+                    EvmDataWriter::new().write(U256::from(amount)).build(),
+                ))
+                .execute_returns(EvmDataWriter::new().build());
+        }
+
+        assert_eq!(Balances::free_balance(TestAccount::Alice), INITIAL_BALANCE);
+        assert_eq!(Balances::free_balance(TestAccount::Bob), INITIAL_BALANCE);
+        assert_eq!(
+            Balances::free_balance(TestAccount::Precompile),
+            ExistentialDeposit::get()
+        );
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Bob,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::TotalSupply).build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(U256::zero()).build());
+    });
+}
+
+#[test]
+fn fund_vault_endows_an_empty_vault() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::slash(&TestAccount::Precompile, ExistentialDeposit::get());
+        assert_eq!(Balances::free_balance(TestAccount::Precompile), 0);
+
+        FundVault::<Runtime, PrecompileAddress>::on_runtime_upgrade();
+        assert_eq!(
+            Balances::free_balance(TestAccount::Precompile),
+            ExistentialDeposit::get()
+        );
+
+        // Running it again is a no-op.
+        FundVault::<Runtime, PrecompileAddress>::on_runtime_upgrade();
+        assert_eq!(
+            Balances::free_balance(TestAccount::Precompile),
+            ExistentialDeposit::get()
+        );
+    });
+}
+
+#[test]
+fn plain_value_transfer_is_a_deposit() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(TestAccount::Bob),
+            TestAccount::Precompile,
+            50
+        )
+        .is_ok());
+
+        precompiles()
+            .prepare_test(TestAccount::Bob, PRECOMPILE_ADDRESS, Vec::new())
+            .with_value(50)
+            .expect_log(LogsBuilder::new(PRECOMPILE_ADDRESS).log2(
+                SELECTOR_LOG_DEPOSIT,
+                H160::from(TestAccount::Bob),
+                EvmDataWriter::new().write(U256::from(50)).build(),
+            ))
+            .execute_returns(EvmDataWriter::new().build());
+
+        assert_eq!(balance_of(TestAccount::Bob), U256::from(50));
+    });
+}
+
+#[test]
+fn withdraw_more_than_owned_reverts() {
+    ExtBuilder::default().build().execute_with(|| {
+        deposit(TestAccount::Alice, 100);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Withdraw)
+                    .write(U256::from(101))
+                    .build(),
+            )
+            .execute_reverts(|output| output == b"trying to withdraw more than owned");
+
+        // Withdraw is not payable
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Withdraw)
+                    .write(U256::from(1))
+                    .build(),
+            )
+            .with_value(1)
+            .execute_reverts(|output| output == b"function is not payable");
+
+        assert_eq!(balance_of(TestAccount::Alice), U256::from(100));
+    });
+}
+
+#[test]
+fn transfer_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        deposit(TestAccount::Alice, 100);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Transfer)
+                    .write(Address(TestAccount::Bob.into()))
+                    .write(U256::from(30))
+                    .build(),
+            )
+            .expect_log(LogsBuilder::new(PRECOMPILE_ADDRESS).log3(
+                SELECTOR_LOG_TRANSFER,
+                H160::from(TestAccount::Alice),
+                H160::from(TestAccount::Bob),
+                EvmDataWriter::new().write(U256::from(30)).build(),
+            ))
+            .execute_returns(EvmDataWriter::new().write(true).build());
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::BalanceOf)
+                    .write(Address(TestAccount::Bob.into()))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(U256::from(30)).build());
+
+        // Self transfer doesn't mint anything
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Transfer)
+                    .write(Address(TestAccount::Alice.into()))
+                    .write(U256::from(70))
+                    .build(),
+            )
+            .execute_returns(EvmDataWriter::new().write(true).build());
+        assert_eq!(balance_of(TestAccount::Alice), U256::from(70));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Transfer)
+                    .write(Address(TestAccount::Bob.into()))
+                    .write(U256::from(71))
+                    .build(),
+            )
+            .execute_reverts(|output| output == b"trying to transfer more than owned");
+    });
+}
+
+#[test]
+fn transfer_from_spends_allowance() {
+    ExtBuilder::default().build().execute_with(|| {
+        deposit(TestAccount::Alice, 100);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Approve)
+                    .write(Address(TestAccount::Bob.into()))
+                    .write(U256::from(50))
+                    .build(),
+            )
+            .expect_log(LogsBuilder::new(PRECOMPILE_ADDRESS).log3(
+                SELECTOR_LOG_APPROVAL,
+                H160::from(TestAccount::Alice),
+                H160::from(TestAccount::Bob),
+                EvmDataWriter::new().write(U256::from(50)).build(),
+            ))
+            .execute_returns(EvmDataWriter::new().write(true).build());
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Bob,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::TransferFrom)
+                    .write(Address(TestAccount::Alice.into()))
+                    .write(Address(TestAccount::Charlie.into()))
+                    .write(U256::from(40))
+                    .build(),
+            )
+            .execute_returns(EvmDataWriter::new().write(true).build());
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Bob,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::Allowance)
+                    .write(Address(TestAccount::Alice.into()))
+                    .write(Address(TestAccount::Bob.into()))
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(EvmDataWriter::new().write(U256::from(10)).build());
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Bob,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::TransferFrom)
+                    .write(Address(TestAccount::Alice.into()))
+                    .write(Address(TestAccount::Charlie.into()))
+                    .write(U256::from(11))
+                    .build(),
+            )
+            .execute_reverts(|output| output == b"trying to spend more than allowed");
+
+        assert_eq!(balance_of(TestAccount::Alice), U256::from(60));
+        assert_eq!(balance_of(TestAccount::Charlie), U256::from(40));
+    });
+}
+
+#[test]
+fn infinite_allowance_is_not_spent() {
+    ExtBuilder::default().build().execute_with(|| {
+        deposit(TestAccount::Alice, 100);
+        Allowances::insert(
+            PRECOMPILE_ADDRESS,
+            (H160::from(TestAccount::Alice), H160::from(TestAccount::Bob)),
+            U256::MAX,
+        );
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Bob,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::TransferFrom)
+                    .write(Address(TestAccount::Alice.into()))
+                    .write(Address(TestAccount::Bob.into()))
+                    .write(U256::from(100))
+                    .build(),
+            )
+            .execute_returns(EvmDataWriter::new().write(true).build());
+
+        assert_eq!(
+            Allowances::get(
+                PRECOMPILE_ADDRESS,
+                (H160::from(TestAccount::Alice), H160::from(TestAccount::Bob))
+            ),
+            Some(U256::MAX)
+        );
+        assert_eq!(balance_of(TestAccount::Bob), U256::from(100));
+    });
+}
+
+#[test]
+fn view_selectors_charge_base_cost() {
+    let view_selectors: Vec<(u32, &str)> = [
+        Action::Name,
+        Action::Symbol,
+        Action::Decimals,
+        Action::TotalSupply,
+        Action::BalanceOf,
+        Action::Allowance,
+    ]
+    .into_iter()
+    .map(|action| {
+        let selector = u32::from(action);
+        *Action::SELECTORS
+            .iter()
+            .find(|(s, _)| *s == selector)
+            .expect("selector is generated")
+    })
+    .collect();
+
+    assert_selectors_charge_base_cost(
+        &view_selectors,
+        RuntimeHelper::<Runtime>::db_read_gas_cost(),
+        &[
+            metadata_works,
+            deposit_and_withdraw_works,
+            transfer_from_spends_allowance,
+        ],
+    );
+}
@@ -25,7 +25,7 @@ use frame_support::{
     traits::Get,
 };
 use pallet_evm::{AddressMapping, Precompile};
-use sp_core::{H160, H256, U256};
+use sp_core::{ConstU32, H160, H256, U256};
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 
@@ -34,7 +34,7 @@ use xcm_executor::traits::Convert;
 
 use pallet_evm_precompile_assets_erc20::AddressToAssetId;
 use precompile_utils::{
-    revert, succeed, Address, Bytes, EvmDataWriter, EvmResult, FunctionModifier,
+    bytes::BoundedBytes, revert, succeed, Address, EvmDataWriter, EvmResult, FunctionModifier,
     PrecompileHandleExt, RuntimeHelper,
 };
 
@@ -55,6 +55,11 @@ pub enum Action {
         "assets_reserve_transfer(address[],uint256[],address,bool,uint256,uint256)",
 }
 
+/// Max size of the encoded call which can be sent for remote execution.
+pub const REMOTE_CALL_LIMIT: u32 = 2u32.pow(16);
+
+type GetRemoteCallLimit = ConstU32<REMOTE_CALL_LIMIT>;
+
 /// Dummy H160 address representing native currency (e.g. ASTR or SDN)
 const NATIVE_ADDRESS: H160 = H160::zero();
 
@@ -213,7 +218,7 @@ where
         let fee_asset_addr = input.read::<Address>()?;
         let fee_amount = input.read::<U256>()?;
 
-        let remote_call: Vec<u8> = input.read::<Bytes>()?.into();
+        let remote_call: Vec<u8> = input.read::<BoundedBytes<GetRemoteCallLimit>>()?.into();
         let transact_weight = input.read::<u64>()?;
 
         log::trace!(target: "xcm-precompile:remote_transact", "Raw arguments: para_id: {}, is_relay: {}, fee_asset_addr: {:?}, \
@@ -19,4 +19,26 @@ interface XVM {
         bytes calldata input,
         uint256 value
     ) external payable returns (bool success, bytes memory data);
+
+    /**
+     * @dev Enqueue external VM call, executed in a subsequent block.
+     * The price of `gas_limit` is held from the caller until the call is executed,
+     * then the result is delivered to the caller with
+     * `onXvmAsyncCallResult(uint64 id, bool success, bytes output)`, sent from the zero address.
+     * @param vm_id - target VM id
+     * @param to - call recipient
+     * @param input - SCALE-encoded call arguments
+     * @param value - value to transfer
+     * @param gas_limit - gas reserved for the call
+     * @return success - whether the call was enqueued
+     * @return id - call id if successful, zero on error
+     * @return error - SCALE-encoded error data on error, empty if successful
+     */
+    function xvm_async_call(
+        uint8 vm_id,
+        bytes calldata to,
+        bytes calldata input,
+        uint256 value,
+        uint64 gas_limit
+    ) external returns (bool success, uint64 id, bytes memory error);
 }
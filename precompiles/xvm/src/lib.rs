@@ -19,69 +19,53 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use astar_primitives::{
-    xvm::{Context, VmId, XvmCall},
+    xvm::{Context, VmId, XvmAsyncCall, XvmCall},
     Balance,
 };
-use fp_evm::{PrecompileHandle, PrecompileOutput};
+use fp_evm::PrecompileHandle;
 use frame_support::dispatch::Dispatchable;
-use pallet_evm::{AddressMapping, GasWeightMapping, Precompile};
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use sp_core::ConstU32;
 use sp_runtime::codec::Encode;
 use sp_std::{marker::PhantomData, prelude::*};
 
-use precompile_utils::{
-    revert, succeed, Bytes, EvmDataWriter, EvmResult, FunctionModifier, PrecompileHandleExt,
-};
+use precompile_utils::{bytes::BoundedBytes, revert, Bytes, EvmResult};
 
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
-#[precompile_utils::generate_function_selector]
-#[derive(Debug, PartialEq)]
-pub enum Action {
-    XvmCall = "xvm_call(uint8,bytes,bytes,uint256)",
-}
+/// Max size of the callee address, big enough for both `H160` and `AccountId32`.
+pub const CALL_TO_LIMIT: u32 = 32;
+/// Max size of the input data passed to the callee.
+pub const CALL_INPUT_LIMIT: u32 = 2u32.pow(16);
+
+type GetCallToLimit = ConstU32<CALL_TO_LIMIT>;
+type GetCallInputLimit = ConstU32<CALL_INPUT_LIMIT>;
 
 /// A precompile that expose XVM related functions.
 pub struct XvmPrecompile<T, XC>(PhantomData<(T, XC)>);
 
-impl<R, XC> Precompile for XvmPrecompile<R, XC>
-where
-    R: pallet_evm::Config,
-    <<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
-        From<Option<R::AccountId>>,
-    XC: XvmCall<R::AccountId>,
-{
-    fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
-        log::trace!(target: "xvm-precompile", "In XVM precompile");
-
-        let selector = handle.read_selector()?;
-
-        handle.check_function_modifier(FunctionModifier::NonPayable)?;
-
-        match selector {
-            // Dispatchables
-            Action::XvmCall => Self::xvm_call(handle),
-        }
-    }
-}
-
+#[precompile_utils::precompile(Action, R)]
 impl<R, XC> XvmPrecompile<R, XC>
 where
     R: pallet_evm::Config,
     <<R as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
         From<Option<R::AccountId>>,
-    XC: XvmCall<R::AccountId>,
+    XC: XvmCall<R::AccountId> + XvmAsyncCall<R::AccountId>,
 {
-    fn xvm_call(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
-        let mut input = handle.read_input()?;
-        input.expect_arguments(4)?;
+    #[precompile::public("xvm_call(uint8,bytes,bytes,uint256)")]
+    fn xvm_call(
+        handle: &mut impl PrecompileHandle,
+        vm_id: u8,
+        call_to: BoundedBytes<GetCallToLimit>,
+        call_input: BoundedBytes<GetCallInputLimit>,
+        value: Balance,
+    ) -> EvmResult<(bool, Bytes)> {
+        log::trace!(target: "xvm-precompile", "In XVM precompile");
 
-        let vm_id = {
-            let id = input.read::<u8>()?;
-            id.try_into().map_err(|_| revert("invalid vm id"))
-        }?;
+        let vm_id = vm_id.try_into().map_err(|_| revert("invalid vm id"))?;
 
         let mut gas_limit = handle.remaining_gas();
         // If user specified a gas limit, make sure it's not exceeded.
@@ -94,12 +78,16 @@ where
             weight_limit,
         };
 
-        let call_to = input.read::<Bytes>()?.0;
-        let call_input = input.read::<Bytes>()?.0;
-        let value = input.read::<Balance>()?;
         let from = R::AddressMapping::into_account_id(handle.context().caller);
 
-        let call_result = XC::call(xvm_context, vm_id, from, call_to, call_input, value);
+        let call_result = XC::call(
+            xvm_context,
+            vm_id,
+            from,
+            call_to.into(),
+            call_input.into(),
+            value,
+        );
 
         let used_weight = match &call_result {
             Ok(s) => s.used_weight,
@@ -116,12 +104,7 @@ where
                     "success: {:?}", success
                 );
 
-                Ok(succeed(
-                    EvmDataWriter::new()
-                        .write(true)
-                        .write(Bytes(success.output))
-                        .build(),
-                ))
+                Ok((true, Bytes(success.output)))
             }
 
             Err(failure) => {
@@ -130,12 +113,61 @@ where
                     "failure: {:?}", failure
                 );
 
-                Ok(succeed(
-                    EvmDataWriter::new()
-                        .write(false)
-                        .write(Bytes(failure.error.encode()))
-                        .build(),
-                ))
+                Ok((false, Bytes(failure.error.encode())))
+            }
+        }
+    }
+
+    #[precompile::public("xvm_async_call(uint8,bytes,bytes,uint256,uint64)")]
+    fn xvm_async_call(
+        handle: &mut impl PrecompileHandle,
+        vm_id: u8,
+        call_to: BoundedBytes<GetCallToLimit>,
+        call_input: BoundedBytes<GetCallInputLimit>,
+        value: Balance,
+        gas_limit: u64,
+    ) -> EvmResult<(bool, u64, Bytes)> {
+        log::trace!(target: "xvm-precompile", "In XVM precompile");
+
+        // Only enqueueing is charged here, the price of the reserved weight is held
+        // from the caller until the call is executed.
+        let enqueue_weight = XC::enqueue_call_weight();
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(enqueue_weight))?;
+        handle.record_external_cost(
+            Some(enqueue_weight.ref_time()),
+            Some(enqueue_weight.proof_size()),
+        )?;
+
+        let vm_id = vm_id.try_into().map_err(|_| revert("invalid vm id"))?;
+
+        let xvm_context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: R::GasWeightMapping::gas_to_weight(gas_limit, true),
+        };
+
+        // The result is delivered back to the calling contract.
+        let caller = handle.context().caller;
+        let from = R::AddressMapping::into_account_id(caller);
+
+        let enqueue_result = XC::enqueue_call(
+            xvm_context,
+            vm_id,
+            from,
+            call_to.into(),
+            call_input.into(),
+            value,
+            caller.as_bytes().to_vec(),
+        );
+
+        match enqueue_result {
+            Ok(id) => Ok((true, id, Bytes(vec![]))),
+            Err(error) => {
+                log::trace!(
+                    target: "xvm-precompile::xvm_async_call",
+                    "failure: {:?}", error
+                );
+
+                Ok((false, 0, Bytes(error.encode())))
             }
         }
     }
@@ -31,7 +31,8 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 
 use pallet_evm::{
-    AddressMapping, EnsureAddressNever, EnsureAddressRoot, PrecompileResult, PrecompileSet,
+    AddressMapping, EnsureAddressNever, EnsureAddressRoot, Precompile, PrecompileResult,
+    PrecompileSet,
 };
 use sp_core::{H160, H256};
 use sp_runtime::{
@@ -40,7 +41,9 @@ use sp_runtime::{
 };
 use sp_std::cell::RefCell;
 
-use astar_primitives::xvm::{CallError::*, CallErrorWithWeight, CallInfo, CallResult};
+use astar_primitives::xvm::{
+    AsyncCallId, CallError, CallError::*, CallErrorWithWeight, CallInfo, CallResult,
+};
 
 pub type AccountId = TestAccount;
 pub type Balance = u128;
@@ -297,6 +300,46 @@ impl XvmCall<AccountId> for MockXvmWithArgsCheck {
     }
 }
 
+/// Id returned for every enqueued asynchronous call.
+pub(crate) const ENQUEUED_CALL_ID: AsyncCallId = 42;
+
+thread_local! {
+    static ENQUEUED_CALL: RefCell<Option<(Context, Vec<u8>)>> = RefCell::new(None);
+}
+
+/// Context & callback of the last enqueued asynchronous call.
+pub(crate) struct EnqueuedCall;
+impl EnqueuedCall {
+    pub(crate) fn get() -> Option<(Context, Vec<u8>)> {
+        ENQUEUED_CALL.with(|call| call.borrow().clone())
+    }
+
+    pub(crate) fn reset() {
+        ENQUEUED_CALL.with(|call| *call.borrow_mut() = None);
+    }
+}
+
+impl XvmAsyncCall<AccountId> for MockXvmWithArgsCheck {
+    fn enqueue_call(
+        context: Context,
+        vm_id: VmId,
+        _source: AccountId,
+        _target: Vec<u8>,
+        _input: Vec<u8>,
+        _value: Balance,
+        callback: Vec<u8>,
+    ) -> Result<AsyncCallId, CallError> {
+        ensure!(vm_id != VmId::Evm, SameVmCallDenied);
+
+        ENQUEUED_CALL.with(|call| *call.borrow_mut() = Some((context, callback)));
+        Ok(ENQUEUED_CALL_ID)
+    }
+
+    fn enqueue_call_weight() -> Weight {
+        Weight::from_parts(1_000, 0)
+    }
+}
+
 // Configure a mock runtime to test the pallet.
 construct_runtime!(
     pub enum Runtime where
@@ -321,6 +364,7 @@ impl ExtBuilder {
             .expect("Frame system builds valid default genesis config");
 
         WeightLimitCalledWith::reset();
+        EnqueuedCall::reset();
 
         let mut ext = sp_io::TestExternalities::new(t);
         ext.execute_with(|| System::set_block_number(1));
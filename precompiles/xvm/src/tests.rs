@@ -23,7 +23,7 @@ use astar_primitives::xvm::CallError;
 use parity_scale_codec::Encode;
 use precompile_utils::testing::*;
 use precompile_utils::EvmDataWriter;
-use sp_core::U256;
+use sp_core::{H160, U256};
 
 fn precompiles() -> TestPrecompileSet<Runtime> {
     PrecompilesValue::get()
@@ -59,6 +59,43 @@ fn wrong_argument_reverts() {
     })
 }
 
+#[test]
+fn oversized_arguments_revert() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::XvmCall)
+                    .write(0x1Fu8)
+                    .write(Bytes(vec![0u8; CALL_TO_LIMIT as usize + 1]))
+                    .write(Bytes(b"".to_vec()))
+                    .write(U256::one())
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| {
+                output == b"failed to decode argument 1 `call_to`: length, value too large"
+            });
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::XvmCall)
+                    .write(0x1Fu8)
+                    .write(Bytes(b"".to_vec()))
+                    .write(Bytes(vec![0u8; CALL_INPUT_LIMIT as usize + 1]))
+                    .write(U256::one())
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| {
+                output == b"failed to decode argument 2 `call_input`: length, value too large"
+            });
+    })
+}
+
 #[test]
 fn correct_arguments_works() {
     ExtBuilder::default().build().execute_with(|| {
@@ -109,7 +146,7 @@ fn weight_limit_is_min_of_remaining_and_user_limit() {
         );
 
         // The caller set a limit.
-        let gas_limit = 1_000;
+        let gas_limit = 1_000u64;
         precompiles()
             .prepare_test(
                 TestAccount::Alice,
@@ -133,3 +170,71 @@ fn weight_limit_is_min_of_remaining_and_user_limit() {
         );
     });
 }
+
+#[test]
+fn async_call_is_enqueued() {
+    ExtBuilder::default().build().execute_with(|| {
+        let gas_limit = 1_000u64;
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::XvmAsyncCall)
+                    .write(0x1Fu8)
+                    .write(Bytes(b"".to_vec()))
+                    .write(Bytes(b"".to_vec()))
+                    .write(U256::one())
+                    .write(gas_limit)
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(true)
+                    .write(ENQUEUED_CALL_ID)
+                    .write(Bytes(vec![]))
+                    .build(),
+            );
+
+        // The call is reserved the given gas limit, and its result is delivered to the caller.
+        assert_eq!(
+            EnqueuedCall::get(),
+            Some((
+                Context {
+                    source_vm_id: VmId::Evm,
+                    weight_limit: <Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(
+                        gas_limit, true
+                    ),
+                },
+                H160::from(TestAccount::Alice).as_bytes().to_vec()
+            ))
+        );
+    })
+}
+
+#[test]
+fn async_call_failure_is_returned() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                EvmDataWriter::new_with_selector(Action::XvmAsyncCall)
+                    .write(0x0Fu8)
+                    .write(Bytes(b"".to_vec()))
+                    .write(Bytes(b"".to_vec()))
+                    .write(U256::one())
+                    .write(1_000u64)
+                    .build(),
+            )
+            .expect_no_logs()
+            .execute_returns(
+                EvmDataWriter::new()
+                    .write(false)
+                    .write(0u64)
+                    .write(Bytes(CallError::SameVmCallDenied.encode()))
+                    .build(),
+            );
+        assert_eq!(EnqueuedCall::get(), None);
+    })
+}
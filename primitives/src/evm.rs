@@ -16,13 +16,26 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{AccountId, AssetId};
+use crate::{AccountId, AssetId, Balance};
 
-use frame_support::ensure;
+use frame_support::{
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    ensure,
+    traits::{Contains, Get},
+    weights::Weight,
+};
+use sp_core::U256;
+use sp_runtime::{
+    traits::{Convert, DispatchInfoOf, Dispatchable, One, PostDispatchInfoOf, Zero},
+    transaction_validity::TransactionValidityError,
+    FixedPointNumber,
+};
 use sp_std::marker::PhantomData;
 
 use pallet_assets::AssetsCallback;
+use pallet_evm::FeeCalculator;
 use pallet_evm_precompile_assets_erc20::AddressToAssetId;
+use pallet_transaction_payment::{Multiplier, OnChargeTransaction};
 
 /// Revert opt code. It's inserted at the precompile addresses, to make them functional in EVM.
 pub const EVM_REVERT_CODE: &[u8] = &[0x60, 0x00, 0x60, 0x00, 0xfd];
@@ -54,3 +67,129 @@ where
         Ok(())
     }
 }
+
+/// Ratio between the current EVM base fee and the reference value `B`, never lower than `M`.
+///
+/// Falls back to one if the reference is zero.
+pub struct BaseFeeRatio<R, B, M>(PhantomData<(R, B, M)>);
+impl<R, B, M> Get<Multiplier> for BaseFeeRatio<R, B, M>
+where
+    R: pallet_evm::Config,
+    B: Get<U256>,
+    M: Get<Multiplier>,
+{
+    fn get() -> Multiplier {
+        let reference = B::get();
+        if reference.is_zero() {
+            return Multiplier::one();
+        }
+
+        let (base_fee, _) = R::FeeCalculator::min_gas_price();
+        let inner = base_fee.saturating_mul(Multiplier::DIV.into()) / reference;
+        Multiplier::from_inner(inner.try_into().unwrap_or(u128::MAX)).max(M::get())
+    }
+}
+
+/// Weight price for `pallet-contracts`, driven by the EVM base fee.
+///
+/// Weight is first priced by `pallet-transaction-payment`, including its fee multiplier, and then scaled by
+/// [`BaseFeeRatio`], so the same workload costs roughly the same in WASM and EVM contracts
+/// while block congestion is still priced as usual.
+pub struct BaseFeeWeightPrice<R, B, M>(PhantomData<(R, B, M)>);
+impl<R, B, M> Convert<Weight, Balance> for BaseFeeWeightPrice<R, B, M>
+where
+    R: pallet_evm::Config + pallet_transaction_payment::Config,
+    B: Get<U256>,
+    M: Get<Multiplier>,
+    pallet_transaction_payment::Pallet<R>: Convert<Weight, Balance>,
+{
+    fn convert(weight: Weight) -> Balance {
+        let fee =
+            <pallet_transaction_payment::Pallet<R> as Convert<Weight, Balance>>::convert(weight);
+        BaseFeeRatio::<R, B, M>::get().saturating_mul_int(fee)
+    }
+}
+
+/// Transaction fee adapter which charges the weight of calls matched by `F` with [`BaseFeeWeightPrice`].
+///
+/// This makes the fee paid for e.g. a `pallet-contracts` call follow the EVM base fee as well.
+/// The length fee, the base fee of the extrinsic and the tip are left untouched.
+pub struct BaseFeeCallAdapter<R, F, B, M, OCT>(PhantomData<(R, F, B, M, OCT)>);
+
+impl<R, F, B, M, OCT> BaseFeeCallAdapter<R, F, B, M, OCT>
+where
+    R: pallet_evm::Config + pallet_transaction_payment::Config,
+    B: Get<U256>,
+    M: Get<Multiplier>,
+    pallet_transaction_payment::Pallet<R>: Convert<Weight, Balance>,
+{
+    /// Replace the weight part of the `fee` with the one priced by the EVM base fee.
+    fn apply(fee: Balance, weight: Weight) -> Balance {
+        // fee is zero for calls which don't pay one
+        if fee.is_zero() {
+            return fee;
+        }
+
+        let weight_fee =
+            <pallet_transaction_payment::Pallet<R> as Convert<Weight, Balance>>::convert(weight);
+        fee.saturating_sub(weight_fee)
+            .saturating_add(BaseFeeWeightPrice::<R, B, M>::convert(weight))
+    }
+}
+
+impl<R, F, B, M, OCT> OnChargeTransaction<R> for BaseFeeCallAdapter<R, F, B, M, OCT>
+where
+    R: pallet_evm::Config + pallet_transaction_payment::Config,
+    R::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    F: Contains<R::RuntimeCall>,
+    B: Get<U256>,
+    M: Get<Multiplier>,
+    OCT: OnChargeTransaction<R, Balance = Balance>,
+    pallet_transaction_payment::Pallet<R>: Convert<Weight, Balance>,
+{
+    type Balance = Balance;
+    /// Liquidity info of the wrapped implementation, together with whether the fee was adjusted.
+    type LiquidityInfo = (OCT::LiquidityInfo, bool);
+
+    fn withdraw_fee(
+        who: &R::AccountId,
+        call: &R::RuntimeCall,
+        dispatch_info: &DispatchInfoOf<R::RuntimeCall>,
+        fee: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let adjusted = F::contains(call);
+        let fee = if adjusted {
+            Self::apply(fee, dispatch_info.weight)
+        } else {
+            fee
+        };
+
+        OCT::withdraw_fee(who, call, dispatch_info, fee, tip).map(|info| (info, adjusted))
+    }
+
+    fn correct_and_deposit_fee(
+        who: &R::AccountId,
+        dispatch_info: &DispatchInfoOf<R::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<R::RuntimeCall>,
+        corrected_fee: Self::Balance,
+        tip: Self::Balance,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        let (already_withdrawn, adjusted) = already_withdrawn;
+        let corrected_fee = if adjusted {
+            Self::apply(corrected_fee, post_info.calc_actual_weight(dispatch_info))
+        } else {
+            corrected_fee
+        };
+
+        OCT::correct_and_deposit_fee(
+            who,
+            dispatch_info,
+            post_info,
+            corrected_fee,
+            tip,
+            already_withdrawn,
+        )
+    }
+}
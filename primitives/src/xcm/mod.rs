@@ -25,7 +25,11 @@
 //! - `AssetLocationIdConverter` - conversion between local asset Id and cross-chain asset multilocation
 //! - `FixedRateOfForeignAsset` - weight trader for execution payment in foreign asset
 //! - `ReserveAssetFilter` - used to check whether asset/origin are a valid reserve location
+//! - `BridgedAssetReserveFilter` - used to accept assets of a bridged consensus, e.g. Ethereum via Snowbridge
 //! - `XcmFungibleFeeHandler` - used to handle XCM fee execution fees
+//! - `HashedDescription` - derives stable accounts for remote locations, e.g. accounts on other chains
+//! - `LocationToAccountApi` - runtime API used to compute the account derived from a location
+//! - `XcAssetsRegistry` - full registry of cross-chain assets, as returned by `XcAssetsApi` runtime API
 //!
 //! Please refer to implementation below for more info.
 //!
@@ -40,11 +44,14 @@ use sp_runtime::traits::{Bounded, Zero};
 use sp_std::{borrow::Borrow, marker::PhantomData, vec::Vec};
 
 // Polkadot imports
-use xcm::latest::{prelude::*, Weight};
+use xcm::{
+    latest::{prelude::*, Weight},
+    VersionedMultiLocation,
+};
 use xcm_builder::TakeRevenue;
 use xcm_executor::traits::{MatchesFungibles, WeightTrader};
 
-use pallet_xc_asset_config::{ExecutionPaymentRate, XcAssetLocation};
+use pallet_xc_asset_config::{ExecutionPaymentRate, XcAssetInfo, XcAssetLocation};
 
 #[cfg(test)]
 mod tests;
@@ -225,6 +232,27 @@ impl ContainsPair<MultiAsset, MultiLocation> for ReserveAssetFilter {
     }
 }
 
+/// Used to accept assets originating from a bridged global consensus, e.g. Ethereum via Snowbridge.
+///
+/// Such assets are identified as `(2, X*(GlobalConsensus(BridgedNetwork), ..))` and
+/// are only accepted when they're deposited by the `Bridge` location, e.g. Bridge Hub.
+pub struct BridgedAssetReserveFilter<BridgedNetwork, Bridge>(PhantomData<(BridgedNetwork, Bridge)>);
+impl<BridgedNetwork: Get<NetworkId>, Bridge: Get<MultiLocation>>
+    ContainsPair<MultiAsset, MultiLocation> for BridgedAssetReserveFilter<BridgedNetwork, Bridge>
+{
+    fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+        let is_bridged_asset = match &asset.id {
+            Concrete(location) => matches!(
+                (location.parents, location.first_interior()),
+                (2, Some(GlobalConsensus(network))) if *network == BridgedNetwork::get()
+            ),
+            _ => false,
+        };
+
+        is_bridged_asset && origin == &Bridge::get()
+    }
+}
+
 /// Used to deposit XCM fees into a destination account.
 ///
 /// Only handles fungible assets for now.
@@ -272,7 +300,7 @@ impl<
 
 // TODO: remove this after uplift to `polkadot-v0.9.44` or beyond, and replace it with code in XCM builder.
 
-use parity_scale_codec::{Compact, Encode};
+use parity_scale_codec::{Codec, Compact, Encode};
 use sp_io::hashing::blake2_256;
 use sp_std::prelude::*;
 use xcm_executor::traits::Convert as XcmConvert;
@@ -372,6 +400,10 @@ impl<Suffix: DescribeLocation> DescribeLocation for DescribeFamily<Suffix> {
     }
 }
 
+/// Converts location into an account by hashing its description.
+///
+/// E.g. with `DescribeFamily<DescribeAllTerminal>`, an `AccountId32` or `AccountKey20` account on a
+/// sibling parachain gets a stable derived account, which can then be controlled remotely via XCM.
 pub struct HashedDescription<AccountId, Describe>(PhantomData<(AccountId, Describe)>);
 impl<AccountId: From<[u8; 32]> + Clone, Describe: DescribeLocation>
     XcmConvert<MultiLocation, AccountId> for HashedDescription<AccountId, Describe>
@@ -384,3 +416,53 @@ impl<AccountId: From<[u8; 32]> + Clone, Describe: DescribeLocation>
         }
     }
 }
+
+sp_api::decl_runtime_apis! {
+    /// API for computing the local account derived from a location.
+    pub trait LocationToAccountApi<AccountId> where AccountId: Codec {
+        /// Local account derived from the `location`, or `None` if the location cannot be converted.
+        fn convert_location(location: VersionedMultiLocation) -> Option<AccountId>;
+    }
+}
+
+/// Max number of entries returned by a single `XcAssetsRegistry::assets` call.
+pub const XC_ASSETS_PAGE_LIMIT: u32 = 100;
+
+/// Full registry of cross-chain assets.
+///
+/// Combines the mappings & payment status kept by `XcAssetConfig` with the
+/// sufficiency and metadata of the local asset kept by `pallet-assets`.
+pub struct XcAssetsRegistry<Runtime>(PhantomData<Runtime>);
+impl<Runtime> XcAssetsRegistry<Runtime>
+where
+    Runtime: pallet_xc_asset_config::Config
+        + pallet_assets::Config<AssetId = <Runtime as pallet_xc_asset_config::Config>::AssetId>,
+{
+    /// Registry entries in storage order, starting after `start_after` if provided.
+    /// At most `limit` entries are returned, capped by `XC_ASSETS_PAGE_LIMIT`.
+    pub fn assets(
+        start_after: Option<<Runtime as pallet_xc_asset_config::Config>::AssetId>,
+        limit: u32,
+    ) -> Vec<XcAssetInfo<<Runtime as pallet_xc_asset_config::Config>::AssetId>> {
+        use fungibles::metadata::Inspect;
+        type Assets<Runtime> = pallet_assets::Pallet<Runtime>;
+
+        pallet_xc_asset_config::Pallet::<Runtime>::registered_assets(
+            start_after,
+            limit.min(XC_ASSETS_PAGE_LIMIT),
+        )
+        .into_iter()
+        .map(|(asset_id, location, units_per_second)| XcAssetInfo {
+            asset_id,
+            location,
+            units_per_second,
+            is_sufficient: pallet_evm_precompile_assets_erc20::is_sufficient::<Runtime, ()>(
+                asset_id,
+            ),
+            name: Assets::<Runtime>::name(asset_id),
+            symbol: Assets::<Runtime>::symbol(asset_id),
+            decimals: Assets::<Runtime>::decimals(asset_id),
+        })
+        .collect()
+    }
+}
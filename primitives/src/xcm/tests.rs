@@ -373,6 +373,75 @@ fn reserve_asset_filter_with_origin_mismatch() {
     assert!(!ReserveAssetFilter::contains(&multi_asset, &origin));
 }
 
+frame_support::parameter_types! {
+    pub const EthereumNetwork: NetworkId = NetworkId::Ethereum { chain_id: 1 };
+    pub BridgeHubLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(1002)));
+}
+
+type EthereumAssetReserveFilter = BridgedAssetReserveFilter<EthereumNetwork, BridgeHubLocation>;
+
+#[test]
+fn bridged_asset_reserve_filter_is_ok() {
+    let asset_xc_location = MultiLocation {
+        parents: 2,
+        interior: X2(
+            GlobalConsensus(EthereumNetwork::get()),
+            AccountKey20 {
+                network: None,
+                key: [1; 20],
+            },
+        ),
+    };
+    let multi_asset = MultiAsset {
+        id: xcm::latest::AssetId::Concrete(asset_xc_location),
+        fun: Fungibility::Fungible(123456),
+    };
+
+    assert!(EthereumAssetReserveFilter::contains(
+        &multi_asset,
+        &BridgeHubLocation::get()
+    ));
+    // Only the bridge is a trusted reserve
+    assert!(!EthereumAssetReserveFilter::contains(
+        &multi_asset,
+        &MultiLocation::new(1, X1(Parachain(1000)))
+    ));
+    assert!(!ReserveAssetFilter::contains(
+        &multi_asset,
+        &BridgeHubLocation::get()
+    ));
+}
+
+#[test]
+fn bridged_asset_reserve_filter_for_other_network_fails() {
+    let asset_xc_location = MultiLocation {
+        parents: 2,
+        interior: X1(GlobalConsensus(NetworkId::Ethereum { chain_id: 5 })),
+    };
+    let multi_asset = MultiAsset {
+        id: xcm::latest::AssetId::Concrete(asset_xc_location),
+        fun: Fungibility::Fungible(123456),
+    };
+
+    assert!(!EthereumAssetReserveFilter::contains(
+        &multi_asset,
+        &BridgeHubLocation::get()
+    ));
+
+    // Assets of the bridge itself aren't accepted
+    let multi_asset = MultiAsset {
+        id: xcm::latest::AssetId::Concrete(MultiLocation::new(
+            1,
+            X2(Parachain(1002), GeneralIndex(1)),
+        )),
+        fun: Fungibility::Fungible(123456),
+    };
+    assert!(!EthereumAssetReserveFilter::contains(
+        &multi_asset,
+        &BridgeHubLocation::get()
+    ));
+}
+
 #[test]
 fn reserve_asset_filter_for_unsupported_asset_multi_location() {
     // 1st case
@@ -453,3 +522,50 @@ fn hashed_description_sanity_check() {
         ])
     );
 }
+
+#[test]
+fn hashed_description_for_remote_evm_account_is_stable() {
+    let remote_evm_account = |para_id: u32, network: Option<NetworkId>| MultiLocation {
+        parents: 1,
+        interior: X2(
+            Parachain(para_id),
+            AccountKey20 {
+                network,
+                key: [7u8; 20],
+            },
+        ),
+    };
+    type Converter = HashedDescription<[u8; 32], DescribeFamily<DescribeAllTerminal>>;
+
+    // Network Id isn't part of the description, hence the derived account is the same
+    let derived_account = Converter::convert(remote_evm_account(1, None)).unwrap();
+    assert_eq!(
+        Converter::convert(remote_evm_account(1, Some(NetworkId::Polkadot))),
+        Ok(derived_account)
+    );
+
+    // Same key on a different chain is a different account
+    assert_ne!(
+        Converter::convert(remote_evm_account(2, None)),
+        Ok(derived_account)
+    );
+
+    // Same key as a native account is a different account
+    let remote_native_account = MultiLocation {
+        parents: 1,
+        interior: X2(
+            Parachain(1),
+            AccountId32 {
+                network: None,
+                id: [[7u8; 20].as_slice(), &[0u8; 12]]
+                    .concat()
+                    .try_into()
+                    .unwrap(),
+            },
+        ),
+    };
+    assert_ne!(
+        Converter::convert(remote_native_account),
+        Ok(derived_account)
+    );
+}
@@ -19,14 +19,14 @@
 use crate::Balance;
 
 use frame_support::weights::Weight;
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 use sp_std::{convert::TryFrom, prelude::*, result::Result};
 
 /// Vm Id.
 #[repr(u8)]
-#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum VmId {
     Evm = 0x0F,
     Wasm = 0x1F,
@@ -70,6 +70,12 @@ pub enum CallError {
     ReentranceDenied,
     /// The call failed on EVM or WASM execution.
     ExecutionFailed(Vec<u8>),
+    /// The asynchronous call queue is full.
+    QueueFull,
+    /// Weight limit exceeds the max weight of an asynchronous call.
+    WeightLimitTooHigh,
+    /// The source can't pay for the weight reserved for an asynchronous call.
+    InsufficientBalance,
 }
 
 /// XVM call error with used weight info.
@@ -87,7 +93,7 @@ pub type CallResult = Result<CallInfo, CallErrorWithWeight>;
 /// XVM context.
 ///
 /// Note this should be set by runtime, instead of passed by callers.
-#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct Context {
     /// The source VM of the call.
     pub source_vm_id: VmId,
@@ -114,3 +120,64 @@ pub trait XvmCall<AccountId> {
         value: Balance,
     ) -> CallResult;
 }
+
+/// Id of an asynchronous XVM call.
+pub type AsyncCallId = u64;
+
+pub trait XvmAsyncCall<AccountId> {
+    /// Enqueue a call to a contract in XVM, to be executed in a subsequent block.
+    ///
+    /// Parameters are the same as in `XvmCall::call`, plus:
+    /// - `callback`: address of the initiating contract in the source VM, which the result is
+    ///   delivered to.
+    ///
+    /// `context.weight_limit` is reserved for the call when it's executed, its price is held
+    /// from `source` until then.
+    ///
+    /// Returns the id of the queued call, the result is delivered to `OnAsyncCallResult`.
+    fn enqueue_call(
+        context: Context,
+        vm_id: VmId,
+        source: AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+        value: Balance,
+        callback: Vec<u8>,
+    ) -> Result<AsyncCallId, CallError>;
+
+    /// Weight of `enqueue_call`, to be charged by the caller.
+    fn enqueue_call_weight() -> Weight;
+}
+
+/// Handler of asynchronous XVM call results.
+pub trait OnAsyncCallResult<AccountId> {
+    /// Called after the asynchronous call `id` initiated by `source` was executed.
+    ///
+    /// `callback` is the address of the initiating contract in `context.source_vm_id`.
+    ///
+    /// Returns the consumed weight, which must not exceed `max_weight`.
+    fn on_async_call_result(
+        id: AsyncCallId,
+        context: &Context,
+        source: &AccountId,
+        callback: &[u8],
+        result: &CallResult,
+    ) -> Weight;
+
+    /// Max weight consumed by `on_async_call_result`, reserved along with the call.
+    fn max_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl<AccountId> OnAsyncCallResult<AccountId> for () {
+    fn on_async_call_result(
+        _: AsyncCallId,
+        _: &Context,
+        _: &AccountId,
+        _: &[u8],
+        _: &CallResult,
+    ) -> Weight {
+        Weight::zero()
+    }
+}
@@ -212,6 +212,13 @@ impl Contains<RuntimeCall> for BaseFilter {
 
                 _ => true,
             },
+            // Runtime upgrades must go through `UpgradeAuthorization`.
+            // Parachain system's own upgrade authorization would bypass `OnSetCode`.
+            RuntimeCall::ParachainSystem(method) => !matches!(
+                method,
+                cumulus_pallet_parachain_system::Call::authorize_upgrade { .. }
+                    | cumulus_pallet_parachain_system::Call::enact_authorized_upgrade { .. }
+            ),
             // These modules are not allowed to be called by transactions:
             // To leave collator just shutdown it, next session funds will be released
             // Other modules should works:
@@ -256,7 +263,10 @@ impl frame_system::Config for Runtime {
     type BlockWeights = RuntimeBlockWeights;
     type BlockLength = RuntimeBlockLength;
     type SS58Prefix = SS58Prefix;
-    type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
+    type OnSetCode = pallet_upgrade_authorization::AuthorizedSetCode<
+        Self,
+        cumulus_pallet_parachain_system::ParachainSetCode<Self>,
+    >;
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
@@ -340,6 +350,9 @@ parameter_types! {
     pub const MaxEraStakeValues: u32 = 5;
     pub const MaxUnlockingChunks: u32 = 4;
     pub const UnbondingPeriod: u32 = 10;
+    pub const FeelessClaimThreshold: Balance = 10 * ASTR;
+    pub const FeelessClaimDelay: u32 = 7;
+    pub const DappsStakingClaimForTip: Perbill = Perbill::from_percent(0);
 }
 
 impl pallet_dapps_staking::Config for Runtime {
@@ -358,6 +371,13 @@ impl pallet_dapps_staking::Config for Runtime {
     type MaxEraStakeValues = MaxEraStakeValues;
     // Not allowed on Astar yet
     type UnregisteredDappRewardRetention = ConstU32<{ u32::MAX }>;
+    type EraHistoryRetention = ConstU32<{ u32::MAX }>;
+    type FeelessClaimThreshold = FeelessClaimThreshold;
+    type FeelessClaimDelay = FeelessClaimDelay;
+    type ClaimForTip = DappsStakingClaimForTip;
+    type InfractionReportOrigin = EnsureRoot<AccountId>;
+    type InfractionDelay = ConstU32<7>;
+    type MaxInfractionEras = ConstU32<30>;
 }
 
 /// Multi-VM pointer to smart contract instance.
@@ -460,6 +480,7 @@ impl pallet_collator_selection::Config for Runtime {
     type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
     type ValidatorRegistration = Session;
     type SlashRatio = SlashRatio;
+    type SessionKeys = Session;
     type WeightInfo = pallet_collator_selection::weights::SubstrateWeight<Runtime>;
 }
 
@@ -837,10 +858,26 @@ impl pallet_sudo::Config for Runtime {
     type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub NativeUnitsPerSecond: u128 = <WeightToFee as frame_support::weights::WeightToFee>::weight_to_fee(
+        &Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, 0),
+    );
+    pub const MaxUnitsPerSecondChange: Perbill = Perbill::from_percent(20);
+}
+
 impl pallet_xc_asset_config::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type ManagerOrigin = EnsureRoot<AccountId>;
+    // No price oracle is available yet, so automatic `units per second` derivation is a no-op
+    type PriceProvider = ();
+    type NativeUnitsPerSecond = NativeUnitsPerSecond;
+    type RecalculationPeriod = BlockPerEra;
+    type MaxAutoUnitsPerSecondAssets = ConstU32<16>;
+    // There's no technical committee in this runtime yet, so fee changes remain root-only
+    type FeeManagerOrigin = EnsureRoot<AccountId>;
+    type FeeChangePeriod = BlockPerEra;
+    type MaxUnitsPerSecondChange = MaxUnitsPerSecondChange;
     type WeightInfo = pallet_xc_asset_config::weights::SubstrateWeight<Self>;
 }
 
@@ -876,6 +913,8 @@ pub enum ProxyType {
     DappsStaking,
     /// Only claim_staker call from pallet DappStaking allowed for proxy account
     StakerRewardClaim,
+    /// Only set_session_keys call from pallet CollatorSelection allowed for proxy account
+    CollatorSelection,
 }
 
 impl Default for ProxyType {
@@ -947,6 +986,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     RuntimeCall::DappsStaking(pallet_dapps_staking::Call::claim_staker { .. })
                 )
             }
+            // Only set_session_keys call from pallet CollatorSelection allowed for proxy account
+            ProxyType::CollatorSelection => {
+                matches!(
+                    c,
+                    RuntimeCall::CollatorSelection(
+                        pallet_collator_selection::Call::set_session_keys { .. }
+                    )
+                )
+            }
         }
     }
 
@@ -987,6 +1035,17 @@ impl pallet_proxy::Config for Runtime {
     type AnnouncementDepositFactor = AnnouncementDepositFactor;
 }
 
+parameter_types! {
+    pub const UpgradeEnactmentPeriod: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_upgrade_authorization::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type EnactmentPeriod = UpgradeEnactmentPeriod;
+    type WeightInfo = pallet_upgrade_authorization::weights::SubstrateWeight<Self>;
+}
+
 construct_runtime!(
     pub struct Runtime where
         Block = Block,
@@ -999,6 +1058,7 @@ construct_runtime!(
         Timestamp: pallet_timestamp = 13,
         Multisig: pallet_multisig = 14,
         Proxy: pallet_proxy = 15,
+        UpgradeAuthorization: pallet_upgrade_authorization = 16,
 
         ParachainSystem: cumulus_pallet_parachain_system = 20,
         ParachainInfo: parachain_info = 21,
@@ -1160,6 +1220,7 @@ mod benches {
         [pallet_xc_asset_config, XcAssetConfig]
         [pallet_collator_selection, CollatorSelection]
         [pallet_xcm, PolkadotXcm]
+        [pallet_upgrade_authorization, UpgradeAuthorization]
     );
 }
 
@@ -1242,6 +1303,54 @@ impl_runtime_apis! {
         }
     }
 
+    impl astar_primitives::xcm::LocationToAccountApi<Block, AccountId> for Runtime {
+        fn convert_location(location: xcm::VersionedMultiLocation) -> Option<AccountId> {
+            use xcm_executor::traits::Convert;
+
+            let location = xcm::latest::MultiLocation::try_from(location).ok()?;
+            xcm_config::LocationToAccountId::convert(location).ok()
+        }
+    }
+
+    impl pallet_chain_metadata_runtime_api::ChainMetadataApi<Block> for Runtime {
+        fn chain_metadata() -> Option<pallet_chain_metadata_runtime_api::EvmChainMetadata> {
+            // Chain metadata registry isn't deployed on this network yet
+            None
+        }
+    }
+
+    impl pallet_dapps_staking_runtime_api::DappsStakingApi<Block, BlockNumber> for Runtime {
+        fn era_status(
+            era: Option<pallet_dapps_staking_runtime_api::EraIndex>,
+        ) -> Option<pallet_dapps_staking_runtime_api::EraStatus<BlockNumber>> {
+            DappsStaking::era_status(era)
+        }
+    }
+
+    impl pallet_xc_asset_config_runtime_api::XcAssetsApi<Block, AssetId> for Runtime {
+        fn assets(
+            start_after: Option<AssetId>,
+            limit: u32,
+        ) -> Vec<pallet_xc_asset_config_runtime_api::XcAssetInfo<AssetId>> {
+            astar_primitives::xcm::XcAssetsRegistry::<Runtime>::assets(start_after, limit)
+        }
+    }
+
+    impl pallet_xvm_runtime_api::XvmApi<Block, AccountId> for Runtime {
+        fn dry_run_call(
+            _source: AccountId,
+            _source_vm_id: pallet_xvm_runtime_api::VmId,
+            _vm_id: pallet_xvm_runtime_api::VmId,
+            _target: Vec<u8>,
+            _input: Vec<u8>,
+            _value: Balance,
+            _weight_limit: Option<Weight>,
+        ) -> Option<pallet_xvm_runtime_api::DryRunInfo> {
+            // XVM isn't supported on this network
+            None
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
         Block,
         Balance,
@@ -28,16 +28,18 @@ use frame_support::{
     weights::Weight,
 };
 use frame_system::EnsureRoot;
+use sp_std::{vec, vec::Vec};
 
 // Polkadot imports
 use xcm::latest::prelude::*;
 use xcm_builder::{
-    Account32Hash, AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
+    AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
     AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, ConvertedConcreteId, CurrencyAdapter,
-    EnsureXcmOrigin, FixedWeightBounds, FungiblesAdapter, IsConcrete, NoChecking,
-    ParentAsSuperuser, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative,
+    EnsureXcmOrigin, FixedWeightBounds, FungiblesAdapter, IsConcrete, NetworkExportTable,
+    NoChecking, ParentAsSuperuser, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative,
     SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
-    SovereignSignedViaLocation, TakeWeightCredit, UsingComponents,
+    SovereignPaidRemoteExporter, SovereignSignedViaLocation, TakeWeightCredit, UsingComponents,
+    WithComputedOrigin,
 };
 use xcm_executor::{
     traits::{JustTry, WithOriginFilter},
@@ -45,7 +47,10 @@ use xcm_executor::{
 };
 
 // Astar imports
-use astar_primitives::xcm::{FixedRateOfForeignAsset, ReserveAssetFilter, XcmFungibleFeeHandler};
+use astar_primitives::xcm::{
+    BridgedAssetReserveFilter, DescribeAllTerminal, DescribeFamily, FixedRateOfForeignAsset,
+    HashedDescription, ReserveAssetFilter, XcmFungibleFeeHandler,
+};
 
 parameter_types! {
     pub RelayNetwork: Option<NetworkId> = Some(NetworkId::Polkadot);
@@ -56,6 +61,16 @@ parameter_types! {
     pub DummyCheckingAccount: AccountId = PolkadotXcm::check_account();
 }
 
+parameter_types! {
+    /// Ethereum mainnet, bridged through Snowbridge.
+    pub const EthereumNetwork: NetworkId = NetworkId::Ethereum { chain_id: 1 };
+    /// Polkadot Bridge Hub, which is the reserve of Snowbridge assets from our point of view.
+    pub BridgeHubLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(1002)));
+    /// Messages for bridged networks are exported through the listed bridges.
+    pub BridgeTable: Vec<(NetworkId, MultiLocation, Option<MultiAsset>)> =
+        vec![(EthereumNetwork::get(), BridgeHubLocation::get(), None)];
+}
+
 /// Type for specifying how a `MultiLocation` can be converted into an `AccountId`. This is used
 /// when determining ownership of accounts for asset transacting and when attempting to use XCM
 /// `Transact` in order to determine the dispatch Origin.
@@ -66,8 +81,15 @@ pub type LocationToAccountId = (
     SiblingParachainConvertsVia<polkadot_parachain::primitives::Sibling, AccountId>,
     // Straight up local `AccountId32` origins just alias directly to `AccountId`.
     AccountId32Aliases<RelayNetwork, AccountId>,
-    // Derives a private `Account32` by hashing `("multiloc", received multilocation)`
-    Account32Hash<RelayNetwork, AccountId>,
+    // Generates private `AccountId`s from `MultiLocation`s, in a stable & safe way.
+    // Replaces the old `Account32Hash` approach.
+    //
+    // Accounts derived by `Account32Hash` are not migrated. They could never be used as an origin,
+    // since the barrier rejected descended origins and `XcmExecuteFilter` is `Nothing`, so only the
+    // relay chain itself could have acted on their behalf. Any balance they hold was already out of
+    // reach of its owner and stays where it is. Deposits to locations not described by
+    // `DescribeFamily` now fail and the assets are trapped, so they can be claimed instead.
+    HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
 );
 
 /// Means for transacting the native currency on this chain.
@@ -216,6 +238,8 @@ impl Contains<RuntimeCall> for SafeCallFilter {
 pub type XcmBarrier = (
     TakeWeightCredit,
     AllowTopLevelPaidExecutionFrom<Everything>,
+    // This will first calculate the derived origin, before checking it against the barrier implementation
+    WithComputedOrigin<AllowTopLevelPaidExecutionFrom<Everything>, UniversalLocation, ConstU32<8>>,
     // Parent and its plurality get free execution
     AllowUnpaidExecutionFrom<ParentOrParentsPlurality>,
     // Expected responses are OK.
@@ -238,7 +262,10 @@ impl xcm_executor::Config for XcmConfig {
     type XcmSender = XcmRouter;
     type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
-    type IsReserve = ReserveAssetFilter;
+    type IsReserve = (
+        ReserveAssetFilter,
+        BridgedAssetReserveFilter<EthereumNetwork, BridgeHubLocation>,
+    );
     type IsTeleporter = ();
     type UniversalLocation = UniversalLocation;
     type Barrier = XcmBarrier;
@@ -277,6 +304,8 @@ pub type XcmRouter = (
     cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, ()>,
     // ..and XCMP to communicate with the sibling chains.
     XcmpQueue,
+    // ..and export messages for bridged networks, e.g. Ethereum, through their bridge.
+    SovereignPaidRemoteExporter<NetworkExportTable<BridgeTable>, XcmpQueue, UniversalLocation>,
 );
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -16,13 +16,15 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use super::{Runtime, Xvm};
+use super::{Runtime, RuntimeAddressMapper, Xvm};
 
 /// Registered WASM contracts chain extensions.
 pub use pallet_chain_extension_assets::AssetsExtension;
 use pallet_contracts::chain_extension::RegisteredChainExtension;
 
+pub use pallet_chain_extension_address_book::AddressBookExtension;
 pub use pallet_chain_extension_dapps_staking::DappsStakingExtension;
+pub use pallet_chain_extension_native_transfer::NativeTransferExtension;
 pub use pallet_chain_extension_xvm::XvmExtension;
 
 // Following impls defines chain extension IDs.
@@ -40,3 +42,11 @@ impl<W: pallet_chain_extension_assets::weights::WeightInfo> RegisteredChainExten
 {
     const ID: u16 = 02;
 }
+
+impl RegisteredChainExtension<Runtime> for AddressBookExtension<Runtime> {
+    const ID: u16 = 03;
+}
+
+impl RegisteredChainExtension<Runtime> for NativeTransferExtension<Runtime, RuntimeAddressMapper> {
+    const ID: u16 = 04;
+}
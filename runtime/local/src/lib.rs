@@ -27,7 +27,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 use frame_support::{
     construct_runtime, parameter_types,
     traits::{
-        AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, Currency, EitherOfDiverse,
+        AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, Contains, Currency, EitherOfDiverse,
         EqualPrivilegeOnly, FindAuthor, Get, InstanceFilter, Nothing, OnFinalize, WithdrawReasons,
     },
     weights::{
@@ -56,13 +56,13 @@ use sp_runtime::{
     transaction_validity::{
         TransactionPriority, TransactionSource, TransactionValidity, TransactionValidityError,
     },
-    ApplyExtrinsicResult, RuntimeDebug,
+    ApplyExtrinsicResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
 pub use astar_primitives::{
-    evm::EvmRevertCodeHandler, AccountId, Address, AssetId, Balance, BlockNumber, Hash, Header,
-    Index, Signature,
+    evm::{BaseFeeCallAdapter, BaseFeeWeightPrice, EvmRevertCodeHandler},
+    AccountId, Address, AssetId, Balance, BlockNumber, Hash, Header, Index, Signature,
 };
 
 #[cfg(feature = "std")]
@@ -73,7 +73,7 @@ pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_grandpa::AuthorityId as GrandpaId;
 pub use pallet_timestamp::Call as TimestampCall;
-use pallet_transaction_payment::CurrencyAdapter;
+use pallet_transaction_payment::{CurrencyAdapter, Multiplier};
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
@@ -109,7 +109,9 @@ impl_opaque_keys! {
 }
 
 mod precompiles;
-pub use precompiles::{LocalNetworkPrecompiles, ASSET_PRECOMPILE_ADDRESS_PREFIX};
+pub use precompiles::{
+    LocalNetworkPrecompiles, WrappedNativeAddress, ASSET_PRECOMPILE_ADDRESS_PREFIX,
+};
 pub type Precompiles = LocalNetworkPrecompiles<Runtime>;
 
 mod chain_extensions;
@@ -335,11 +337,27 @@ impl pallet_assets::Config for Runtime {
 parameter_types! {
     pub const TransactionByteFee: Balance = 1;
     pub const OperationalFeeMultiplier: u8 = 5;
+    /// Lower bound of the ratio between the EVM base fee and its default, used to price contracts weight.
+    pub MinimumBaseFeeRatio: Multiplier = Multiplier::saturating_from_rational(1, 10);
+}
+
+/// Calls whose weight is priced by the EVM base fee.
+pub struct ContractsCalls;
+impl Contains<RuntimeCall> for ContractsCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::Contracts(..))
+    }
 }
 
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+    type OnChargeTransaction = BaseFeeCallAdapter<
+        Self,
+        ContractsCalls,
+        DefaultBaseFeePerGas,
+        MinimumBaseFeeRatio,
+        pallet_call_fee_multiplier::CallFeeMultiplierAdapter<Self, CurrencyAdapter<Balances, ()>>,
+    >;
     type WeightToFee = IdentityFee<Balance>;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type FeeMultiplierUpdate = ();
@@ -397,6 +415,9 @@ parameter_types! {
     pub const MaxUnlockingChunks: u32 = 2;
     pub const UnbondingPeriod: u32 = 2;
     pub const MaxEraStakeValues: u32 = 5;
+    pub const FeelessClaimThreshold: Balance = 10 * AST;
+    pub const FeelessClaimDelay: u32 = 2;
+    pub const DappsStakingClaimForTip: Perbill = Perbill::from_percent(1);
 }
 
 impl pallet_dapps_staking::Config for Runtime {
@@ -414,6 +435,13 @@ impl pallet_dapps_staking::Config for Runtime {
     type MinimumRemainingAmount = MinimumRemainingAmount;
     type MaxEraStakeValues = MaxEraStakeValues;
     type UnregisteredDappRewardRetention = ConstU32<3>;
+    type EraHistoryRetention = ConstU32<30>;
+    type FeelessClaimThreshold = FeelessClaimThreshold;
+    type FeelessClaimDelay = FeelessClaimDelay;
+    type ClaimForTip = DappsStakingClaimForTip;
+    type InfractionReportOrigin = EnsureRoot<AccountId>;
+    type InfractionDelay = ConstU32<2>;
+    type MaxInfractionEras = ConstU32<30>;
 }
 
 /// Multi-VM pointer to smart contract instance.
@@ -446,12 +474,18 @@ impl pallet_utility::Config for Runtime {
     type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
 }
 
+/// Conversion between EVM addresses and native accounts, shared by the EVM, XVM, precompiles
+/// and chain extensions so an account is resolved the same way everywhere.
+///
 ///TODO: Placeholder account mapping. This would be replaced once account abstraction is finished.
-pub struct HashedAccountMapping;
-impl astar_primitives::ethereum_checked::AccountMapping<AccountId> for HashedAccountMapping {
+pub type RuntimeAddressMapper = precompile_utils::HashedAddressMapper<BlakeTwo256>;
+
+/// EVM address of native accounts for XVM & checked Ethereum transactions, following
+/// `RuntimeAddressMapper`.
+pub struct RuntimeAccountMapping;
+impl astar_primitives::ethereum_checked::AccountMapping<AccountId> for RuntimeAccountMapping {
     fn into_h160(account_id: AccountId) -> H160 {
-        let data = (b"evm:", account_id);
-        return H160::from_slice(&data.using_encoded(sp_io::hashing::blake2_256)[0..20]);
+        <RuntimeAddressMapper as precompile_utils::AddressMapper<AccountId>>::into_h160(account_id)
     }
 }
 
@@ -466,18 +500,73 @@ impl pallet_ethereum_checked::Config for Runtime {
     type XvmTxWeightLimit = XvmTxWeightLimit;
     type InvalidEvmTransactionError = pallet_ethereum::InvalidTransactionWrapper;
     type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
-    type AccountMapping = HashedAccountMapping;
+    type AccountMapping = RuntimeAccountMapping;
     type XcmTransactOrigin = pallet_ethereum_checked::EnsureXcmEthereumTx<AccountId>;
+    type MultisigTransactOrigin = EnsureSigned<AccountId>;
+    type CreateFilter = frame_support::traits::Everything;
     type WeightInfo = pallet_ethereum_checked::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    /// Max weight limit of a queued cross-VM call, executed with the weight left for `on_idle`.
+    pub MaxAsyncXvmCallWeight: Weight = Perbill::from_percent(10) * Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, u64::MAX);
+    /// Max weight of delivering the result of a queued cross-VM call back to its initiator.
+    pub AsyncXvmCallbackWeight: Weight = Perbill::from_percent(1) * Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, u64::MAX);
+    /// Results of synchronous cross-VM calls are mirrored as EVM logs of the XVM precompile.
+    pub XvmCallResultsLogAddress: Option<H160> = Some(H160::from_low_u64_be(0x5005));
+}
+
 impl pallet_xvm::Config for Runtime {
     type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
-    type AccountMapping = HashedAccountMapping;
+    type AccountMapping = RuntimeAccountMapping;
     type EthereumTransact = EthereumChecked;
+    type RuntimeEvent = RuntimeEvent;
+    type OnAsyncCallResult = pallet_xvm::ContractCallback<Self, AsyncXvmCallbackWeight>;
+    type OnChargeAsyncCall = ();
+    type MaxQueuedCalls = ConstU32<128>;
+    type MaxAsyncCallInputLen = ConstU32<{ 16 * 1024 }>;
+    type MaxAsyncCallWeight = MaxAsyncXvmCallWeight;
+    type MirrorCallResults = XvmCallResultsLogAddress;
     type WeightInfo = pallet_xvm::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_address_book::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxNameLength = ConstU32<32>;
+    type WeightInfo = pallet_address_book::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const MerkleDistributorPalletId: PalletId = PalletId(*b"py/mrkld");
+}
+
+impl pallet_merkle_distributor::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type PalletId = MerkleDistributorPalletId;
+    type EvmAddressMapping = RuntimeAddressMapper;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxProofLength = ConstU32<32>;
+    type WeightInfo = pallet_merkle_distributor::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_call_fee_multiplier::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxNameLength = ConstU32<64>;
+    type WeightInfo = pallet_call_fee_multiplier::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_chain_metadata::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ChainId = ChainId;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxTextLength = ConstU32<128>;
+    type MaxUrls = ConstU32<4>;
+    type WeightInfo = pallet_chain_metadata::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     // Tells `pallet_base_fee` whether to calculate a new BaseFee `on_finalize` or not.
     pub DefaultBaseFeePerGas: U256 = (MILLIAST / 1_000_000).into();
@@ -537,10 +626,8 @@ parameter_types! {
     /// * Shiden: 336
     /// * Local: 4369
     pub ChainId: u64 = 0x1111;
-    /// EVM gas limit
-    pub BlockGasLimit: U256 = U256::from(
-        NORMAL_DISPATCH_RATIO * WEIGHT_REF_TIME_PER_SECOND / WEIGHT_PER_GAS
-    );
+    /// Default EVM gas limit, can be changed by governance through `EvmGasLimit`
+    pub DefaultBlockGasLimit: u64 = NORMAL_DISPATCH_RATIO * WEIGHT_REF_TIME_PER_SECOND / WEIGHT_PER_GAS;
     pub PrecompilesValue: Precompiles = LocalNetworkPrecompiles::<_>::new();
     pub WeightPerGas: Weight = Weight::from_parts(WEIGHT_PER_GAS, 0);
     /// The amount of gas per PoV size. Value is calculated as:
@@ -559,7 +646,7 @@ impl pallet_evm::Config for Runtime {
     type BlockHashMapping = pallet_ethereum::EthereumBlockHashMapping<Runtime>;
     type CallOrigin = pallet_evm::EnsureAddressRoot<AccountId>;
     type WithdrawOrigin = pallet_evm::EnsureAddressTruncated;
-    type AddressMapping = pallet_evm::HashedAddressMapping<BlakeTwo256>;
+    type AddressMapping = RuntimeAddressMapper;
     type Currency = Balances;
     type RuntimeEvent = RuntimeEvent;
     type Runner = pallet_evm::runner::stack::Runner<Self>;
@@ -567,7 +654,7 @@ impl pallet_evm::Config for Runtime {
     type PrecompilesValue = PrecompilesValue;
     type ChainId = ChainId;
     type OnChargeTransaction = pallet_evm::EVMCurrencyAdapter<Balances, ()>;
-    type BlockGasLimit = BlockGasLimit;
+    type BlockGasLimit = EvmGasLimit;
     type Timestamp = Timestamp;
     type OnCreate = ();
     type FindAuthor = FindAuthorTruncated<Aura>;
@@ -575,6 +662,14 @@ impl pallet_evm::Config for Runtime {
     type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_evm_gas_limit::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type WeightPerGas = WeightPerGas;
+    type DefaultBlockGasLimit = DefaultBlockGasLimit;
+    type WeightInfo = pallet_evm_gas_limit::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub const PostBlockAndTxnHashes: PostLogContent = PostLogContent::BlockAndTxnHashes;
 }
@@ -824,12 +919,15 @@ impl pallet_contracts::Config for Runtime {
     type DepositPerByte = DepositPerByte;
     type DefaultDepositLimit = DefaultDepositLimit;
     type CallStack = [pallet_contracts::Frame<Self>; 5];
-    type WeightPrice = pallet_transaction_payment::Pallet<Self>;
+    /// Contracts weight is priced by the same signal as the EVM base fee.
+    type WeightPrice = BaseFeeWeightPrice<Self, DefaultBaseFeePerGas, MinimumBaseFeeRatio>;
     type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
     type ChainExtension = (
         DappsStakingExtension<Self>,
         XvmExtension<Self, Xvm>,
         AssetsExtension<Self, pallet_chain_extension_assets::weights::SubstrateWeight<Self>>,
+        AddressBookExtension<Self>,
+        NativeTransferExtension<Self, RuntimeAddressMapper>,
     );
     type Schedule = Schedule;
     type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
@@ -1023,6 +1121,11 @@ construct_runtime!(
         Proxy: pallet_proxy,
         Preimage: pallet_preimage,
         EthereumChecked: pallet_ethereum_checked,
+        AddressBook: pallet_address_book,
+        MerkleDistributor: pallet_merkle_distributor,
+        CallFeeMultiplier: pallet_call_fee_multiplier,
+        ChainMetadata: pallet_chain_metadata,
+        EvmGasLimit: pallet_evm_gas_limit,
     }
 );
 
@@ -1137,6 +1240,11 @@ mod benches {
         [pallet_dapps_staking, DappsStaking]
         [pallet_block_reward, BlockReward]
         [pallet_ethereum_checked, EthereumChecked]
+        [pallet_address_book, AddressBook]
+        [pallet_merkle_distributor, MerkleDistributor]
+        [pallet_call_fee_multiplier, CallFeeMultiplier]
+        [pallet_chain_metadata, ChainMetadata]
+        [pallet_evm_gas_limit, EvmGasLimit]
     );
 }
 
@@ -1264,6 +1372,53 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_chain_metadata_runtime_api::ChainMetadataApi<Block> for Runtime {
+        fn chain_metadata() -> Option<pallet_chain_metadata_runtime_api::EvmChainMetadata> {
+            ChainMetadata::evm_chain_metadata()
+        }
+    }
+
+    impl pallet_dapps_staking_runtime_api::DappsStakingApi<Block, BlockNumber> for Runtime {
+        fn era_status(
+            era: Option<pallet_dapps_staking_runtime_api::EraIndex>,
+        ) -> Option<pallet_dapps_staking_runtime_api::EraStatus<BlockNumber>> {
+            DappsStaking::era_status(era)
+        }
+    }
+
+    impl pallet_xc_asset_config_runtime_api::XcAssetsApi<Block, AssetId> for Runtime {
+        fn assets(
+            _start_after: Option<AssetId>,
+            _limit: u32,
+        ) -> Vec<pallet_xc_asset_config_runtime_api::XcAssetInfo<AssetId>> {
+            // Cross-chain assets aren't supported on this network
+            Vec::new()
+        }
+    }
+
+    impl pallet_xvm_runtime_api::XvmApi<Block, AccountId> for Runtime {
+        fn dry_run_call(
+            source: AccountId,
+            source_vm_id: pallet_xvm_runtime_api::VmId,
+            vm_id: pallet_xvm_runtime_api::VmId,
+            target: Vec<u8>,
+            input: Vec<u8>,
+            value: Balance,
+            weight_limit: Option<Weight>,
+        ) -> Option<pallet_xvm_runtime_api::DryRunInfo> {
+            let context = astar_primitives::xvm::Context {
+                source_vm_id,
+                weight_limit: weight_limit.unwrap_or(RuntimeBlockWeights::get().max_block),
+            };
+            let (result, events) = Xvm::dry_run_call(context, vm_id, source, target, input, value);
+
+            Some(pallet_xvm_runtime_api::DryRunInfo {
+                result,
+                events: events.iter().map(Encode::encode).collect(),
+            })
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
         fn query_info(
             uxt: <Block as BlockT>::Extrinsic,
@@ -18,27 +18,39 @@
 
 //! The Local EVM precompiles. This can be compiled with ``#[no_std]`, ready for Wasm.
 
+use frame_support::parameter_types;
 use pallet_evm::{
     ExitRevert, IsPrecompileResult, Precompile, PrecompileFailure, PrecompileHandle,
     PrecompileResult, PrecompileSet,
 };
-use pallet_evm_precompile_assets_erc20::{AddressToAssetId, Erc20AssetsPrecompileSet};
+use pallet_evm_precompile_address_book::AddressBookPrecompile;
+use pallet_evm_precompile_assets_erc20::{
+    AddressToAssetId, AssetsUtilityPrecompile, Erc20AssetsPrecompileSet,
+};
 use pallet_evm_precompile_batch::BatchPrecompile;
 use pallet_evm_precompile_blake2::Blake2F;
 use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
-use pallet_evm_precompile_dapps_staking::DappsStakingWrapper;
+use pallet_evm_precompile_dapps_staking::{
+    DappsStakingReceipt, DappsStakingWrapper, ReceiptMetadata,
+};
 use pallet_evm_precompile_dispatch::Dispatch;
+use pallet_evm_precompile_dispatch_logs::DispatchWithLogs;
 use pallet_evm_precompile_ed25519::Ed25519Verify;
+use pallet_evm_precompile_merkle_distributor::MerkleDistributorPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_native_transfer::NativeTransferPrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
 use pallet_evm_precompile_sr25519::Sr25519Precompile;
 use pallet_evm_precompile_substrate_ecdsa::SubstrateEcdsaPrecompile;
+use pallet_evm_precompile_wrapped_native::{WrappedNativeMetadata, WrappedNativePrecompile};
 use pallet_evm_precompile_xvm::XvmPrecompile;
 use sp_core::H160;
 use sp_std::fmt::Debug;
 use sp_std::marker::PhantomData;
 
+use crate::RuntimeAddressMapper;
+
 /// The asset precompile address prefix. Addresses that match against this prefix will be routed
 /// to Erc20AssetsPrecompileSet
 pub const ASSET_PRECOMPILE_ADDRESS_PREFIX: &[u8] = &[255u8; 4];
@@ -56,13 +68,54 @@ impl<R> LocalNetworkPrecompiles<R> {
     /// under the precompile.
     pub fn used_addresses() -> impl Iterator<Item = H160> {
         sp_std::vec![
-            1, 2, 3, 4, 5, 6, 7, 8, 9, 1024, 1025, 1026, 1027, 20481, 20482, 20483, 20485, 20846
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 1024, 1025, 1026, 1027, 20481, 20482, 20483, 20485, 20846,
+            20487, 20488, 20489, 20490, 20491, 20492
         ]
         .into_iter()
         .map(hash)
     }
 }
 
+parameter_types! {
+    /// Address of the canonical wrapped native currency, whose vault holds the existential
+    /// deposit.
+    pub WrappedNativeAddress: H160 = hash(20489);
+}
+
+/// Metadata of the canonical wrapped native currency.
+pub struct NativeMetadata;
+
+impl WrappedNativeMetadata for NativeMetadata {
+    fn name() -> &'static str {
+        "Wrapped Local"
+    }
+
+    fn symbol() -> &'static str {
+        "WLOC"
+    }
+
+    fn decimals() -> u8 {
+        18
+    }
+}
+
+/// Metadata of the dApps staking receipt token.
+pub struct StakingReceiptMetadata;
+
+impl ReceiptMetadata for StakingReceiptMetadata {
+    fn name() -> &'static str {
+        "Staked LOC"
+    }
+
+    fn symbol() -> &'static str {
+        "stLOC"
+    }
+
+    fn decimals() -> u8 {
+        18
+    }
+}
+
 /// The following distribution has been decided for the precompiles
 /// 0-1023: Ethereum Mainnet Precompiles
 /// 1024-2047 Precompiles that are not in Ethereum Mainnet
@@ -73,8 +126,16 @@ where
     BatchPrecompile<R>: Precompile,
     XvmPrecompile<R, pallet_xvm::Pallet<R>>: Precompile,
     Dispatch<R>: Precompile,
+    AddressBookPrecompile<R, RuntimeAddressMapper>: Precompile,
+    MerkleDistributorPrecompile<R>: Precompile,
+    WrappedNativePrecompile<R, NativeMetadata>: Precompile,
+    NativeTransferPrecompile<R>: Precompile,
+    DappsStakingReceipt<R, StakingReceiptMetadata>: Precompile,
+    AssetsUtilityPrecompile<R>: Precompile,
     R: pallet_evm::Config
         + pallet_xvm::Config
+        + pallet_address_book::Config
+        + pallet_merkle_distributor::Config
         + pallet_assets::Config
         + AddressToAssetId<<R as pallet_assets::Config>::AssetId>,
 {
@@ -103,7 +164,7 @@ where
             a if a == hash(9) => Some(Blake2F::execute(handle)),
             // nor Ethereum precompiles :
             a if a == hash(1024) => Some(Sha3FIPS256::execute(handle)),
-            a if a == hash(1025) => Some(Dispatch::<R>::execute(handle)),
+            a if a == hash(1025) => Some(DispatchWithLogs::<Dispatch<R>>::execute(handle)),
             a if a == hash(1026) => Some(ECRecoverPublicKey::execute(handle)),
             a if a == hash(1027) => Some(Ed25519Verify::execute(handle)),
             // Astar precompiles (starts from 0x5000):
@@ -119,6 +180,24 @@ where
             }
             // Batch 0x5006
             a if a == hash(20486) => Some(BatchPrecompile::<R>::execute(handle)),
+            // AddressBook 0x5007
+            a if a == hash(20487) => Some(
+                AddressBookPrecompile::<R, RuntimeAddressMapper>::execute(handle),
+            ),
+            // MerkleDistributor 0x5008
+            a if a == hash(20488) => Some(MerkleDistributorPrecompile::<R>::execute(handle)),
+            // WrappedNative 0x5009
+            a if a == hash(20489) => Some(WrappedNativePrecompile::<R, NativeMetadata>::execute(
+                handle,
+            )),
+            // NativeTransfer 0x500A
+            a if a == hash(20490) => Some(NativeTransferPrecompile::<R>::execute(handle)),
+            // DappsStakingReceipt 0x500B
+            a if a == hash(20491) => Some(
+                DappsStakingReceipt::<R, StakingReceiptMetadata>::execute(handle),
+            ),
+            // AssetsUtility 0x500C
+            a if a == hash(20492) => Some(AssetsUtilityPrecompile::<R>::execute(handle)),
 
             // If the address matches asset prefix, the we route through the asset precompile set
             a if &a.to_fixed_bytes()[0..4] == ASSET_PRECOMPILE_ADDRESS_PREFIX => {
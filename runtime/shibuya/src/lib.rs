@@ -69,12 +69,13 @@ use sp_runtime::{
 use sp_std::prelude::*;
 
 pub use astar_primitives::{
-    ethereum_checked::CheckedEthereumTransact, evm::EvmRevertCodeHandler,
-    xcm::AssetLocationIdConverter, AccountId, Address, AssetId, Balance, BlockNumber, Hash, Header,
-    Index, Signature,
+    ethereum_checked::CheckedEthereumTransact,
+    evm::{BaseFeeCallAdapter, BaseFeeWeightPrice, EvmRevertCodeHandler},
+    xcm::AssetLocationIdConverter,
+    AccountId, Address, AssetId, Balance, BlockNumber, Hash, Header, Index, Signature,
 };
 
-use pallet_evm_precompile_assets_erc20::AddressToAssetId;
+use pallet_evm_precompile_assets_erc20::{AddressToAssetId, TotalSupplyHistory};
 
 #[cfg(any(feature = "std", test))]
 use sp_version::NativeVersion;
@@ -93,7 +94,9 @@ mod xcm_config;
 
 pub type ShibuyaAssetLocationIdConverter = AssetLocationIdConverter<AssetId, XcAssetConfig>;
 
-pub use precompiles::{ShibuyaNetworkPrecompiles, ASSET_PRECOMPILE_ADDRESS_PREFIX};
+pub use precompiles::{
+    ShibuyaNetworkPrecompiles, WrappedNativeAddress, ASSET_PRECOMPILE_ADDRESS_PREFIX,
+};
 pub type Precompiles = ShibuyaNetworkPrecompiles<Runtime, ShibuyaAssetLocationIdConverter>;
 
 use chain_extensions::*;
@@ -147,6 +150,18 @@ impl AddressToAssetId<AssetId> for Runtime {
     }
 }
 
+/// Exposes the supply history recorded by `AssetsSupplyHistory` to the assets precompile.
+pub struct AssetsSupplyHistoryProvider;
+impl TotalSupplyHistory<AssetId, Balance> for AssetsSupplyHistoryProvider {
+    fn total_supply_at(asset_id: AssetId, block_number: u32) -> Option<Balance> {
+        AssetsSupplyHistory::total_supply_at(asset_id, block_number)
+    }
+
+    fn max_history_len() -> usize {
+        pallet_assets_supply_history::HistoryOf::<Runtime>::max_encoded_len()
+    }
+}
+
 // Make the WASM binary available.
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
@@ -243,6 +258,13 @@ impl Contains<RuntimeCall> for BaseFilter {
                 // registering the asset location should be good enough for users, any change can be handled via issue ticket or help request
                 _ => false,
             },
+            // Runtime upgrades must go through `UpgradeAuthorization`.
+            // Parachain system's own upgrade authorization would bypass `OnSetCode`.
+            RuntimeCall::ParachainSystem(method) => !matches!(
+                method,
+                cumulus_pallet_parachain_system::Call::authorize_upgrade { .. }
+                    | cumulus_pallet_parachain_system::Call::enact_authorized_upgrade { .. }
+            ),
             // These modules are not allowed to be called by transactions:
             // Other modules should works:
             _ => true,
@@ -286,7 +308,10 @@ impl frame_system::Config for Runtime {
     type BlockWeights = RuntimeBlockWeights;
     type BlockLength = RuntimeBlockLength;
     type SS58Prefix = SS58Prefix;
-    type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
+    type OnSetCode = pallet_upgrade_authorization::AuthorizedSetCode<
+        Self,
+        cumulus_pallet_parachain_system::ParachainSetCode<Self>,
+    >;
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
@@ -403,6 +428,9 @@ parameter_types! {
     pub const MaxEraStakeValues: u32 = 5;
     pub const MaxUnlockingChunks: u32 = 32;
     pub const UnbondingPeriod: u32 = 2;
+    pub const FeelessClaimThreshold: Balance = 10 * SBY;
+    pub const FeelessClaimDelay: u32 = 3;
+    pub const DappsStakingClaimForTip: Perbill = Perbill::from_percent(1);
 }
 
 impl pallet_dapps_staking::Config for Runtime {
@@ -420,6 +448,13 @@ impl pallet_dapps_staking::Config for Runtime {
     type MinimumRemainingAmount = MinimumRemainingAmount;
     type MaxEraStakeValues = MaxEraStakeValues;
     type UnregisteredDappRewardRetention = ConstU32<10>;
+    type EraHistoryRetention = ConstU32<180>;
+    type FeelessClaimThreshold = FeelessClaimThreshold;
+    type FeelessClaimDelay = FeelessClaimDelay;
+    type ClaimForTip = DappsStakingClaimForTip;
+    type InfractionReportOrigin = EnsureRoot<AccountId>;
+    type InfractionDelay = ConstU32<2>;
+    type MaxInfractionEras = ConstU32<30>;
 }
 
 /// Multi-VM pointer to smart contract instance.
@@ -529,9 +564,63 @@ impl pallet_collator_selection::Config for Runtime {
     type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
     type ValidatorRegistration = Session;
     type SlashRatio = SlashRatio;
+    type SessionKeys = Session;
     type WeightInfo = pallet_collator_selection::weights::SubstrateWeight<Runtime>;
 }
 
+/// Resolves the collator owning an aura key from the session keys.
+pub struct AuraKeyOwner;
+impl sp_runtime::traits::Convert<AuraId, Option<AccountId>> for AuraKeyOwner {
+    fn convert(authority: AuraId) -> Option<AccountId> {
+        Session::key_owner(
+            <AuraId as sp_runtime::RuntimeAppPublic>::ID,
+            &sp_runtime::RuntimeAppPublic::to_raw_vec(&authority),
+        )
+    }
+}
+
+/// Slashes the candidacy bond of an equivocating collator.
+pub struct SlashEquivocatingCollator;
+impl pallet_collator_offences::OnEquivocation<AccountId> for SlashEquivocatingCollator {
+    fn on_equivocation(offender: &AccountId) {
+        // Invulnerables have no candidacy bond, there's nothing to slash.
+        let _ = CollatorSelection::slash_candidate(offender);
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CollatorOffencesBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_collator_offences::BenchmarkHelper<AuraId, AccountId>
+    for CollatorOffencesBenchmarkHelper
+{
+    fn register_offender(authority: &AuraId) -> AccountId {
+        let offender: AccountId = frame_benchmarking::account("offender", 0, 0);
+        let _ =
+            Balances::deposit_creating(&offender, 100 * SBY + CollatorSelection::candidacy_bond());
+        let _ = Session::set_keys(
+            RuntimeOrigin::signed(offender.clone()),
+            SessionKeys {
+                aura: authority.clone(),
+            },
+            Vec::new(),
+        );
+        let _ = CollatorSelection::register_as_candidate(RuntimeOrigin::signed(offender.clone()));
+        offender
+    }
+}
+
+impl pallet_collator_offences::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = AuraId;
+    type KeyOwner = AuraKeyOwner;
+    type OnEquivocation = SlashEquivocatingCollator;
+    type ReportLongevity = SessionPeriod;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CollatorOffencesBenchmarkHelper;
+    type WeightInfo = pallet_collator_offences::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
     pub const DappsStakingPalletId: PalletId = PalletId(*b"py/dpsst");
@@ -638,6 +727,34 @@ impl pallet_assets::Config for Runtime {
     type BenchmarkHelper = astar_primitives::benchmarks::AssetsBenchmarkHelper;
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetsSupplyHistoryBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_assets_supply_history::BenchmarkHelper<AssetId> for AssetsSupplyHistoryBenchmarkHelper {
+    fn create_asset(index: u32) -> AssetId {
+        let asset_id = AssetId::from(index);
+        let _ = <Assets as frame_support::traits::fungibles::Create<AccountId>>::create(
+            asset_id,
+            TreasuryAccountId::get(),
+            true,
+            1,
+        );
+        asset_id
+    }
+}
+
+impl pallet_assets_supply_history::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetId = AssetId;
+    type Assets = Assets;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxTrackedAssets = ConstU32<20>;
+    type HistoryDepth = ConstU32<256>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = AssetsSupplyHistoryBenchmarkHelper;
+    type WeightInfo = pallet_assets_supply_history::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub const MinVestedTransfer: Balance = 1 * SBY;
     pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
@@ -682,12 +799,15 @@ impl pallet_contracts::Config for Runtime {
     type DepositPerByte = DepositPerByte;
     type DefaultDepositLimit = DefaultDepositLimit;
     type CallStack = [pallet_contracts::Frame<Self>; 5];
-    type WeightPrice = pallet_transaction_payment::Pallet<Self>;
+    /// Contracts weight is priced by the same signal as the EVM base fee.
+    type WeightPrice = BaseFeeWeightPrice<Self, DefaultBaseFeePerGas, MinimumBaseFeeRatio>;
     type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
     type ChainExtension = (
         DappsStakingExtension<Self>,
         XvmExtension<Self, Xvm>,
         AssetsExtension<Self, pallet_chain_extension_assets::weights::SubstrateWeight<Self>>,
+        AddressBookExtension<Self>,
+        NativeTransferExtension<Self, RuntimeAddressMapper>,
     );
     type Schedule = Schedule;
     type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
@@ -704,6 +824,16 @@ parameter_types! {
     pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(1, 100_000);
     pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 1_000_000_000u128);
     pub MaximumMultiplier: Multiplier = Bounded::max_value();
+    /// Lower bound of the ratio between the EVM base fee and its default, used to price contracts weight.
+    pub MinimumBaseFeeRatio: Multiplier = Multiplier::saturating_from_rational(1, 10);
+}
+
+/// Calls whose weight is priced by the EVM base fee.
+pub struct ContractsCalls;
+impl Contains<RuntimeCall> for ContractsCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::Contracts(..))
+    }
 }
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -752,7 +882,16 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, DealWithFees>;
+    type OnChargeTransaction = BaseFeeCallAdapter<
+        Self,
+        ContractsCalls,
+        DefaultBaseFeePerGas,
+        MinimumBaseFeeRatio,
+        pallet_call_fee_multiplier::CallFeeMultiplierAdapter<
+            Self,
+            pallet_transaction_payment::CurrencyAdapter<Balances, DealWithFees>,
+        >,
+    >;
     type WeightToFee = WeightToFee;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type FeeMultiplierUpdate = TargetedFeeAdjustment<
@@ -765,12 +904,18 @@ impl pallet_transaction_payment::Config for Runtime {
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 }
 
+/// Conversion between EVM addresses and native accounts, shared by the EVM, XVM, precompiles
+/// and chain extensions so an account is resolved the same way everywhere.
+///
 ///TODO: Placeholder account mapping. This would be replaced once account abstraction is finished.
-pub struct HashedAccountMapping;
-impl astar_primitives::ethereum_checked::AccountMapping<AccountId> for HashedAccountMapping {
+pub type RuntimeAddressMapper = precompile_utils::HashedAddressMapper<BlakeTwo256>;
+
+/// EVM address of native accounts for XVM & checked Ethereum transactions, following
+/// `RuntimeAddressMapper`.
+pub struct RuntimeAccountMapping;
+impl astar_primitives::ethereum_checked::AccountMapping<AccountId> for RuntimeAccountMapping {
     fn into_h160(account_id: AccountId) -> H160 {
-        let data = (b"evm:", account_id);
-        return H160::from_slice(&data.using_encoded(sp_io::hashing::blake2_256)[0..20]);
+        <RuntimeAddressMapper as precompile_utils::AddressMapper<AccountId>>::into_h160(account_id)
     }
 }
 
@@ -784,18 +929,73 @@ impl pallet_ethereum_checked::Config for Runtime {
     type XvmTxWeightLimit = XvmTxWeightLimit;
     type InvalidEvmTransactionError = pallet_ethereum::InvalidTransactionWrapper;
     type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
-    type AccountMapping = HashedAccountMapping;
+    type AccountMapping = RuntimeAccountMapping;
     type XcmTransactOrigin = pallet_ethereum_checked::EnsureXcmEthereumTx<AccountId>;
+    type MultisigTransactOrigin = EnsureSigned<AccountId>;
+    type CreateFilter = frame_support::traits::Everything;
     type WeightInfo = pallet_ethereum_checked::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    /// Max weight limit of a queued cross-VM call, executed with the weight left for `on_idle`.
+    pub MaxAsyncXvmCallWeight: Weight = Perbill::from_percent(10) * MAXIMUM_BLOCK_WEIGHT;
+    /// Max weight of delivering the result of a queued cross-VM call back to its initiator.
+    pub AsyncXvmCallbackWeight: Weight = Perbill::from_percent(1) * MAXIMUM_BLOCK_WEIGHT;
+    /// Results of synchronous cross-VM calls are mirrored as EVM logs of the XVM precompile.
+    pub XvmCallResultsLogAddress: Option<H160> = Some(H160::from_low_u64_be(0x5005));
+}
+
 impl pallet_xvm::Config for Runtime {
     type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
-    type AccountMapping = HashedAccountMapping;
+    type AccountMapping = RuntimeAccountMapping;
     type EthereumTransact = EthereumChecked;
+    type RuntimeEvent = RuntimeEvent;
+    type OnAsyncCallResult = pallet_xvm::ContractCallback<Self, AsyncXvmCallbackWeight>;
+    type OnChargeAsyncCall = DealWithFees;
+    type MaxQueuedCalls = ConstU32<128>;
+    type MaxAsyncCallInputLen = ConstU32<{ 16 * 1024 }>;
+    type MaxAsyncCallWeight = MaxAsyncXvmCallWeight;
+    type MirrorCallResults = XvmCallResultsLogAddress;
     type WeightInfo = pallet_xvm::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_address_book::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxNameLength = ConstU32<32>;
+    type WeightInfo = pallet_address_book::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const MerkleDistributorPalletId: PalletId = PalletId(*b"py/mrkld");
+}
+
+impl pallet_merkle_distributor::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type PalletId = MerkleDistributorPalletId;
+    type EvmAddressMapping = RuntimeAddressMapper;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxProofLength = ConstU32<32>;
+    type WeightInfo = pallet_merkle_distributor::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_call_fee_multiplier::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxNameLength = ConstU32<64>;
+    type WeightInfo = pallet_call_fee_multiplier::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_chain_metadata::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ChainId = EVMChainId;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type MaxTextLength = ConstU32<128>;
+    type MaxUrls = ConstU32<4>;
+    type WeightInfo = pallet_chain_metadata::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     // Tells `pallet_base_fee` whether to calculate a new BaseFee `on_finalize` or not.
     pub DefaultBaseFeePerGas: U256 = (MILLISBY / 1_000_000).into();
@@ -849,10 +1049,9 @@ impl<F: FindAuthor<u32>> FindAuthor<H160> for FindAuthorTruncated<F> {
 }
 
 parameter_types! {
-    /// EVM gas limit
-    pub BlockGasLimit: U256 = U256::from(
-        NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT.ref_time() / WEIGHT_PER_GAS
-    );
+    /// Default EVM gas limit, can be changed by governance through `EvmGasLimit`
+    pub DefaultBlockGasLimit: u64 =
+        NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT.ref_time() / WEIGHT_PER_GAS;
     pub PrecompilesValue: Precompiles = ShibuyaNetworkPrecompiles::<_, _>::new();
     pub WeightPerGas: Weight = Weight::from_parts(WEIGHT_PER_GAS, 0);
     /// The amount of gas per PoV size. Value is calculated as:
@@ -871,7 +1070,7 @@ impl pallet_evm::Config for Runtime {
     type BlockHashMapping = pallet_ethereum::EthereumBlockHashMapping<Runtime>;
     type CallOrigin = pallet_evm::EnsureAddressRoot<AccountId>;
     type WithdrawOrigin = pallet_evm::EnsureAddressTruncated;
-    type AddressMapping = pallet_evm::HashedAddressMapping<BlakeTwo256>;
+    type AddressMapping = RuntimeAddressMapper;
     type Currency = Balances;
     type RuntimeEvent = RuntimeEvent;
     type Runner = pallet_evm::runner::stack::Runner<Self>;
@@ -881,7 +1080,7 @@ impl pallet_evm::Config for Runtime {
     // * Shibuya: 81
     type ChainId = EVMChainId;
     type OnChargeTransaction = pallet_evm::EVMCurrencyAdapter<Balances, ToStakingPot>;
-    type BlockGasLimit = BlockGasLimit;
+    type BlockGasLimit = EvmGasLimit;
     type Timestamp = Timestamp;
     type OnCreate = ();
     type FindAuthor = FindAuthorTruncated<Aura>;
@@ -891,6 +1090,14 @@ impl pallet_evm::Config for Runtime {
 
 impl pallet_evm_chain_id::Config for Runtime {}
 
+impl pallet_evm_gas_limit::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type WeightPerGas = WeightPerGas;
+    type DefaultBlockGasLimit = DefaultBlockGasLimit;
+    type WeightInfo = pallet_evm_gas_limit::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub const PostBlockAndTxnHashes: PostLogContent = PostLogContent::BlockAndTxnHashes;
 }
@@ -1047,6 +1254,38 @@ impl pallet_democracy::Config for Runtime {
     type MaxBlacklisted = ConstU32<100>;
 }
 
+/// Calls which governance can delegate to EVM executors,
+/// i.e. parameter updates and decisions on treasury proposals.
+pub struct GovernanceExecutorCallFilter;
+impl Contains<RuntimeCall> for GovernanceExecutorCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        match call {
+            RuntimeCall::CallFeeMultiplier(method) => matches!(
+                method,
+                pallet_call_fee_multiplier::Call::set_call_fee_multiplier { .. }
+            ),
+            RuntimeCall::EvmGasLimit(method) => matches!(
+                method,
+                pallet_evm_gas_limit::Call::set_block_gas_limit { .. }
+            ),
+            RuntimeCall::Treasury(method) => matches!(
+                method,
+                pallet_treasury::Call::approve_proposal { .. }
+                    | pallet_treasury::Call::reject_proposal { .. }
+            ),
+            _ => false,
+        }
+    }
+}
+
+impl pallet_governance_executor::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type CallFilter = GovernanceExecutorCallFilter;
+    type WeightInfo = pallet_governance_executor::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_sudo::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -1097,6 +1336,8 @@ pub enum ProxyType {
     DappsStaking,
     /// Only claim_staker call from pallet DappStaking allowed for proxy account
     StakerRewardClaim,
+    /// Only set_session_keys call from pallet CollatorSelection allowed for proxy account
+    CollatorSelection,
 }
 
 impl Default for ProxyType {
@@ -1190,6 +1431,15 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                     RuntimeCall::DappsStaking(pallet_dapps_staking::Call::claim_staker { .. })
                 )
             }
+            // Only set_session_keys call from pallet CollatorSelection allowed for proxy account
+            ProxyType::CollatorSelection => {
+                matches!(
+                    c,
+                    RuntimeCall::CollatorSelection(
+                        pallet_collator_selection::Call::set_session_keys { .. }
+                    )
+                )
+            }
         }
     }
 
@@ -1224,14 +1474,41 @@ impl pallet_proxy::Config for Runtime {
     type AnnouncementDepositFactor = AnnouncementDepositFactor;
 }
 
+parameter_types! {
+    pub NativeUnitsPerSecond: u128 = <WeightToFee as frame_support::weights::WeightToFee>::weight_to_fee(
+        &Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, 0),
+    );
+    pub const MaxUnitsPerSecondChange: Perbill = Perbill::from_percent(20);
+}
+
 impl pallet_xc_asset_config::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     // Good enough for testnet since we lack pallet-assets hooks for now
     type ManagerOrigin = EnsureRoot<AccountId>;
+    // No price oracle is available yet, so automatic `units per second` derivation is a no-op
+    type PriceProvider = ();
+    type NativeUnitsPerSecond = NativeUnitsPerSecond;
+    type RecalculationPeriod = BlockPerEra;
+    type MaxAutoUnitsPerSecondAssets = ConstU32<16>;
+    // There's no technical committee in this runtime yet, so fee changes remain root-only
+    type FeeManagerOrigin = EnsureRoot<AccountId>;
+    type FeeChangePeriod = BlockPerEra;
+    type MaxUnitsPerSecondChange = MaxUnitsPerSecondChange;
     type WeightInfo = pallet_xc_asset_config::weights::SubstrateWeight<Self>;
 }
 
+parameter_types! {
+    pub const UpgradeEnactmentPeriod: BlockNumber = DAYS;
+}
+
+impl pallet_upgrade_authorization::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type EnactmentPeriod = UpgradeEnactmentPeriod;
+    type WeightInfo = pallet_upgrade_authorization::weights::SubstrateWeight<Self>;
+}
+
 construct_runtime!(
     pub struct Runtime where
         Block = Block,
@@ -1247,6 +1524,7 @@ construct_runtime!(
         RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip = 16,
         Scheduler: pallet_scheduler = 17,
         Proxy: pallet_proxy = 18,
+        UpgradeAuthorization: pallet_upgrade_authorization = 19,
 
         ParachainSystem: cumulus_pallet_parachain_system = 20,
         ParachainInfo: parachain_info = 21,
@@ -1270,6 +1548,7 @@ construct_runtime!(
         DmpQueue: cumulus_pallet_dmp_queue = 53,
         XcAssetConfig: pallet_xc_asset_config = 54,
         Xtokens: orml_xtokens = 55,
+        XcmRateLimiter: pallet_xcm_rate_limiter = 56,
 
         EVM: pallet_evm = 60,
         Ethereum: pallet_ethereum = 61,
@@ -1284,8 +1563,17 @@ construct_runtime!(
         TechnicalCommittee: pallet_collective::<Instance2> = 82,
         Treasury: pallet_treasury = 83,
         Preimage: pallet_preimage = 84,
+        GovernanceExecutor: pallet_governance_executor = 85,
 
         Xvm: pallet_xvm = 90,
+        AddressBook: pallet_address_book = 91,
+        MerkleDistributor: pallet_merkle_distributor = 92,
+        CallFeeMultiplier: pallet_call_fee_multiplier = 93,
+        ChainMetadata: pallet_chain_metadata = 94,
+        EvmGasLimit: pallet_evm_gas_limit = 95,
+        AssetsSupplyHistory: pallet_assets_supply_history = 96,
+        CollatorOffences: pallet_collator_offences = 97,
+        XcmRemoteGovernance: pallet_xcm_remote_governance = 98,
 
         Sudo: pallet_sudo = 99,
     }
@@ -1336,6 +1624,8 @@ parameter_types! {
 pub type Migrations = (
     frame_support::migrations::RemovePallet<StateTrieMigrationStr, RocksDbWeight>,
     pallet_contracts::Migration<Runtime>,
+    pallet_evm_precompile_wrapped_native::FundVault<Runtime, WrappedNativeAddress>,
+    pallet_dapps_staking::migrations::EnableEraHistoryRetention<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -1420,6 +1710,17 @@ mod benches {
         [pallet_xcm, PolkadotXcm]
         [pallet_ethereum_checked, EthereumChecked]
         [pallet_xvm, Xvm]
+        [pallet_address_book, AddressBook]
+        [pallet_merkle_distributor, MerkleDistributor]
+        [pallet_call_fee_multiplier, CallFeeMultiplier]
+        [pallet_chain_metadata, ChainMetadata]
+        [pallet_evm_gas_limit, EvmGasLimit]
+        [pallet_assets_supply_history, AssetsSupplyHistory]
+        [pallet_collator_offences, CollatorOffences]
+        [pallet_xcm_remote_governance, XcmRemoteGovernance]
+        [pallet_xcm_rate_limiter, XcmRateLimiter]
+        [pallet_governance_executor, GovernanceExecutor]
+        [pallet_upgrade_authorization, UpgradeAuthorization]
     );
 }
 
@@ -1502,6 +1803,61 @@ impl_runtime_apis! {
         }
     }
 
+    impl astar_primitives::xcm::LocationToAccountApi<Block, AccountId> for Runtime {
+        fn convert_location(location: xcm::VersionedMultiLocation) -> Option<AccountId> {
+            use xcm_executor::traits::Convert;
+
+            let location = xcm::latest::MultiLocation::try_from(location).ok()?;
+            xcm_config::LocationToAccountId::convert(location).ok()
+        }
+    }
+
+    impl pallet_chain_metadata_runtime_api::ChainMetadataApi<Block> for Runtime {
+        fn chain_metadata() -> Option<pallet_chain_metadata_runtime_api::EvmChainMetadata> {
+            ChainMetadata::evm_chain_metadata()
+        }
+    }
+
+    impl pallet_dapps_staking_runtime_api::DappsStakingApi<Block, BlockNumber> for Runtime {
+        fn era_status(
+            era: Option<pallet_dapps_staking_runtime_api::EraIndex>,
+        ) -> Option<pallet_dapps_staking_runtime_api::EraStatus<BlockNumber>> {
+            DappsStaking::era_status(era)
+        }
+    }
+
+    impl pallet_xc_asset_config_runtime_api::XcAssetsApi<Block, AssetId> for Runtime {
+        fn assets(
+            start_after: Option<AssetId>,
+            limit: u32,
+        ) -> Vec<pallet_xc_asset_config_runtime_api::XcAssetInfo<AssetId>> {
+            astar_primitives::xcm::XcAssetsRegistry::<Runtime>::assets(start_after, limit)
+        }
+    }
+
+    impl pallet_xvm_runtime_api::XvmApi<Block, AccountId> for Runtime {
+        fn dry_run_call(
+            source: AccountId,
+            source_vm_id: pallet_xvm_runtime_api::VmId,
+            vm_id: pallet_xvm_runtime_api::VmId,
+            target: Vec<u8>,
+            input: Vec<u8>,
+            value: Balance,
+            weight_limit: Option<Weight>,
+        ) -> Option<pallet_xvm_runtime_api::DryRunInfo> {
+            let context = astar_primitives::xvm::Context {
+                source_vm_id,
+                weight_limit: weight_limit.unwrap_or(RuntimeBlockWeights::get().max_block),
+            };
+            let (result, events) = Xvm::dry_run_call(context, vm_id, source, target, input, value);
+
+            Some(pallet_xvm_runtime_api::DryRunInfo {
+                result,
+                events: events.iter().map(Encode::encode).collect(),
+            })
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
         Block,
         Balance,
@@ -17,10 +17,10 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::{
-    AccountId, AllPalletsWithSystem, AssetId, Assets, Balance, Balances, DealWithFees,
+    AccountId, AllPalletsWithSystem, AssetId, Assets, Balance, Balances, BlockNumber, DealWithFees,
     ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
     ShibuyaAssetLocationIdConverter, TreasuryAccountId, WeightToFee, XcAssetConfig, XcmpQueue,
-    MAXIMUM_BLOCK_WEIGHT,
+    HOURS, MAXIMUM_BLOCK_WEIGHT,
 };
 use frame_support::{
     match_types, parameter_types,
@@ -55,6 +55,7 @@ use astar_primitives::xcm::{
     DescribeAllTerminal, DescribeFamily, FixedRateOfForeignAsset, HashedDescription,
     ReserveAssetFilter, XcmFungibleFeeHandler,
 };
+use pallet_xcm_rate_limiter::{RateLimit, RateLimitedRouter};
 
 parameter_types! {
     pub RelayNetwork: Option<NetworkId> = Some(NetworkId::Rococo);
@@ -213,13 +214,16 @@ parameter_types! {
 pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
 
 /// The means for routing XCM messages which are not for local execution into the right message
-/// queues.
-pub type XcmRouter = (
-    // Two routers - use UMP to communicate with the relay chain:
-    cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, ()>,
-    // ..and XCMP to communicate with the sibling chains.
-    XcmpQueue,
-);
+/// queues. Outbound messages are subject to the rate limit of their channel.
+pub type XcmRouter = RateLimitedRouter<
+    Runtime,
+    (
+        // Two routers - use UMP to communicate with the relay chain:
+        cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, ()>,
+        // ..and XCMP to communicate with the sibling chains.
+        XcmpQueue,
+    ),
+>;
 
 #[cfg(feature = "runtime-benchmarks")]
 parameter_types! {
@@ -253,6 +257,37 @@ impl pallet_xcm::Config for Runtime {
     type AdminOrigin = EnsureRoot<AccountId>;
 }
 
+impl pallet_xcm_remote_governance::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type DispatchOrigin = EnsureRoot<AccountId>;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type XcmRouter = XcmRouter;
+    type MaxCallLength = ConstU32<{ 16 * 1024 }>;
+    type MaxAllowedCalls = ConstU32<32>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type ReachableDest = ReachableDest;
+    type WeightInfo = pallet_xcm_remote_governance::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    // The default only limits the number of messages, limits on the amount of SBY leaving
+    // through a channel are set per channel by governance.
+    pub const XcmChannelRateLimit: Option<RateLimit<BlockNumber>> = Some(RateLimit {
+        period: HOURS,
+        max_messages: 1_000,
+        max_amount: u128::MAX,
+    });
+}
+
+impl pallet_xcm_rate_limiter::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type DefaultRateLimit = XcmChannelRateLimit;
+    type NativeAssetLocation = ShibuyaLocation;
+    type UniversalLocation = UniversalLocation;
+    type WeightInfo = pallet_xcm_rate_limiter::weights::SubstrateWeight<Runtime>;
+}
+
 impl cumulus_pallet_xcm::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type XcmExecutor = XcmExecutor<XcmConfig>;
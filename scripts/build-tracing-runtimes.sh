@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+
+# Builds EVM tracing enabled runtimes and collects them into a single directory.
+#
+# On-chain runtimes don't include the EVM tracing runtime API, so a node serving `debug` & `trace`
+# requests must override them with tracing runtimes of the same spec version. The resulting
+# directory should be provided to the collator via `--tracing-runtimes-dir`.
+#
+# Usage: ./scripts/build-tracing-runtimes.sh [output-dir] [runtime...]
+# e.g.   ./scripts/build-tracing-runtimes.sh ./tracing-runtimes shibuya
+
+set -e
+
+output_dir=${1:-"./tracing-runtimes"}
+shift || true
+runtimes=("$@")
+if [ ${#runtimes[@]} -eq 0 ]; then
+  runtimes=("astar" "shiden" "shibuya")
+fi
+
+mkdir -p "$output_dir"
+
+for runtime in "${runtimes[@]}"; do
+  echo "[+] Building $runtime EVM tracing runtime"
+  cargo build --release --locked -p "$runtime-runtime" --features evm-tracing
+
+  spec_version=$(grep -m1 "spec_version:" "runtime/$runtime/src/lib.rs" | tr -dc '0-9')
+  wasm="target/release/wbuild/$runtime-runtime/${runtime}_runtime.compact.compressed.wasm"
+  target="$output_dir/${runtime}_runtime_${spec_version}_evm_tracing.compact.compressed.wasm"
+
+  cp "$wasm" "$target"
+  echo "[+] $target"
+done
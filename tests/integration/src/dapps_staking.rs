@@ -0,0 +1,83 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+
+use frame_support::dispatch::{GetDispatchInfo, Pays};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use parity_scale_codec::Encode;
+use sp_runtime::traits::{Dispatchable, SignedExtension};
+
+/// Advance to the next dApp staking era.
+fn advance_era() {
+    assert_ok!(DappsStaking::force_new_era(RuntimeOrigin::root()));
+    run_to_block(System::block_number() + 1);
+}
+
+#[test]
+fn overdue_withdraw_unbonded_fee_is_refunded() {
+    new_test_ext().execute_with(|| {
+        let contract = SmartContract::Evm(H160::repeat_byte(0x01));
+        assert_ok!(DappsStaking::register(
+            RuntimeOrigin::root(),
+            BOB.clone(),
+            contract.clone()
+        ));
+        assert_ok!(DappsStaking::bond_and_stake(
+            RuntimeOrigin::signed(ALICE),
+            contract.clone(),
+            1000 * UNIT
+        ));
+        advance_era();
+        assert_ok!(DappsStaking::unbond_and_unstake(
+            RuntimeOrigin::signed(ALICE),
+            contract,
+            100 * UNIT
+        ));
+
+        // Unbonded chunk is left unclaimed for `FeelessClaimDelay` eras
+        for _ in 0..(UnbondingPeriod::get() + FeelessClaimDelay::get()) {
+            advance_era();
+        }
+
+        let call = RuntimeCall::DappsStaking(DappStakingCall::Call::withdraw_unbonded {});
+        let info = call.get_dispatch_info();
+        let len = call.encoded_size();
+        let balance_before = Balances::free_balance(&ALICE);
+
+        // The fee is charged upfront, like for any other call
+        let pre = ChargeTransactionPayment::<Runtime>::from(0)
+            .pre_dispatch(&ALICE, &call, &info, len)
+            .expect("ALICE can pay the fee");
+        assert!(Balances::free_balance(&ALICE) < balance_before);
+
+        // and refunded once the withdrawal succeeds
+        let post_info = call
+            .dispatch(RuntimeOrigin::signed(ALICE))
+            .expect("unbonded chunk can be withdrawn");
+        assert_eq!(post_info.pays_fee, Pays::No);
+        assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+            Some(pre),
+            &info,
+            &post_info,
+            len,
+            &Ok(())
+        ));
+        assert_eq!(Balances::free_balance(&ALICE), balance_before);
+    });
+}
@@ -0,0 +1,124 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+
+use frame_support::dispatch::GetDispatchInfo;
+use pallet_transaction_payment::ChargeTransactionPayment;
+use parity_scale_codec::Encode;
+use sp_runtime::{
+    traits::{Convert, SignedExtension, Zero},
+    FixedPointNumber,
+};
+
+/// Charge the transaction fee of `call` from `ALICE`, returning the charged amount.
+fn charge_fee(call: &RuntimeCall) -> Balance {
+    let info = call.get_dispatch_info();
+    let len = call.encoded_size();
+
+    let balance_before = Balances::free_balance(&ALICE);
+    assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&ALICE, call, &info, len));
+    balance_before - Balances::free_balance(&ALICE)
+}
+
+#[test]
+fn contracts_call_fee_follows_evm_base_fee() {
+    new_test_ext().execute_with(|| {
+        let gas_limit = Weight::from_parts(1_000_000_000, 1024 * 1024);
+        let call = RuntimeCall::Contracts(pallet_contracts::Call::call {
+            dest: MultiAddress::Id(BOB),
+            value: 0,
+            gas_limit,
+            storage_deposit_limit: None,
+            data: vec![],
+        });
+        let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer {
+            dest: MultiAddress::Id(BOB),
+            value: 1,
+        });
+        let weight_fee = || {
+            TransactionPayment::compute_fee_details(
+                call.encoded_size() as u32,
+                &call.get_dispatch_info(),
+                0,
+            )
+            .inclusion_fee
+            .expect("contracts call pays fee")
+            .adjusted_weight_fee
+        };
+
+        // with the default base fee, weight is priced as usual
+        let multiplier = TransactionPayment::next_fee_multiplier();
+        let reference_weight_fee = weight_fee();
+        let reference_fee = charge_fee(&call);
+        let reference_transfer_fee = charge_fee(&transfer);
+        let reference_gas_price =
+            <Runtime as pallet_contracts::Config>::WeightPrice::convert(gas_limit);
+
+        // doubling the base fee doubles the price of contracts weight only
+        assert_ok!(BaseFee::set_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            DefaultBaseFeePerGas::get() * 2
+        ));
+        assert_eq!(charge_fee(&call), reference_fee + reference_weight_fee);
+        assert_eq!(charge_fee(&transfer), reference_transfer_fee);
+        assert_eq!(
+            <Runtime as pallet_contracts::Config>::WeightPrice::convert(gas_limit),
+            reference_gas_price * 2
+        );
+
+        // halving it halves the price of contracts weight
+        assert_ok!(BaseFee::set_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            DefaultBaseFeePerGas::get() / 2
+        ));
+        assert_eq!(
+            charge_fee(&call),
+            reference_fee - reference_weight_fee + reference_weight_fee / 2
+        );
+        assert_eq!(
+            <Runtime as pallet_contracts::Config>::WeightPrice::convert(gas_limit),
+            reference_gas_price / 2
+        );
+
+        // the congestion multiplier is left to transaction payment
+        assert_eq!(TransactionPayment::next_fee_multiplier(), multiplier);
+        assert_eq!(weight_fee(), reference_weight_fee);
+    });
+}
+
+#[test]
+fn contracts_weight_price_has_a_floor() {
+    new_test_ext().execute_with(|| {
+        let gas_limit = Weight::from_parts(1_000_000_000, 1024 * 1024);
+        let reference_gas_price =
+            <Runtime as pallet_contracts::Config>::WeightPrice::convert(gas_limit);
+
+        // even a zero base fee doesn't make contracts weight free
+        assert_ok!(BaseFee::set_base_fee_per_gas(
+            RuntimeOrigin::root(),
+            U256::zero()
+        ));
+        let gas_price = <Runtime as pallet_contracts::Config>::WeightPrice::convert(gas_limit);
+        assert!(!gas_price.is_zero());
+        assert_eq!(
+            gas_price,
+            MinimumBaseFeeRatio::get().saturating_mul_int(reference_gas_price)
+        );
+    });
+}
@@ -29,5 +29,14 @@ mod proxy;
 #[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
 mod assets;
 
+#[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
+mod upgrade;
+
+#[cfg(any(feature = "shibuya", feature = "shiden", feature = "astar"))]
+mod dapps_staking;
+
 #[cfg(feature = "shibuya")]
 mod xvm;
+
+#[cfg(feature = "shibuya")]
+mod fees;
@@ -211,3 +211,49 @@ fn test_staker_reward_claim_proxy_works() {
         expect_events(vec![ProxyEvent::ProxyExecuted { result: Ok(()) }.into()]);
     })
 }
+
+#[test]
+fn test_collator_selection_proxy_only_sets_session_keys() {
+    new_test_ext().execute_with(|| {
+        // Make CAT delegate for CollatorSelection proxy
+        assert_ok!(Proxy::add_proxy(
+            RuntimeOrigin::signed(BOB),
+            MultiAddress::Id(CAT),
+            ProxyType::CollatorSelection,
+            0
+        ));
+
+        // Other collator selection calls are filtered out
+        let leave_intent_call =
+            RuntimeCall::CollatorSelection(pallet_collator_selection::Call::leave_intent {});
+        assert_ok!(Proxy::proxy(
+            RuntimeOrigin::signed(CAT),
+            MultiAddress::Id(BOB),
+            None,
+            Box::new(leave_intent_call)
+        ));
+        expect_events(vec![ProxyEvent::ProxyExecuted {
+            result: Err(SystemError::CallFiltered.into()),
+        }
+        .into()]);
+
+        // Setting session keys passes the filter, and is then rejected since BOB isn't a candidate
+        let set_session_keys_call =
+            RuntimeCall::CollatorSelection(pallet_collator_selection::Call::set_session_keys {
+                keys: SessionKeys {
+                    aura: AuraId::from(sp_core::sr25519::Public::from_raw([1; 32])),
+                },
+                proof: vec![],
+            });
+        assert_ok!(Proxy::proxy(
+            RuntimeOrigin::signed(CAT),
+            MultiAddress::Id(BOB),
+            None,
+            Box::new(set_session_keys_call)
+        ));
+        expect_events(vec![ProxyEvent::ProxyExecuted {
+            result: Err(pallet_collator_selection::Error::<Runtime>::NotCandidate.into()),
+        }
+        .into()]);
+    })
+}
@@ -0,0 +1,41 @@
+// This file is part of Astar.
+
+// Copyright (C) 2019-2023 Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+
+use sp_runtime::traits::Dispatchable;
+
+#[test]
+fn parachain_system_upgrade_is_filtered() {
+    new_test_ext().execute_with(|| {
+        // Enacting an upgrade authorized by parachain system would bypass `OnSetCode`
+        let call = RuntimeCall::ParachainSystem(
+            cumulus_pallet_parachain_system::Call::enact_authorized_upgrade { code: vec![] },
+        );
+
+        // neither signed nor unsigned extrinsics can do it
+        assert_noop!(
+            call.clone().dispatch(RuntimeOrigin::signed(ALICE)),
+            SystemError::CallFiltered
+        );
+        assert_noop!(
+            call.dispatch(RuntimeOrigin::none()),
+            SystemError::CallFiltered
+        );
+    });
+}
@@ -311,6 +311,7 @@ impl pallet_dapps_staking::Config for Runtime {
     type UnbondingPeriod = ConstU32<2>;
     type MaxEraStakeValues = ConstU32<4>;
     type UnregisteredDappRewardRetention = ConstU32<7>;
+    type EraHistoryRetention = ConstU32<{ u32::MAX }>;
 }
 
 /// The type used to represent the kinds of proxying allowed.
@@ -390,10 +391,21 @@ parameter_types! {
     pub TreasuryAccountId: AccountId = TreasuryPalletId::get().into_account_truncating();
 }
 
+parameter_types! {
+    pub const MaxUnitsPerSecondChange: Perbill = Perbill::from_percent(20);
+}
+
 impl pallet_xc_asset_config::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type AssetId = AssetId;
     type ManagerOrigin = EnsureRoot<AccountId>;
+    type PriceProvider = ();
+    type NativeUnitsPerSecond = ConstU128<1_000_000_000_000>;
+    type RecalculationPeriod = ConstU64<10>;
+    type MaxAutoUnitsPerSecondAssets = ConstU32<16>;
+    type FeeManagerOrigin = EnsureRoot<AccountId>;
+    type FeeChangePeriod = ConstU64<10>;
+    type MaxUnitsPerSecondChange = MaxUnitsPerSecondChange;
     type WeightInfo = pallet_xc_asset_config::weights::SubstrateWeight<Runtime>;
 }
 